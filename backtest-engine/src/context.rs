@@ -1,4 +1,5 @@
 mod event_handler;
+mod optimization_control;
 mod strategy_control;
 mod strategy_manager;
 
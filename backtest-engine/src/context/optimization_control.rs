@@ -0,0 +1,90 @@
+use database::mutation::{
+    backtest_optimization_mutation::{BacktestOptimizationMutation, NewBacktestOptimization},
+    backtest_walk_forward_mutation::{BacktestWalkForwardMutation, NewBacktestWalkForward},
+};
+use snafu::ResultExt;
+use star_river_core::{backtest_optimization::BacktestOptimizationInfo, backtest_walk_forward::BacktestWalkForwardInfo};
+
+use super::BacktestEngineContext;
+use crate::{
+    engine_error::{BacktestEngineError, SerializeOptimizationFailedSnafu},
+    optimizer::{
+        BacktestOptimizer, OptimizationConfig, OptimizationTrial, WalkForwardConfig, WalkForwardPlan, WalkForwardRunner,
+        apply_parameter_set, build_parameter_sets,
+    },
+};
+
+impl BacktestEngineContext {
+    // The parameter space is validated before anything is stored, the trials then run in the background
+    pub async fn start_optimization(
+        &self,
+        strategy_id: i32,
+        optimization_config: OptimizationConfig,
+    ) -> Result<BacktestOptimizationInfo, BacktestEngineError> {
+        let strategy_config = self.get_strategy_info_by_id(strategy_id).await?;
+        let max_concurrency = optimization_config.concurrency()?;
+        let parameter_sets = build_parameter_sets(&optimization_config.parameters, &optimization_config.search_method)?;
+
+        let trials = parameter_sets
+            .into_iter()
+            .map(|parameter_set| {
+                let mut strategy_config = strategy_config.clone();
+                let mut nodes = strategy_config.nodes.take().unwrap_or_default();
+                apply_parameter_set(&mut nodes, &parameter_set)?;
+                strategy_config.nodes = Some(nodes);
+                OptimizationTrial::new(parameter_set, strategy_config)
+            })
+            .collect::<Result<Vec<OptimizationTrial>, BacktestEngineError>>()?;
+
+        let new_optimization = NewBacktestOptimization {
+            strategy_id,
+            strategy_name: strategy_config.name.clone(),
+            optimization_config: serde_json::to_value(&optimization_config).context(SerializeOptimizationFailedSnafu)?,
+            metric: optimization_config.metric.to_string(),
+            total_trials: trials.len() as i32,
+        };
+        let optimization = BacktestOptimizationMutation::create_backtest_optimization(&self.database, new_optimization).await?;
+
+        let optimizer = BacktestOptimizer {
+            optimization_id: optimization.id,
+            strategy_name: strategy_config.name,
+            metric: optimization_config.metric,
+            max_concurrency,
+            database: self.database.clone(),
+            heartbeat: self.heartbeat.clone(),
+        };
+        tokio::spawn(optimizer.run(trials));
+
+        Ok(optimization)
+    }
+
+    // The windows and the parameter space are validated before anything is stored, the windows then run in the background
+    pub async fn start_walk_forward(
+        &self,
+        strategy_id: i32,
+        walk_forward_config: WalkForwardConfig,
+    ) -> Result<BacktestWalkForwardInfo, BacktestEngineError> {
+        let strategy_config = self.get_strategy_info_by_id(strategy_id).await?;
+        let plan = WalkForwardPlan::new(strategy_config, walk_forward_config)?;
+
+        let new_walk_forward = NewBacktestWalkForward {
+            strategy_id,
+            strategy_name: plan.strategy_config.name.clone(),
+            walk_forward_config: serde_json::to_value(&plan.config).context(SerializeOptimizationFailedSnafu)?,
+            metric: plan.config.optimization.metric.to_string(),
+            window_mode: plan.config.window_mode.to_string(),
+            total_windows: plan.windows.len() as i32,
+        };
+        let walk_forward = BacktestWalkForwardMutation::create_backtest_walk_forward(&self.database, new_walk_forward).await?;
+
+        let runner = WalkForwardRunner {
+            walk_forward_id: walk_forward.id,
+            plan,
+            database: self.database.clone(),
+            heartbeat: self.heartbeat.clone(),
+        };
+        tokio::spawn(runner.run());
+
+        Ok(walk_forward)
+    }
+}
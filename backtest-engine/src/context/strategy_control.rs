@@ -1,11 +1,18 @@
 use star_river_core::error::StarRiverErrorTrait;
-use strategy_core::strategy::{StrategyConfig, TradeMode, strategy_trait::StrategyLifecycle};
+use strategy_core::strategy::{
+    StrategyConfig, TradeMode,
+    context_trait::{StrategyCommunicationExt, StrategyIdentityExt},
+    strategy_trait::StrategyLifecycle,
+};
 use tokio::time::Duration;
 
 use super::BacktestEngineContext;
 use crate::{
     engine_error::{BacktestEngineError, StrategyIsExistSnafu},
-    strategy::BacktestStrategy,
+    strategy::{
+        BacktestStrategy,
+        strategy_context::{HeadlessRunResult, SeekResult, SeekTarget},
+    },
 };
 
 impl BacktestEngineContext {
@@ -68,4 +75,26 @@ impl BacktestEngineContext {
 
         Ok(())
     }
+
+    // Only the command sender is taken from the strategy, so the strategy stays unlocked while the run plays
+    pub async fn run_headless(&self, strategy_id: i32) -> Result<HeadlessRunResult, BacktestEngineError> {
+        let (strategy_name, command_sender) = self
+            .with_strategy_ctx_read(strategy_id, |ctx| {
+                (ctx.strategy_name().clone(), ctx.strategy_command_sender().clone())
+            })
+            .await?;
+        let result = BacktestStrategy::request_headless_run(strategy_name, command_sender).await?;
+        Ok(result)
+    }
+
+    // Same as run_headless, the replay to the target plays with the strategy unlocked
+    pub async fn seek(&self, strategy_id: i32, target: SeekTarget) -> Result<SeekResult, BacktestEngineError> {
+        let (strategy_name, command_sender) = self
+            .with_strategy_ctx_read(strategy_id, |ctx| {
+                (ctx.strategy_name().clone(), ctx.strategy_command_sender().clone())
+            })
+            .await?;
+        let result = BacktestStrategy::request_seek(strategy_name, command_sender, target).await?;
+        Ok(result)
+    }
 }
@@ -3,7 +3,7 @@
 use database::error::DatabaseError;
 use engine_core::state_machine_error::EngineStateMachineError;
 use snafu::{Backtrace, Snafu};
-use star_river_core::error::{ErrorCode, ErrorLanguage, StarRiverErrorTrait, generate_error_code_chain};
+use star_river_core::error::{ErrorCode, ErrorLanguage, StarRiverErrorTrait, StatusCode, generate_error_code_chain};
 
 // Current crate imports
 use crate::strategy::strategy_error::BacktestStrategyError;
@@ -37,6 +37,37 @@ pub enum BacktestEngineError {
 
     #[snafu(display("trade mode {} is unsupported", trade_mode))]
     UnsupportedTradeMode { trade_mode: String, backtrace: Backtrace },
+
+    #[snafu(display("invalid optimization parameter {}{}: {}", node_id, path, reason))]
+    InvalidOptimizationParameter {
+        node_id: String,
+        path: String,
+        reason: String,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("optimization parameter {}{} not found in strategy config", node_id, path))]
+    OptimizationParameterNotFound {
+        node_id: String,
+        path: String,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("optimization has {} trials, at most {} are allowed", trial_count, max_trial_count))]
+    TooManyOptimizationTrials {
+        trial_count: usize,
+        max_trial_count: usize,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("invalid walk-forward window: {}", reason))]
+    InvalidWalkForwardWindow { reason: String, backtrace: Backtrace },
+
+    #[snafu(display("optimization max concurrency must be at least 1, got {}", max_concurrency))]
+    InvalidOptimizationConcurrency { max_concurrency: usize, backtrace: Backtrace },
+
+    #[snafu(display("serialize optimization failed: {}", source))]
+    SerializeOptimizationFailed { source: serde_json::Error, backtrace: Backtrace },
 }
 
 // Implement the StarRiverErrorTrait for StrategyEngineError
@@ -57,10 +88,30 @@ impl StarRiverErrorTrait for BacktestEngineError {
             BacktestEngineError::StrategyInstanceNotFound { .. } => 1005,
             BacktestEngineError::Database { .. } => 1006,
             BacktestEngineError::UnsupportedTradeMode { .. } => 1007,
+            BacktestEngineError::InvalidOptimizationParameter { .. } => 1008,
+            BacktestEngineError::OptimizationParameterNotFound { .. } => 1009,
+            BacktestEngineError::TooManyOptimizationTrials { .. } => 1010,
+            BacktestEngineError::InvalidWalkForwardWindow { .. } => 1011,
+            BacktestEngineError::InvalidOptimizationConcurrency { .. } => 1012,
+            BacktestEngineError::SerializeOptimizationFailed { .. } => 1013,
         };
         format!("{}_{:04}", prefix, code)
     }
 
+    fn http_status_code(&self) -> StatusCode {
+        match self {
+            BacktestEngineError::BacktestStrategyError { source, .. } => source.http_status_code(),
+            BacktestEngineError::EngineStateMachineError { source, .. } => source.http_status_code(),
+            BacktestEngineError::Database { source, .. } => source.http_status_code(),
+            BacktestEngineError::InvalidOptimizationParameter { .. }
+            | BacktestEngineError::OptimizationParameterNotFound { .. }
+            | BacktestEngineError::TooManyOptimizationTrials { .. }
+            | BacktestEngineError::InvalidWalkForwardWindow { .. }
+            | BacktestEngineError::InvalidOptimizationConcurrency { .. } => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
     fn error_code_chain(&self) -> Vec<ErrorCode> {
         match self {
             BacktestEngineError::BacktestStrategyError { source, .. } => generate_error_code_chain(source, self.error_code()),
@@ -91,6 +142,28 @@ impl StarRiverErrorTrait for BacktestEngineError {
                 BacktestEngineError::UnsupportedTradeMode { trade_mode, .. } => {
                     format!("不支持的交易模式: {}", trade_mode)
                 }
+                BacktestEngineError::InvalidOptimizationParameter { node_id, path, reason, .. } => {
+                    format!("优化参数 {}{} 无效: {}", node_id, path, reason)
+                }
+                BacktestEngineError::OptimizationParameterNotFound { node_id, path, .. } => {
+                    format!("策略配置中不存在优化参数 {}{}", node_id, path)
+                }
+                BacktestEngineError::TooManyOptimizationTrials {
+                    trial_count,
+                    max_trial_count,
+                    ..
+                } => {
+                    format!("优化共有 {} 组参数, 最多允许 {} 组", trial_count, max_trial_count)
+                }
+                BacktestEngineError::InvalidWalkForwardWindow { reason, .. } => {
+                    format!("前推分析窗口设置无效: {}", reason)
+                }
+                BacktestEngineError::InvalidOptimizationConcurrency { max_concurrency, .. } => {
+                    format!("优化并发数至少为 1, 当前为 {}", max_concurrency)
+                }
+                BacktestEngineError::SerializeOptimizationFailed { source, .. } => {
+                    format!("优化序列化失败: {}", source)
+                }
             },
         }
     }
@@ -4,6 +4,7 @@ mod engine_lifecycle;
 mod engine_state_machine;
 mod node;
 mod node_catalog;
+pub mod optimizer;
 pub(crate) mod strategy;
 mod strategy_stats;
 mod virtual_trading_system;
@@ -11,6 +12,8 @@ mod virtual_trading_system;
 // Standard library imports
 use std::sync::Arc;
 
+pub use strategy::strategy_context::{HeadlessRunResult, SeekResult, SeekTarget};
+
 // Workspace crate imports
 use engine_core::{EngineBase, EngineContextAccessor, EngineMetadata, engine_trait::Engine, state_machine::EngineRunState};
 // External crate imports
@@ -2,6 +2,7 @@ pub(crate) mod node_command;
 pub(crate) mod node_error;
 pub(crate) mod node_event;
 pub(crate) mod node_message;
+pub(crate) mod node_snapshot;
 pub(crate) mod node_state_machine;
 pub(crate) mod node_utils;
 
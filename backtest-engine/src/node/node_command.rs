@@ -5,13 +5,18 @@ use strategy_core::communication::{
     node::{NodeCommand, NodeResponse},
 };
 
-use crate::{node_catalog::futures_order_node::FuturesOrderNodeConfig, strategy::strategy_config::BacktestStrategyConfig};
+use crate::{
+    node::node_snapshot::NodeSnapshot, node_catalog::futures_order_node::FuturesOrderNodeConfig,
+    strategy::strategy_config::BacktestStrategyConfig,
+};
 
 #[derive(Debug, From)]
 pub enum BacktestNodeCommand {
     GetStartNodeConfig(GetStartNodeConfigCommand),
     GetFuturesOrderConfig(GetFuturesOrderConfigCommand),
     NodeReset(NodeResetCommand),
+    GetNodeSnapshot(GetNodeSnapshotCommand),
+    RestoreNodeSnapshot(RestoreNodeSnapshotCommand),
 }
 
 impl NodeCommandTrait for BacktestNodeCommand {
@@ -20,6 +25,8 @@ impl NodeCommandTrait for BacktestNodeCommand {
             BacktestNodeCommand::GetStartNodeConfig(command) => command.node_id(),
             BacktestNodeCommand::GetFuturesOrderConfig(command) => command.node_id(),
             BacktestNodeCommand::NodeReset(command) => command.node_id(),
+            BacktestNodeCommand::GetNodeSnapshot(command) => command.node_id(),
+            BacktestNodeCommand::RestoreNodeSnapshot(command) => command.node_id(),
         }
     }
     fn node_name(&self) -> &NodeName {
@@ -27,6 +34,8 @@ impl NodeCommandTrait for BacktestNodeCommand {
             BacktestNodeCommand::GetStartNodeConfig(command) => command.node_name(),
             BacktestNodeCommand::GetFuturesOrderConfig(command) => command.node_name(),
             BacktestNodeCommand::NodeReset(command) => command.node_name(),
+            BacktestNodeCommand::GetNodeSnapshot(command) => command.node_name(),
+            BacktestNodeCommand::RestoreNodeSnapshot(command) => command.node_name(),
         }
     }
 }
@@ -43,6 +52,14 @@ pub type GetFuturesOrderConfigResponse = NodeResponse<GetFuturesOrderConfigRespP
 pub type NodeResetCommand = NodeCommand<NodeResetCmdPayload, NodeResetRespPayload>;
 pub type NodeResetResponse = NodeResponse<NodeResetRespPayload>;
 
+// ============ Get Node Snapshot ============
+pub type GetNodeSnapshotCommand = NodeCommand<GetNodeSnapshotCmdPayload, GetNodeSnapshotRespPayload>;
+pub type GetNodeSnapshotResponse = NodeResponse<GetNodeSnapshotRespPayload>;
+
+// ============ Restore Node Snapshot ============
+pub type RestoreNodeSnapshotCommand = NodeCommand<RestoreNodeSnapshotCmdPayload, RestoreNodeSnapshotRespPayload>;
+pub type RestoreNodeSnapshotResponse = NodeResponse<RestoreNodeSnapshotRespPayload>;
+
 // ============ Get Start Node Config ============
 #[derive(Debug)]
 pub struct GetStartNodeConfigCmdPayload;
@@ -77,3 +94,33 @@ impl GetFuturesOrderConfigRespPayload {
 pub struct NodeResetCmdPayload;
 #[derive(Debug)]
 pub struct NodeResetRespPayload;
+
+// ============ Get Node Snapshot ============
+#[derive(Debug)]
+pub struct GetNodeSnapshotCmdPayload;
+
+#[derive(Debug)]
+pub struct GetNodeSnapshotRespPayload {
+    pub snapshot: NodeSnapshot,
+}
+
+impl GetNodeSnapshotRespPayload {
+    pub fn new(snapshot: NodeSnapshot) -> Self {
+        Self { snapshot }
+    }
+}
+
+// ============ Restore Node Snapshot ============
+#[derive(Debug)]
+pub struct RestoreNodeSnapshotCmdPayload {
+    pub snapshot: NodeSnapshot,
+}
+
+impl RestoreNodeSnapshotCmdPayload {
+    pub fn new(snapshot: NodeSnapshot) -> Self {
+        Self { snapshot }
+    }
+}
+
+#[derive(Debug)]
+pub struct RestoreNodeSnapshotRespPayload;
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use event_center::EventCenterError;
 use snafu::{Backtrace, Snafu};
 use star_river_core::{
-    custom_type::NodeName,
+    custom_type::{NodeId, NodeName},
     error::{ErrorCode, ErrorLanguage, StarRiverErrorTrait, generate_error_code_chain},
 };
 use strategy_core::error::{NodeError, NodeStateMachineError};
@@ -49,6 +49,17 @@ pub enum FuturesOrderNodeError {
 
     #[snafu(display("@[{node_name}] exchange mode not configured"))]
     ExchangeModeNotConfigured { node_name: NodeName, backtrace: Backtrace },
+
+    #[snafu(display(
+        "@[{node_name}] trailing stop of order config {order_config_id} refers to no ATR indicator of config {config_id} in node {indicator_node_id}"
+    ))]
+    TrailingStopIndicatorNotFound {
+        node_name: NodeName,
+        order_config_id: i32,
+        indicator_node_id: NodeId,
+        config_id: i32,
+        backtrace: Backtrace,
+    },
 }
 
 // Implement the StarRiverErrorTrait for FuturesOrderNodeError
@@ -60,15 +71,16 @@ impl StarRiverErrorTrait for FuturesOrderNodeError {
     fn error_code(&self) -> ErrorCode {
         let prefix = self.get_prefix();
         let code = match self {
-            FuturesOrderNodeError::NodeError { .. } => 1000,                 // node error
-            FuturesOrderNodeError::VirtualTradingSystem { .. } => 1001,      // virtual trading system error
-            FuturesOrderNodeError::NodeStateMachineError { .. } => 1002,     // node state machine error
-            FuturesOrderNodeError::CannotCreateOrder { .. } => 1003,         // cannot create order
-            FuturesOrderNodeError::EventCenterError { .. } => 1004,          // event center error
-            FuturesOrderNodeError::OrderConfigNotFound { .. } => 1005,       // order config not found
-            FuturesOrderNodeError::GetSymbolInfoFailed { .. } => 1006,       // get symbol info failed
-            FuturesOrderNodeError::SymbolInfoNotFound { .. } => 1007,        // symbol info not found
-            FuturesOrderNodeError::ExchangeModeNotConfigured { .. } => 1008, // exchange mode not configured
+            FuturesOrderNodeError::NodeError { .. } => 1000,                     // node error
+            FuturesOrderNodeError::VirtualTradingSystem { .. } => 1001,          // virtual trading system error
+            FuturesOrderNodeError::NodeStateMachineError { .. } => 1002,         // node state machine error
+            FuturesOrderNodeError::CannotCreateOrder { .. } => 1003,             // cannot create order
+            FuturesOrderNodeError::EventCenterError { .. } => 1004,              // event center error
+            FuturesOrderNodeError::OrderConfigNotFound { .. } => 1005,           // order config not found
+            FuturesOrderNodeError::GetSymbolInfoFailed { .. } => 1006,           // get symbol info failed
+            FuturesOrderNodeError::SymbolInfoNotFound { .. } => 1007,            // symbol info not found
+            FuturesOrderNodeError::ExchangeModeNotConfigured { .. } => 1008,     // exchange mode not configured
+            FuturesOrderNodeError::TrailingStopIndicatorNotFound { .. } => 1009, // trailing stop indicator not found
         };
 
         format!("{}_{:04}", prefix, code)
@@ -89,6 +101,7 @@ impl StarRiverErrorTrait for FuturesOrderNodeError {
             }
             FuturesOrderNodeError::SymbolInfoNotFound { .. } => vec![self.error_code()],
             FuturesOrderNodeError::ExchangeModeNotConfigured { .. } => vec![self.error_code()],
+            FuturesOrderNodeError::TrailingStopIndicatorNotFound { .. } => vec![self.error_code()],
         }
     }
 
@@ -115,6 +128,17 @@ impl StarRiverErrorTrait for FuturesOrderNodeError {
                 FuturesOrderNodeError::ExchangeModeNotConfigured { node_name, .. } => {
                     format!("@[{node_name}] 交易所模式未配置")
                 }
+                FuturesOrderNodeError::TrailingStopIndicatorNotFound {
+                    node_name,
+                    order_config_id,
+                    indicator_node_id,
+                    config_id,
+                    ..
+                } => {
+                    format!(
+                        "@[{node_name}] 订单配置 {order_config_id} 的移动止损引用的 ATR 指标不存在: 节点 {indicator_node_id}, 配置 {config_id}"
+                    )
+                }
             },
         }
     }
@@ -20,6 +20,15 @@ pub enum IfElseNodeError {
         source: serde_json::Error,
         backtrace: Backtrace,
     },
+
+    #[snafu(display("[{node_name}] case {case_id} condition {condition_id}: the bar count of `{comparison_symbol}` must be a constant"))]
+    BarCountNotConstant {
+        node_name: String,
+        case_id: i32,
+        condition_id: i32,
+        comparison_symbol: String,
+        backtrace: Backtrace,
+    },
 }
 
 impl StarRiverErrorTrait for IfElseNodeError {
@@ -33,6 +42,7 @@ impl StarRiverErrorTrait for IfElseNodeError {
             IfElseNodeError::NodeError { .. } => 1000,                         // node error
             IfElseNodeError::NodeStateMachineError { .. } => 1001,             // node state machine error
             IfElseNodeError::EvaluateResultSerializationFailed { .. } => 1002, // evaluate result serialization failed
+            IfElseNodeError::BarCountNotConstant { .. } => 1003,               // bar count of rising/falling for is a variable
         };
 
         format!("{}_{:04}", prefix, code)
@@ -43,6 +53,7 @@ impl StarRiverErrorTrait for IfElseNodeError {
             IfElseNodeError::NodeError { source, .. } => source.http_status_code(),
             IfElseNodeError::NodeStateMachineError { source, .. } => source.http_status_code(),
             IfElseNodeError::EvaluateResultSerializationFailed { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            IfElseNodeError::BarCountNotConstant { .. } => StatusCode::BAD_REQUEST,
         }
     }
 
@@ -50,7 +61,9 @@ impl StarRiverErrorTrait for IfElseNodeError {
         match self {
             IfElseNodeError::NodeError { source, .. } => generate_error_code_chain(source, self.error_code()),
             IfElseNodeError::NodeStateMachineError { source, .. } => generate_error_code_chain(source, self.error_code()),
-            IfElseNodeError::EvaluateResultSerializationFailed { .. } => vec![self.error_code()],
+            IfElseNodeError::EvaluateResultSerializationFailed { .. } | IfElseNodeError::BarCountNotConstant { .. } => {
+                vec![self.error_code()]
+            }
         }
     }
 
@@ -63,6 +76,15 @@ impl StarRiverErrorTrait for IfElseNodeError {
                 IfElseNodeError::EvaluateResultSerializationFailed { node_name, source, .. } => {
                     format!("[{node_name}] 条件结果序列化失败，原因: {source}")
                 }
+                IfElseNodeError::BarCountNotConstant {
+                    node_name,
+                    case_id,
+                    condition_id,
+                    comparison_symbol,
+                    ..
+                } => {
+                    format!("[{node_name}] 分支 {case_id} 条件 {condition_id}: `{comparison_symbol}` 的K线数量必须是常量")
+                }
             },
         }
     }
@@ -4,6 +4,7 @@ use event_center::EventCenterError;
 use key::error::KeyError;
 use snafu::{Backtrace, Snafu};
 use star_river_core::{
+    core_error::CoreError,
     custom_type::NodeName,
     error::{ErrorCode, ErrorLanguage, StarRiverErrorTrait, StatusCode, generate_error_code_chain},
 };
@@ -27,6 +28,9 @@ pub enum KlineNodeError {
     #[snafu(transparent)]
     KeyError { source: KeyError, backtrace: Backtrace },
 
+    #[snafu(transparent)]
+    CoreError { source: CoreError, backtrace: Backtrace },
+
     #[snafu(display("@[{node_name}] Exchange register failed"))]
     RegisterExchangeFailed {
         node_name: NodeName,
@@ -103,10 +107,91 @@ pub enum KlineNodeError {
         backtrace: Backtrace,
     },
 
-    #[snafu(display("pending update kline not exist for {symbol}-{interval}"))]
-    PendingUpdateKlineNotExist {
+    #[snafu(display("@[{node_name}] file mode is not configured"))]
+    FileModeNotConfigured { node_name: NodeName, backtrace: Backtrace },
+
+    #[snafu(display("unsupported kline file format: {file_path}"))]
+    UnsupportedKlineFileFormat { file_path: String, backtrace: Backtrace },
+
+    #[snafu(display("open kline file failed: {file_path}"))]
+    OpenKlineFileFailed {
+        file_path: String,
+        source: std::io::Error,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("read csv kline file failed: {file_path}"))]
+    ReadCsvKlineFileFailed {
+        file_path: String,
+        source: csv::Error,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("read parquet kline file failed: {file_path}"))]
+    ReadParquetKlineFileFailed {
+        file_path: String,
+        source: parquet::errors::ParquetError,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("column [{column}] not found in kline file: {file_path}"))]
+    KlineFileColumnNotFound {
+        file_path: String,
+        column: String,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("invalid value [{value}] of column [{column}] at row {row} in kline file: {file_path}"))]
+    InvalidKlineFileValue {
+        file_path: String,
+        row: usize,
+        column: String,
+        value: String,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("kline timestamps are not increasing at row {row} ({previous} -> {current}) in kline file: {file_path}"))]
+    KlineFileTimestampNotMonotonic {
+        file_path: String,
+        row: usize,
+        previous: String,
+        current: String,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("kline timestamps {previous} -> {current} do not match interval {interval} in kline file: {file_path}"))]
+    KlineFileIntervalMismatch {
+        file_path: String,
+        interval: String,
+        previous: String,
+        current: String,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("{missing_bars} bars missing between {previous} and {current} in kline file: {file_path}"))]
+    KlineFileGapDetected {
+        file_path: String,
+        previous: String,
+        current: String,
+        missing_bars: i64,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("no kline in time range {start_time} ~ {end_time} in kline file: {file_path}"))]
+    KlineFileNoDataInRange {
+        file_path: String,
+        start_time: String,
+        end_time: String,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("@[{node_name}] no {symbol}-{interval} kline at {datetime}, last kline is at {last_datetime}"))]
+    MissingBar {
+        node_name: NodeName,
         symbol: String,
         interval: String,
+        datetime: String,
+        last_datetime: String,
         backtrace: Backtrace,
     },
 }
@@ -134,7 +219,20 @@ impl StarRiverErrorTrait for KlineNodeError {
             KlineNodeError::AcquireSemaphoreFailed { .. } => 1012,                 // acquire semaphore failed
             KlineNodeError::FetchKlineDataTaskFailed { .. } => 1013,               // fetch kline data task failed
             KlineNodeError::InsufficientBacktestData { .. } => 1014,               // insufficient backtest data for exchange
-            KlineNodeError::PendingUpdateKlineNotExist { .. } => 1015,             // pending update kline not exist
+            // 1015 is retired (pending update kline not exist), do not reuse it
+            KlineNodeError::FileModeNotConfigured { .. } => 1016,                  // file mode is not configured
+            KlineNodeError::UnsupportedKlineFileFormat { .. } => 1017,             // unsupported kline file format
+            KlineNodeError::OpenKlineFileFailed { .. } => 1018,                    // open kline file failed
+            KlineNodeError::ReadCsvKlineFileFailed { .. } => 1019,                 // read csv kline file failed
+            KlineNodeError::ReadParquetKlineFileFailed { .. } => 1020,             // read parquet kline file failed
+            KlineNodeError::KlineFileColumnNotFound { .. } => 1021,                // kline file column not found
+            KlineNodeError::InvalidKlineFileValue { .. } => 1022,                  // invalid kline file value
+            KlineNodeError::KlineFileTimestampNotMonotonic { .. } => 1023,         // kline file timestamp not monotonic
+            KlineNodeError::KlineFileIntervalMismatch { .. } => 1024,              // kline file interval mismatch
+            KlineNodeError::KlineFileGapDetected { .. } => 1025,                   // kline file gap detected
+            KlineNodeError::KlineFileNoDataInRange { .. } => 1026,                 // no kline in time range
+            KlineNodeError::MissingBar { .. } => 1027,                             // no kline at the signal time
+            KlineNodeError::CoreError { .. } => 1028,                              // core error
         };
 
         format!("{}_{:04}", prefix, code)
@@ -146,6 +244,7 @@ impl StarRiverErrorTrait for KlineNodeError {
             KlineNodeError::NodeStateMachineError { source, .. } => source.http_status_code(),
             KlineNodeError::EventCenterError { source, .. } => source.http_status_code(),
             KlineNodeError::KeyError { source, .. } => source.http_status_code(),
+            KlineNodeError::CoreError { source, .. } => source.http_status_code(),
             KlineNodeError::RegisterExchangeFailed { source, .. } => source.http_status_code(),
             KlineNodeError::LoadKlineFromExchangeFailed { source, .. } => source.http_status_code(),
 
@@ -158,7 +257,18 @@ impl StarRiverErrorTrait for KlineNodeError {
             KlineNodeError::AcquireSemaphoreFailed { .. } => StatusCode::INTERNAL_SERVER_ERROR,           // 500 - acquire semaphore failed
             KlineNodeError::FetchKlineDataTaskFailed { .. } => StatusCode::INTERNAL_SERVER_ERROR, // 500 - fetch kline data task failed
             KlineNodeError::InsufficientBacktestData { .. } => StatusCode::BAD_REQUEST, // 400 - insufficient backtest data for exchange
-            KlineNodeError::PendingUpdateKlineNotExist { .. } => StatusCode::BAD_REQUEST, // 400 - pending update kline not exist
+            KlineNodeError::FileModeNotConfigured { .. } => StatusCode::BAD_REQUEST,    // 400 - file mode is not configured
+            KlineNodeError::UnsupportedKlineFileFormat { .. } => StatusCode::BAD_REQUEST, // 400 - unsupported kline file format
+            KlineNodeError::OpenKlineFileFailed { .. } => StatusCode::NOT_FOUND,        // 404 - open kline file failed
+            KlineNodeError::ReadCsvKlineFileFailed { .. } => StatusCode::BAD_REQUEST,   // 400 - read csv kline file failed
+            KlineNodeError::ReadParquetKlineFileFailed { .. } => StatusCode::BAD_REQUEST, // 400 - read parquet kline file failed
+            KlineNodeError::KlineFileColumnNotFound { .. } => StatusCode::BAD_REQUEST,  // 400 - kline file column not found
+            KlineNodeError::InvalidKlineFileValue { .. } => StatusCode::BAD_REQUEST,    // 400 - invalid kline file value
+            KlineNodeError::KlineFileTimestampNotMonotonic { .. } => StatusCode::BAD_REQUEST, // 400 - kline file timestamp not monotonic
+            KlineNodeError::KlineFileIntervalMismatch { .. } => StatusCode::BAD_REQUEST, // 400 - kline file interval mismatch
+            KlineNodeError::KlineFileGapDetected { .. } => StatusCode::BAD_REQUEST,     // 400 - kline file gap detected
+            KlineNodeError::KlineFileNoDataInRange { .. } => StatusCode::BAD_REQUEST,   // 400 - no kline in time range
+            KlineNodeError::MissingBar { .. } => StatusCode::UNPROCESSABLE_ENTITY,      // 422 - no kline at the signal time
         }
     }
 
@@ -169,6 +279,7 @@ impl StarRiverErrorTrait for KlineNodeError {
             KlineNodeError::NodeStateMachineError { source, .. } => generate_error_code_chain(source, self.error_code()),
             KlineNodeError::EventCenterError { source, .. } => generate_error_code_chain(source, self.error_code()),
             KlineNodeError::KeyError { source, .. } => generate_error_code_chain(source, self.error_code()),
+            KlineNodeError::CoreError { source, .. } => generate_error_code_chain(source, self.error_code()),
             KlineNodeError::RegisterExchangeFailed { source, .. }
             | KlineNodeError::LoadKlineFromExchangeFailed { source, .. }
             | KlineNodeError::GetMinIntervalFromStrategyFailed { source, .. } => {
@@ -186,6 +297,7 @@ impl StarRiverErrorTrait for KlineNodeError {
                 KlineNodeError::NodeStateMachineError { source, .. } => source.error_message(language),
                 KlineNodeError::EventCenterError { source, .. } => source.error_message(language),
                 KlineNodeError::KeyError { source, .. } => source.error_message(language),
+                KlineNodeError::CoreError { source, .. } => source.error_message(language),
                 KlineNodeError::RegisterExchangeFailed { node_name, .. } => {
                     format!("[{}] 注册交易所错误", node_name)
                 }
@@ -245,8 +357,77 @@ impl StarRiverErrorTrait for KlineNodeError {
                         "回测时间范围从{start_time}到{end_time}，但{exchange}-{symbol}-{interval}的第一根K线的时间为{first_kline_datetime}"
                     )
                 }
-                KlineNodeError::PendingUpdateKlineNotExist { symbol, interval, .. } => {
-                    format!("待更新K线不存在: {symbol}-{interval}")
+                KlineNodeError::FileModeNotConfigured { node_name, .. } => {
+                    format!("@[{node_name}] 文件模式未配置")
+                }
+                KlineNodeError::UnsupportedKlineFileFormat { file_path, .. } => {
+                    format!("不支持的K线文件格式: {file_path}")
+                }
+                KlineNodeError::OpenKlineFileFailed { file_path, .. } => {
+                    format!("打开K线文件失败: {file_path}")
+                }
+                KlineNodeError::ReadCsvKlineFileFailed { file_path, .. } => {
+                    format!("读取CSV K线文件失败: {file_path}")
+                }
+                KlineNodeError::ReadParquetKlineFileFailed { file_path, .. } => {
+                    format!("读取Parquet K线文件失败: {file_path}")
+                }
+                KlineNodeError::KlineFileColumnNotFound { file_path, column, .. } => {
+                    format!("K线文件中未找到列 [{column}]: {file_path}")
+                }
+                KlineNodeError::InvalidKlineFileValue {
+                    file_path,
+                    row,
+                    column,
+                    value,
+                    ..
+                } => {
+                    format!("K线文件第{row}行列 [{column}] 的值 [{value}] 无效: {file_path}")
+                }
+                KlineNodeError::KlineFileTimestampNotMonotonic {
+                    file_path,
+                    row,
+                    previous,
+                    current,
+                    ..
+                } => {
+                    format!("K线文件第{row}行时间未递增 ({previous} -> {current}): {file_path}")
+                }
+                KlineNodeError::KlineFileIntervalMismatch {
+                    file_path,
+                    interval,
+                    previous,
+                    current,
+                    ..
+                } => {
+                    format!("K线时间 {previous} -> {current} 与周期 {interval} 不匹配: {file_path}")
+                }
+                KlineNodeError::KlineFileGapDetected {
+                    file_path,
+                    previous,
+                    current,
+                    missing_bars,
+                    ..
+                } => {
+                    format!("K线文件在 {previous} 与 {current} 之间缺失 {missing_bars} 根K线: {file_path}")
+                }
+                KlineNodeError::KlineFileNoDataInRange {
+                    file_path,
+                    start_time,
+                    end_time,
+                    ..
+                } => {
+                    format!("K线文件在时间范围 {start_time} ~ {end_time} 内没有数据: {file_path}")
+                }
+                KlineNodeError::MissingBar {
+                    node_name,
+                    symbol,
+                    interval,
+                    datetime,
+                    last_datetime,
+                    ..
+                } => {
+                    format!("@[{node_name}] {symbol}-{interval} 在 {datetime} 缺失K线, 最近一根K线的时间为 {last_datetime}")
                 }
             },
         }
@@ -30,6 +30,13 @@ pub enum PositionNodeError {
 
     #[snafu(display("@[{node_name}] symbol not configured for position operation: {op}"))]
     SymbolNotConfigured { node_name: NodeName, op: String },
+
+    #[snafu(display("@[{node_name}] partial close size not configured for position operation config: {config_id}"))]
+    PartialCloseSizeNotConfigured {
+        node_name: NodeName,
+        config_id: i32,
+        backtrace: Backtrace,
+    },
 }
 
 impl StarRiverErrorTrait for PositionNodeError {
@@ -45,6 +52,7 @@ impl StarRiverErrorTrait for PositionNodeError {
             PositionNodeError::NodeStateMachineError { .. } => 1003, // node state machine error
             PositionNodeError::OperationConfigNotFound { .. } => 1004,
             PositionNodeError::SymbolNotConfigured { .. } => 1005,
+            PositionNodeError::PartialCloseSizeNotConfigured { .. } => 1006,
         };
         format!("{}_{:04}", prefix, code)
     }
@@ -56,6 +64,7 @@ impl StarRiverErrorTrait for PositionNodeError {
             PositionNodeError::NodeStateMachineError { source, .. } => source.http_status_code(),
             PositionNodeError::OperationConfigNotFound { .. } => StatusCode::BAD_REQUEST,
             PositionNodeError::SymbolNotConfigured { .. } => StatusCode::BAD_REQUEST,
+            PositionNodeError::PartialCloseSizeNotConfigured { .. } => StatusCode::BAD_REQUEST,
         }
     }
 
@@ -66,6 +75,7 @@ impl StarRiverErrorTrait for PositionNodeError {
             PositionNodeError::NodeStateMachineError { source, .. } => generate_error_code_chain(source, self.error_code()),
             PositionNodeError::OperationConfigNotFound { .. } => vec![self.error_code()],
             PositionNodeError::SymbolNotConfigured { .. } => vec![self.error_code()],
+            PositionNodeError::PartialCloseSizeNotConfigured { .. } => vec![self.error_code()],
         }
     }
 
@@ -82,6 +92,9 @@ impl StarRiverErrorTrait for PositionNodeError {
                 PositionNodeError::SymbolNotConfigured { node_name, op, .. } => {
                     format!("@[{node_name}] 仓位操作未配置交易对: {op}")
                 }
+                PositionNodeError::PartialCloseSizeNotConfigured { node_name, config_id, .. } => {
+                    format!("@[{node_name}] 仓位操作配置 {config_id} 未配置部分平仓数量")
+                }
             },
         }
     }
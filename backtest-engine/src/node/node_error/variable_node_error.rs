@@ -32,6 +32,14 @@ pub enum VariableNodeError {
         source: tokio::task::JoinError,
         backtrace: Backtrace,
     },
+
+    #[snafu(display("@[{node_name}] timer trigger of variable config {config_id} is invalid: {reason}"))]
+    InvalidTimerTrigger {
+        node_name: NodeName,
+        config_id: i32,
+        reason: String,
+        backtrace: Backtrace,
+    },
 }
 
 impl StarRiverErrorTrait for VariableNodeError {
@@ -48,6 +56,7 @@ impl StarRiverErrorTrait for VariableNodeError {
             VariableNodeError::SysVariableSymbolIsNull { .. } => 1004,   //system variable symbol is null
             VariableNodeError::ExchangeModeNotConfigured { .. } => 1005, //exchange mode not configured
             VariableNodeError::TaskFailed { .. } => 1006,                //task failed
+            VariableNodeError::InvalidTimerTrigger { .. } => 1007,       //invalid timer trigger
         };
 
         format!("{}_{:04}", prefix, code)
@@ -61,6 +70,7 @@ impl StarRiverErrorTrait for VariableNodeError {
             VariableNodeError::SysVariableSymbolIsNull { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             VariableNodeError::ExchangeModeNotConfigured { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             VariableNodeError::TaskFailed { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            VariableNodeError::InvalidTimerTrigger { .. } => StatusCode::BAD_REQUEST,
         }
     }
 
@@ -73,7 +83,8 @@ impl StarRiverErrorTrait for VariableNodeError {
             VariableNodeError::NodeStateMachineError { source, .. } => generate_error_code_chain(source, self.error_code()),
             VariableNodeError::SysVariableSymbolIsNull { .. }
             | VariableNodeError::TaskFailed { .. }
-            | VariableNodeError::ExchangeModeNotConfigured { .. } => vec![self.error_code()],
+            | VariableNodeError::ExchangeModeNotConfigured { .. }
+            | VariableNodeError::InvalidTimerTrigger { .. } => vec![self.error_code()],
         }
     }
 
@@ -91,6 +102,14 @@ impl StarRiverErrorTrait for VariableNodeError {
                     format!("@{} 交易所模式未配置", node_name)
                 }
                 VariableNodeError::TaskFailed { source, .. } => format!("任务失败: {}", source),
+                VariableNodeError::InvalidTimerTrigger {
+                    node_name,
+                    config_id,
+                    reason,
+                    ..
+                } => {
+                    format!("@{} 变量配置 {} 的定时触发无效: {}", node_name, config_id, reason)
+                }
             },
         }
     }
@@ -923,6 +923,18 @@ pub mod futures_order_node_log_message {
         zh: "@[{node_name}] 订单已取消 - 订单ID: {order_id}"
     );
 
+    log_message!(
+        OrderModifiedMsg,
+        params: (
+            node_name: String,
+            order_id: OrderId,
+            price: f64,
+            quantity: f64,
+        ),
+        en: "@[{node_name}] order modified - Order ID: {order_id}, Price: {price}, Quantity: {quantity}",
+        zh: "@[{node_name}] 订单已修改 - 订单ID: {order_id}, 价格: {price}, 数量: {quantity}"
+    );
+
     log_message!(
         OrderExpiredMsg,
         params: (
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use key::IndicatorKey;
+use serde::{Deserialize, Serialize};
+use star_river_core::{custom_type::NodeId, kline::Kline, system::map_entries};
+use strategy_core::variable::{custom_variable::VariableValue, tagged_variable::value_entries};
+use virtual_trading::types::{VirtualOrder, VirtualTransaction};
+
+use crate::{node::node_event::BacktestNodeEvent, node_catalog::if_else_node::value_history::ValueHistory};
+
+/// State a node carries from one cycle to the next, captured by strategy checkpoints
+///
+/// Kline, position and start nodes read everything they need from the strategy on each cycle, they are `Stateless`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum NodeSnapshot {
+    Stateless,
+    IfElse {
+        #[serde(with = "map_entries")]
+        received_flag: HashMap<(NodeId, i32), bool>,
+        // Not saved with the checkpoint, the node waits for those values again once restored
+        #[serde(skip)]
+        received_message: HashMap<(NodeId, i32), Option<BacktestNodeEvent>>,
+        #[serde(with = "map_entries")]
+        value_history: HashMap<(NodeId, i32, String), ValueHistory>,
+        superior_case_status: bool,
+    },
+    Indicator {
+        #[serde(with = "map_entries")]
+        cache_kline_slice: HashMap<IndicatorKey, Vec<Kline>>,
+    },
+    Variable {
+        #[serde(with = "value_entries")]
+        variable_cache_value: HashMap<(NodeId, i32, String), VariableValue>,
+        // Next fire time of each timer trigger, keyed by config id
+        #[serde(default)]
+        timer_fire_time: HashMap<i32, Option<DateTime<Utc>>>,
+    },
+    FuturesOrder {
+        is_processing_order: HashMap<i32, (bool, i32)>,
+        unfilled_virtual_order: Vec<VirtualOrder>,
+        virtual_order_history: Vec<VirtualOrder>,
+        virtual_transaction_history: Vec<VirtualTransaction>,
+    },
+}
@@ -32,7 +32,9 @@ use virtual_trading::{
 
 use super::{futures_order_node_types::FuturesOrderNodeConfig, state_machine::FuturesOrderNodeStateMachine};
 use crate::{
-    node::{node_command::BacktestNodeCommand, node_error::FuturesOrderNodeError, node_event::BacktestNodeEvent},
+    node::{
+        node_command::BacktestNodeCommand, node_error::FuturesOrderNodeError, node_event::BacktestNodeEvent, node_snapshot::NodeSnapshot,
+    },
     strategy::strategy_command::BacktestStrategyCommand,
 };
 
@@ -84,6 +86,30 @@ impl FuturesOrderNodeContext {
     pub fn node_config(&self) -> &FuturesOrderNodeConfig {
         &self.node_config
     }
+
+    pub async fn snapshot(&self) -> NodeSnapshot {
+        NodeSnapshot::FuturesOrder {
+            is_processing_order: self.is_processing_order.read().await.clone(),
+            unfilled_virtual_order: self.unfilled_virtual_order.read().await.clone(),
+            virtual_order_history: self.virtual_order_history.read().await.clone(),
+            virtual_transaction_history: self.virtual_transaction_history.read().await.clone(),
+        }
+    }
+
+    pub async fn restore_snapshot(&mut self, snapshot: NodeSnapshot) {
+        if let NodeSnapshot::FuturesOrder {
+            is_processing_order,
+            unfilled_virtual_order,
+            virtual_order_history,
+            virtual_transaction_history,
+        } = snapshot
+        {
+            *self.is_processing_order.write().await = is_processing_order;
+            *self.unfilled_virtual_order.write().await = unfilled_virtual_order;
+            *self.virtual_order_history.write().await = virtual_order_history;
+            *self.virtual_transaction_history.write().await = virtual_transaction_history;
+        }
+    }
 }
 
 impl NodeMetaDataExt for FuturesOrderNodeContext {
@@ -5,8 +5,8 @@ use star_river_event::backtest_strategy::node_event::{
         FuturesOrderCanceledEvent, FuturesOrderCanceledPayload, FuturesOrderCreatedEvent, FuturesOrderCreatedPayload,
         FuturesOrderFilledEvent, FuturesOrderFilledPayload, StopLossOrderCanceledEvent, StopLossOrderCanceledPayload,
         StopLossOrderCreatedEvent, StopLossOrderCreatedPayload, StopLossOrderFilledEvent, StopLossOrderFilledPayload,
-        TakeProfitOrderCanceledEvent, TakeProfitOrderCanceledPayload, TakeProfitOrderCreatedEvent, TakeProfitOrderCreatedPayload,
-        TakeProfitOrderFilledEvent, TakeProfitOrderFilledPayload,
+        StopLossOrderUpdatedEvent, StopLossOrderUpdatedPayload, TakeProfitOrderCanceledEvent, TakeProfitOrderCanceledPayload,
+        TakeProfitOrderCreatedEvent, TakeProfitOrderCreatedPayload, TakeProfitOrderFilledEvent, TakeProfitOrderFilledPayload,
     },
 };
 use strategy_core::node::context_trait::{NodeCommunicationExt, NodeHandleExt, NodeInfoExt};
@@ -67,6 +67,7 @@ impl FuturesOrderNodeContext {
             VtsEvent::StopLossOrderCreated(_) => create_event!(StopLossOrderCreatedEvent, StopLossOrderCreatedPayload),
             VtsEvent::StopLossOrderFilled(_) => create_event!(StopLossOrderFilledEvent, StopLossOrderFilledPayload),
             VtsEvent::StopLossOrderCanceled(_) => create_event!(StopLossOrderCanceledEvent, StopLossOrderCanceledPayload),
+            VtsEvent::StopLossOrderUpdated(_) => create_event!(StopLossOrderUpdatedEvent, StopLossOrderUpdatedPayload),
             _ => None,
         }
     }
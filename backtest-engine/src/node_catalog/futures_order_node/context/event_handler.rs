@@ -18,11 +18,12 @@ use super::FuturesOrderNodeContext;
 use crate::{
     node::{
         node_command::{
-            BacktestNodeCommand, GetFuturesOrderConfigRespPayload, GetFuturesOrderConfigResponse, NodeResetRespPayload, NodeResetResponse,
+            BacktestNodeCommand, GetFuturesOrderConfigRespPayload, GetFuturesOrderConfigResponse, GetNodeSnapshotRespPayload,
+            GetNodeSnapshotResponse, NodeResetRespPayload, NodeResetResponse, RestoreNodeSnapshotRespPayload, RestoreNodeSnapshotResponse,
         },
         node_error::FuturesOrderNodeError,
         node_event::BacktestNodeEvent,
-        node_message::futures_order_node_log_message::{OrderCanceledMsg, OrderCreatedMsg, OrderFilledMsg},
+        node_message::futures_order_node_log_message::{OrderCanceledMsg, OrderCreatedMsg, OrderFilledMsg, OrderModifiedMsg},
     },
     node_catalog::futures_order_node::context::config_filter::{filter_case_trigger_configs, filter_else_trigger_configs},
 };
@@ -79,6 +80,24 @@ impl NodeEventHandlerExt for FuturesOrderNodeContext {
                     cmd.respond(response);
                 }
             }
+            BacktestNodeCommand::GetNodeSnapshot(cmd) => {
+                if self.node_id() == cmd.node_id() {
+                    let payload = GetNodeSnapshotRespPayload::new(self.snapshot().await);
+                    let response = GetNodeSnapshotResponse::success(self.node_id().clone(), self.node_name().clone(), payload);
+                    cmd.respond(response);
+                }
+            }
+            BacktestNodeCommand::RestoreNodeSnapshot(cmd) => {
+                if self.node_id() == cmd.node_id() {
+                    self.restore_snapshot(cmd.snapshot.clone()).await;
+                    let response = RestoreNodeSnapshotResponse::success(
+                        self.node_id().clone(),
+                        self.node_name().clone(),
+                        RestoreNodeSnapshotRespPayload,
+                    );
+                    cmd.respond(response);
+                }
+            }
             _ => {}
         }
     }
@@ -148,7 +167,7 @@ impl FuturesOrderNodeContext {
 
                 for config_id in config_ids {
                     // if create order failed, send trigger event, no block strategy loop
-                    self.create_order(config_id).await?;
+                    self.handle_order_operation(config_id).await?;
                     if self.is_leaf_node() {
                         self.send_execute_over_event(
                             Some(config_id),
@@ -199,7 +218,7 @@ impl FuturesOrderNodeContext {
 
                 for config_id in config_ids {
                     // if create order failed, send trigger event, no block strategy loop
-                    self.create_order(config_id).await?;
+                    self.handle_order_operation(config_id).await?;
                     if self.is_leaf_node() {
                         self.send_execute_over_event(
                             Some(config_id),
@@ -292,12 +311,15 @@ impl FuturesOrderNodeContext {
             VtsEvent::FuturesOrderCreated(order)
             | VtsEvent::FuturesOrderFilled(order)
             | VtsEvent::FuturesOrderCanceled(order)
+            | VtsEvent::FuturesOrderModified(order)
+            | VtsEvent::OrderGroupLegActivated(order)
             | VtsEvent::TakeProfitOrderCreated(order)
             | VtsEvent::TakeProfitOrderFilled(order)
             | VtsEvent::TakeProfitOrderCanceled(order)
             | VtsEvent::StopLossOrderCreated(order)
             | VtsEvent::StopLossOrderFilled(order)
-            | VtsEvent::StopLossOrderCanceled(order) => Some(order),
+            | VtsEvent::StopLossOrderCanceled(order)
+            | VtsEvent::StopLossOrderUpdated(order) => Some(order),
             _ => None,
         };
 
@@ -365,13 +387,37 @@ impl FuturesOrderNodeContext {
                         self.strategy_bound_handle_send(log_event.into())?;
                     }
 
+                    VtsEvent::FuturesOrderModified(_) => {
+                        self.remove_unfilled_virtual_order(order.order_id).await;
+                        self.add_unfilled_virtual_order(order.clone()).await;
+                        let message = OrderModifiedMsg::new(self.node_name().clone(), order.order_id, order.open_price, order.quantity);
+                        let log_event: CommonEvent = NodeRunningLogEvent::info_with_time(
+                            self.cycle_id(),
+                            self.strategy_id().clone(),
+                            self.node_id().clone(),
+                            self.node_name().clone(),
+                            message.to_string(),
+                            order.to_value()?,
+                            order.update_time,
+                        )
+                        .into();
+                        self.strategy_bound_handle_send(log_event.into())?;
+                    }
+
+                    // tp/sl legs are not kept in the unfilled order cache
+                    VtsEvent::OrderGroupLegActivated(_) if !order.is_tpsl_order() => {
+                        self.remove_unfilled_virtual_order(order.order_id).await;
+                        self.add_unfilled_virtual_order(order.clone()).await;
+                    }
+
                     // Only send events
                     VtsEvent::TakeProfitOrderCreated(_)
                     | VtsEvent::TakeProfitOrderFilled(_)
                     | VtsEvent::TakeProfitOrderCanceled(_)
                     | VtsEvent::StopLossOrderCreated(_)
                     | VtsEvent::StopLossOrderFilled(_)
-                    | VtsEvent::StopLossOrderCanceled(_) => {
+                    | VtsEvent::StopLossOrderCanceled(_)
+                    | VtsEvent::StopLossOrderUpdated(_) => {
                         self.send_order_status_event(order.clone(), &virtual_trading_system_event).await?;
                     }
 
@@ -1,60 +1,155 @@
 use snafu::{OptionExt, ResultExt};
+use star_river_core::{custom_type::OrderId, order::OrderType};
 use strategy_core::{
     event::node_common_event::{CommonEvent, NodeRunningLogEvent},
     node::context_trait::{NodeCommunicationExt, NodeInfoExt, NodeRelationExt},
 };
 use tokio::sync::oneshot;
 use virtual_trading::{
-    command::{CreateOrderCmdPayload, CreateOrderCommand, VtsResponse},
+    command::{
+        CancelAllOrdersCmdPayload, CancelAllOrdersCommand, CancelOrderCmdPayload, CancelOrderCommand, CreateOrderCmdPayload,
+        CreateOrderCommand, CreateOrderGroupCmdPayload, CreateOrderGroupCommand, ModifyOrderCmdPayload, ModifyOrderCommand, VtsResponse,
+    },
     error::{CommandSendFailedSnafu, ResponseRecvFailedSnafu},
+    types::OrderGroupType,
 };
 
 use super::FuturesOrderNodeContext;
-use crate::node::{
-    node_error::{FuturesOrderNodeError, futures_order_node_error::SymbolInfoNotFoundSnafu},
-    node_message::futures_order_node_log_message::ProcessingOrderMsg,
+use crate::{
+    node::{
+        node_error::{FuturesOrderNodeError, futures_order_node_error::SymbolInfoNotFoundSnafu},
+        node_message::futures_order_node_log_message::ProcessingOrderMsg,
+    },
+    node_catalog::futures_order_node::futures_order_node_types::{CancelOrderScope, FuturesOrderOperation},
 };
 
 impl FuturesOrderNodeContext {
-    // create a virtual order
-    pub(super) async fn create_order(&mut self, config_id: i32) -> Result<(), FuturesOrderNodeError> {
-        tracing::debug!("@[{}] creating order for config: {:?}", self.node_name(), config_id);
-        // If currently processing order or unfilled order list is not empty, do not create order
-        if !self.can_create_order(&config_id).await {
-            tracing::warn!("@[{}] config {:?} is processing order, skip", self.node_name(), config_id);
-            if self.warn_log_send_count(&config_id).await < 3 {
-                let message = ProcessingOrderMsg::new(config_id);
-                let current_time = self.strategy_time();
-                let log_event: CommonEvent = NodeRunningLogEvent::warn_with_time(
-                    self.cycle_id(),
-                    self.strategy_id().clone(),
-                    self.node_id().clone(),
-                    self.node_name().clone(),
-                    message.to_string(),
-                    None,
-                    None,
-                    current_time,
-                )
-                .into();
-                self.strategy_bound_handle_send(log_event.into())?;
-                self.increment_warn_log_send_count(config_id).await;
+    // execute the operation of the triggered order config
+    pub(super) async fn handle_order_operation(&mut self, config_id: i32) -> Result<(), FuturesOrderNodeError> {
+        let order_operation = self.node_config.find_order_config(config_id)?.order_operation.clone();
+        match order_operation {
+            FuturesOrderOperation::CreateOrder => self.create_order(config_id).await,
+            FuturesOrderOperation::CreateOrderGroup {
+                group_type,
+                linked_config_ids,
+            } => self.create_order_group(config_id, group_type, linked_config_ids).await,
+            FuturesOrderOperation::CancelOrder { target_config_id } => self.cancel_order(target_config_id.unwrap_or(config_id)).await,
+            FuturesOrderOperation::CancelAllOrders { scope } => self.cancel_all_orders(config_id, scope).await,
+            FuturesOrderOperation::ModifyOrder { target_config_id } => {
+                self.modify_order(config_id, target_config_id.unwrap_or(config_id)).await
             }
+        }
+    }
+
+    // unfilled entry orders created by the order config
+    async fn unfilled_order_ids_of_config(&self, config_id: i32) -> Vec<OrderId> {
+        self.unfilled_virtual_order
+            .read()
+            .await
+            .iter()
+            .filter(|order| order.order_config_id == config_id && !order.is_tpsl_order())
+            .map(|order| order.order_id)
+            .collect()
+    }
+
+    // cancel the unfilled orders of the target config
+    async fn cancel_order(&mut self, target_config_id: i32) -> Result<(), FuturesOrderNodeError> {
+        for order_id in self.unfilled_order_ids_of_config(target_config_id).await {
+            let (tx, rx) = oneshot::channel();
+            let cmd = CancelOrderCommand::new(tx, CancelOrderCmdPayload::new(order_id));
+            self.vts_command_sender.send(cmd.into()).await.context(CommandSendFailedSnafu {})?;
+            let response = rx.await.context(ResponseRecvFailedSnafu {})?;
+            response.into_payload()?;
+        }
+        Ok(())
+    }
 
-            if self.is_leaf_node() {
-                self.send_execute_over_event(Some(config_id), Some("is processing order".to_string()), Some(self.strategy_time()))?;
-            } else {
-                self.independent_order_send_trigger_event(config_id, Some("create order failed".to_string()))
-                    .await?;
+    async fn cancel_all_orders(&mut self, config_id: i32, scope: CancelOrderScope) -> Result<(), FuturesOrderNodeError> {
+        let exchange = self.node_config.exchange_mode()?.selected_account.exchange.clone();
+        let payload = match scope {
+            CancelOrderScope::Symbol => {
+                let symbol = self.node_config.find_order_config(config_id)?.symbol.clone();
+                CancelAllOrdersCmdPayload::new(exchange, Some(symbol), None)
             }
-            return Ok(());
+            CancelOrderScope::Node => CancelAllOrdersCmdPayload::new(exchange, None, Some(self.node_id().clone())),
+        };
+
+        let (tx, rx) = oneshot::channel();
+        let cmd = CancelAllOrdersCommand::new(tx, payload);
+        self.vts_command_sender.send(cmd.into()).await.context(CommandSendFailedSnafu {})?;
+        let response = rx.await.context(ResponseRecvFailedSnafu {})?;
+        response.into_payload()?;
+        Ok(())
+    }
+
+    // apply the price, quantity and tp/sl of the order config to the unfilled orders of the target config
+    async fn modify_order(&mut self, config_id: i32, target_config_id: i32) -> Result<(), FuturesOrderNodeError> {
+        let order_config = self.node_config.find_order_config(config_id)?.clone();
+        let point = self
+            .symbol_info
+            .iter()
+            .find(|s| s.name == order_config.symbol)
+            .context(SymbolInfoNotFoundSnafu {
+                symbol: order_config.symbol.clone(),
+            })?
+            .point();
+        // the price of a market order follows the market
+        let price = (order_config.order_type != OrderType::Market).then_some(order_config.price);
+
+        for order_id in self.unfilled_order_ids_of_config(target_config_id).await {
+            let payload = ModifyOrderCmdPayload::new(
+                order_id,
+                price,
+                Some(order_config.quantity),
+                order_config.tp,
+                order_config.sl,
+                order_config.tp_type.clone(),
+                order_config.sl_type.clone(),
+                Some(point as f64),
+            );
+            let (tx, rx) = oneshot::channel();
+            let cmd = ModifyOrderCommand::new(tx, payload);
+            self.vts_command_sender.send(cmd.into()).await.context(CommandSendFailedSnafu {})?;
+            let response = rx.await.context(ResponseRecvFailedSnafu {})?;
+            response.into_payload()?;
         }
-        // Set is_processing_order for input_handle_id to true
-        self.set_is_processing_order(config_id, true).await;
+        Ok(())
+    }
 
-        // let mut virtual_trading_system_guard = self.virtual_trading_system.lock().await;
+    // log and pass on the trigger when the config is still processing an order
+    async fn skip_processing_order(&mut self, config_id: i32) -> Result<(), FuturesOrderNodeError> {
+        tracing::warn!("@[{}] config {:?} is processing order, skip", self.node_name(), config_id);
+        if self.warn_log_send_count(&config_id).await < 3 {
+            let message = ProcessingOrderMsg::new(config_id);
+            let current_time = self.strategy_time();
+            let log_event: CommonEvent = NodeRunningLogEvent::warn_with_time(
+                self.cycle_id(),
+                self.strategy_id().clone(),
+                self.node_id().clone(),
+                self.node_name().clone(),
+                message.to_string(),
+                None,
+                None,
+                current_time,
+            )
+            .into();
+            self.strategy_bound_handle_send(log_event.into())?;
+            self.increment_warn_log_send_count(config_id).await;
+        }
+
+        if self.is_leaf_node() {
+            self.send_execute_over_event(Some(config_id), Some("is processing order".to_string()), Some(self.strategy_time()))?;
+        } else {
+            self.independent_order_send_trigger_event(config_id, Some("create order failed".to_string()))
+                .await?;
+        }
+        Ok(())
+    }
+
+    // vts create order payload of the order config
+    fn create_order_payload(&self, config_id: i32) -> Result<CreateOrderCmdPayload, FuturesOrderNodeError> {
         let exchange = self.node_config.exchange_mode()?.selected_account.exchange.clone();
         let order_config = self.node_config.find_order_config(config_id)?;
-        // Create order
         // Get symbol point value
         let point = self
             .symbol_info
@@ -65,7 +160,7 @@ impl FuturesOrderNodeContext {
             })?
             .point();
 
-        let payload = CreateOrderCmdPayload::new(
+        Ok(CreateOrderCmdPayload::new(
             self.strategy_id().clone(),
             self.node_id().clone(),
             self.node_name().clone(),
@@ -81,7 +176,24 @@ impl FuturesOrderNodeContext {
             order_config.tp_type.clone(),
             order_config.sl_type.clone(),
             Some(point as f64),
-        );
+            order_config.reduce_only,
+            order_config.stop_price,
+            order_config.trailing_stop.clone(),
+        ))
+    }
+
+    // create a virtual order
+    pub(super) async fn create_order(&mut self, config_id: i32) -> Result<(), FuturesOrderNodeError> {
+        tracing::debug!("@[{}] creating order for config: {:?}", self.node_name(), config_id);
+        // If currently processing order or unfilled order list is not empty, do not create order
+        if !self.can_create_order(&config_id).await {
+            return self.skip_processing_order(config_id).await;
+        }
+        // Set is_processing_order for input_handle_id to true
+        self.set_is_processing_order(config_id, true).await;
+
+        // let mut virtual_trading_system_guard = self.virtual_trading_system.lock().await;
+        let payload = self.create_order_payload(config_id)?;
 
         let (tx, rx) = oneshot::channel();
         let cmd = CreateOrderCommand::new(tx, payload);
@@ -98,4 +210,44 @@ impl FuturesOrderNodeContext {
             }
         }
     }
+
+    // create the orders of the config and the linked configs as one order group, the config order is the oto parent or bracket entry
+    async fn create_order_group(
+        &mut self,
+        config_id: i32,
+        group_type: OrderGroupType,
+        linked_config_ids: Vec<i32>,
+    ) -> Result<(), FuturesOrderNodeError> {
+        tracing::debug!(
+            "@[{}] creating {} order group for config: {:?}",
+            self.node_name(),
+            group_type,
+            config_id
+        );
+        let config_ids: Vec<i32> = std::iter::once(config_id).chain(linked_config_ids).collect();
+        for id in config_ids.iter() {
+            if !self.can_create_order(id).await {
+                return self.skip_processing_order(config_id).await;
+            }
+        }
+        let orders = config_ids
+            .iter()
+            .map(|id| self.create_order_payload(*id))
+            .collect::<Result<Vec<_>, _>>()?;
+        for id in config_ids.iter() {
+            self.set_is_processing_order(*id, true).await;
+        }
+
+        let (tx, rx) = oneshot::channel();
+        let cmd = CreateOrderGroupCommand::new(tx, CreateOrderGroupCmdPayload::new(group_type, orders));
+        self.vts_command_sender.send(cmd.into()).await.context(CommandSendFailedSnafu {})?;
+        let response = rx.await.context(ResponseRecvFailedSnafu {})?;
+        if let Err(error) = response.into_payload() {
+            for id in config_ids {
+                self.set_is_processing_order(id, false).await;
+            }
+            return Err(error.into());
+        }
+        Ok(())
+    }
 }
@@ -1,11 +1,12 @@
 use serde::Deserialize;
 use snafu::OptionExt;
 use star_river_core::{
-    custom_type::{InputHandleId, NodeName},
+    custom_type::{InputHandleId, NodeId, NodeName},
     order::{FuturesOrderSide, OrderType, TpslType},
     system::{TimeRange, deserialize_time_range},
 };
 use strategy_core::{node_infra::condition_trigger::ConditionTrigger, strategy::SelectedAccount};
+use virtual_trading::types::{OrderGroupType, TrailingStop};
 
 use crate::{
     node::node_error::{
@@ -15,6 +16,44 @@ use crate::{
     strategy::strategy_config::BacktestDataSource,
 };
 
+// Scope of the cancel all orders operation
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CancelOrderScope {
+    // Pending orders of the config symbol
+    #[default]
+    Symbol,
+    // Pending orders created by this node
+    Node,
+}
+
+// Operation executed when the order config is triggered
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum FuturesOrderOperation {
+    #[default]
+    CreateOrder,
+    // Create the order of this config and the orders of the linked configs as one oco, oto or bracket group
+    // this config's order is the oto parent or the bracket entry, a bracket takes its tp/sl legs from this config
+    CreateOrderGroup {
+        group_type: OrderGroupType,
+        #[serde(default)]
+        linked_config_ids: Vec<i32>,
+    },
+    // Cancel the unfilled orders of the target config (this config if not set)
+    CancelOrder {
+        target_config_id: Option<i32>,
+    },
+    CancelAllOrders {
+        #[serde(default)]
+        scope: CancelOrderScope,
+    },
+    // Apply the price, quantity and tp/sl of this config to the unfilled orders of the target config (this config if not set)
+    ModifyOrder {
+        target_config_id: Option<i32>,
+    },
+}
+
 // Futures order configuration
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -30,7 +69,10 @@ pub struct FuturesOrderConfig {
     // #[serde(deserialize_with = "deserialize_futures_order_side")]
     pub order_side: FuturesOrderSide,
 
-    pub price: f64,
+    pub price: f64, // Limit price, the stop price of a stop market order
+
+    #[serde(default)]
+    pub stop_price: Option<f64>, // Stop price of a stop limit order
 
     pub quantity: f64,
 
@@ -42,6 +84,17 @@ pub struct FuturesOrderConfig {
     pub sl_type: Option<TpslType>,
 
     pub trigger_config: ConditionTrigger,
+
+    #[serde(default)]
+    pub order_operation: FuturesOrderOperation,
+
+    // Only reduce the opposite position, never open or add to one
+    #[serde(default)]
+    pub reduce_only: bool,
+
+    // Trailing stop and break-even moves of the stop loss order, re-evaluated every bar
+    #[serde(default)]
+    pub trailing_stop: Option<TrailingStop>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -77,6 +130,21 @@ impl FuturesOrderNodeConfig {
                 order_config_id: config_id,
             })
     }
+
+    /// (order config id, indicator node id, indicator config id) of the ATR indicators referenced by trailing stops
+    pub fn trailing_stop_indicators(&self) -> Vec<(i32, NodeId, i32)> {
+        self.futures_order_configs
+            .iter()
+            .flat_map(|config| {
+                config.trailing_stop.iter().flat_map(|trailing_stop| {
+                    trailing_stop
+                        .atr_indicators()
+                        .into_iter()
+                        .map(|(node_id, config_id)| (config.order_config_id, node_id.clone(), config_id))
+                })
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
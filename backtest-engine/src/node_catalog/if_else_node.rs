@@ -4,6 +4,7 @@ mod if_else_node_type;
 mod node_lifecycle;
 mod state_machine;
 mod utils;
+pub(crate) mod value_history;
 
 use std::sync::Arc;
 
@@ -17,14 +18,17 @@ use strategy_core::{
     NodeType,
     error::node_error::{ConfigDeserializationFailedSnafu, ConfigFieldValueNullSnafu},
     node::{NodeBase, metadata::NodeMetadata, node_trait::NodeContextAccessor, utils::generate_strategy_output_handle},
-    node_infra::if_else_node::Case,
+    node_infra::if_else_node::{Case, ComparisonSymbol, FormulaRight},
     strategy::cycle::Cycle,
 };
 use tokio::sync::{Mutex, RwLock, mpsc, watch};
 
 use crate::{
     node::{
-        node_command::BacktestNodeCommand, node_error::BacktestNodeError, node_event::BacktestNodeEvent, node_state_machine::NodeRunState,
+        node_command::BacktestNodeCommand,
+        node_error::{BacktestNodeError, IfElseNodeError, if_else_node_error::BarCountNotConstantSnafu},
+        node_event::BacktestNodeEvent,
+        node_state_machine::NodeRunState,
     },
     strategy::strategy_command::BacktestStrategyCommand,
 };
@@ -156,7 +160,157 @@ impl IfElseNode {
         let cases = serde_json::from_value::<Vec<Case>>(cases_json).context(ConfigDeserializationFailedSnafu {
             node_name: node_name.clone(),
         })?;
+        // The value history of `rising for` / `falling for` is sized from the bar count when the node is built
+        for case in cases.iter() {
+            if let Some(condition) = case.all_conditions().into_iter().find(|condition| {
+                matches!(
+                    condition.comparison_symbol,
+                    ComparisonSymbol::RisingFor | ComparisonSymbol::FallingFor
+                ) && matches!(condition.right, FormulaRight::Variable(_))
+            }) {
+                return Err(IfElseNodeError::from(
+                    BarCountNotConstantSnafu {
+                        node_name: node_name.clone(),
+                        case_id: case.case_id,
+                        condition_id: condition.condition_id,
+                        comparison_symbol: condition.comparison_symbol.to_string(),
+                    }
+                    .build(),
+                )
+                .into());
+            }
+        }
         let backtest_config = IfElseNodeBacktestConfig { cases, is_nested };
         Ok((strategy_id, node_id, node_name, backtest_config))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn if_else_node_config(right: serde_json::Value) -> serde_json::Value {
+        let variable = |node_id: &str| {
+            json!({
+                "nodeId": node_id,
+                "nodeName": "Calculate Ma",
+                "nodeType": "indicatorNode",
+                "outputHandleId": format!("{node_id}_output_1"),
+                "varConfigId": 1,
+                "varDisplayName": "MA",
+                "varName": "ma",
+                "varType": "variable",
+                "varValueType": "number"
+            })
+        };
+        let right = if right.is_null() { variable("variable_node_1") } else { right };
+        json!({
+            "id": "if_else_node_1",
+            "data": {
+                "nodeName": "Is Rising",
+                "strategyId": 1,
+                "isNested": false,
+                "backtestConfig": {
+                    "cases": [{
+                        "caseId": 1,
+                        "outputHandleId": "if_else_node_1_output_1",
+                        "logicalSymbol": "and",
+                        "conditions": [{
+                            "conditionId": 1,
+                            "comparisonSymbol": "rising for",
+                            "left": variable("indicator_node_1"),
+                            "right": right
+                        }]
+                    }]
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_rising_for_requires_constant_bar_count() {
+        let constant = json!({"varType": "constant", "varValue": 3, "varValueType": "number"});
+        assert!(IfElseNode::check_if_else_node_config(if_else_node_config(constant)).is_ok());
+
+        let error = IfElseNode::check_if_else_node_config(if_else_node_config(serde_json::Value::Null)).unwrap_err();
+        assert!(matches!(
+            error,
+            BacktestNodeError::IfElseNodeError {
+                source: IfElseNodeError::BarCountNotConstant {
+                    case_id: 1,
+                    condition_id: 1,
+                    ..
+                },
+                ..
+            }
+        ));
+    }
+
+    // The cycle of the evaluation task starts when the last input arrives, the wake-up is its first phase
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_evaluation_wait_is_recorded_as_first_phase() {
+        use std::time::Duration;
+
+        use strategy_core::node::{
+            context_trait::{NodeHandleExt, NodeTaskControlExt},
+            node_trait::NodeContextAccessor,
+        };
+
+        use crate::strategy::strategy_command::{AddNodeCycleTrackerRespPayload, AddNodeCycleTrackerResponse};
+
+        const CYCLES: usize = 200;
+
+        let constant = json!({"varType": "constant", "varValue": 3, "varValueType": "number"});
+        let (_cycle_tx, cycle_rx) = watch::channel(Cycle::new());
+        let (_strategy_time_tx, strategy_time_rx) = watch::channel(Utc::now());
+        let (strategy_command_tx, mut strategy_command_rx) = mpsc::channel(10);
+        let (_node_command_tx, node_command_rx) = mpsc::channel(10);
+        let node = IfElseNode::new(
+            cycle_rx,
+            strategy_time_rx,
+            if_else_node_config(constant),
+            strategy_command_tx,
+            Arc::new(Mutex::new(node_command_rx)),
+        )
+        .unwrap();
+        {
+            let mut ctx = node.context().write().await;
+            ctx.set_output_handles().unwrap();
+            ctx.init_received_data().await;
+        }
+        node.evaluate().await.unwrap();
+
+        let mut waits = Vec::with_capacity(CYCLES);
+        for _ in 0..CYCLES {
+            {
+                let mut ctx = node.context().write().await;
+                ctx.update_received_flag("indicator_node_1".to_string(), 1, true);
+                ctx.notify_if_ready();
+            }
+            let command = tokio::time::timeout(Duration::from_secs(5), strategy_command_rx.recv())
+                .await
+                .expect("evaluation was not woken up")
+                .unwrap();
+            let BacktestStrategyCommand::AddNodeCycleTracker(command) = command else {
+                panic!("unexpected strategy command");
+            };
+            let phases = command.cycle_tracker.get_all_phase_durations();
+            assert_eq!(phases[0].0, "wait for evaluation");
+            let wait = phases[0].1;
+            assert!(wait <= command.cycle_tracker.get_duration());
+            waits.push(wait);
+            command.respond(AddNodeCycleTrackerResponse::success(AddNodeCycleTrackerRespPayload));
+        }
+        node.context().read().await.cancel_token().cancel();
+
+        waits.sort();
+        println!(
+            "wait for evaluation over {CYCLES} cycles: median {:?}, p99 {:?}, max {:?}",
+            waits[CYCLES / 2],
+            waits[CYCLES * 99 / 100],
+            waits[CYCLES - 1]
+        );
+    }
+}
@@ -2,7 +2,7 @@ mod condition_op;
 mod event_handler;
 mod node_handles;
 
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
 use star_river_core::custom_type::{NodeId, NodeName};
@@ -14,9 +14,11 @@ use strategy_core::{
     },
 };
 
-use super::{if_else_node_type::IfElseNodeBacktestConfig, state_machine::IfElseNodeStateMachine};
+use tokio::{sync::Notify, time::Instant};
+
+use super::{if_else_node_type::IfElseNodeBacktestConfig, state_machine::IfElseNodeStateMachine, value_history::ValueHistory};
 use crate::{
-    node::{node_command::BacktestNodeCommand, node_error::IfElseNodeError, node_event::BacktestNodeEvent},
+    node::{node_command::BacktestNodeCommand, node_error::IfElseNodeError, node_event::BacktestNodeEvent, node_snapshot::NodeSnapshot},
     strategy::strategy_command::BacktestStrategyCommand,
 };
 
@@ -30,8 +32,11 @@ pub struct IfElseNodeContext {
     node_config: IfElseNodeBacktestConfig,
     received_flag: HashMap<(NodeId, ConfigId), bool>, // Track whether data for each variable has been received
     received_message: HashMap<(NodeId, ConfigId), Option<BacktestNodeEvent>>, // Store each variable's data, keyed by (node_id + variable_id)
+    value_history: HashMap<(NodeId, ConfigId, String), ValueHistory>,         // Recent values of variables compared to earlier bars
     is_nested: bool,
     superior_case_status: bool,
+    evaluate_notify: Arc<Notify>, // Wakes the evaluation task once the node is ready to evaluate
+    ready_at: Option<Instant>,    // When the last input of the current cycle arrived
 }
 
 impl IfElseNodeContext {
@@ -41,8 +46,11 @@ impl IfElseNodeContext {
             node_config,
             received_flag: HashMap::new(),
             received_message: HashMap::new(),
+            value_history: HashMap::new(),
             is_nested,
             superior_case_status: false,
+            evaluate_notify: Arc::new(Notify::new()),
+            ready_at: None,
         }
     }
 }
@@ -59,6 +67,72 @@ impl IfElseNodeContext {
     pub fn set_superior_case_status(&mut self, superior_case_status: bool) {
         self.superior_case_status = superior_case_status;
     }
+
+    pub fn evaluate_notify(&self) -> &Arc<Notify> {
+        &self.evaluate_notify
+    }
+
+    // All values received, and for a nested node the superior case is true
+    pub fn is_ready_to_evaluate(&self) -> bool {
+        self.is_all_value_received() && (!self.is_nested || self.superior_case_status)
+    }
+
+    // Wake the evaluation task, called whenever an input that can complete the cycle changes
+    pub fn notify_if_ready(&mut self) {
+        if self.is_ready_to_evaluate() {
+            self.ready_at.get_or_insert_with(Instant::now);
+            self.evaluate_notify.notify_one();
+        }
+    }
+
+    pub fn take_ready_at(&mut self) -> Option<Instant> {
+        self.ready_at.take()
+    }
+
+    pub fn clear_value_history(&mut self) {
+        for history in self.value_history.values_mut() {
+            *history = ValueHistory::new(history.depth());
+        }
+    }
+
+    pub fn snapshot(&self) -> NodeSnapshot {
+        NodeSnapshot::IfElse {
+            received_flag: self.received_flag.clone(),
+            received_message: self.received_message.clone(),
+            value_history: self.value_history.clone(),
+            superior_case_status: self.superior_case_status,
+        }
+    }
+
+    pub fn restore_snapshot(&mut self, snapshot: NodeSnapshot) {
+        if let NodeSnapshot::IfElse {
+            received_flag,
+            received_message,
+            value_history,
+            superior_case_status,
+        } = snapshot
+        {
+            self.received_flag = received_flag;
+            self.received_message = received_message;
+            self.superior_case_status = superior_case_status;
+            // Conditions may have changed since the checkpoint was saved, the current depths are kept
+            self.clear_value_history();
+            for (key, mut saved_history) in value_history {
+                if let Some(history) = self.value_history.get_mut(&key) {
+                    saved_history.set_depth(history.depth());
+                    *history = saved_history;
+                }
+            }
+            // Saved checkpoints carry no received values, those are waited for again
+            for (key, flag) in self.received_flag.iter_mut() {
+                if self.received_message.entry(key.clone()).or_insert(None).is_none() {
+                    *flag = false;
+                }
+            }
+            self.ready_at = None;
+            self.notify_if_ready();
+        }
+    }
 }
 
 impl NodeMetaDataExt for IfElseNodeContext {
@@ -14,7 +14,8 @@ use strategy_core::{
     benchmark::node_benchmark::CycleTracker,
     event::node_common_event::{CommonEvent, NodeRunningLogEvent},
     node::context_trait::{NodeBenchmarkExt, NodeCommunicationExt, NodeHandleExt, NodeInfoExt, NodeRelationExt},
-    node_infra::if_else_node::{Case, Condition, ConditionResult, FormulaRight, LogicalSymbol},
+    node_infra::if_else_node::{Case, ComparisonSymbol, Condition, ConditionResult, FormulaRight, GroupResult, Variable},
+    variable::custom_variable::VariableValue,
 };
 
 // Relative imports
@@ -27,7 +28,10 @@ use crate::{
             ConditionLeftAndRightValueNullMsg, ConditionLeftValueNullMsg, ConditionMatchedMsg, ConditionRightValueNullMsg,
         },
     },
-    node_catalog::if_else_node::utils::{compare, parse_condition_left_value, parse_condition_right_value},
+    node_catalog::if_else_node::{
+        utils::{compare, compare_cross, compare_trend, parse_condition_left_value},
+        value_history::ValueHistory,
+    },
 };
 
 impl IfElseNodeContext {
@@ -41,7 +45,7 @@ impl IfElseNodeContext {
     // Initialize receive flags
     pub async fn init_received_data(&mut self) {
         for case in &self.node_config.cases {
-            for condition in &case.conditions {
+            for condition in case.all_conditions() {
                 // Handle left value
 
                 let key = (condition.left.node_id.clone(), condition.left.var_config_id);
@@ -54,6 +58,17 @@ impl IfElseNodeContext {
                     self.received_flag.insert(key.clone(), false);
                     self.received_message.insert(key, None);
                 }
+
+                // Keep the recent values of variables compared with previous bars
+                for (variable, depth) in condition.history_depth() {
+                    if depth <= 1 {
+                        continue;
+                    }
+                    self.value_history
+                        .entry((variable.node_id.clone(), variable.var_config_id, variable.var_name.clone()))
+                        .and_modify(|history| history.set_depth(history.depth().max(depth)))
+                        .or_insert_with(|| ValueHistory::new(depth));
+                }
             }
         }
         tracing::debug!(node_id = %self.node_id(), "init received data success: {:?}, {:?}", self.received_flag, self.received_message);
@@ -68,11 +83,20 @@ impl IfElseNodeContext {
         for flag in self.received_flag.values_mut() {
             *flag = false;
         }
+        self.take_ready_at();
     }
 
     // Start evaluating all branches
     pub async fn evaluate(&mut self) -> Result<(), IfElseNodeError> {
-        let mut cycle_tracker: CycleTracker = CycleTracker::new(self.cycle_id());
+        // The cycle starts when the last input arrived, the wait for the evaluation task is its first phase
+        let mut cycle_tracker = match self.take_ready_at() {
+            Some(ready_at) => {
+                let mut cycle_tracker = CycleTracker::new_at(self.cycle_id(), ready_at);
+                cycle_tracker.record_phase("wait for evaluation", ready_at.elapsed());
+                cycle_tracker
+            }
+            None => CycleTracker::new(self.cycle_id()),
+        };
 
         let mut have_true_case = false; // Track whether any case has matched
         let current_time = self.strategy_time();
@@ -84,21 +108,20 @@ impl IfElseNodeContext {
 
             // Only evaluate condition if no case has matched yet
             if !have_true_case {
-                // evaluate result (is true, result)
                 let case_result = self.evaluate_case(case);
 
                 // If condition matches, handle the matched case
-                if case_result.0 {
+                if case_result.group_result {
                     // tracing::debug!("[{}] condition matched, handle matched case branch", self.node_name());
                     have_true_case = true;
                     cycle_tracker.end_phase(&phase_name);
-                    self.handle_case_true(case, case_result.1, current_time)?;
+                    self.handle_case_true(case, case_result, current_time)?;
                     // Continue to process remaining cases as false (do not break)
                 }
                 // condition is false
                 else {
                     // tracing::debug!("@[{}] condition not matched, send case false event", self.node_name());
-                    self.handle_case_false(case, Some(case_result.1))?;
+                    self.handle_case_false(case, Some(case_result))?;
                 }
             } else {
                 // A case has already matched, treat all subsequent cases as false
@@ -128,12 +151,7 @@ impl IfElseNodeContext {
     }
 
     // Handle matched case
-    fn handle_case_true(
-        &self,
-        case: &Case,
-        condition_results: Vec<ConditionResult>,
-        current_time: DateTimeUtc,
-    ) -> Result<(), IfElseNodeError> {
+    fn handle_case_true(&self, case: &Case, case_result: GroupResult, current_time: DateTimeUtc) -> Result<(), IfElseNodeError> {
         let strategy_id = self.strategy_id().clone();
         let node_id = self.node_id().clone();
         let node_name = self.node_name().clone();
@@ -153,7 +171,7 @@ impl IfElseNodeContext {
         .into();
 
         // Create and send log event
-        let condition_result_json = serde_json::to_value(condition_results).context(EvaluateResultSerializationFailedSnafu {
+        let condition_result_json = serde_json::to_value(case_result).context(EvaluateResultSerializationFailedSnafu {
             node_name: node_name.clone(),
         })?;
         let message = ConditionMatchedMsg::new(node_name.clone(), case.case_id);
@@ -182,10 +200,11 @@ impl IfElseNodeContext {
         Ok(())
     }
 
-    pub(super) fn handle_case_false(&self, case: &Case, case_result: Option<Vec<ConditionResult>>) -> Result<(), IfElseNodeError> {
+    pub(super) fn handle_case_false(&self, case: &Case, case_result: Option<GroupResult>) -> Result<(), IfElseNodeError> {
         if let Some(case_result) = case_result {
             case_result
-                .iter()
+                .condition_results()
+                .into_iter()
                 .filter(|condition_result| !condition_result.condition_result)
                 .try_for_each(|false_result| {
                     let message = if false_result.left_value.is_null() && false_result.right_value.is_null() {
@@ -277,62 +296,54 @@ impl IfElseNodeContext {
         Ok(())
     }
 
-    pub fn evaluate_case(&self, case: &Case) -> (bool, Vec<ConditionResult>) {
-        match case.logical_symbol {
-            LogicalSymbol::And => self.evaluate_and_conditions(&case.conditions),
-            LogicalSymbol::Or => self.evaluate_or_conditions(&case.conditions),
+    // Evaluate the condition tree of a case, groups short-circuit
+    pub fn evaluate_case(&self, case: &Case) -> GroupResult {
+        case.evaluate(|condition| self.evaluate_single_condition(condition))
+    }
+
+    // Value of the variable `offset` bars before the compared one, null when not received yet
+    fn variable_value(&self, variable: &Variable, offset: usize) -> VariableValue {
+        let bars_ago = variable.bars_ago as usize + offset;
+        if bars_ago == 0 {
+            return parse_condition_left_value(variable, &self.received_message);
+        }
+        self.value_history
+            .get(&(variable.node_id.clone(), variable.var_config_id, variable.var_name.clone()))
+            .map(|history| history.get(bars_ago))
+            .unwrap_or(VariableValue::Null)
+    }
+
+    fn right_value(&self, right: &FormulaRight, offset: usize) -> VariableValue {
+        match right {
+            FormulaRight::Variable(variable) => self.variable_value(variable, offset),
+            FormulaRight::Constant(constant) => constant.var_value.clone(),
         }
     }
 
     // Evaluate single condition
     fn evaluate_single_condition(&self, condition: &Condition) -> ConditionResult {
-        let received_value = &self.received_message;
-
-        // Get left value
-        let left_value = parse_condition_left_value(&condition.left, received_value);
-
-        // Get right value
-        let right_value = parse_condition_right_value(&condition.right, received_value);
+        // Get left and right value
+        let left_value = self.variable_value(&condition.left, 0);
+        let right_value = self.right_value(&condition.right, 0);
 
         // Get comparison symbol
         let comparison_symbol = &condition.comparison_symbol;
 
-        let compare_result = compare(&left_value, &right_value, comparison_symbol);
+        let compare_result = match comparison_symbol {
+            ComparisonSymbol::CrossesAbove | ComparisonSymbol::CrossesBelow => {
+                let prev_left_value = self.variable_value(&condition.left, 1);
+                let prev_right_value = self.right_value(&condition.right, 1);
+                compare_cross(&prev_left_value, &prev_right_value, &left_value, &right_value, comparison_symbol)
+            }
+            ComparisonSymbol::RisingFor | ComparisonSymbol::FallingFor => match right_value.as_bar_count() {
+                Some(bar_count) => {
+                    let values: Vec<VariableValue> = (0..=bar_count).map(|offset| self.variable_value(&condition.left, offset)).collect();
+                    compare_trend(&values, comparison_symbol)
+                }
+                None => false,
+            },
+            _ => compare(&left_value, &right_value, comparison_symbol),
+        };
         ConditionResult::new(condition, left_value, right_value, compare_result)
     }
-
-    // Evaluate AND condition group
-    fn evaluate_and_conditions(&self, conditions: &Vec<Condition>) -> (bool, Vec<ConditionResult>) {
-        // tracing::debug!("{}: Starting to evaluate AND condition group: {:#?}", self.node_id(), conditions);
-        if conditions.is_empty() {
-            return (true, vec![]); // Empty condition group defaults to true
-        }
-
-        let mut condition_results = vec![];
-        // Use iterator's all method, more concise and short-circuits at first false
-        let result = conditions.iter().all(|condition| {
-            let condition_result = self.evaluate_single_condition(condition);
-            let result = condition_result.condition_result.clone();
-            condition_results.push(condition_result);
-            result
-        });
-        (result, condition_results)
-    }
-
-    // Evaluate OR condition group
-    fn evaluate_or_conditions(&self, conditions: &Vec<Condition>) -> (bool, Vec<ConditionResult>) {
-        if conditions.is_empty() {
-            return (false, vec![]); // Empty condition group defaults to false
-        }
-
-        let mut condition_results = vec![];
-        // Use iterator's any method, more concise and short-circuits at first true
-        let result = conditions.iter().any(|condition| {
-            let condition_result = self.evaluate_single_condition(condition);
-            let result = condition_result.condition_result.clone();
-            condition_results.push(condition_result);
-            result
-        });
-        (result, condition_results)
-    }
 }
@@ -7,10 +7,16 @@ use strategy_core::{
 };
 
 use super::IfElseNodeContext;
-use crate::node::{
-    node_command::{BacktestNodeCommand, NodeResetRespPayload, NodeResetResponse},
-    node_error::IfElseNodeError,
-    node_event::BacktestNodeEvent,
+use crate::{
+    node::{
+        node_command::{
+            BacktestNodeCommand, GetNodeSnapshotRespPayload, GetNodeSnapshotResponse, NodeResetRespPayload, NodeResetResponse,
+            RestoreNodeSnapshotRespPayload, RestoreNodeSnapshotResponse,
+        },
+        node_error::IfElseNodeError,
+        node_event::BacktestNodeEvent,
+    },
+    node_catalog::if_else_node::utils::parse_variable_value,
 };
 
 #[async_trait]
@@ -21,11 +27,30 @@ impl NodeEventHandlerExt for IfElseNodeContext {
         match node_command {
             BacktestNodeCommand::NodeReset(cmd) => {
                 if self.node_id() == cmd.node_id() {
+                    self.clear_value_history();
                     let payload = NodeResetRespPayload;
                     let response = NodeResetResponse::success(self.node_id().clone(), self.node_name().clone(), payload);
                     cmd.respond(response);
                 }
             }
+            BacktestNodeCommand::GetNodeSnapshot(cmd) => {
+                if self.node_id() == cmd.node_id() {
+                    let payload = GetNodeSnapshotRespPayload::new(self.snapshot());
+                    let response = GetNodeSnapshotResponse::success(self.node_id().clone(), self.node_name().clone(), payload);
+                    cmd.respond(response);
+                }
+            }
+            BacktestNodeCommand::RestoreNodeSnapshot(cmd) => {
+                if self.node_id() == cmd.node_id() {
+                    self.restore_snapshot(cmd.snapshot.clone());
+                    let response = RestoreNodeSnapshotResponse::success(
+                        self.node_id().clone(),
+                        self.node_name().clone(),
+                        RestoreNodeSnapshotRespPayload,
+                    );
+                    cmd.respond(response);
+                }
+            }
             _ => {}
         }
     }
@@ -53,6 +78,7 @@ impl NodeEventHandlerExt for IfElseNodeContext {
             match ifelse_event {
                 IfElseNodeEvent::CaseTrue(_) | IfElseNodeEvent::ElseTrue(_) => {
                     self.set_superior_case_status(true);
+                    self.notify_if_ready();
                     return Ok(());
                 }
                 IfElseNodeEvent::CaseFalse(_) | IfElseNodeEvent::ElseFalse(_) => {
@@ -100,7 +126,17 @@ impl IfElseNodeContext {
             .or_insert(Some(received_event));
         // tracing::debug!("Received messages: {:?}", self.received_message);
 
+        // Values of this cycle go into the history, whether the conditions are evaluated or not
+        let cycle_id = self.cycle_id();
+        for ((node_id, config_id, var_name), history) in self.value_history.iter_mut() {
+            if *node_id == from_node_id && *config_id == from_variable_id {
+                let value = parse_variable_value(node_id.clone(), *config_id, var_name, &self.received_message);
+                history.push(cycle_id, value);
+            }
+        }
+
         self.update_received_flag(from_node_id, from_variable_id, true);
+        self.notify_if_ready();
         Ok(())
     }
 
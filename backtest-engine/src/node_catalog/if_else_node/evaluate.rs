@@ -12,31 +12,30 @@ use crate::node::node_error::IfElseNodeError;
 
 impl IfElseNode {
     pub async fn evaluate(&self) -> Result<(), IfElseNodeError> {
-        let (node_name, cancel_token) = self
+        let (node_name, cancel_token, evaluate_notify) = self
             .with_ctx_read(|ctx| {
                 let node_name = ctx.node_name().clone();
                 let cancel_token = ctx.cancel_token().clone();
-                (node_name, cancel_token)
+                let evaluate_notify = ctx.evaluate_notify().clone();
+                (node_name, cancel_token, evaluate_notify)
             })
             .await;
 
         let context = self.context().clone();
         tokio::spawn(async move {
             loop {
-                if cancel_token.is_cancelled() {
-                    tracing::info!("[{}] condition evaluation task cancelled", node_name);
-                    break;
+                // Sleep until the last input of a cycle arrives
+                tokio::select! {
+                    _ = cancel_token.cancelled() => {
+                        tracing::info!("[{}] condition evaluation task cancelled", node_name);
+                        break;
+                    }
+                    _ = evaluate_notify.notified() => {}
                 }
 
-                let should_evaluate = {
-                    let ctx_guard = context.read().await;
-                    // if node is nested, and superior case is true, then should evaluate
-                    ctx_guard.is_all_value_received() && (!ctx_guard.is_nested() || ctx_guard.superior_case_status())
-                };
-                // tracing::info!("[{}] should evaluate: {:?}", node_name, should_evaluate);
-
-                if should_evaluate {
-                    let mut ctx_guard = context.write().await;
+                let mut ctx_guard = context.write().await;
+                // The inputs may have been reset between the notification and taking the lock
+                if ctx_guard.is_ready_to_evaluate() {
                     if let Err(e) = ctx_guard.evaluate().await {
                         let current_time = ctx_guard.strategy_time();
                         let running_error_log: CommonEvent = NodeRunningLogEvent::error_with_time(
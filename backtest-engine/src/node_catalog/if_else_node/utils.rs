@@ -4,7 +4,7 @@ use rust_decimal::Decimal;
 use star_river_core::custom_type::NodeId;
 use star_river_event::backtest_strategy::node_event::{IndicatorNodeEvent, KlineNodeEvent, VariableNodeEvent};
 use strategy_core::{
-    node_infra::if_else_node::{ComparisonSymbol, FormulaRight, Variable},
+    node_infra::if_else_node::{ComparisonSymbol, Variable},
     variable::custom_variable::VariableValue,
 };
 
@@ -67,21 +67,6 @@ pub fn parse_condition_left_value(
     parse_variable_value(node_id.clone(), variable_id, variable_name, received_value)
 }
 
-pub fn parse_condition_right_value(
-    right: &FormulaRight,
-    received_value: &HashMap<(NodeId, ConfigId), Option<BacktestNodeEvent>>,
-) -> VariableValue {
-    match right {
-        FormulaRight::Variable(variable) => {
-            let node_id = variable.node_id.clone();
-            let variable_id = variable.var_config_id;
-            let variable_name = &variable.var_name;
-            parse_variable_value(node_id.clone(), variable_id, variable_name, received_value)
-        }
-        FormulaRight::Constant(constant) => constant.var_value.clone(),
-    }
-}
-
 pub fn compare(left: &VariableValue, right: &VariableValue, comparison_symbol: &ComparisonSymbol) -> bool {
     match (left, right) {
         // Compare number with number
@@ -141,3 +126,81 @@ pub fn compare(left: &VariableValue, right: &VariableValue, comparison_symbol: &
         _ => false,
     }
 }
+
+// Left crossed the right value between the previous and the current bar
+pub fn compare_cross(
+    prev_left: &VariableValue,
+    prev_right: &VariableValue,
+    left: &VariableValue,
+    right: &VariableValue,
+    comparison_symbol: &ComparisonSymbol,
+) -> bool {
+    match comparison_symbol {
+        ComparisonSymbol::CrossesAbove => {
+            compare(prev_left, prev_right, &ComparisonSymbol::LessThanOrEqual) && compare(left, right, &ComparisonSymbol::GreaterThan)
+        }
+        ComparisonSymbol::CrossesBelow => {
+            compare(prev_left, prev_right, &ComparisonSymbol::GreaterThanOrEqual) && compare(left, right, &ComparisonSymbol::LessThan)
+        }
+        _ => false,
+    }
+}
+
+// Values are newest first, each one has to be above (rising) or below (falling) the one before it
+pub fn compare_trend(values: &[VariableValue], comparison_symbol: &ComparisonSymbol) -> bool {
+    let step_symbol = match comparison_symbol {
+        ComparisonSymbol::RisingFor => ComparisonSymbol::GreaterThan,
+        ComparisonSymbol::FallingFor => ComparisonSymbol::LessThan,
+        _ => return false,
+    };
+    values.len() >= 2 && values.windows(2).all(|pair| compare(&pair[0], &pair[1], &step_symbol))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(value: i64) -> VariableValue {
+        VariableValue::from(value)
+    }
+
+    #[test]
+    fn test_compare_cross_and_trend() {
+        // 9 <= 10 then 11 > 10
+        assert!(compare_cross(
+            &number(9),
+            &number(10),
+            &number(11),
+            &number(10),
+            &ComparisonSymbol::CrossesAbove
+        ));
+        // Already above on the previous bar
+        assert!(!compare_cross(
+            &number(11),
+            &number(10),
+            &number(12),
+            &number(10),
+            &ComparisonSymbol::CrossesAbove
+        ));
+        assert!(compare_cross(
+            &number(10),
+            &number(10),
+            &number(9),
+            &number(10),
+            &ComparisonSymbol::CrossesBelow
+        ));
+        // Previous bar not received yet
+        assert!(!compare_cross(
+            &VariableValue::Null,
+            &number(10),
+            &number(11),
+            &number(10),
+            &ComparisonSymbol::CrossesAbove
+        ));
+
+        assert!(compare_trend(&[number(3), number(2), number(1)], &ComparisonSymbol::RisingFor));
+        assert!(!compare_trend(&[number(3), number(3), number(1)], &ComparisonSymbol::RisingFor));
+        assert!(compare_trend(&[number(1), number(2)], &ComparisonSymbol::FallingFor));
+        assert!(!compare_trend(&[number(1)], &ComparisonSymbol::FallingFor));
+    }
+}
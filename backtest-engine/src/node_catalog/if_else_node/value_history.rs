@@ -0,0 +1,73 @@
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+use star_river_core::custom_type::CycleId;
+use strategy_core::variable::{custom_variable::VariableValue, tagged_variable::value_list};
+
+/// Recent values of one condition variable, newest first
+///
+/// Only as many values as the conditions reading the variable need are kept
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValueHistory {
+    depth: usize,
+    // Cycle of the newest value, a value received again in the same cycle replaces it
+    cycle_id: Option<CycleId>,
+    #[serde(with = "value_list")]
+    values: VecDeque<VariableValue>,
+}
+
+impl ValueHistory {
+    pub fn new(depth: usize) -> Self {
+        Self {
+            depth,
+            cycle_id: None,
+            values: VecDeque::with_capacity(depth),
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+        self.values.truncate(depth);
+    }
+
+    pub fn push(&mut self, cycle_id: CycleId, value: VariableValue) {
+        if self.cycle_id == Some(cycle_id)
+            && let Some(newest) = self.values.front_mut()
+        {
+            *newest = value;
+            return;
+        }
+        self.cycle_id = Some(cycle_id);
+        self.values.push_front(value);
+        self.values.truncate(self.depth);
+    }
+
+    /// Value of `bars_ago` bars before the newest one, null when not received yet
+    pub fn get(&self, bars_ago: usize) -> VariableValue {
+        self.values.get(bars_ago).cloned().unwrap_or(VariableValue::Null)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_history_keeps_one_value_per_cycle() {
+        let mut history = ValueHistory::new(2);
+        history.push(1, VariableValue::from(1i64));
+        history.push(2, VariableValue::from(2i64));
+        // Received twice in cycle 2, the last value wins
+        history.push(2, VariableValue::from(3i64));
+        history.push(3, VariableValue::from(4i64));
+
+        assert_eq!(history.get(0), VariableValue::from(4i64));
+        assert_eq!(history.get(1), VariableValue::from(3i64));
+        assert!(history.get(2).is_null());
+    }
+}
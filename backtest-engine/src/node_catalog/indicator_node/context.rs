@@ -25,7 +25,10 @@ use strategy_core::{
 use super::{indicator_node_type::IndicatorNodeBacktestConfig, state_machine::IndicatorNodeStateMachine};
 // Crate imports
 use crate::{
-    node::{node_command::BacktestNodeCommand, node_error::IndicatorNodeError, node_event::BacktestNodeEvent, node_utils::NodeUtils},
+    node::{
+        node_command::BacktestNodeCommand, node_error::IndicatorNodeError, node_event::BacktestNodeEvent, node_snapshot::NodeSnapshot,
+        node_utils::NodeUtils,
+    },
     strategy::strategy_command::BacktestStrategyCommand,
 };
 
@@ -40,7 +43,7 @@ pub struct IndicatorNodeContext {
     cache_kline_slice: HashMap<IndicatorKey, Vec<Kline>>, // Indicator key -> kline values
     indicator_lookback: HashMap<IndicatorKey, usize>,     // Indicator key -> lookback
     min_interval: KlineInterval,
-    correct_index: u64, // Correct index
+    last_indicator_index: Option<u64>, // Index of the last indicator value found, hint of the next lookup
 }
 
 impl IndicatorNodeContext {
@@ -58,7 +61,7 @@ impl IndicatorNodeContext {
             cache_kline_slice: HashMap::new(),
             indicator_lookback: HashMap::new(),
             min_interval: KlineInterval::Months1,
-            correct_index: 0,
+            last_indicator_index: None,
         }
     }
 
@@ -73,6 +76,18 @@ impl IndicatorNodeContext {
     pub fn indicator_keys(&self) -> &HashMap<IndicatorKey, (i32, String)> {
         &self.indicator_keys
     }
+
+    pub fn snapshot(&self) -> NodeSnapshot {
+        NodeSnapshot::Indicator {
+            cache_kline_slice: self.cache_kline_slice.clone(),
+        }
+    }
+
+    pub fn restore_snapshot(&mut self, snapshot: NodeSnapshot) {
+        if let NodeSnapshot::Indicator { cache_kline_slice } = snapshot {
+            self.cache_kline_slice = cache_kline_slice;
+        }
+    }
 }
 
 impl NodeMetaDataExt for IndicatorNodeContext {
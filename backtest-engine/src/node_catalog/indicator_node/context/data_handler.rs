@@ -60,14 +60,9 @@ impl IndicatorNodeContext {
         &mut self,
         indicator_key: &IndicatorKey,
     ) -> Result<Option<Indicator>, IndicatorNodeError> {
-        // Calculate index hint based on correct_index
-        // If cycle_id is sequential (correct_index + 1), use cycle_id for fast path
-        // Otherwise, use correct_index + 1 to recover from index drift
-        let index = if self.cycle_id() == self.correct_index + 1 {
-            Some(self.cycle_id()) // Sequential playback, use cycle_id directly
-        } else {
-            Some(self.correct_index + 1) // Index drift detected, use corrected value
-        };
+        // The signal index is not the indicator index (warm-up bars, session gaps, merged symbol timestamps),
+        // the value after the last one found is the hint, the strategy falls back to a binary search on a miss
+        let index = self.last_indicator_index.map(|index| index + 1);
 
         let (resp_tx, resp_rx) = oneshot::channel();
         let payload = GetIndicatorDataCmdPayload::new(indicator_key.clone(), Some(self.strategy_time()), index, Some(1));
@@ -81,9 +76,8 @@ impl IndicatorNodeContext {
         })?;
         match response {
             StrategyResponse::Success { payload, .. } => {
-                // Update correct_index if provided in response
                 if let Some(correct_index) = payload.correct_index {
-                    self.correct_index = correct_index;
+                    self.last_indicator_index = Some(correct_index);
                 }
                 return Ok(payload.indicator_series.first().cloned());
             }
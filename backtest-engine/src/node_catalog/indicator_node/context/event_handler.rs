@@ -24,7 +24,10 @@ use tokio::sync::oneshot;
 use super::IndicatorNodeContext;
 use crate::{
     node::{
-        node_command::{BacktestNodeCommand, NodeResetRespPayload, NodeResetResponse},
+        node_command::{
+            BacktestNodeCommand, GetNodeSnapshotRespPayload, GetNodeSnapshotResponse, NodeResetRespPayload, NodeResetResponse,
+            RestoreNodeSnapshotRespPayload, RestoreNodeSnapshotResponse,
+        },
         node_error::{
             IndicatorNodeError,
             indicator_node_error::{CalculateIndicatorFailedSnafu, CalculateResultEmptySnafu},
@@ -50,6 +53,24 @@ impl NodeEventHandlerExt for IndicatorNodeContext {
                     cmd.respond(response);
                 }
             }
+            BacktestNodeCommand::GetNodeSnapshot(cmd) => {
+                if self.node_id() == cmd.node_id() {
+                    let payload = GetNodeSnapshotRespPayload::new(self.snapshot());
+                    let response = GetNodeSnapshotResponse::success(self.node_id().clone(), self.node_name().clone(), payload);
+                    cmd.respond(response);
+                }
+            }
+            BacktestNodeCommand::RestoreNodeSnapshot(cmd) => {
+                if self.node_id() == cmd.node_id() {
+                    self.restore_snapshot(cmd.snapshot.clone());
+                    let response = RestoreNodeSnapshotResponse::success(
+                        self.node_id().clone(),
+                        self.node_name().clone(),
+                        RestoreNodeSnapshotRespPayload,
+                    );
+                    cmd.respond(response);
+                }
+            }
             _ => {}
         }
     }
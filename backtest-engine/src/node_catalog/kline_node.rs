@@ -31,6 +31,7 @@ use crate::{
         node_state_machine::NodeRunState,
     },
     node_catalog::kline_node::state_machine::{KlineNodeStateMachine, kline_node_transition},
+    optimizer::KlineHistoryCache,
     strategy::strategy_command::BacktestStrategyCommand,
 };
 
@@ -61,6 +62,7 @@ impl KlineNode {
         strategy_command_sender: mpsc::Sender<BacktestStrategyCommand>,
         node_command_receiver: Arc<Mutex<mpsc::Receiver<BacktestNodeCommand>>>,
         strategy_time_watch_rx: watch::Receiver<DateTime<Utc>>,
+        kline_history_cache: Option<KlineHistoryCache>,
     ) -> Result<Self, KlineNodeError> {
         let (strategy_id, node_id, node_name, node_config) = Self::check_kline_node_config(node_config)?;
 
@@ -91,7 +93,7 @@ impl KlineNode {
             node_command_receiver,
         );
 
-        let context = KlineNodeContext::new(metadata, node_config)?;
+        let context = KlineNodeContext::new(metadata, node_config, kline_history_cache)?;
         Ok(Self {
             inner: NodeBase::new(context),
         })
@@ -147,15 +149,6 @@ impl KlineNode {
                 node_name: node_name.clone(),
             })?;
 
-        kline_node_backtest_config
-            .get("exchangeModeConfig")
-            .and_then(|config| config.get("selectedSymbols"))
-            .and_then(|symbols| symbols.as_array())
-            .and_then(|arr| if arr.is_empty() { None } else { Some(arr) })
-            .context(SymbolsIsNotConfiguredSnafuSnafu {
-                node_name: node_name.clone(),
-            })?;
-
         // Add nodeName to the backtest config
         if let Some(obj) = kline_node_backtest_config.as_object_mut() {
             obj.insert("nodeName".to_string(), serde_json::Value::String(node_name.clone()));
@@ -166,6 +159,14 @@ impl KlineNode {
                 node_name: node_name.clone(),
             })?;
 
+        // exchange symbols come from the exchange mode config, file symbols from the file config
+        if node_config.selected_symbols()?.is_empty() {
+            return SymbolsIsNotConfiguredSnafuSnafu {
+                node_name: node_name.clone(),
+            }
+            .fail();
+        }
+
         Ok((strategy_id, node_id, node_name, node_config))
     }
 }
@@ -4,6 +4,7 @@ mod node_handles;
 mod binance_data_handler;
 // mod command_handler;
 mod data_handler;
+mod file_data_handler;
 mod mt5_data_handler;
 mod status_handler;
 mod utils;
@@ -28,6 +29,7 @@ use strategy_core::{
 use super::{kline_node_type::KlineNodeBacktestConfig, state_machine::KlineNodeStateMachine};
 use crate::{
     node::{node_command::BacktestNodeCommand, node_error::kline_node_error::*, node_event::BacktestNodeEvent},
+    optimizer::KlineHistoryCache,
     strategy::strategy_command::BacktestStrategyCommand,
 };
 
@@ -39,25 +41,29 @@ pub struct KlineNodeContext {
     pub node_config: KlineNodeBacktestConfig,
     min_interval: KlineInterval,
     selected_symbol_keys: HashMap<KlineKey, (i32, String)>, // Configured symbol keys -> (config_id, output_handle_id)
-    correct_index: u64,                                     // Correct index (needs correction if index is incorrect)
+    kline_history_cache: Option<KlineHistoryCache>,         // Kline history shared by optimization trials
+    last_kline_index: HashMap<KlineKey, u64>,               // Index of the last kline found per symbol, hint of the next lookup
 }
 
 impl KlineNodeContext {
-    pub fn new(metadata: KlineNodeMetadata, node_config: KlineNodeBacktestConfig) -> Result<Self, KlineNodeError> {
+    pub fn new(
+        metadata: KlineNodeMetadata,
+        node_config: KlineNodeBacktestConfig,
+        kline_history_cache: Option<KlineHistoryCache>,
+    ) -> Result<Self, KlineNodeError> {
         let exchange = node_config.exchange_mode()?.selected_account.exchange.clone();
-        let time_range = node_config.exchange_mode()?.time_range.clone();
+        let time_range = node_config.time_range()?.cloned();
 
         let selected_symbol_keys = node_config
-            .exchange_mode()?
-            .selected_symbols
+            .selected_symbols()?
             .iter()
             .map(|symbol| {
                 let kline_key = KlineKey::new(
                     exchange.clone(),
                     symbol.symbol.clone(),
                     symbol.interval.clone(),
-                    Some(time_range.start_date.to_string()),
-                    Some(time_range.end_date.to_string()),
+                    time_range.as_ref().map(|time_range| time_range.start_date.to_string()),
+                    time_range.as_ref().map(|time_range| time_range.end_date.to_string()),
                 );
                 (kline_key, (symbol.config_id, symbol.output_handle_id.clone()))
             })
@@ -68,7 +74,8 @@ impl KlineNodeContext {
             node_config,
             min_interval: KlineInterval::Minutes1,
             selected_symbol_keys,
-            correct_index: 0,
+            kline_history_cache,
+            last_kline_index: HashMap::new(),
         })
     }
 
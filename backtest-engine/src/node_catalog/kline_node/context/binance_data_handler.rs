@@ -8,7 +8,11 @@ use event_center::{CmdRespRecvFailedSnafu, EventCenterSingleton};
 use event_center_core::communication::response::Response;
 use key::{KeyTrait, KlineKey, error::TimeRangeNotSetSnafu};
 use snafu::{IntoError, OptionExt, ResultExt};
-use star_river_core::{custom_type::AccountId, kline::KlineInterval, system::TimeRange};
+use star_river_core::{
+    custom_type::AccountId,
+    kline::{Kline, KlineInterval},
+    system::TimeRange,
+};
 use star_river_event::communication::{GetKlineHistoryCmdPayload, GetKlineHistoryCommand, MarketEngineCommand};
 use strategy_core::node::context_trait::NodeInfoExt;
 use tokio::sync::{Semaphore, oneshot};
@@ -21,9 +25,17 @@ use crate::node::node_error::kline_node_error::{
 };
 
 impl KlineNodeContext {
-    pub(super) async fn get_binance_kline_history(&self, account_id: AccountId, time_range: &TimeRange) -> Result<(), KlineNodeError> {
-        let bar_number = bar_number(&time_range, &self.min_interval);
+    // Validate and fetch the kline history of one min interval symbol
+    pub(super) async fn fetch_binance_kline_history(
+        &self,
+        account_id: AccountId,
+        symbol_key: &KlineKey,
+        time_range: &TimeRange,
+    ) -> Result<Vec<Kline>, KlineNodeError> {
+        // Validate data availability
+        self.validate_binance_data_availability(account_id, symbol_key, time_range).await?;
 
+        let bar_number = bar_number(time_range, &self.min_interval);
         // Binance API limit is 1000 bars per request, use concurrent loading if > 1000
         if bar_number >= 1000 {
             tracing::info!(
@@ -31,49 +43,10 @@ impl KlineNodeContext {
                 self.node_name(),
                 bar_number
             );
-
-            for (symbol_key, _) in self.selected_symbol_keys.iter() {
-                tracing::debug!("selected symbol key: {:?}", symbol_key);
-                if symbol_key.interval() != self.min_interval {
-                    tracing::debug!(
-                        "[{}] symbol: {}-{}, is not min interval, skip",
-                        self.node_name(),
-                        symbol_key.symbol(),
-                        symbol_key.interval()
-                    );
-                    continue;
-                }
-
-                // Validate data availability before concurrent loading
-                self.validate_binance_data_availability(account_id.clone(), &symbol_key, time_range)
-                    .await?;
-                // Use concurrent loading
-                self.load_binance_symbol_concurrently(account_id.clone(), symbol_key.clone())
-                    .await?;
-            }
+            self.load_binance_symbol_concurrently(account_id, symbol_key.clone()).await
         } else {
-            // Sequential loading for small datasets
-            for (symbol_key, _) in self.selected_symbol_keys.iter() {
-                if symbol_key.interval() != self.min_interval {
-                    tracing::warn!(
-                        "[{}] symbol: {}-{}, is not min interval, skip",
-                        self.node_name(),
-                        symbol_key.symbol(),
-                        symbol_key.interval()
-                    );
-                    continue;
-                }
-                // Validate data availability
-                self.validate_binance_data_availability(account_id.clone(), &symbol_key, time_range)
-                    .await?;
-
-                // Fetch and initialize kline data
-                let kline_history = self.request_kline_history(account_id.clone(), symbol_key).await?;
-                self.init_strategy_kline_data(symbol_key, &kline_history).await?;
-            }
+            self.request_kline_history(account_id, symbol_key).await
         }
-
-        Ok(())
     }
 
     // Get the first kline to validate data availability for Binance
@@ -136,7 +109,7 @@ impl KlineNodeContext {
         Ok(())
     }
 
-    async fn load_binance_symbol_concurrently(&self, account_id: AccountId, symbol_key: KlineKey) -> Result<(), KlineNodeError> {
+    async fn load_binance_symbol_concurrently(&self, account_id: AccountId, symbol_key: KlineKey) -> Result<Vec<Kline>, KlineNodeError> {
         let time_range = symbol_key.time_range().context(TimeRangeNotSetSnafu {
             exchange: symbol_key.exchange().to_string(),
             symbol: symbol_key.symbol().to_string(),
@@ -195,7 +168,8 @@ impl KlineNodeContext {
             handles.push(handle);
         }
 
-        // Append kline data as each handle completes
+        // Collect the chunks in time order, overlapping bars are removed when they are appended to the strategy
+        let mut kline_history = Vec::new();
         for handle in handles {
             let chunk_klines = handle.await.context(FetchKlineDataTaskFailedSnafu {
                 node_name: self.node_name().clone(),
@@ -203,10 +177,10 @@ impl KlineNodeContext {
                 symbol: symbol_key.symbol().to_string(),
                 interval: symbol_key.interval().to_string(),
             })??;
-            self.append_kline_data(&symbol_key, &chunk_klines).await?;
+            kline_history.extend(chunk_klines);
         }
 
-        Ok(())
+        Ok(kline_history)
     }
 
     fn split_time_range_for_binance(&self, time_range: &TimeRange, interval: &KlineInterval) -> Vec<TimeRange> {
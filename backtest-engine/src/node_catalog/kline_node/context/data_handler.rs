@@ -1,5 +1,5 @@
 use chrono::{DateTime, Utc};
-use key::KlineKey;
+use key::{KeyTrait, KlineKey};
 use snafu::{IntoError, ResultExt};
 use star_river_core::kline::Kline;
 use strategy_core::{
@@ -12,23 +12,58 @@ use tokio::sync::oneshot;
 
 // current crate
 use super::{KlineNodeContext, KlineNodeError};
-use crate::strategy::strategy_command::{GetKlineDataCmdPayload, GetKlineDataCommand};
+use crate::{
+    node::node_error::kline_node_error::MissingBarSnafu,
+    node_catalog::kline_node::kline_node_type::MissingBarPolicy,
+    strategy::{
+        signal_schedule::interval_time_at,
+        strategy_command::{GetKlineDataCmdPayload, GetKlineDataCommand},
+    },
+};
 
 impl KlineNodeContext {
+    // Min interval kline of the current signal, a symbol without one is handled by the missing bar policy
+    pub async fn get_signal_kline_from_strategy(&mut self, kline_key: &KlineKey) -> Result<Option<Kline>, KlineNodeError> {
+        let signal_time = self.strategy_time();
+        // The strategy returns the last kline at or before the signal time
+        let Some(kline) = self.get_single_kline_from_strategy(kline_key, Some(signal_time)).await? else {
+            return Ok(None);
+        };
+        if interval_time_at(kline.datetime(), &kline_key.interval(), 1) > signal_time {
+            return Ok(Some(kline));
+        }
+
+        match self.node_config.missing_bar_policy {
+            // Flat kline at the last close, merging it into a higher interval leaves that kline unchanged
+            MissingBarPolicy::ForwardFill => Ok(Some(Kline::new(
+                signal_time,
+                kline.close,
+                kline.close,
+                kline.close,
+                kline.close,
+                0.0,
+            ))),
+            MissingBarPolicy::Skip => Ok(None),
+            MissingBarPolicy::Error => MissingBarSnafu {
+                node_name: self.node_name().clone(),
+                symbol: kline_key.symbol(),
+                interval: kline_key.interval().to_string(),
+                datetime: signal_time.to_string(),
+                last_datetime: kline.datetime().to_string(),
+            }
+            .fail(),
+        }
+    }
+
     // Get kline data from strategy
     pub async fn get_single_kline_from_strategy(
         &mut self,
         kline_key: &KlineKey,
         datetime: Option<DateTime<Utc>>,
     ) -> Result<Option<Kline>, KlineNodeError> {
-        // Calculate index hint based on correct_index
-        // If cycle_id is sequential (correct_index + 1), use cycle_id for fast path
-        // Otherwise, use correct_index + 1 to recover from index drift
-        let index = if self.cycle_id() == self.correct_index + 1 {
-            Some(self.cycle_id()) // Sequential playback, use cycle_id directly
-        } else {
-            Some(self.correct_index + 1) // Index drift detected, use corrected value
-        };
+        // The signal index is not the kline index (warm-up bars, session gaps, merged symbol timestamps),
+        // the kline after the last one found is the hint, the strategy falls back to a binary search on a miss
+        let index = self.last_kline_index.get(kline_key).map(|index| index + 1);
 
         let (resp_tx, resp_rx) = oneshot::channel();
         let payload = GetKlineDataCmdPayload::new(kline_key.clone(), datetime, index, Some(1));
@@ -42,7 +77,7 @@ impl KlineNodeContext {
         match response {
             StrategyResponse::Success { payload, .. } => {
                 if let Some(correct_index) = payload.correct_index {
-                    self.correct_index = correct_index;
+                    self.last_kline_index.insert(kline_key.clone(), correct_index);
                 }
                 return Ok(payload.kline_series.first().cloned());
             }
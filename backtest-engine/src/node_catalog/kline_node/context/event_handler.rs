@@ -14,14 +14,18 @@ use strategy_core::{
 use tokio::sync::oneshot;
 
 // current crate
-use super::{KlineNodeContext, utils::is_cross_interval};
+use super::KlineNodeContext;
 // workspace crate
-use crate::node::node_error::kline_node_error::{GetMinIntervalFromStrategyFailedSnafu, PendingUpdateKlineNotExistSnafu};
+use crate::node::node_error::kline_node_error::GetMinIntervalFromStrategyFailedSnafu;
 use crate::{
     node::{
-        node_command::{BacktestNodeCommand, NodeResetRespPayload, NodeResetResponse},
+        node_command::{
+            BacktestNodeCommand, GetNodeSnapshotRespPayload, GetNodeSnapshotResponse, NodeResetRespPayload, NodeResetResponse,
+            RestoreNodeSnapshotRespPayload, RestoreNodeSnapshotResponse,
+        },
         node_error::kline_node_error::KlineNodeError,
         node_event::{BacktestNodeEvent, KlineNodeEvent, StartNodeEvent},
+        node_snapshot::NodeSnapshot,
     },
     strategy::strategy_command::{GetMinIntervalCmdPayload, GetMinIntervalCommand, UpdateKlineDataCmdPayload, UpdateKlineDataCommand},
 };
@@ -115,82 +119,69 @@ impl KlineNodeContext {
     }
 
     // Dedicated method for handling interpolation algorithm
+    // Higher interval klines are resampled from the min interval kline of the current cycle only,
+    // so the last kline is a partially-formed bar until the next period begins (no look-ahead)
     async fn handle_interpolated_kline(&mut self, symbol_key: &KlineKey, symbol_info: &(i32, String)) -> Result<(), KlineNodeError> {
         // Clone kline_key and set to minimum interval
         let mut min_interval_kline_key = symbol_key.clone();
         min_interval_kline_key.interval = self.min_interval.clone();
 
         // Get kline data from strategy
-        let min_interval_kline = self
-            .get_single_kline_from_strategy(&min_interval_kline_key, Some(self.strategy_time()))
-            .await?;
-        if let Some(min_interval_kline) = min_interval_kline {
-            // Check current cycle_id
-            if self.cycle_id() == 0 {
-                // If cycle_id is 0, insert new kline to cache engine
-                self.insert_new_kline_to_strategy(symbol_key, &min_interval_kline).await?;
+        let min_interval_kline = self.get_signal_kline_from_strategy(&min_interval_kline_key).await?;
+        let Some(min_interval_kline) = min_interval_kline else {
+            return self
+                .handle_event_send(
+                    symbol_info,
+                    symbol_key,
+                    false,
+                    None,
+                    false,
+                    Some("no min interval kline".to_string()),
+                )
+                .await;
+        };
+
+        // Core step (interpolation algorithm)
+        // Open time of the period that the min interval kline belongs to
+        let open_time = symbol_key.interval().open_time(min_interval_kline.datetime())?;
+        // If cycle_id is 0, there is no pending kline in cache engine yet
+        let last_kline = if self.cycle_id() == 0 {
+            None
+        } else {
+            self.get_single_kline_from_strategy(symbol_key, None).await?
+        };
+
+        match last_kline {
+            // Same period, update value in cache engine
+            Some(last_kline) if last_kline.datetime() == open_time => {
+                let new_kline = self.update_existing_kline(&last_kline, symbol_key, &min_interval_kline).await?;
                 self.handle_event_send(
                     symbol_info,
                     symbol_key,
                     true,
-                    Some(min_interval_kline.clone()),
+                    Some(new_kline),
                     false,
-                    Some("insert first new kline".to_string()),
+                    Some("update existing kline".to_string()),
+                )
+                .await
+            }
+            // New period (also covers missing min interval klines at the period boundary), insert new kline to cache engine
+            _ => {
+                let new_kline = Kline {
+                    datetime: open_time,
+                    ..min_interval_kline
+                };
+                self.insert_new_kline_to_strategy(symbol_key, &new_kline).await?;
+                self.handle_event_send(
+                    symbol_info,
+                    symbol_key,
+                    true,
+                    Some(new_kline),
+                    false,
+                    Some("insert cross interval new kline".to_string()),
                 )
                 .await
-            } else {
-                // Core step (interpolation algorithm)
-                let current_interval = symbol_key.interval();
-                let is_cross_interval = is_cross_interval(&current_interval, &min_interval_kline.datetime());
-
-                if is_cross_interval {
-                    // If current is a new period, insert new kline to cache engine
-                    self.insert_new_kline_to_strategy(symbol_key, &min_interval_kline).await?;
-                    // Send kline event
-                    self.handle_event_send(
-                        symbol_info,
-                        symbol_key,
-                        true,
-                        Some(min_interval_kline.clone()),
-                        false,
-                        Some("insert cross interval new kline".to_string()),
-                    )
-                    .await
-                } else {
-                    // If current is not a new period, update value in cache engine
-                    let last_kline = self.get_single_kline_from_strategy(symbol_key, None).await?;
-                    if let Some(last_kline) = last_kline {
-                        let new_kline = self.update_existing_kline(&last_kline, symbol_key, &min_interval_kline).await?;
-                        // Send kline event
-                        self.handle_event_send(
-                            symbol_info,
-                            symbol_key,
-                            true,
-                            Some(new_kline),
-                            false,
-                            Some("update existing kline".to_string()),
-                        )
-                        .await
-                    } else {
-                        return Err(PendingUpdateKlineNotExistSnafu {
-                            symbol: symbol_key.symbol().to_string(),
-                            interval: symbol_key.interval().to_string(),
-                        }
-                        .build());
-                    }
-                }
             }
-        } else {
-            self.handle_event_send(
-                symbol_info,
-                symbol_key,
-                false,
-                None,
-                false,
-                Some("no min interval kline".to_string()),
-            )
-            .await?;
-            Ok(())
         }
     }
 
@@ -228,23 +219,7 @@ impl KlineNodeContext {
         symbol_key: &KlineKey,
         min_interval_kline: &Kline,
     ) -> Result<Kline, KlineNodeError> {
-        // Current open, close, high, low of minimum interval kline
-        let min_interval_close = min_interval_kline.close();
-        let min_interval_high = min_interval_kline.high();
-        let min_interval_low = min_interval_kline.low();
-        let min_interval_volume = min_interval_kline.volume();
-
-        // Calculate new kline's open, close, high, low
-        let new_high = pending_update_kline.high().max(min_interval_high);
-        let new_low = pending_update_kline.low().min(min_interval_low);
-        let new_kline = Kline::new(
-            pending_update_kline.datetime(), // Time must match last_kline's time since it's based on last_kline update
-            pending_update_kline.open(),     // Same time has same open price
-            new_high,                        // High price
-            new_low,                         // Low price
-            min_interval_close,              // Close price
-            pending_update_kline.volume() + min_interval_volume, // Volume accumulation
-        );
+        let new_kline = pending_update_kline.merge(min_interval_kline);
 
         // Update to cache engine
         let (resp_tx, resp_rx) = oneshot::channel();
@@ -273,7 +248,7 @@ impl KlineNodeContext {
         symbol_key: &KlineKey,
         symbol_info: &(i32, String), // (config_id, handle_id)
     ) -> Result<(), KlineNodeError> {
-        let kline = self.get_single_kline_from_strategy(symbol_key, Some(self.strategy_time())).await?;
+        let kline = self.get_signal_kline_from_strategy(symbol_key).await?;
         self.handle_event_send(
             symbol_info,
             symbol_key,
@@ -325,6 +300,23 @@ impl NodeEventHandlerExt for KlineNodeContext {
                     cmd.respond(response);
                 }
             }
+            BacktestNodeCommand::GetNodeSnapshot(cmd) => {
+                if self.node_id() == cmd.node_id() {
+                    let payload = GetNodeSnapshotRespPayload::new(NodeSnapshot::Stateless);
+                    let response = GetNodeSnapshotResponse::success(self.node_id().clone(), self.node_name().clone(), payload);
+                    cmd.respond(response);
+                }
+            }
+            BacktestNodeCommand::RestoreNodeSnapshot(cmd) => {
+                if self.node_id() == cmd.node_id() {
+                    let response = RestoreNodeSnapshotResponse::success(
+                        self.node_id().clone(),
+                        self.node_name().clone(),
+                        RestoreNodeSnapshotRespPayload,
+                    );
+                    cmd.respond(response);
+                }
+            }
             _ => {}
         }
     }
@@ -0,0 +1,626 @@
+// std
+use std::{fs::File, io::Read};
+
+// third-party
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use parquet::{
+    file::reader::{FileReader, SerializedFileReader},
+    record::Field,
+};
+use snafu::{OptionExt, ResultExt};
+// workspace crate
+use key::{KeyTrait, KlineKey};
+use star_river_core::{
+    kline::{Kline, KlineInterval},
+    system::TimeRange,
+};
+use strategy_core::node::context_trait::NodeInfoExt;
+
+// current crate
+use super::{KlineNodeContext, KlineNodeError};
+use crate::{
+    node::node_error::kline_node_error::{
+        FetchKlineDataTaskFailedSnafu, InsufficientBacktestDataSnafu, InvalidKlineFileValueSnafu, KlineFileColumnNotFoundSnafu,
+        KlineFileGapDetectedSnafu, KlineFileIntervalMismatchSnafu, KlineFileNoDataInRangeSnafu, KlineFileTimestampNotMonotonicSnafu,
+        OpenKlineFileFailedSnafu, ReadCsvKlineFileFailedSnafu, ReadParquetKlineFileFailedSnafu, UnsupportedKlineFileFormatSnafu,
+    },
+    node_catalog::kline_node::kline_node_type::{ColumnRef, FileConfig, KlineFileFormat, KlineGapPolicy, TimestampFormat},
+};
+
+// Naive datetime formats tried by `TimestampFormat::Auto`
+const AUTO_DATETIME_FORMATS: [&str; 6] = [
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y.%m.%d %H:%M:%S",
+    "%Y.%m.%d %H:%M",
+    "%Y/%m/%d %H:%M:%S",
+];
+
+// Numeric timestamps above this value are treated as milliseconds by `TimestampFormat::Auto`
+const AUTO_MILLIS_THRESHOLD: f64 = 1e11;
+
+impl KlineNodeContext {
+    // Load kline history from local files (only load minimum interval klines)
+    pub async fn load_kline_history_from_file(&self) -> Result<(), KlineNodeError> {
+        let file_config = self.node_config.file_mode()?.clone();
+        let time_range = self.node_config.time_range()?.cloned();
+
+        for (symbol_key, _) in self.selected_symbol_keys.iter() {
+            if symbol_key.interval() != self.min_interval {
+                tracing::debug!(
+                    "[{}] symbol: {}-{}, is not min interval, skip",
+                    self.node_name(),
+                    symbol_key.symbol(),
+                    symbol_key.interval()
+                );
+                continue;
+            }
+
+            let file_path = file_config.resolve_file_path(&symbol_key.symbol(), &symbol_key.interval());
+            tracing::info!(
+                "[{}] start to load {}-{} klines from file: {}",
+                self.node_name(),
+                symbol_key.symbol(),
+                symbol_key.interval(),
+                file_path
+            );
+
+            let kline_series = self.read_kline_file(symbol_key, &file_config, &file_path).await?;
+
+            // The file must cover the start of a configured time range, without one the whole file is played
+            let kline_series = match &time_range {
+                Some(time_range) => {
+                    if let Some(first_kline) = kline_series.first()
+                        && first_kline.datetime() > time_range.start_date
+                    {
+                        return InsufficientBacktestDataSnafu {
+                            first_kline_datetime: first_kline.datetime().to_string(),
+                            symbol: symbol_key.symbol(),
+                            interval: symbol_key.interval().to_string(),
+                            exchange: symbol_key.exchange().to_string(),
+                            start_time: time_range.start_date.to_string(),
+                            end_time: time_range.end_date.to_string(),
+                        }
+                        .fail();
+                    }
+                    select_klines_in_range(&file_path, &kline_series, time_range)?
+                }
+                None => kline_series,
+            };
+            let (gap_count, missing_bars) = check_kline_gaps(&file_path, &kline_series, &symbol_key.interval(), &file_config.gap_policy)?;
+            if gap_count > 0 && file_config.gap_policy == KlineGapPolicy::Warn {
+                tracing::warn!(
+                    "[{}] {} gaps ({} bars) found in {}-{} klines of file: {}",
+                    self.node_name(),
+                    gap_count,
+                    missing_bars,
+                    symbol_key.symbol(),
+                    symbol_key.interval(),
+                    file_path
+                );
+            }
+
+            tracing::info!(
+                "[{}] loaded {} klines of {}-{} from file: {}",
+                self.node_name(),
+                kline_series.len(),
+                symbol_key.symbol(),
+                symbol_key.interval(),
+                file_path
+            );
+            self.init_strategy_kline_data(symbol_key, &kline_series).await?;
+        }
+
+        Ok(())
+    }
+
+    // Parse the file on the blocking pool, files can be large
+    async fn read_kline_file(
+        &self,
+        symbol_key: &KlineKey,
+        file_config: &FileConfig,
+        file_path: &str,
+    ) -> Result<Vec<Kline>, KlineNodeError> {
+        let file_format = file_config
+            .resolve_file_format(file_path)
+            .context(UnsupportedKlineFileFormatSnafu { file_path })?;
+
+        let read_config = file_config.clone();
+        let read_path = file_path.to_string();
+        let kline_series = tokio::task::spawn_blocking(move || {
+            let file = File::open(&read_path).context(OpenKlineFileFailedSnafu {
+                file_path: read_path.clone(),
+            })?;
+            let kline_series = match file_format {
+                KlineFileFormat::Csv => read_csv_klines(&read_path, file, &read_config)?,
+                KlineFileFormat::Parquet => read_parquet_klines(&read_path, file, &read_config)?,
+            };
+            check_kline_order(&read_path, &kline_series)?;
+            Ok::<Vec<Kline>, KlineNodeError>(kline_series)
+        })
+        .await
+        .context(FetchKlineDataTaskFailedSnafu {
+            node_name: self.node_name().clone(),
+            exchange: symbol_key.exchange().to_string(),
+            symbol: symbol_key.symbol(),
+            interval: symbol_key.interval().to_string(),
+        })??;
+
+        Ok(kline_series)
+    }
+}
+
+fn read_csv_klines<R: Read>(file_path: &str, reader: R, file_config: &FileConfig) -> Result<Vec<Kline>, KlineNodeError> {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .delimiter(file_config.delimiter as u8)
+        .has_headers(file_config.has_header)
+        .trim(csv::Trim::All)
+        .from_reader(reader);
+
+    let headers: Vec<String> = if file_config.has_header {
+        csv_reader
+            .headers()
+            .context(ReadCsvKlineFileFailedSnafu { file_path })?
+            .iter()
+            .map(|header| header.trim_start_matches('\u{feff}').to_string())
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let columns = KlineColumnIndexes::resolve(file_path, &headers, file_config)?;
+
+    let mut kline_series = Vec::new();
+    for (row_index, record) in csv_reader.records().enumerate() {
+        let record = record.context(ReadCsvKlineFileFailedSnafu { file_path })?;
+        let row = row_index + 1;
+        let cell = |index: usize, column: &ColumnRef| {
+            record.get(index).context(KlineFileColumnNotFoundSnafu {
+                file_path,
+                column: column.to_string(),
+            })
+        };
+
+        let mapping = &file_config.column_mapping;
+        let datetime_value = cell(columns.datetime, &mapping.datetime)?;
+        let datetime = parse_timestamp_str(datetime_value, &file_config.timestamp_format, file_config.timezone.as_ref()).context(
+            InvalidKlineFileValueSnafu {
+                file_path,
+                row,
+                column: mapping.datetime.to_string(),
+                value: datetime_value,
+            },
+        )?;
+        let open = parse_price(file_path, row, &mapping.open, cell(columns.open, &mapping.open)?)?;
+        let high = parse_price(file_path, row, &mapping.high, cell(columns.high, &mapping.high)?)?;
+        let low = parse_price(file_path, row, &mapping.low, cell(columns.low, &mapping.low)?)?;
+        let close = parse_price(file_path, row, &mapping.close, cell(columns.close, &mapping.close)?)?;
+        let volume = match (columns.volume, &mapping.volume) {
+            (Some(index), Some(column)) => parse_price(file_path, row, column, cell(index, column)?)?,
+            _ => 0.0,
+        };
+
+        kline_series.push(Kline::new(datetime, open, high, low, close, volume));
+    }
+
+    Ok(kline_series)
+}
+
+fn read_parquet_klines(file_path: &str, file: File, file_config: &FileConfig) -> Result<Vec<Kline>, KlineNodeError> {
+    let reader = SerializedFileReader::new(file).context(ReadParquetKlineFileFailedSnafu { file_path })?;
+    let headers: Vec<String> = reader
+        .metadata()
+        .file_metadata()
+        .schema_descr()
+        .root_schema()
+        .get_fields()
+        .iter()
+        .map(|field| field.name().to_string())
+        .collect();
+    let columns = KlineColumnIndexes::resolve(file_path, &headers, file_config)?;
+    let mapping = &file_config.column_mapping;
+
+    let rows = reader.get_row_iter(None).context(ReadParquetKlineFileFailedSnafu { file_path })?;
+    let mut kline_series = Vec::new();
+    for (row_index, row) in rows.enumerate() {
+        let row_fields = row.context(ReadParquetKlineFileFailedSnafu { file_path })?;
+        let fields: Vec<&Field> = row_fields.get_column_iter().map(|(_, field)| field).collect();
+        let row = row_index + 1;
+        let field = |index: usize, column: &ColumnRef| {
+            fields.get(index).copied().context(KlineFileColumnNotFoundSnafu {
+                file_path,
+                column: column.to_string(),
+            })
+        };
+        let number = |index: usize, column: &ColumnRef| -> Result<f64, KlineNodeError> {
+            let value = field(index, column)?;
+            field_to_f64(value).context(InvalidKlineFileValueSnafu {
+                file_path,
+                row,
+                column: column.to_string(),
+                value: value.to_string(),
+            })
+        };
+
+        let datetime_field = field(columns.datetime, &mapping.datetime)?;
+        let datetime = field_to_datetime(datetime_field, &file_config.timestamp_format, file_config.timezone.as_ref()).context(
+            InvalidKlineFileValueSnafu {
+                file_path,
+                row,
+                column: mapping.datetime.to_string(),
+                value: datetime_field.to_string(),
+            },
+        )?;
+        let open = number(columns.open, &mapping.open)?;
+        let high = number(columns.high, &mapping.high)?;
+        let low = number(columns.low, &mapping.low)?;
+        let close = number(columns.close, &mapping.close)?;
+        let volume = match (columns.volume, &mapping.volume) {
+            (Some(index), Some(column)) => number(index, column)?,
+            _ => 0.0,
+        };
+
+        kline_series.push(Kline::new(datetime, open, high, low, close, volume));
+    }
+
+    Ok(kline_series)
+}
+
+// Column positions resolved from the column mapping
+struct KlineColumnIndexes {
+    datetime: usize,
+    open: usize,
+    high: usize,
+    low: usize,
+    close: usize,
+    volume: Option<usize>,
+}
+
+impl KlineColumnIndexes {
+    fn resolve(file_path: &str, headers: &[String], file_config: &FileConfig) -> Result<Self, KlineNodeError> {
+        let resolve_column = |column: &ColumnRef| match column {
+            ColumnRef::Index(index) => Ok(*index),
+            ColumnRef::Name(name) => {
+                headers
+                    .iter()
+                    .position(|header| header.eq_ignore_ascii_case(name))
+                    .context(KlineFileColumnNotFoundSnafu {
+                        file_path,
+                        column: name.clone(),
+                    })
+            }
+        };
+
+        let mapping = &file_config.column_mapping;
+        Ok(Self {
+            datetime: resolve_column(&mapping.datetime)?,
+            open: resolve_column(&mapping.open)?,
+            high: resolve_column(&mapping.high)?,
+            low: resolve_column(&mapping.low)?,
+            close: resolve_column(&mapping.close)?,
+            volume: mapping.volume.as_ref().map(resolve_column).transpose()?,
+        })
+    }
+}
+
+fn parse_price(file_path: &str, row: usize, column: &ColumnRef, value: &str) -> Result<f64, KlineNodeError> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|price| price.is_finite())
+        .context(InvalidKlineFileValueSnafu {
+            file_path,
+            row,
+            column: column.to_string(),
+            value,
+        })
+}
+
+fn field_to_f64(field: &Field) -> Option<f64> {
+    match field {
+        Field::Double(value) => Some(*value),
+        Field::Float(value) => Some(*value as f64),
+        Field::Long(value) => Some(*value as f64),
+        Field::Int(value) => Some(*value as f64),
+        Field::Short(value) => Some(*value as f64),
+        Field::ULong(value) => Some(*value as f64),
+        Field::UInt(value) => Some(*value as f64),
+        Field::Str(value) => value.trim().parse::<f64>().ok(),
+        _ => None,
+    }
+    .filter(|value| value.is_finite())
+}
+
+fn field_to_datetime(field: &Field, timestamp_format: &TimestampFormat, timezone: Option<&Tz>) -> Option<DateTime<Utc>> {
+    match field {
+        Field::TimestampMillis(millis) => DateTime::from_timestamp_millis(*millis),
+        Field::TimestampMicros(micros) => DateTime::from_timestamp_micros(*micros),
+        Field::Date(days) => DateTime::from_timestamp(*days as i64 * 86_400, 0),
+        Field::Str(value) => parse_timestamp_str(value, timestamp_format, timezone),
+        _ => field_to_f64(field).and_then(|value| parse_timestamp_number(value, timestamp_format)),
+    }
+}
+
+fn parse_timestamp_number(value: f64, timestamp_format: &TimestampFormat) -> Option<DateTime<Utc>> {
+    match timestamp_format {
+        TimestampFormat::UnixSeconds => DateTime::from_timestamp_millis((value * 1000.0) as i64),
+        TimestampFormat::UnixMillis => DateTime::from_timestamp_millis(value as i64),
+        TimestampFormat::Auto if value >= AUTO_MILLIS_THRESHOLD => DateTime::from_timestamp_millis(value as i64),
+        TimestampFormat::Auto => DateTime::from_timestamp_millis((value * 1000.0) as i64),
+        TimestampFormat::Rfc3339 | TimestampFormat::Custom(_) => None,
+    }
+}
+
+fn parse_timestamp_str(value: &str, timestamp_format: &TimestampFormat, timezone: Option<&Tz>) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    match timestamp_format {
+        TimestampFormat::UnixSeconds | TimestampFormat::UnixMillis => value
+            .parse::<f64>()
+            .ok()
+            .and_then(|number| parse_timestamp_number(number, timestamp_format)),
+        TimestampFormat::Rfc3339 => DateTime::parse_from_rfc3339(value)
+            .ok()
+            .map(|datetime| datetime.with_timezone(&Utc)),
+        TimestampFormat::Custom(format) => {
+            if let Ok(datetime) = DateTime::parse_from_str(value, format) {
+                return Some(datetime.with_timezone(&Utc));
+            }
+            if let Ok(naive_datetime) = NaiveDateTime::parse_from_str(value, format) {
+                return localize(naive_datetime, timezone);
+            }
+            NaiveDate::parse_from_str(value, format)
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .and_then(|naive_datetime| localize(naive_datetime, timezone))
+        }
+        TimestampFormat::Auto => {
+            if let Ok(number) = value.parse::<f64>() {
+                return parse_timestamp_number(number, timestamp_format);
+            }
+            if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+                return Some(datetime.with_timezone(&Utc));
+            }
+            if let Some(naive_datetime) = AUTO_DATETIME_FORMATS
+                .iter()
+                .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+            {
+                return localize(naive_datetime, timezone);
+            }
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .and_then(|naive_datetime| localize(naive_datetime, timezone))
+        }
+    }
+}
+
+// Interpret a naive datetime in the configured timezone (UTC by default)
+fn localize(naive_datetime: NaiveDateTime, timezone: Option<&Tz>) -> Option<DateTime<Utc>> {
+    match timezone {
+        Some(timezone) => timezone
+            .from_local_datetime(&naive_datetime)
+            .earliest()
+            .map(|datetime| datetime.with_timezone(&Utc)),
+        None => Some(naive_datetime.and_utc()),
+    }
+}
+
+// Kline timestamps must be strictly increasing
+fn check_kline_order(file_path: &str, kline_series: &[Kline]) -> Result<(), KlineNodeError> {
+    for (index, pair) in kline_series.windows(2).enumerate() {
+        if pair[1].datetime() <= pair[0].datetime() {
+            return KlineFileTimestampNotMonotonicSnafu {
+                file_path,
+                row: index + 2,
+                previous: pair[0].datetime().to_string(),
+                current: pair[1].datetime().to_string(),
+            }
+            .fail();
+        }
+    }
+    Ok(())
+}
+
+// Slice the (sorted) klines to the backtest time range
+fn select_klines_in_range(file_path: &str, kline_series: &[Kline], time_range: &TimeRange) -> Result<Vec<Kline>, KlineNodeError> {
+    let start = kline_series.partition_point(|kline| kline.datetime() < time_range.start_date);
+    let end = kline_series.partition_point(|kline| kline.datetime() <= time_range.end_date);
+    if start >= end {
+        return KlineFileNoDataInRangeSnafu {
+            file_path,
+            start_time: time_range.start_date.to_string(),
+            end_time: time_range.end_date.to_string(),
+        }
+        .fail();
+    }
+    Ok(kline_series[start..end].to_vec())
+}
+
+// Check the spacing between consecutive klines, returns (gap count, missing bar count)
+fn check_kline_gaps(
+    file_path: &str,
+    kline_series: &[Kline],
+    interval: &KlineInterval,
+    gap_policy: &KlineGapPolicy,
+) -> Result<(usize, i64), KlineNodeError> {
+    // Months have no fixed length
+    if *interval == KlineInterval::Months1 {
+        return Ok((0, 0));
+    }
+
+    let interval_seconds = interval.to_seconds() as i64;
+    let mut gap_count = 0;
+    let mut missing_bars = 0;
+    for pair in kline_series.windows(2) {
+        let step = (pair[1].datetime() - pair[0].datetime()).num_seconds();
+        if step % interval_seconds != 0 {
+            return KlineFileIntervalMismatchSnafu {
+                file_path,
+                interval: interval.to_string(),
+                previous: pair[0].datetime().to_string(),
+                current: pair[1].datetime().to_string(),
+            }
+            .fail();
+        }
+
+        let missing = step / interval_seconds - 1;
+        if missing > 0 {
+            if *gap_policy == KlineGapPolicy::Error {
+                return KlineFileGapDetectedSnafu {
+                    file_path,
+                    previous: pair[0].datetime().to_string(),
+                    current: pair[1].datetime().to_string(),
+                    missing_bars: missing,
+                }
+                .fail();
+            }
+            gap_count += 1;
+            missing_bars += missing;
+        }
+    }
+    Ok((gap_count, missing_bars))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node_catalog::kline_node::kline_node_type::{KlineColumnMapping, KlineNodeBacktestConfig};
+
+    fn file_config(json: serde_json::Value) -> FileConfig {
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn time_range(start: &str, end: &str) -> TimeRange {
+        TimeRange::new(start.to_string(), end.to_string())
+    }
+
+    #[test]
+    fn test_read_csv_klines_with_default_mapping() {
+        let config = file_config(serde_json::json!({ "filePath": "btc.csv" }));
+        let csv = "datetime,open,high,low,close,volume\n\
+                   2024-01-01 00:00:00,1,2,0.5,1.5,10\n\
+                   2024-01-01 00:01:00,1.5,2.5,1,2,20\n";
+
+        let klines = read_csv_klines("btc.csv", csv.as_bytes(), &config).unwrap();
+
+        assert_eq!(klines.len(), 2);
+        assert_eq!(
+            klines[1].datetime(),
+            time_range("2024-01-01 00:01:00", "2024-01-01 00:01:00").start_date
+        );
+        assert_eq!(klines[1].close(), 2.0);
+        assert_eq!(klines[1].volume(), 20.0);
+    }
+
+    #[test]
+    fn test_read_csv_klines_with_index_mapping_and_timezone() {
+        let config = file_config(serde_json::json!({
+            "filePath": "eurusd.csv",
+            "delimiter": ";",
+            "hasHeader": false,
+            "timezone": "Europe/Athens",
+            "timestampFormat": { "custom": "%Y.%m.%d %H:%M" },
+            "columnMapping": { "datetime": 0, "open": 1, "high": 2, "low": 3, "close": 4, "volume": null }
+        }));
+        let csv = "2024.01.02 02:00;1.1;1.2;1.0;1.15\n";
+
+        let klines = read_csv_klines("eurusd.csv", csv.as_bytes(), &config).unwrap();
+
+        // Athens is UTC+2 in winter
+        assert_eq!(
+            klines[0].datetime(),
+            time_range("2024-01-02 00:00:00", "2024-01-02 00:00:00").start_date
+        );
+        assert_eq!(klines[0].volume(), 0.0);
+    }
+
+    #[test]
+    fn test_read_csv_klines_reports_missing_column_and_invalid_value() {
+        let mut config = file_config(serde_json::json!({ "filePath": "btc.csv" }));
+        let csv = "time,open,high,low,close,volume\n1704067200000,1,2,0.5,1.5,10\n";
+        let result = read_csv_klines("btc.csv", csv.as_bytes(), &config);
+        assert!(matches!(result, Err(KlineNodeError::KlineFileColumnNotFound { .. })));
+
+        config.column_mapping = KlineColumnMapping {
+            datetime: ColumnRef::Name("time".to_string()),
+            ..KlineColumnMapping::default()
+        };
+        let klines = read_csv_klines("btc.csv", csv.as_bytes(), &config).unwrap();
+        assert_eq!(
+            klines[0].datetime(),
+            time_range("2024-01-01 00:00:00", "2024-01-01 00:00:00").start_date
+        );
+
+        let csv = "time,open,high,low,close,volume\n1704067200,abc,2,0.5,1.5,10\n";
+        let result = read_csv_klines("btc.csv", csv.as_bytes(), &config);
+        assert!(matches!(result, Err(KlineNodeError::InvalidKlineFileValue { row: 1, .. })));
+    }
+
+    #[test]
+    fn test_check_kline_order_and_gaps() {
+        let kline = |datetime: &str| Kline::new(time_range(datetime, datetime).start_date, 1.0, 1.0, 1.0, 1.0, 0.0);
+        let klines = vec![
+            kline("2024-01-01 00:00:00"),
+            kline("2024-01-01 00:01:00"),
+            kline("2024-01-01 00:04:00"),
+        ];
+
+        assert!(check_kline_order("f.csv", &klines).is_ok());
+        let (gap_count, missing_bars) = check_kline_gaps("f.csv", &klines, &KlineInterval::Minutes1, &KlineGapPolicy::Warn).unwrap();
+        assert_eq!((gap_count, missing_bars), (1, 2));
+        let result = check_kline_gaps("f.csv", &klines, &KlineInterval::Minutes1, &KlineGapPolicy::Error);
+        assert!(matches!(result, Err(KlineNodeError::KlineFileGapDetected { missing_bars: 2, .. })));
+        let result = check_kline_gaps("f.csv", &klines, &KlineInterval::Minutes2, &KlineGapPolicy::Ignore);
+        assert!(matches!(result, Err(KlineNodeError::KlineFileIntervalMismatch { .. })));
+
+        let unordered = vec![kline("2024-01-01 00:01:00"), kline("2024-01-01 00:01:00")];
+        let result = check_kline_order("f.csv", &unordered);
+        assert!(matches!(result, Err(KlineNodeError::KlineFileTimestampNotMonotonic { row: 2, .. })));
+    }
+
+    #[test]
+    fn test_select_klines_in_range() {
+        let kline = |datetime: &str| Kline::new(time_range(datetime, datetime).start_date, 1.0, 1.0, 1.0, 1.0, 0.0);
+        let klines = vec![
+            kline("2024-01-01 00:00:00"),
+            kline("2024-01-01 00:01:00"),
+            kline("2024-01-01 00:02:00"),
+        ];
+
+        let selected = select_klines_in_range("f.csv", &klines, &time_range("2024-01-01 00:01:00", "2024-01-01 00:05:00")).unwrap();
+        assert_eq!(selected.len(), 2);
+
+        let result = select_klines_in_range("f.csv", &klines, &time_range("2024-02-01 00:00:00", "2024-02-02 00:00:00"));
+        assert!(matches!(result, Err(KlineNodeError::KlineFileNoDataInRange { .. })));
+    }
+
+    #[test]
+    fn test_file_mode_symbols_and_time_range() {
+        let symbol = serde_json::json!({ "configId": 1, "outputHandleId": "out", "symbol": "BTCUSDT", "interval": "1m" });
+        let node_config = |file_config: serde_json::Value| {
+            serde_json::from_value::<KlineNodeBacktestConfig>(serde_json::json!({
+                "nodeName": "kline",
+                "dataSource": "file",
+                "fileConfig": file_config,
+                "exchangeModeConfig": null,
+            }))
+            .unwrap()
+        };
+
+        // Without a time range the file bounds are used
+        let config = node_config(serde_json::json!({ "filePath": "btc.csv", "selectedSymbols": [symbol.clone()] }));
+        assert_eq!(config.selected_symbols().unwrap()[0].symbol, "BTCUSDT");
+        assert!(config.time_range().unwrap().is_none());
+
+        let config = node_config(serde_json::json!({
+            "filePath": "btc.csv",
+            "selectedSymbols": [symbol],
+            "timeRange": { "startDate": "2024-01-01 00:00:00 +00:00", "endDate": "2024-01-02 00:00:00 +00:00" },
+        }));
+        assert_eq!(
+            config.time_range().unwrap().unwrap().start_date,
+            time_range("2024-01-01 00:00:00", "2024-01-01 00:00:00").start_date
+        );
+    }
+}
@@ -22,77 +22,40 @@ use super::{KlineNodeContext, KlineNodeError, utils::bar_number};
 use crate::node::node_error::kline_node_error::{InsufficientBacktestDataForMetaTrader5Snafu, LoadKlineFromExchangeFailedSnafu};
 
 impl KlineNodeContext {
-    pub(super) async fn get_mt5_kline_history(&self, account_id: AccountId, time_range: &TimeRange) -> Result<(), KlineNodeError> {
-        let bar_number = bar_number(&time_range, &self.min_interval);
-        tracing::debug!("[{}] bar number: {}", self.node_name(), bar_number);
+    // Validate and fetch the kline history of one min interval symbol
+    pub(super) async fn fetch_mt5_kline_history(
+        &self,
+        account_id: AccountId,
+        symbol_key: &KlineKey,
+        time_range: &TimeRange,
+    ) -> Result<Vec<Kline>, KlineNodeError> {
+        let first_kline = self.request_first_kline_from_mt5(account_id, symbol_key).await?;
+        // Time of the first kline
+        let first_kline_datetime = first_kline.first().unwrap().datetime();
+        // Error if first kline time is less than start_time
+        let start_time = time_range.start_date;
+        if first_kline_datetime > start_time {
+            InsufficientBacktestDataForMetaTrader5Snafu {
+                first_kline_datetime: first_kline_datetime.to_string(),
+                start_time: start_time.to_string(),
+                end_time: time_range.end_date.to_string(),
+            }
+            .fail()?;
+        }
 
-        // If greater than 2000 bars, enable multi-threaded loading
+        let bar_number = bar_number(time_range, &self.min_interval);
+        tracing::debug!("[{}] bar number: {}", self.node_name(), bar_number);
+        // If greater than 10000 bars, enable multi-threaded loading
         if bar_number >= 10000 {
             tracing::info!(
                 "[{}] Large data set detected ({} bars), using concurrent loading",
                 self.node_name(),
                 bar_number
             );
-
-            for (symbol_key, _) in self.selected_symbol_keys.iter() {
-                if symbol_key.interval() != self.min_interval {
-                    tracing::warn!(
-                        "[{}] symbol: {}-{}, is not min interval, skip",
-                        self.node_name(),
-                        symbol_key.symbol(),
-                        symbol_key.interval()
-                    );
-                    continue;
-                }
-
-                let first_kline = self.request_first_kline_from_mt5(account_id.clone(), &symbol_key).await?;
-                // Time of the first kline
-                let first_kline_datetime = first_kline.first().unwrap().datetime();
-                // Error if first kline time is less than start_time
-                let start_time = time_range.start_date;
-                if first_kline_datetime > start_time {
-                    InsufficientBacktestDataForMetaTrader5Snafu {
-                        first_kline_datetime: first_kline_datetime.to_string(),
-                        start_time: start_time.to_string(),
-                        end_time: time_range.end_date.to_string(),
-                    }
-                    .fail()?;
-                }
-                // Use concurrent loading
-                self.load_symbol_concurrently_from_mt5(account_id.clone(), symbol_key.clone())
-                    .await?;
-            }
+            self.load_symbol_concurrently_from_mt5(account_id, symbol_key.clone()).await
         } else {
-            // Iterate over each symbol to get kline history from exchange
-            for (symbol_key, _) in self.selected_symbol_keys.iter() {
-                // Skip if key is not in minimum interval trading pair list
-                if symbol_key.interval() != self.min_interval {
-                    tracing::warn!(
-                        "[{}] symbol: {}-{}, is not min interval, skip",
-                        self.node_name(),
-                        symbol_key.symbol(),
-                        symbol_key.interval()
-                    );
-                    continue;
-                }
-                let first_kline = self.request_first_kline_from_mt5(account_id.clone(), &symbol_key).await?;
-                let first_kline_datetime = first_kline.first().unwrap().datetime();
-                let start_time = time_range.start_date;
-                if first_kline_datetime > start_time {
-                    InsufficientBacktestDataForMetaTrader5Snafu {
-                        first_kline_datetime: first_kline_datetime.to_string(),
-                        start_time: start_time.to_string(),
-                        end_time: time_range.end_date.to_string(),
-                    }
-                    .fail()?;
-                }
-
-                let kline_history = self.request_kline_history(account_id.clone(), symbol_key).await?;
-                self.init_strategy_kline_data(symbol_key, &kline_history).await?;
-            }
+            self.request_kline_history(account_id, symbol_key).await
         }
-
-        Ok(())
     }
 
     // Get the first kline
@@ -127,7 +90,7 @@ impl KlineNodeContext {
         }
     }
 
-    async fn load_symbol_concurrently_from_mt5(&self, account_id: AccountId, symbol_key: KlineKey) -> Result<(), KlineNodeError> {
+    async fn load_symbol_concurrently_from_mt5(&self, account_id: AccountId, symbol_key: KlineKey) -> Result<Vec<Kline>, KlineNodeError> {
         let time_range = symbol_key.time_range().unwrap();
 
         // Determine chunking strategy based on time range size
@@ -178,13 +141,14 @@ impl KlineNodeContext {
             handles.push(handle);
         }
 
-        // When each handle completes, send append kline data command
+        // Collect the chunks in time order, overlapping bars are removed when they are appended to the strategy
+        let mut kline_history = Vec::new();
         for handle in handles {
             let chunk_klines = handle.await.unwrap()?;
-            self.append_kline_data(&symbol_key, &chunk_klines).await?;
+            kline_history.extend(chunk_klines);
         }
 
-        Ok(())
+        Ok(kline_history)
     }
 
     fn split_time_range_for_mt5(&self, time_range: &TimeRange, interval: &KlineInterval) -> Vec<TimeRange> {
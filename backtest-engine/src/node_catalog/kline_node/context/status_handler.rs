@@ -3,7 +3,7 @@ use event_center::{CmdRespRecvFailedSnafu, EventCenterSingleton};
 use event_center_core::communication::response::Response;
 use key::{KeyTrait, KlineKey, error::TimeRangeNotSetSnafu};
 use snafu::{IntoError, OptionExt, ResultExt};
-use star_river_core::{custom_type::AccountId, exchange::Exchange, kline::Kline};
+use star_river_core::{custom_type::AccountId, exchange::Exchange, kline::Kline, system::TimeRange};
 use star_river_event::communication::{
     ExchangeEngineCommand, GetKlineHistoryCmdPayload, GetKlineHistoryCommand, MarketEngineCommand, RegisterExchangeCmdPayload,
     RegisterExchangeCommand,
@@ -25,6 +25,7 @@ use crate::{
 
 impl KlineNodeContext {
     // Get kline history from exchange (only get minimum interval klines)
+    // Optimization trials take the history from the cache shared with the other trials
     #[instrument(target = "backtest::kline::binance", skip(self))]
     pub async fn load_kline_history_from_exchange(&self) -> Result<(), KlineNodeError> {
         let account_id = self.node_config.exchange_mode()?.selected_account.account_id;
@@ -33,17 +34,49 @@ impl KlineNodeContext {
 
         let time_range = self.node_config.exchange_mode()?.time_range.clone();
 
-        match exchange {
-            Exchange::Metatrader5(_) => self.get_mt5_kline_history(account_id, &time_range).await?,
-            Exchange::Binance => self.get_binance_kline_history(account_id, &time_range).await?,
-            _ => {
-                return Ok(());
+        if !matches!(exchange, Exchange::Metatrader5(_) | Exchange::Binance) {
+            return Ok(());
+        }
+
+        for (symbol_key, _) in self.selected_symbol_keys.iter() {
+            if symbol_key.interval() != self.min_interval {
+                tracing::debug!(
+                    "[{}] symbol: {}-{}, is not min interval, skip",
+                    self.node_name(),
+                    symbol_key.symbol(),
+                    symbol_key.interval()
+                );
+                continue;
             }
+
+            let kline_history = match &self.kline_history_cache {
+                Some(kline_history_cache) => {
+                    kline_history_cache
+                        .get_or_load(account_id, symbol_key, || {
+                            self.fetch_kline_history(account_id, symbol_key, &time_range)
+                        })
+                        .await?
+                }
+                None => self.fetch_kline_history(account_id, symbol_key, &time_range).await?,
+            };
+            self.append_kline_data(symbol_key, &kline_history).await?;
         }
 
         Ok(())
     }
 
+    async fn fetch_kline_history(
+        &self,
+        account_id: AccountId,
+        symbol_key: &KlineKey,
+        time_range: &TimeRange,
+    ) -> Result<Vec<Kline>, KlineNodeError> {
+        match symbol_key.exchange() {
+            Exchange::Metatrader5(_) => self.fetch_mt5_kline_history(account_id, symbol_key, time_range).await,
+            _ => self.fetch_binance_kline_history(account_id, symbol_key, time_range).await,
+        }
+    }
+
     // request kline history from market engine
     pub(super) async fn request_kline_history(&self, account_id: AccountId, kline_key: &KlineKey) -> Result<Vec<Kline>, KlineNodeError> {
         let node_id = self.node_id().clone();
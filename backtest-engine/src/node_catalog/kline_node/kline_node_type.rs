@@ -1,12 +1,14 @@
-use serde::{Deserialize, Serialize};
+use chrono_tz::Tz;
+use serde::{Deserialize, Deserializer, Serialize};
 use star_river_core::{
     custom_type::NodeName,
+    kline::KlineInterval,
     system::{TimeRange, deserialize_time_range},
 };
 use strategy_core::strategy::{SelectedAccount, SelectedSymbol};
 
 use crate::{
-    node::node_error::kline_node_error::{ExchangeModeNotConfiguredSnafu, KlineNodeError},
+    node::node_error::kline_node_error::{ExchangeModeNotConfiguredSnafu, FileModeNotConfiguredSnafu, KlineNodeError},
     strategy::strategy_config::BacktestDataSource,
 };
 
@@ -17,6 +19,8 @@ pub struct KlineNodeBacktestConfig {
     pub data_source: BacktestDataSource,
     pub file_config: Option<FileConfig>,
     pub exchange_mode_config: Option<KlineNodeExchangeModeConfig>,
+    #[serde(default)]
+    pub missing_bar_policy: MissingBarPolicy,
 }
 
 impl KlineNodeBacktestConfig {
@@ -30,12 +34,199 @@ impl KlineNodeBacktestConfig {
             .build())
         }
     }
+
+    pub fn file_mode(&self) -> Result<&FileConfig, KlineNodeError> {
+        if let Some(file_config) = &self.file_config {
+            Ok(file_config)
+        } else {
+            Err(FileModeNotConfiguredSnafu {
+                node_name: self.node_name.clone(),
+            }
+            .build())
+        }
+    }
+
+    /// Symbols to load from the configured data source
+    pub fn selected_symbols(&self) -> Result<&Vec<SelectedSymbol>, KlineNodeError> {
+        match self.data_source {
+            BacktestDataSource::Exchange => Ok(&self.exchange_mode()?.selected_symbols),
+            BacktestDataSource::File => Ok(&self.file_mode()?.selected_symbols),
+        }
+    }
+
+    /// Time range to load from the configured data source, None plays the whole file
+    pub fn time_range(&self) -> Result<Option<&TimeRange>, KlineNodeError> {
+        match self.data_source {
+            BacktestDataSource::Exchange => Ok(Some(&self.exchange_mode()?.time_range)),
+            BacktestDataSource::File => Ok(self.file_mode()?.time_range.as_ref()),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileConfig {
+    // Supports `{symbol}` and `{interval}` placeholders, e.g. `data/{symbol}_{interval}.csv`
     #[serde(rename = "filePath")]
     pub file_path: String,
+
+    // Inferred from the file extension when not set
+    #[serde(rename = "fileFormat", default)]
+    pub file_format: Option<KlineFileFormat>,
+
+    #[serde(rename = "columnMapping", default)]
+    pub column_mapping: KlineColumnMapping,
+
+    #[serde(rename = "timestampFormat", default)]
+    pub timestamp_format: TimestampFormat,
+
+    // Timezone of naive datetime strings, UTC when not set
+    #[serde(rename = "timezone", default)]
+    pub timezone: Option<Tz>,
+
+    // CSV only
+    #[serde(rename = "delimiter", default = "default_delimiter")]
+    pub delimiter: char,
+
+    // CSV only, when false columns must be mapped by index
+    #[serde(rename = "hasHeader", default = "default_has_header")]
+    pub has_header: bool,
+
+    #[serde(rename = "gapPolicy", default)]
+    pub gap_policy: KlineGapPolicy,
+
+    #[serde(rename = "selectedSymbols", default)]
+    pub selected_symbols: Vec<SelectedSymbol>,
+
+    // Bounded by the first and last kline of the file when not set
+    #[serde(rename = "timeRange", default, deserialize_with = "deserialize_optional_time_range")]
+    pub time_range: Option<TimeRange>,
+}
+
+impl FileConfig {
+    pub fn resolve_file_path(&self, symbol: &str, interval: &KlineInterval) -> String {
+        self.file_path
+            .replace("{symbol}", symbol)
+            .replace("{interval}", &interval.to_string())
+    }
+
+    pub fn resolve_file_format(&self, file_path: &str) -> Option<KlineFileFormat> {
+        if let Some(file_format) = &self.file_format {
+            return Some(file_format.clone());
+        }
+        let extension = std::path::Path::new(file_path).extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "csv" | "txt" => Some(KlineFileFormat::Csv),
+            "parquet" | "pq" => Some(KlineFileFormat::Parquet),
+            _ => None,
+        }
+    }
+}
+
+fn default_delimiter() -> char {
+    ','
+}
+
+fn default_has_header() -> bool {
+    true
+}
+
+fn deserialize_optional_time_range<'de, D>(deserializer: D) -> Result<Option<TimeRange>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<serde_json::Value>::deserialize(deserializer)?
+        .map(|value| deserialize_time_range(value).map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum KlineFileFormat {
+    Csv,
+    Parquet,
+}
+
+/// Column reference by header name or zero-based index
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum ColumnRef {
+    Index(usize),
+    Name(String),
+}
+
+impl std::fmt::Display for ColumnRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnRef::Index(index) => write!(f, "#{index}"),
+            ColumnRef::Name(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KlineColumnMapping {
+    #[serde(rename = "datetime")]
+    pub datetime: ColumnRef,
+    #[serde(rename = "open")]
+    pub open: ColumnRef,
+    #[serde(rename = "high")]
+    pub high: ColumnRef,
+    #[serde(rename = "low")]
+    pub low: ColumnRef,
+    #[serde(rename = "close")]
+    pub close: ColumnRef,
+    // Volume is filled with 0 when not mapped
+    #[serde(rename = "volume", default)]
+    pub volume: Option<ColumnRef>,
+}
+
+impl Default for KlineColumnMapping {
+    fn default() -> Self {
+        Self {
+            datetime: ColumnRef::Name("datetime".to_string()),
+            open: ColumnRef::Name("open".to_string()),
+            high: ColumnRef::Name("high".to_string()),
+            low: ColumnRef::Name("low".to_string()),
+            close: ColumnRef::Name("close".to_string()),
+            volume: Some(ColumnRef::Name("volume".to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum TimestampFormat {
+    // Numbers are treated as unix milliseconds (>= 1e11) or seconds, strings as RFC 3339 or `%Y-%m-%d %H:%M:%S`
+    #[default]
+    Auto,
+    UnixSeconds,
+    UnixMillis,
+    Rfc3339,
+    // chrono format string, e.g. `%Y.%m.%d %H:%M`
+    Custom(String),
+}
+
+/// How to handle missing bars between two consecutive klines
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum KlineGapPolicy {
+    Ignore,
+    #[default]
+    Warn,
+    Error,
+}
+
+/// What the node emits when a symbol has no kline at the signal time, e.g. a market closed while another one trades
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum MissingBarPolicy {
+    // Emit the last kline before the signal time again
+    #[default]
+    ForwardFill,
+    // Emit nothing for the symbol on this cycle, downstream nodes are only triggered
+    Skip,
+    // Fail the cycle
+    Error,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -243,6 +243,43 @@ impl NodeLifecycle for KlineNode {
                         }
                     }
                 }
+                KlineNodeAction::LoadHistoryFromFile => {
+                    tracing::info!("[{node_name}] starting to load kline data from file");
+                    let load_result = self
+                        .with_ctx_write_async(|ctx| Box::pin(async move { ctx.load_kline_history_from_file().await }))
+                        .await;
+
+                    match load_result {
+                        Ok(()) => {
+                            tracing::info!("[{node_name}] load kline history from file success");
+                            let log_message = LoadKlineDataSuccessMsg::new(node_name.clone());
+                            NodeUtils::send_run_state_info(
+                                strategy_id,
+                                node_id.clone(),
+                                node_name.clone(),
+                                NodeType::KlineNode,
+                                log_message.to_string(),
+                                current_state,
+                                KlineNodeAction::LoadHistoryFromFile,
+                                &strategy_output_handle,
+                            )
+                            .await;
+                        }
+                        Err(err) => {
+                            NodeUtils::send_run_state_error(
+                                strategy_id,
+                                node_id.clone(),
+                                node_name.clone(),
+                                NodeType::KlineNode,
+                                KlineNodeAction::LoadHistoryFromFile,
+                                &err,
+                                &strategy_output_handle,
+                            )
+                            .await;
+                            return Err(err);
+                        }
+                    }
+                }
                 KlineNodeAction::ListenAndHandleStrategyCommand => {
                     tracing::info!("[{node_name}] start to listen strategy command");
                     self.listen_command().await;
@@ -258,7 +295,6 @@ impl NodeLifecycle for KlineNode {
                 KlineNodeAction::LogError(error) => {
                     tracing::error!("[{node_name}] node failed: {:?}", error);
                 }
-                _ => {}
             }
             tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
         }
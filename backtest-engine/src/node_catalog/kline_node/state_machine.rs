@@ -75,6 +75,7 @@ pub fn kline_node_transition(
                     KlineNodeAction::ListenAndHandleExternalEvents,
                     KlineNodeAction::ListenAndHandleNodeEvents,
                     KlineNodeAction::ListenAndHandleStrategyCommand,
+                    KlineNodeAction::InitMinInterval,
                     KlineNodeAction::LoadHistoryFromFile,
                 ],
             };
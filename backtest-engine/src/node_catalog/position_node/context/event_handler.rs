@@ -14,8 +14,12 @@ use super::{
 };
 use crate::{
     node::{
-        node_command::{NodeResetRespPayload, NodeResetResponse},
+        node_command::{
+            GetNodeSnapshotRespPayload, GetNodeSnapshotResponse, NodeResetRespPayload, NodeResetResponse, RestoreNodeSnapshotRespPayload,
+            RestoreNodeSnapshotResponse,
+        },
         node_error::PositionNodeError,
+        node_snapshot::NodeSnapshot,
     },
     node_catalog::position_node::{BacktestNodeCommand, context::BacktestNodeEvent},
 };
@@ -148,6 +152,23 @@ impl NodeEventHandlerExt for PositionNodeContext {
                     cmd.respond(response);
                 }
             }
+            BacktestNodeCommand::GetNodeSnapshot(cmd) => {
+                if self.node_id() == cmd.node_id() {
+                    let payload = GetNodeSnapshotRespPayload::new(NodeSnapshot::Stateless);
+                    let response = GetNodeSnapshotResponse::success(self.node_id().clone(), self.node_name().clone(), payload);
+                    cmd.respond(response);
+                }
+            }
+            BacktestNodeCommand::RestoreNodeSnapshot(cmd) => {
+                if self.node_id() == cmd.node_id() {
+                    let response = RestoreNodeSnapshotResponse::success(
+                        self.node_id().clone(),
+                        self.node_name().clone(),
+                        RestoreNodeSnapshotRespPayload,
+                    );
+                    cmd.respond(response);
+                }
+            }
             _ => {}
         }
     }
@@ -2,13 +2,19 @@ use snafu::{OptionExt, ResultExt};
 use strategy_core::node::context_trait::NodeInfoExt;
 use tokio::sync::oneshot;
 use virtual_trading::{
-    command::{CloseAllPositionsCmdPayload, CloseAllPositionsCommand, ClosePositionCmdPayload, ClosePositionCommand, VtsResponse},
+    command::{
+        CloseAllPositionsCmdPayload, CloseAllPositionsCommand, ClosePositionCmdPayload, ClosePositionCommand,
+        PartiallyClosePositionCmdPayload, PartiallyClosePositionCommand, VtsResponse,
+    },
     error::{CommandSendFailedSnafu, ResponseRecvFailedSnafu},
 };
 
 use super::PositionNodeContext;
 use crate::{
-    node::node_error::{PositionNodeError, position_node_error::SymbolNotConfiguredSnafu},
+    node::node_error::{
+        PositionNodeError,
+        position_node_error::{PartialCloseSizeNotConfiguredSnafu, SymbolNotConfiguredSnafu},
+    },
     node_catalog::position_node::position_node_types::{PositionOperation, PositionOperationConfig},
 };
 
@@ -24,7 +30,9 @@ impl PositionNodeContext {
             PositionOperation::CloseAllPositions => {
                 self.close_all_positions(config).await?;
             }
-            _ => return Ok(()),
+            PositionOperation::PartiallyClosePosition => {
+                self.partially_close_position(config).await?;
+            }
         }
 
         Ok(())
@@ -63,6 +71,39 @@ impl PositionNodeContext {
         }
     }
 
+    async fn partially_close_position(&self, config: &PositionOperationConfig) -> Result<(), PositionNodeError> {
+        let symbol = config.symbol.clone().context(SymbolNotConfiguredSnafu {
+            node_name: self.node_name(),
+            op: config.position_operation.to_string(),
+        })?;
+        let size = config.partial_close_size.context(PartialCloseSizeNotConfiguredSnafu {
+            node_name: self.node_name(),
+            config_id: config.config_id,
+        })?;
+
+        let exchange = self.node_config.selected_account.exchange.clone();
+
+        let (tx, rx) = oneshot::channel();
+        let payload = PartiallyClosePositionCmdPayload::new(
+            self.node_id().clone(),
+            self.node_name().clone(),
+            symbol,
+            exchange,
+            config.config_id,
+            size,
+        );
+        let partially_close_position_cmd = PartiallyClosePositionCommand::new(tx, payload);
+        self.vts_command_sender
+            .send(partially_close_position_cmd.into())
+            .await
+            .context(CommandSendFailedSnafu {})?;
+        let response = rx.await.context(ResponseRecvFailedSnafu {})?;
+        match response {
+            VtsResponse::Success { .. } => Ok(()),
+            VtsResponse::Fail { error, .. } => Err(error.into()),
+        }
+    }
+
     async fn close_all_positions(&self, config: &PositionOperationConfig) -> Result<(), PositionNodeError> {
         let (tx, rx) = oneshot::channel();
         let payload = CloseAllPositionsCmdPayload::new(self.node_id().clone(), self.node_name().clone(), config.config_id);
@@ -3,6 +3,7 @@ use snafu::OptionExt;
 use star_river_core::custom_type::NodeName;
 use strategy_core::{node_infra::condition_trigger::ConditionTrigger, strategy::SelectedAccount};
 use strum::{Display, EnumString};
+use virtual_trading::types::PartialCloseSize;
 
 use crate::node::node_error::{PositionNodeError, position_node_error::OperationConfigNotFoundSnafu};
 
@@ -27,6 +28,9 @@ pub struct PositionOperationConfig {
     pub operation_name: String, // Operation name
 
     pub trigger_config: ConditionTrigger,
+
+    #[serde(default)]
+    pub partial_close_size: Option<PartialCloseSize>, // Size of PartiallyClosePosition
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -5,9 +5,11 @@ use strategy_core::node::context_trait::{NodeEventHandlerExt, NodeInfoExt};
 use super::StartNodeContext;
 use crate::node::{
     node_command::{
-        BacktestNodeCommand, GetStartNodeConfigRespPayload, GetStartNodeConfigResponse, NodeResetRespPayload, NodeResetResponse,
+        BacktestNodeCommand, GetNodeSnapshotRespPayload, GetNodeSnapshotResponse, GetStartNodeConfigRespPayload,
+        GetStartNodeConfigResponse, NodeResetRespPayload, NodeResetResponse, RestoreNodeSnapshotRespPayload, RestoreNodeSnapshotResponse,
     },
     node_error::StartNodeError,
+    node_snapshot::NodeSnapshot,
 };
 #[async_trait]
 impl NodeEventHandlerExt for StartNodeContext {
@@ -39,6 +41,23 @@ impl NodeEventHandlerExt for StartNodeContext {
                     cmd.respond(response);
                 }
             }
+            BacktestNodeCommand::GetNodeSnapshot(cmd) => {
+                if self.node_id() == cmd.node_id() {
+                    let payload = GetNodeSnapshotRespPayload::new(NodeSnapshot::Stateless);
+                    let response = GetNodeSnapshotResponse::success(self.node_id().clone(), self.node_name().clone(), payload);
+                    cmd.respond(response);
+                }
+            }
+            BacktestNodeCommand::RestoreNodeSnapshot(cmd) => {
+                if self.node_id() == cmd.node_id() {
+                    let response = RestoreNodeSnapshotResponse::success(
+                        self.node_id().clone(),
+                        self.node_name().clone(),
+                        RestoreNodeSnapshotRespPayload,
+                    );
+                    cmd.respond(response);
+                }
+            }
             _ => {}
         }
     }
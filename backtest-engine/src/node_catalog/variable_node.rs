@@ -55,6 +55,7 @@ impl VariableNode {
         strategy_time_watch_rx: watch::Receiver<DateTime<Utc>>,
     ) -> Result<Self, BacktestNodeError> {
         let (strategy_id, node_id, node_name, node_config) = Self::check_variable_node_config(node_config)?;
+        let timer_schedules = VariableNodeContext::build_timer_schedules(&node_name, &node_config.variable_configs)?;
         let strategy_output_handle = generate_strategy_output_handle(&node_id, &node_name);
         let state_machine = VariableNodeStateMachine::new(node_name.clone(), NodeRunState::Created, variable_node_transition);
         let metadata = NodeMetadata::new(
@@ -69,7 +70,7 @@ impl VariableNode {
             strategy_command_sender,
             node_command_receiver,
         );
-        let context = VariableNodeContext::new(metadata, node_config, virtual_trading_system);
+        let context = VariableNodeContext::new(metadata, node_config, virtual_trading_system, timer_schedules);
         Ok(Self {
             inner: NodeBase::new(context),
         })
@@ -139,3 +140,157 @@ impl VariableNode {
         Ok((strategy_id, node_id, node_name, backtest_config))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use chrono::{Duration, TimeZone};
+    use serde_json::json;
+    use star_river_event::backtest_strategy::node_event::{
+        StartNodeEvent,
+        start_node_event::{KlinePlayEvent, KlinePlayPayload},
+    };
+    use strategy_core::{
+        node::{
+            context_trait::{NodeEventHandlerExt, NodeHandleExt},
+            node_handles::NodeInputHandle,
+            utils::generate_default_output_handle_id,
+        },
+        variable::custom_variable::{CustomVariable, VariableValue},
+    };
+    use tokio::sync::broadcast;
+
+    use super::*;
+    use crate::{
+        node::node_event::BacktestNodeEvent,
+        strategy::strategy_command::{
+            AddNodeCycleTrackerRespPayload, AddNodeCycleTrackerResponse, UpdateCustomVarRespPayload, UpdateCustomVarValueResponse,
+        },
+        virtual_trading_system::BacktestVtsContext,
+    };
+
+    // A custom variable updated every 2 minutes
+    fn timer_node(
+        strategy_time_rx: watch::Receiver<DateTime<Utc>>,
+        strategy_command_tx: mpsc::Sender<BacktestStrategyCommand>,
+    ) -> VariableNode {
+        let node_config = json!({
+            "id": "variable_node_1",
+            "data": {
+                "nodeName": "Count Timer",
+                "strategyId": 1,
+                "backtestConfig": {
+                    "dataSource": "exchange",
+                    "exchangeModeConfig": null,
+                    "variableConfigs": [{
+                        "configId": 1,
+                        "inputHandleId": "variable_node_1_input_1",
+                        "outputHandleId": "variable_node_1_output_1",
+                        "triggerConfig": {"type": "timer", "config": {"mode": "interval", "interval": 2, "unit": "minute"}},
+                        "updateOperationValue": null,
+                        "updateVarValueOperation": "set",
+                        "varDisplayName": "Counter",
+                        "varName": "counter",
+                        "varOperation": "update",
+                        "varType": "custom",
+                        "varValueType": "number"
+                    }]
+                }
+            }
+        });
+        let (_cycle_tx, cycle_rx) = watch::channel(Cycle::new());
+        let (_node_command_tx, node_command_rx) = mpsc::channel(10);
+        let vts = Arc::new(BacktestVts::new(BacktestVtsContext::new(strategy_time_rx.clone())));
+        VariableNode::new(
+            cycle_rx,
+            node_config,
+            strategy_command_tx,
+            Arc::new(Mutex::new(node_command_rx)),
+            vts,
+            strategy_time_rx,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_timer_trigger_requires_start_node_edge() {
+        let (_strategy_time_tx, strategy_time_rx) = watch::channel(Utc::now());
+        let (strategy_command_tx, _strategy_command_rx) = mpsc::channel(10);
+        let node = timer_node(strategy_time_rx, strategy_command_tx);
+        let start_node_id = "start_node".to_string();
+
+        let mut ctx = node.context().write().await;
+        assert!(ctx.check_timer_trigger_edge(&start_node_id).is_err());
+
+        let (_tx, rx) = broadcast::channel::<BacktestNodeEvent>(1);
+        ctx.add_input_handle(NodeInputHandle::new(
+            start_node_id.clone(),
+            generate_default_output_handle_id(&start_node_id),
+            "variable_node_1_input_1".to_string(),
+            rx,
+        ));
+        assert!(ctx.check_timer_trigger_edge(&start_node_id).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_timer_trigger_runs_on_play_signal() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let (strategy_time_tx, strategy_time_rx) = watch::channel(start);
+        let (strategy_command_tx, mut strategy_command_rx) = mpsc::channel(10);
+        let node = timer_node(strategy_time_rx, strategy_command_tx);
+        node.context().write().await.set_output_handles().unwrap();
+
+        // Stands in for the strategy, counts the variable updates
+        let update_count = Arc::new(AtomicUsize::new(0));
+        let responder_update_count = update_count.clone();
+        tokio::spawn(async move {
+            while let Some(command) = strategy_command_rx.recv().await {
+                match command {
+                    BacktestStrategyCommand::UpdateCustomVariableValue(command) => {
+                        responder_update_count.fetch_add(1, Ordering::SeqCst);
+                        let custom_variable = CustomVariable {
+                            var_name: "counter".to_string(),
+                            var_display_name: "Counter".to_string(),
+                            initial_value: VariableValue::Null,
+                            previous_value: VariableValue::Null,
+                            var_value: VariableValue::Null,
+                        };
+                        command.respond(UpdateCustomVarValueResponse::success(UpdateCustomVarRespPayload::new(
+                            custom_variable,
+                        )));
+                    }
+                    BacktestStrategyCommand::AddNodeCycleTracker(command) => {
+                        command.respond(AddNodeCycleTrackerResponse::success(AddNodeCycleTrackerRespPayload));
+                    }
+                    _ => {}
+                }
+            }
+        });
+
+        // One play signal per minute, the interval starts on the first one
+        let mut fired = Vec::new();
+        for minute in 0..5 {
+            let strategy_time = start + Duration::minutes(minute);
+            strategy_time_tx.send(strategy_time).unwrap();
+            let play_event: StartNodeEvent = KlinePlayEvent::new_with_time(
+                minute as u64,
+                "start_node".to_string(),
+                "Start Node".to_string(),
+                "start_node_default_output".to_string(),
+                strategy_time,
+                KlinePlayPayload,
+            )
+            .into();
+            let before = update_count.load(Ordering::SeqCst);
+            node.context()
+                .write()
+                .await
+                .handle_source_node_event(play_event.into())
+                .await
+                .unwrap();
+            fired.push(update_count.load(Ordering::SeqCst) > before);
+        }
+        assert_eq!(fired, vec![false, false, true, false, true]);
+    }
+}
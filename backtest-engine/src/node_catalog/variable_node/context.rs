@@ -8,16 +8,27 @@ mod variable_handler;
 
 use std::{collections::HashMap, sync::Arc};
 
-use star_river_core::custom_type::NodeId;
+use chrono::{DateTime, Utc};
+use star_river_core::custom_type::{NodeId, NodeName};
 use strategy_core::{
-    node::{context_trait::NodeMetaDataExt, metadata::NodeMetadata},
+    node::{
+        context_trait::{NodeHandleExt, NodeInfoExt, NodeMetaDataExt},
+        metadata::NodeMetadata,
+        utils::generate_default_output_handle_id,
+    },
+    node_infra::variable_node::{VariableConfig, timer_schedule::TimerSchedule, trigger::TriggerConfig},
     variable::custom_variable::VariableValue,
 };
 use tokio::sync::RwLock;
 
 use super::{state_machine::VariableNodeStateMachine, variable_node_type::VariableNodeBacktestConfig};
 use crate::{
-    node::{node_command::BacktestNodeCommand, node_error::VariableNodeError, node_event::BacktestNodeEvent},
+    node::{
+        node_command::BacktestNodeCommand,
+        node_error::{VariableNodeError, variable_node_error::InvalidTimerTriggerSnafu},
+        node_event::BacktestNodeEvent,
+        node_snapshot::NodeSnapshot,
+    },
     strategy::strategy_command::BacktestStrategyCommand,
     virtual_trading_system::BacktestVts,
 };
@@ -30,17 +41,72 @@ pub struct VariableNodeContext {
     node_config: VariableNodeBacktestConfig,
     virtual_trading_system: Arc<BacktestVts>,
     variable_cache_value: Arc<RwLock<HashMap<(NodeId, i32, String), VariableValue>>>,
+    timer_schedules: HashMap<i32, TimerSchedule>, // Timer trigger schedules, keyed by config id
+    timer_fire_time: HashMap<i32, Option<DateTime<Utc>>>, // Next strategy time each timer fires at, None when it never fires again
 }
 
 impl VariableNodeContext {
-    pub fn new(metadata: VariableNodeMetadata, node_config: VariableNodeBacktestConfig, virtual_trading_system: Arc<BacktestVts>) -> Self {
+    pub fn new(
+        metadata: VariableNodeMetadata,
+        node_config: VariableNodeBacktestConfig,
+        virtual_trading_system: Arc<BacktestVts>,
+        timer_schedules: HashMap<i32, TimerSchedule>,
+    ) -> Self {
         Self {
             metadata,
             node_config,
             virtual_trading_system,
             variable_cache_value: Arc::new(RwLock::new(HashMap::new())),
+            timer_schedules,
+            timer_fire_time: HashMap::new(),
         }
     }
+
+    /// Schedules of the timer triggered variable configs, an invalid timer fails the node creation
+    pub fn build_timer_schedules(
+        node_name: &NodeName,
+        variable_configs: &[VariableConfig],
+    ) -> Result<HashMap<i32, TimerSchedule>, VariableNodeError> {
+        variable_configs
+            .iter()
+            .filter_map(|config| match config.trigger_config() {
+                TriggerConfig::Timer(timer_trigger) => Some((config.config_id(), timer_trigger)),
+                _ => None,
+            })
+            .map(|(config_id, timer_trigger)| {
+                let schedule = timer_trigger.schedule().map_err(|reason| {
+                    InvalidTimerTriggerSnafu {
+                        node_name: node_name.clone(),
+                        config_id,
+                        reason,
+                    }
+                    .build()
+                })?;
+                Ok((config_id, schedule))
+            })
+            .collect()
+    }
+
+    /// Timers run on the play signal of the start node, a timer triggered node must be connected to it
+    pub fn check_timer_trigger_edge(&self, start_node_id: &NodeId) -> Result<(), VariableNodeError> {
+        let Some(config_id) = self.timer_schedules.keys().min() else {
+            return Ok(());
+        };
+        let start_handle_id = generate_default_output_handle_id(start_node_id);
+        let connected = self
+            .input_handles()
+            .iter()
+            .any(|handle| &handle.from_node_id == start_node_id && handle.from_handle_id == start_handle_id);
+        if !connected {
+            return Err(InvalidTimerTriggerSnafu {
+                node_name: self.node_name().clone(),
+                config_id: *config_id,
+                reason: "the node is not connected to the start node".to_string(),
+            }
+            .build());
+        }
+        Ok(())
+    }
 }
 
 impl NodeMetaDataExt for VariableNodeContext {
@@ -75,4 +141,54 @@ impl VariableNodeContext {
         let variable_cache_value_guard = self.variable_cache_value.read().await;
         variable_cache_value_guard.get(&(node_id, config_id, variable_name)).cloned()
     }
+
+    /// Split timer triggered configs into the ones due at `current_time` and the others
+    ///
+    /// A timer starts on the first strategy time it sees, due timers move on to their next fire time
+    pub(super) fn take_due_timer_configs(
+        &mut self,
+        timer_trigger_configs: Vec<VariableConfig>,
+        current_time: DateTime<Utc>,
+    ) -> (Vec<VariableConfig>, Vec<VariableConfig>) {
+        timer_trigger_configs.into_iter().partition(|config| {
+            let config_id = config.config_id();
+            let Some(schedule) = self.timer_schedules.get(&config_id) else {
+                return false;
+            };
+            let fire_time = *self
+                .timer_fire_time
+                .entry(config_id)
+                .or_insert_with(|| schedule.first_fire_time(current_time));
+            match fire_time {
+                Some(fire_time) if fire_time <= current_time => {
+                    self.timer_fire_time
+                        .insert(config_id, schedule.next_fire_time(fire_time, current_time));
+                    true
+                }
+                _ => false,
+            }
+        })
+    }
+
+    pub fn reset_timers(&mut self) {
+        self.timer_fire_time.clear();
+    }
+
+    pub async fn snapshot(&self) -> NodeSnapshot {
+        NodeSnapshot::Variable {
+            variable_cache_value: self.variable_cache_value.read().await.clone(),
+            timer_fire_time: self.timer_fire_time.clone(),
+        }
+    }
+
+    pub async fn restore_snapshot(&mut self, snapshot: NodeSnapshot) {
+        if let NodeSnapshot::Variable {
+            variable_cache_value,
+            timer_fire_time,
+        } = snapshot
+        {
+            *self.variable_cache_value.write().await = variable_cache_value;
+            self.timer_fire_time = timer_fire_time;
+        }
+    }
 }
@@ -78,3 +78,20 @@ where
         .cloned()
         .collect()
 }
+
+/// Filter variable configurations with timer trigger
+///
+/// # Arguments
+/// * `variable_configs` - Iterator of variable configurations
+///
+/// # Returns
+/// Filtered vector of variable configurations
+pub fn filter_timer_trigger_configs<'a, I>(variable_configs: I) -> Vec<VariableConfig>
+where
+    I: Iterator<Item = &'a VariableConfig>,
+{
+    variable_configs
+        .filter(|config| matches!(config.trigger_config(), TriggerConfig::Timer(_)))
+        .cloned()
+        .collect()
+}
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use event_center::Event;
 use futures::{TryStreamExt, stream};
-use star_river_event::backtest_strategy::node_event::{IfElseNodeEvent, IndicatorNodeEvent, KlineNodeEvent};
+use star_river_event::backtest_strategy::node_event::{IfElseNodeEvent, IndicatorNodeEvent, KlineNodeEvent, StartNodeEvent};
 use strategy_core::{
     benchmark::node_benchmark::CycleTracker,
     event::node_common_event::CommonEvent,
@@ -11,10 +11,15 @@ use strategy_core::{
 
 use super::{
     VariableNodeContext,
-    config_filter::{filter_case_trigger_configs, filter_dataflow_trigger_configs, filter_else_trigger_configs},
+    config_filter::{
+        filter_case_trigger_configs, filter_dataflow_trigger_configs, filter_else_trigger_configs, filter_timer_trigger_configs,
+    },
 };
 use crate::node::{
-    node_command::{BacktestNodeCommand, NodeResetRespPayload, NodeResetResponse},
+    node_command::{
+        BacktestNodeCommand, GetNodeSnapshotRespPayload, GetNodeSnapshotResponse, NodeResetRespPayload, NodeResetResponse,
+        RestoreNodeSnapshotRespPayload, RestoreNodeSnapshotResponse,
+    },
     node_error::VariableNodeError,
     node_event::BacktestNodeEvent,
 };
@@ -175,6 +180,49 @@ impl NodeEventHandlerExt for VariableNodeContext {
                     .await?;
                 Ok(())
             }
+            // Play signal of a cycle, run the timers due at the strategy time
+            BacktestNodeEvent::StartNode(StartNodeEvent::KlinePlay(_)) => {
+                let mut node_cycle_tracker = CycleTracker::new(self.cycle_id());
+                node_cycle_tracker.start_phase("handle_timer_trigger");
+                let timer_trigger_configs = filter_timer_trigger_configs(self.node_config.variable_configs.iter());
+                let (due_configs, idle_configs) = self.take_due_timer_configs(timer_trigger_configs, self.strategy_time());
+                self.handle_condition_trigger(&due_configs).await?;
+
+                // Timers not due pass the cycle on like a false condition
+                if self.is_leaf_node() {
+                    idle_configs.iter().try_for_each(|config| {
+                        self.send_execute_over_event(
+                            Some(config.config_id()),
+                            Some("timer not due for variable node".to_string()),
+                            Some(self.strategy_time()),
+                        )
+                    })?;
+                } else {
+                    stream::iter(idle_configs.iter().map(Ok::<_, VariableNodeError>))
+                        .try_for_each_concurrent(None, |config| async {
+                            self.send_trigger_event(
+                                config.output_handle_id(),
+                                config.config_id(),
+                                Some("timer not due for variable node".to_string()),
+                                Some(self.strategy_time()),
+                            )
+                            .await?;
+                            self.default_output_handle_send_trigger_event(
+                                config.config_id(),
+                                Some("timer not due for variable node".to_string()),
+                                Some(self.strategy_time()),
+                            )
+                            .await?;
+                            Ok(())
+                        })
+                        .await?;
+                }
+                node_cycle_tracker.end_phase("handle_timer_trigger");
+                let completed_tracker = node_cycle_tracker.end();
+                self.mount_node_cycle_tracker(self.node_id().clone(), self.node_name().clone(), completed_tracker)
+                    .await?;
+                Ok(())
+            }
             BacktestNodeEvent::Common(CommonEvent::Trigger(trigger_event)) => {
                 let mut node_cycle_tracker = CycleTracker::new(self.cycle_id());
                 node_cycle_tracker.start_phase("handle_trigger_event");
@@ -228,11 +276,30 @@ impl NodeEventHandlerExt for VariableNodeContext {
         match node_command {
             BacktestNodeCommand::NodeReset(cmd) => {
                 if self.node_id() == cmd.node_id() {
+                    self.reset_timers();
                     let paylod = NodeResetRespPayload;
                     let response = NodeResetResponse::success(self.node_id().clone(), self.node_name().clone(), paylod);
                     cmd.respond(response);
                 }
             }
+            BacktestNodeCommand::GetNodeSnapshot(cmd) => {
+                if self.node_id() == cmd.node_id() {
+                    let payload = GetNodeSnapshotRespPayload::new(self.snapshot().await);
+                    let response = GetNodeSnapshotResponse::success(self.node_id().clone(), self.node_name().clone(), payload);
+                    cmd.respond(response);
+                }
+            }
+            BacktestNodeCommand::RestoreNodeSnapshot(cmd) => {
+                if self.node_id() == cmd.node_id() {
+                    self.restore_snapshot(cmd.snapshot.clone()).await;
+                    let response = RestoreNodeSnapshotResponse::success(
+                        self.node_id().clone(),
+                        self.node_name().clone(),
+                        RestoreNodeSnapshotRespPayload,
+                    );
+                    cmd.respond(response);
+                }
+            }
             _ => {}
         }
     }
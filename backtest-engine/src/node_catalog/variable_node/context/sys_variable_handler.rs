@@ -25,9 +25,11 @@ use crate::{
     node::node_error::{VariableNodeError, variable_node_error::SysVariableSymbolIsNullSnafu},
     node_catalog::variable_node::context::BacktestNodeEvent,
     strategy::strategy_command::{UpdateSysVarCmdPayload, UpdateSysVarValueCommand},
-    virtual_trading_system::BacktestVts,
+    virtual_trading_system::{BacktestVts, BacktestVtsContext},
 };
 
+type VtsValueReader = Box<dyn FnOnce(&BacktestVtsContext) -> VariableValue + Send>;
+
 impl VariableNodeContext {
     /// Generic system variable handle creator
     ///
@@ -380,4 +382,74 @@ impl VariableNodeContext {
 
         Ok(handle)
     }
+
+    fn sys_variable_symbol(system_var_config: &GetSystemVariableConfig) -> Result<String, VariableNodeError> {
+        system_var_config.symbol().clone().ok_or_else(|| {
+            SysVariableSymbolIsNullSnafu {
+                sys_var_name: system_var_config.var_name().to_string(),
+            }
+            .build()
+        })
+    }
+
+    /// Create handle of an account, position or market variable read from the virtual trading system
+    pub(super) async fn create_vts_state_handle(
+        &self,
+        system_var_config: GetSystemVariableConfig,
+        var_name: SysVariableType,
+    ) -> Result<JoinHandle<Result<(), VariableNodeError>>, VariableNodeError> {
+        let var_display_name = system_var_config.var_display_name().clone();
+        let (symbol, value_reader): (Option<String>, VtsValueReader) = match var_name {
+            SysVariableType::TotalUnrealizedPnl => (
+                None,
+                Box::new(|ctx| VariableValue::from(ctx.current_positions.iter().map(|p| p.unrealized_profit).sum::<f64>())),
+            ),
+            SysVariableType::IsMarketOpen => (None, Box::new(|ctx| VariableValue::Boolean(ctx.is_market_open()))),
+            SysVariableType::IsMarketClosed => (None, Box::new(|ctx| VariableValue::Boolean(!ctx.is_market_open()))),
+            SysVariableType::Equity => (None, Box::new(|ctx| VariableValue::from(ctx.equity))),
+            SysVariableType::AvailableBalance => (None, Box::new(|ctx| VariableValue::from(ctx.available_balance))),
+            SysVariableType::MarginRatio => (None, Box::new(|ctx| VariableValue::percentage(ctx.margin_ratio * 100.0))),
+            SysVariableType::CurrentDrawdown => (None, Box::new(|ctx| VariableValue::percentage(ctx.current_drawdown() * 100.0))),
+            _ => {
+                let symbol = Self::sys_variable_symbol(&system_var_config)?;
+                let exchange = self.node_config.exchange_mode()?.selected_account.exchange.clone();
+                let value_symbol = symbol.clone();
+                let value_reader: VtsValueReader = match var_name {
+                    // A symbol without a position has no unrealized pnl
+                    SysVariableType::UnrealizedPnl => Box::new(move |ctx| {
+                        let position = ctx.find_position_for(&value_symbol, &exchange).ok();
+                        VariableValue::from(position.map(|p| p.unrealized_profit).unwrap_or_default())
+                    }),
+                    SysVariableType::IsTradable => Box::new(move |ctx| VariableValue::Boolean(ctx.is_tradable(&exchange, &value_symbol))),
+                    SysVariableType::PositionEntryPrice => Box::new(move |ctx| {
+                        let position = ctx.find_position_for(&value_symbol, &exchange).ok();
+                        position.map_or(VariableValue::Null, |p| VariableValue::from(p.open_price))
+                    }),
+                    SysVariableType::PositionSide => Box::new(move |ctx| {
+                        let position = ctx.find_position_for(&value_symbol, &exchange).ok();
+                        position.map_or(VariableValue::Null, |p| VariableValue::String(p.position_side.to_string()))
+                    }),
+                    SysVariableType::PositionHoldingBars => Box::new(move |ctx| {
+                        let position = ctx.find_position_for(&value_symbol, &exchange).ok();
+                        position.map_or(VariableValue::Null, |p| VariableValue::from(ctx.holding_bars(p) as i64))
+                    }),
+                    SysVariableType::BarsSinceLastTrade => Box::new(move |ctx| {
+                        let bars = ctx.bars_since_last_trade(&exchange, &value_symbol);
+                        bars.map_or(VariableValue::Null, |bars| VariableValue::from(bars as i64))
+                    }),
+                    _ => Box::new(|_| VariableValue::Null),
+                };
+                (Some(symbol), value_reader)
+            }
+        };
+        let handle = self
+            .create_sys_variable_handle(system_var_config, move |vts| {
+                Box::pin(async move {
+                    let var_value = vts.with_ctx_read(value_reader).await;
+                    SysVariable::new(var_name, var_display_name, symbol, var_value)
+                })
+            })
+            .await?;
+        Ok(handle)
+    }
 }
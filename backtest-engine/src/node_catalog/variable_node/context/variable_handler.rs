@@ -237,7 +237,22 @@ impl VariableNodeContext {
                             let handle = self.create_current_roi_handle(system_config.clone()).await?;
                             get_var_handles.push(handle);
                         }
-                        _ => {}
+                        SysVariableType::TotalUnrealizedPnl
+                        | SysVariableType::UnrealizedPnl
+                        | SysVariableType::IsMarketOpen
+                        | SysVariableType::IsMarketClosed
+                        | SysVariableType::IsTradable
+                        | SysVariableType::Equity
+                        | SysVariableType::AvailableBalance
+                        | SysVariableType::MarginRatio
+                        | SysVariableType::CurrentDrawdown
+                        | SysVariableType::PositionEntryPrice
+                        | SysVariableType::PositionSide
+                        | SysVariableType::PositionHoldingBars
+                        | SysVariableType::BarsSinceLastTrade => {
+                            let handle = self.create_vts_state_handle(system_config.clone(), system_var).await?;
+                            get_var_handles.push(handle);
+                        }
                     }
                 }
             }
@@ -0,0 +1,213 @@
+mod kline_history_cache;
+mod optimization_config;
+mod parameter_space;
+mod walk_forward;
+
+// Standard library imports
+use std::sync::Arc;
+
+// External crate imports
+use database::mutation::backtest_optimization_mutation::{BacktestOptimizationMutation, NewBacktestOptimizationTrial};
+use futures::{Stream, StreamExt};
+use heartbeat::Heartbeat;
+use sea_orm::DatabaseConnection;
+use serde_json::Value;
+use snafu::ResultExt;
+use star_river_core::{backtest_optimization::BacktestOptimizationStatus, error::StarRiverErrorTrait};
+use strategy_core::strategy::{
+    StrategyConfig,
+    context_trait::{StrategyCommunicationExt, StrategyIdentityExt, StrategyInfoExt},
+    strategy_trait::{StrategyContextAccessor, StrategyLifecycle},
+};
+use strategy_stats::{
+    PerformanceMetric,
+    snapshot::StatsSnapshotHistory,
+    strategy_stats::{StrategyStatsAccessor, StrategyStatsInfoExt},
+};
+use tokio::sync::Mutex;
+
+pub use kline_history_cache::KlineHistoryCache;
+pub use optimization_config::{MAX_OPTIMIZATION_CONCURRENCY, OptimizationConfig, ParameterRange, ParameterValues, SearchMethod};
+pub use parameter_space::{MAX_OPTIMIZATION_TRIALS, ParameterValue};
+pub(crate) use parameter_space::{ParameterSet, apply_parameter_set, build_parameter_sets};
+pub(crate) use walk_forward::{WalkForwardPlan, WalkForwardRunner};
+pub use walk_forward::{WalkForwardConfig, WalkForwardWindow, WindowMode};
+
+// Current crate imports
+use crate::{
+    engine_error::{BacktestEngineError, SerializeOptimizationFailedSnafu},
+    strategy::{BacktestStrategy, strategy_context::HeadlessRunResult, strategy_error::BacktestStrategyError},
+};
+
+/// Strategy config of one trial with the parameter values it was built from
+pub(crate) struct OptimizationTrial {
+    pub parameter_set: ParameterSet,
+    /// Parameter values as stored with the trial result
+    pub parameters: Value,
+    pub strategy_config: StrategyConfig,
+}
+
+impl OptimizationTrial {
+    pub(crate) fn new(parameter_set: ParameterSet, strategy_config: StrategyConfig) -> Result<Self, BacktestEngineError> {
+        let parameters = serde_json::to_value(&parameter_set).context(SerializeOptimizationFailedSnafu)?;
+        Ok(Self {
+            parameter_set,
+            parameters,
+            strategy_config,
+        })
+    }
+}
+
+/// Result of a finished trial
+pub(crate) struct TrialResult {
+    pub headless_result: HeadlessRunResult,
+    pub snapshot_history: StatsSnapshotHistory,
+}
+
+/// Play the trials concurrently up to max_concurrency, results are yielded in completion order with the trial index
+///
+/// The trials share one kline history cache, market data is requested once instead of once per trial
+pub(crate) fn run_trials(
+    trials: Vec<OptimizationTrial>,
+    max_concurrency: usize,
+    database: DatabaseConnection,
+    heartbeat: Arc<Mutex<Heartbeat>>,
+) -> impl Stream<Item = (usize, ParameterSet, Result<TrialResult, BacktestStrategyError>)> {
+    let kline_history_cache = KlineHistoryCache::default();
+    futures::stream::iter(trials.into_iter().enumerate())
+        .map(move |(index, trial)| {
+            let database = database.clone();
+            let heartbeat = heartbeat.clone();
+            let kline_history_cache = kline_history_cache.clone();
+            async move {
+                let result = run_trial(trial.strategy_config, database, heartbeat, kline_history_cache).await;
+                (index, trial.parameter_set, result)
+            }
+        })
+        .buffer_unordered(max_concurrency.clamp(1, MAX_OPTIMIZATION_CONCURRENCY))
+}
+
+// A detached strategy instance is checked, initialized, played headless and stopped
+async fn run_trial(
+    strategy_config: StrategyConfig,
+    database: DatabaseConnection,
+    heartbeat: Arc<Mutex<Heartbeat>>,
+    kline_history_cache: KlineHistoryCache,
+) -> Result<TrialResult, BacktestStrategyError> {
+    let mut strategy = BacktestStrategy::new_trial(strategy_config, database, heartbeat, kline_history_cache);
+    strategy.check_strategy().await?;
+
+    let result = async {
+        strategy.init_strategy().await?;
+        let (strategy_name, command_sender) = strategy
+            .with_ctx_read(|ctx| (ctx.strategy_name().clone(), ctx.strategy_command_sender().clone()))
+            .await;
+        let headless_result = BacktestStrategy::request_headless_run(strategy_name, command_sender).await?;
+        let snapshot_history = strategy
+            .with_ctx_read_async(|ctx| {
+                Box::pin(async move {
+                    ctx.strategy_stats()
+                        .with_ctx_read(|stats| stats.asset_snapshot_history().clone())
+                        .await
+                })
+            })
+            .await;
+        Ok(TrialResult {
+            headless_result,
+            snapshot_history,
+        })
+    }
+    .await;
+
+    // Nodes may have been started even if the run failed
+    if let Err(e) = strategy.stop_strategy().await {
+        e.report_log();
+    }
+    result
+}
+
+/// Plays the trials of one optimization and stores their results
+pub(crate) struct BacktestOptimizer {
+    pub optimization_id: i32,
+    pub strategy_name: String,
+    pub metric: PerformanceMetric,
+    pub max_concurrency: usize,
+    pub database: DatabaseConnection,
+    pub heartbeat: Arc<Mutex<Heartbeat>>,
+}
+
+impl BacktestOptimizer {
+    // Each result is stored as soon as its trial ends
+    pub(crate) async fn run(self, trials: Vec<OptimizationTrial>) {
+        let total_trials = trials.len();
+        let trial_parameters = trials.iter().map(|trial| trial.parameters.clone()).collect::<Vec<Value>>();
+        tracing::info!(
+            "[{}] optimization {} started, {} trials",
+            self.strategy_name,
+            self.optimization_id,
+            total_trials
+        );
+
+        let mut results = std::pin::pin!(run_trials(
+            trials,
+            self.max_concurrency,
+            self.database.clone(),
+            self.heartbeat.clone()
+        ));
+        let mut finished_trials = 0;
+        while let Some((index, _, result)) = results.next().await {
+            finished_trials += 1;
+            let parameters = trial_parameters[index].clone();
+            let new_trial = match result {
+                Ok(result) => {
+                    let performance = &result.headless_result.trading_performance;
+                    match serde_json::to_value(performance) {
+                        Ok(performance_json) => NewBacktestOptimizationTrial {
+                            parameters,
+                            score: Some(performance.score(&self.metric)),
+                            performance: Some(performance_json),
+                            error: None,
+                        },
+                        Err(e) => NewBacktestOptimizationTrial {
+                            parameters,
+                            score: None,
+                            performance: None,
+                            error: Some(format!("serialize trial performance failed: {e}")),
+                        },
+                    }
+                }
+                Err(e) => {
+                    e.report_log();
+                    NewBacktestOptimizationTrial {
+                        parameters,
+                        score: None,
+                        performance: None,
+                        error: Some(e.to_string()),
+                    }
+                }
+            };
+            if let Err(e) =
+                BacktestOptimizationMutation::create_backtest_optimization_trial(&self.database, self.optimization_id, new_trial).await
+            {
+                tracing::error!("[{}] save optimization trial failed: {}", self.strategy_name, e);
+            }
+            tracing::debug!(
+                "[{}] optimization trial {}/{} finished",
+                self.strategy_name,
+                finished_trials,
+                total_trials
+            );
+        }
+
+        if let Err(e) = BacktestOptimizationMutation::finish_backtest_optimization(
+            &self.database,
+            self.optimization_id,
+            BacktestOptimizationStatus::Completed,
+        )
+        .await
+        {
+            tracing::error!("[{}] finish optimization failed: {}", self.strategy_name, e);
+        }
+        tracing::info!("[{}] optimization {} finished", self.strategy_name, self.optimization_id);
+    }
+}
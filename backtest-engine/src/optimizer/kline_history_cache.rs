@@ -0,0 +1,66 @@
+use std::{collections::HashMap, future::Future, sync::Arc};
+
+use key::KlineKey;
+use star_river_core::{custom_type::AccountId, kline::Kline};
+use tokio::sync::{Mutex, OnceCell};
+
+/// Kline history shared by the trials of one optimization
+///
+/// Each symbol is requested from the exchange once, concurrent trials wait for that request instead of sending their own.
+/// A failed request is not cached, the next trial requests it again
+#[derive(Debug, Clone, Default)]
+pub struct KlineHistoryCache {
+    kline_history: Arc<Mutex<HashMap<(AccountId, KlineKey), Arc<OnceCell<Vec<Kline>>>>>>,
+}
+
+impl KlineHistoryCache {
+    pub(crate) async fn get_or_load<F, Fut, E>(&self, account_id: AccountId, kline_key: &KlineKey, load: F) -> Result<Vec<Kline>, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Vec<Kline>, E>>,
+    {
+        let kline_history = self
+            .kline_history
+            .lock()
+            .await
+            .entry((account_id, kline_key.clone()))
+            .or_default()
+            .clone();
+        kline_history.get_or_try_init(load).await.cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use star_river_core::{exchange::Exchange, kline::KlineInterval};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_kline_history_loaded_once() {
+        let cache = KlineHistoryCache::default();
+        let kline_key = KlineKey::new(
+            Exchange::Binance,
+            "BTCUSDT".to_string(),
+            KlineInterval::Minutes1,
+            Some("2024-01-01 00:00:00".to_string()),
+            Some("2024-01-02 00:00:00".to_string()),
+        );
+        let load_count = AtomicUsize::new(0);
+        let load = || async {
+            load_count.fetch_add(1, Ordering::SeqCst);
+            tokio::task::yield_now().await;
+            Ok::<Vec<Kline>, ()>(vec![])
+        };
+
+        let (first, second) = tokio::join!(cache.get_or_load(1, &kline_key, load), cache.get_or_load(1, &kline_key, load));
+        assert!(first.is_ok() && second.is_ok());
+        assert_eq!(load_count.load(Ordering::SeqCst), 1);
+
+        // another account is a different history
+        cache.get_or_load(2, &kline_key, load).await.unwrap();
+        assert_eq!(load_count.load(Ordering::SeqCst), 2);
+    }
+}
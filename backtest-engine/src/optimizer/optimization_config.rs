@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use strategy_stats::PerformanceMetric;
+use utoipa::ToSchema;
+
+use crate::engine_error::{BacktestEngineError, InvalidOptimizationConcurrencySnafu};
+
+/// Upper bound of the trials played at the same time, every trial holds its own copy of the market data
+pub const MAX_OPTIMIZATION_CONCURRENCY: usize = 16;
+
+/// Parameter optimization request
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OptimizationConfig {
+    /// Parameters to optimize
+    pub parameters: Vec<ParameterRange>,
+
+    /// How parameter combinations are picked
+    #[serde(default)]
+    pub search_method: SearchMethod,
+
+    /// Metric the trials are ranked by
+    #[serde(default)]
+    pub metric: PerformanceMetric,
+
+    /// Number of trials played at the same time, at most MAX_OPTIMIZATION_CONCURRENCY
+    #[serde(default = "default_max_concurrency")]
+    pub max_concurrency: usize,
+}
+
+fn default_max_concurrency() -> usize {
+    4
+}
+
+impl OptimizationConfig {
+    /// Concurrency the trials are played with, 0 is rejected and larger values are clamped
+    pub fn concurrency(&self) -> Result<usize, BacktestEngineError> {
+        if self.max_concurrency == 0 {
+            return InvalidOptimizationConcurrencySnafu {
+                max_concurrency: self.max_concurrency,
+            }
+            .fail();
+        }
+        Ok(self.max_concurrency.min(MAX_OPTIMIZATION_CONCURRENCY))
+    }
+}
+
+/// A parameter of the strategy config, addressed by node id and a JSON pointer into the node
+///
+/// e.g. node_id = "indicator_node_1", path = "/data/backtestConfig/indicatorConfig/timePeriod"
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ParameterRange {
+    pub node_id: String,
+    pub path: String,
+    pub values: ParameterValues,
+}
+
+/// Values a parameter is tried with
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ParameterValues {
+    /// start, start + step, ... up to end (inclusive), integers stay integers
+    Range { start: f64, end: f64, step: f64 },
+    /// Explicit values, e.g. indicator types or booleans
+    List {
+        #[schema(value_type = Vec<Object>)]
+        values: Vec<serde_json::Value>,
+    },
+}
+
+/// Search method of the parameter space
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SearchMethod {
+    /// Every combination of the parameter values
+    #[default]
+    Grid,
+    /// Distinct combinations sampled at random, a fixed seed reproduces the same samples
+    #[serde(rename_all = "camelCase")]
+    Random { sample_count: usize, seed: Option<u64> },
+}
@@ -0,0 +1,297 @@
+use std::collections::HashSet;
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use snafu::OptionExt;
+use utoipa::ToSchema;
+
+use super::optimization_config::{ParameterRange, ParameterValues, SearchMethod};
+use crate::engine_error::{
+    BacktestEngineError, InvalidOptimizationParameterSnafu, OptimizationParameterNotFoundSnafu, TooManyOptimizationTrialsSnafu,
+};
+
+/// Upper bound of the trials of one optimization
+pub const MAX_OPTIMIZATION_TRIALS: usize = 1000;
+
+/// Value of one parameter in a trial
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ParameterValue {
+    pub node_id: String,
+    pub path: String,
+    #[schema(value_type = Object)]
+    pub value: Value,
+}
+
+/// Parameter values of one trial
+pub type ParameterSet = Vec<ParameterValue>;
+
+impl ParameterRange {
+    fn invalid(&self, reason: &str) -> BacktestEngineError {
+        InvalidOptimizationParameterSnafu {
+            node_id: self.node_id.clone(),
+            path: self.path.clone(),
+            reason: reason.to_string(),
+        }
+        .build()
+    }
+
+    // Expand the range into the concrete values to try
+    fn expand(&self) -> Result<Vec<Value>, BacktestEngineError> {
+        if !self.path.starts_with('/') {
+            return Err(self.invalid("path must be a JSON pointer starting with '/'"));
+        }
+
+        match &self.values {
+            ParameterValues::List { values } => {
+                if values.is_empty() {
+                    return Err(self.invalid("value list is empty"));
+                }
+                Ok(values.clone())
+            }
+            ParameterValues::Range { start, end, step } => {
+                if !(start.is_finite() && end.is_finite() && step.is_finite()) || *step <= 0.0 || start > end {
+                    return Err(self.invalid("range requires start <= end and step > 0"));
+                }
+                let count = ((end - start) / step + 1e-9).floor() as usize + 1;
+                if count > MAX_OPTIMIZATION_TRIALS {
+                    return TooManyOptimizationTrialsSnafu {
+                        trial_count: count,
+                        max_trial_count: MAX_OPTIMIZATION_TRIALS,
+                    }
+                    .fail();
+                }
+                // Periods and lengths must be written back as integers
+                let is_integer = start.fract() == 0.0 && step.fract() == 0.0;
+                let values = (0..count)
+                    .map(|i| {
+                        let value = start + step * i as f64;
+                        if is_integer {
+                            Value::from(value as i64)
+                        } else {
+                            // Drop the float noise of the accumulated step
+                            Value::from((value * 1e10).round() / 1e10)
+                        }
+                    })
+                    .collect();
+                Ok(values)
+            }
+        }
+    }
+}
+
+/// Parameter sets of every trial, in a stable order
+pub fn build_parameter_sets(parameters: &[ParameterRange], search_method: &SearchMethod) -> Result<Vec<ParameterSet>, BacktestEngineError> {
+    let values = parameters.iter().map(|range| range.expand()).collect::<Result<Vec<_>, _>>()?;
+    let combination_count = values.iter().fold(1_usize, |count, values| count.saturating_mul(values.len()));
+
+    let indices = match search_method {
+        SearchMethod::Grid => {
+            if combination_count > MAX_OPTIMIZATION_TRIALS {
+                return TooManyOptimizationTrialsSnafu {
+                    trial_count: combination_count,
+                    max_trial_count: MAX_OPTIMIZATION_TRIALS,
+                }
+                .fail();
+            }
+            grid_indices(&values)
+        }
+        SearchMethod::Random { sample_count, seed } => {
+            if *sample_count > MAX_OPTIMIZATION_TRIALS {
+                return TooManyOptimizationTrialsSnafu {
+                    trial_count: *sample_count,
+                    max_trial_count: MAX_OPTIMIZATION_TRIALS,
+                }
+                .fail();
+            }
+            if *sample_count >= combination_count {
+                grid_indices(&values)
+            } else {
+                random_indices(&values, *sample_count, *seed)
+            }
+        }
+    };
+
+    let parameter_sets = indices
+        .into_iter()
+        .map(|combination| {
+            parameters
+                .iter()
+                .zip(combination)
+                .enumerate()
+                .map(|(i, (range, index))| ParameterValue {
+                    node_id: range.node_id.clone(),
+                    path: range.path.clone(),
+                    value: values[i][index].clone(),
+                })
+                .collect()
+        })
+        .collect();
+    Ok(parameter_sets)
+}
+
+// Cartesian product of the value indices, the last parameter changes fastest
+fn grid_indices(values: &[Vec<Value>]) -> Vec<Vec<usize>> {
+    values.iter().fold(vec![vec![]], |combinations, values| {
+        combinations
+            .into_iter()
+            .flat_map(|combination| {
+                (0..values.len()).map(move |index| {
+                    let mut combination = combination.clone();
+                    combination.push(index);
+                    combination
+                })
+            })
+            .collect()
+    })
+}
+
+// sample_count must be less than the number of combinations, otherwise this never ends
+fn random_indices(values: &[Vec<Value>], sample_count: usize, seed: Option<u64>) -> Vec<Vec<usize>> {
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_os_rng(),
+    };
+    let mut seen = HashSet::new();
+    let mut combinations = Vec::with_capacity(sample_count);
+    while combinations.len() < sample_count {
+        let combination = values
+            .iter()
+            .map(|values| rng.random_range(0..values.len()))
+            .collect::<Vec<usize>>();
+        if seen.insert(combination.clone()) {
+            combinations.push(combination);
+        }
+    }
+    combinations
+}
+
+/// Write the parameter values into the nodes of a strategy config, only existing values can be replaced
+pub fn apply_parameter_set(nodes: &mut Value, parameter_set: &ParameterSet) -> Result<(), BacktestEngineError> {
+    for parameter in parameter_set {
+        let target = nodes
+            .as_array_mut()
+            .and_then(|nodes| {
+                nodes
+                    .iter_mut()
+                    .find(|node| node.get("id").and_then(Value::as_str) == Some(parameter.node_id.as_str()))
+            })
+            .and_then(|node| node.pointer_mut(&parameter.path))
+            .context(OptimizationParameterNotFoundSnafu {
+                node_id: parameter.node_id.clone(),
+                path: parameter.path.clone(),
+            })?;
+        *target = parameter.value.clone();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn range(node_id: &str, path: &str, values: ParameterValues) -> ParameterRange {
+        ParameterRange {
+            node_id: node_id.to_string(),
+            path: path.to_string(),
+            values,
+        }
+    }
+
+    #[test]
+    fn test_grid_search() {
+        let parameters = vec![
+            range(
+                "indicator_node",
+                "/data/period",
+                ParameterValues::Range {
+                    start: 10.0,
+                    end: 30.0,
+                    step: 10.0,
+                },
+            ),
+            range(
+                "if_else_node",
+                "/data/threshold",
+                ParameterValues::Range {
+                    start: 0.1,
+                    end: 0.3,
+                    step: 0.1,
+                },
+            ),
+        ];
+        let parameter_sets = build_parameter_sets(&parameters, &SearchMethod::Grid).unwrap();
+        assert_eq!(parameter_sets.len(), 9);
+        assert_eq!(parameter_sets[0][0].value, json!(10));
+        assert_eq!(parameter_sets[1][1].value, json!(0.2));
+        assert_eq!(parameter_sets[8][0].value, json!(30));
+        assert_eq!(parameter_sets[8][1].value, json!(0.3));
+
+        let too_many = vec![range(
+            "indicator_node",
+            "/data/period",
+            ParameterValues::Range {
+                start: 1.0,
+                end: 2000.0,
+                step: 1.0,
+            },
+        )];
+        assert!(build_parameter_sets(&too_many, &SearchMethod::Grid).is_err());
+    }
+
+    #[test]
+    fn test_random_search_is_reproducible() {
+        let parameters = vec![
+            range(
+                "indicator_node",
+                "/data/period",
+                ParameterValues::Range {
+                    start: 5.0,
+                    end: 50.0,
+                    step: 1.0,
+                },
+            ),
+            range(
+                "indicator_node",
+                "/data/maType",
+                ParameterValues::List {
+                    values: vec![json!("SMA"), json!("EMA")],
+                },
+            ),
+        ];
+        let search_method = SearchMethod::Random {
+            sample_count: 20,
+            seed: Some(7),
+        };
+        let parameter_sets = build_parameter_sets(&parameters, &search_method).unwrap();
+        assert_eq!(parameter_sets.len(), 20);
+        assert_eq!(parameter_sets, build_parameter_sets(&parameters, &search_method).unwrap());
+        let distinct = parameter_sets.iter().map(|set| format!("{:?}", set)).collect::<HashSet<_>>();
+        assert_eq!(distinct.len(), 20);
+    }
+
+    #[test]
+    fn test_apply_parameter_set() {
+        let mut nodes = json!([
+            {"id": "start_node", "data": {}},
+            {"id": "indicator_node", "data": {"period": 14}},
+        ]);
+        let parameter_set = vec![ParameterValue {
+            node_id: "indicator_node".to_string(),
+            path: "/data/period".to_string(),
+            value: json!(21),
+        }];
+        apply_parameter_set(&mut nodes, &parameter_set).unwrap();
+        assert_eq!(nodes[1]["data"]["period"], json!(21));
+
+        let missing = vec![ParameterValue {
+            node_id: "indicator_node".to_string(),
+            path: "/data/length".to_string(),
+            value: json!(21),
+        }];
+        assert!(apply_parameter_set(&mut nodes, &missing).is_err());
+    }
+}
@@ -0,0 +1,515 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use database::mutation::backtest_walk_forward_mutation::{BacktestWalkForwardMutation, NewBacktestWalkForwardWindow};
+use futures::StreamExt;
+use heartbeat::Heartbeat;
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use star_river_core::{backtest_walk_forward::BacktestWalkForwardStatus, error::StarRiverErrorTrait, kline::KlineInterval};
+use strategy_core::strategy::StrategyConfig;
+use strategy_stats::{TradingPerformanceReport, snapshot::StatsSnapshotHistory};
+use strum::Display;
+use ta_lib::{IndicatorConfig, TALib};
+use tokio::sync::Mutex;
+use utoipa::ToSchema;
+use virtual_trading::types::VirtualTransaction;
+
+use super::{OptimizationConfig, OptimizationTrial, ParameterSet, TrialResult, apply_parameter_set, build_parameter_sets, run_trials};
+use crate::{
+    engine_error::{BacktestEngineError, InvalidWalkForwardWindowSnafu},
+    strategy::{strategy_config::BacktestStrategyConfig, strategy_error::TimeRangeNotConfiguredSnafu},
+};
+
+// Every node loading market data keeps its own copy of the backtest time range
+const TIME_RANGE_POINTER: &str = "/data/backtestConfig/exchangeModeConfig/timeRange";
+const EXCHANGE_MODE_CONFIG_POINTER: &str = "/data/backtestConfig/exchangeModeConfig";
+const BACKTEST_CONFIG_POINTER: &str = "/data/backtestConfig";
+const START_NODE_ID: &str = "start_node";
+const TIME_RANGE_FORMAT: &str = "%Y-%m-%d %H:%M:%S %z";
+
+/// Walk-forward analysis request
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WalkForwardConfig {
+    /// Parameters optimized on every in-sample window
+    #[serde(flatten)]
+    pub optimization: OptimizationConfig,
+
+    /// How the in-sample window moves forward
+    #[serde(default)]
+    pub window_mode: WindowMode,
+
+    /// Length of the in-sample window
+    pub in_sample_days: u32,
+
+    /// Length of the out-of-sample window, also the step between two windows
+    pub out_of_sample_days: u32,
+}
+
+/// In-sample window mode
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema, Display)]
+#[serde(rename_all = "camelCase")]
+#[strum(serialize_all = "camelCase")]
+pub enum WindowMode {
+    /// The in-sample window keeps its length and moves with the out-of-sample window
+    #[default]
+    Rolling,
+    /// The in-sample window always starts at the beginning of the time range and grows
+    Anchored,
+}
+
+/// Time ranges of one walk-forward step
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WalkForwardWindow {
+    pub index: usize,
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub in_sample_start: DateTime<Utc>,
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub in_sample_end: DateTime<Utc>,
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub out_of_sample_start: DateTime<Utc>,
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub out_of_sample_end: DateTime<Utc>,
+}
+
+impl WalkForwardWindow {
+    /// Split a time range into consecutive windows, the last out-of-sample window may be shorter
+    pub fn split(
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        mode: WindowMode,
+        in_sample: Duration,
+        out_of_sample: Duration,
+    ) -> Result<Vec<Self>, BacktestEngineError> {
+        if in_sample <= Duration::zero() || out_of_sample <= Duration::zero() {
+            return InvalidWalkForwardWindowSnafu {
+                reason: "window length must be greater than 0".to_string(),
+            }
+            .fail();
+        }
+        if start + in_sample >= end {
+            return InvalidWalkForwardWindowSnafu {
+                reason: "time range is not longer than one in-sample window".to_string(),
+            }
+            .fail();
+        }
+
+        let mut windows = vec![];
+        let mut out_of_sample_start = start + in_sample;
+        while out_of_sample_start < end {
+            let in_sample_start = match mode {
+                WindowMode::Rolling => out_of_sample_start - in_sample,
+                WindowMode::Anchored => start,
+            };
+            windows.push(Self {
+                index: windows.len(),
+                in_sample_start,
+                in_sample_end: out_of_sample_start,
+                out_of_sample_start,
+                out_of_sample_end: (out_of_sample_start + out_of_sample).min(end),
+            });
+            out_of_sample_start += out_of_sample;
+        }
+        Ok(windows)
+    }
+}
+
+/// Result of one walk-forward step
+struct WalkForwardWindowReport {
+    window: WalkForwardWindow,
+    /// Best parameters of the in-sample window, applied to the out-of-sample window
+    best_parameters: Option<ParameterSet>,
+    in_sample_score: Option<f64>,
+    out_of_sample_score: Option<f64>,
+    out_of_sample_performance: Option<TradingPerformanceReport>,
+    failed_trial_count: usize,
+    /// Why the window has no out-of-sample result
+    error: Option<String>,
+}
+
+impl WalkForwardWindowReport {
+    fn new(window: WalkForwardWindow) -> Self {
+        Self {
+            window,
+            best_parameters: None,
+            in_sample_score: None,
+            out_of_sample_score: None,
+            out_of_sample_performance: None,
+            failed_trial_count: 0,
+            error: None,
+        }
+    }
+
+    // A result that cannot be serialized is stored as the window error
+    fn into_new_window(self) -> NewBacktestWalkForwardWindow {
+        let best_parameters = self.best_parameters.as_ref().map(serde_json::to_value).transpose();
+        let performance = self.out_of_sample_performance.as_ref().map(serde_json::to_value).transpose();
+        let (best_parameters, out_of_sample_performance, error) = match (best_parameters, performance) {
+            (Ok(best_parameters), Ok(performance)) => (best_parameters, performance, self.error),
+            (Err(e), _) | (_, Err(e)) => (None, None, Some(format!("serialize window result failed: {e}"))),
+        };
+        NewBacktestWalkForwardWindow {
+            window_index: self.window.index as i32,
+            in_sample_start: self.window.in_sample_start,
+            in_sample_end: self.window.in_sample_end,
+            out_of_sample_start: self.window.out_of_sample_start,
+            out_of_sample_end: self.window.out_of_sample_end,
+            best_parameters,
+            in_sample_score: self.in_sample_score,
+            out_of_sample_score: self.out_of_sample_score,
+            out_of_sample_performance,
+            failed_trial_count: self.failed_trial_count as i32,
+            error,
+        }
+    }
+}
+
+/// Walk-forward request checked against the strategy, built before anything is stored
+pub(crate) struct WalkForwardPlan {
+    pub strategy_config: StrategyConfig,
+    pub config: WalkForwardConfig,
+    pub max_concurrency: usize,
+    pub parameter_sets: Vec<ParameterSet>,
+    pub windows: Vec<WalkForwardWindow>,
+    /// Start of the whole backtest time range, no window loads data before it
+    pub start_time: DateTime<Utc>,
+    pub initial_balance: f64,
+}
+
+impl WalkForwardPlan {
+    pub(crate) fn new(strategy_config: StrategyConfig, config: WalkForwardConfig) -> Result<Self, BacktestEngineError> {
+        let strategy_name = strategy_config.name.clone();
+        let nodes = strategy_config.nodes.clone().unwrap_or_default();
+        let backtest_config = nodes
+            .as_array()
+            .and_then(|nodes| nodes.iter().find(|node| is_start_node(node)))
+            .and_then(|node| node.pointer(BACKTEST_CONFIG_POINTER))
+            .and_then(|config| serde_json::from_value::<BacktestStrategyConfig>(config.clone()).ok());
+        let (Some(start_time), Some(end_time), Some(initial_balance)) = (
+            backtest_config.as_ref().and_then(|config| config.start_time()),
+            backtest_config.as_ref().and_then(|config| config.end_time()),
+            backtest_config.as_ref().map(|config| config.initial_balance),
+        ) else {
+            return Err(TimeRangeNotConfiguredSnafu { strategy_name }.build().into());
+        };
+
+        let max_concurrency = config.optimization.concurrency()?;
+        let parameter_sets = build_parameter_sets(&config.optimization.parameters, &config.optimization.search_method)?;
+        let windows = WalkForwardWindow::split(
+            start_time,
+            end_time,
+            config.window_mode,
+            Duration::days(config.in_sample_days as i64),
+            Duration::days(config.out_of_sample_days as i64),
+        )?;
+        Ok(Self {
+            strategy_config,
+            config,
+            max_concurrency,
+            parameter_sets,
+            windows,
+            start_time,
+            initial_balance,
+        })
+    }
+}
+
+/// Optimizes every in-sample window and plays the best parameters on the following out-of-sample window
+pub(crate) struct WalkForwardRunner {
+    pub walk_forward_id: i32,
+    pub plan: WalkForwardPlan,
+    pub database: DatabaseConnection,
+    pub heartbeat: Arc<Mutex<Heartbeat>>,
+}
+
+impl WalkForwardRunner {
+    // Each window is stored as soon as its out-of-sample run ends, the stitched result once the last window is stored
+    pub(crate) async fn run(self) {
+        let strategy_name = self.plan.strategy_config.name.clone();
+        tracing::info!(
+            "[{}] walk-forward {} started, {} windows, {} trials per window",
+            strategy_name,
+            self.walk_forward_id,
+            self.plan.windows.len(),
+            self.plan.parameter_sets.len()
+        );
+
+        let mut out_of_sample_histories = vec![];
+        let mut out_of_sample_transactions: Vec<VirtualTransaction> = vec![];
+        for window in self.plan.windows.iter().cloned() {
+            let window_index = window.index;
+            let (window_report, out_of_sample_result) = self.run_window(window).await;
+            if let Some(result) = out_of_sample_result {
+                out_of_sample_histories.push(result.snapshot_history);
+                out_of_sample_transactions.extend(result.headless_result.transactions);
+            }
+            if let Err(e) = BacktestWalkForwardMutation::create_backtest_walk_forward_window(
+                &self.database,
+                self.walk_forward_id,
+                window_report.into_new_window(),
+            )
+            .await
+            {
+                tracing::error!("[{}] save walk-forward window failed: {}", strategy_name, e);
+            }
+            tracing::debug!(
+                "[{}] walk-forward window {}/{} finished",
+                strategy_name,
+                window_index + 1,
+                self.plan.windows.len()
+            );
+        }
+
+        let equity_curve = StatsSnapshotHistory::stitch(self.plan.initial_balance, &out_of_sample_histories);
+        let performance = TradingPerformanceReport::new(self.plan.initial_balance, &equity_curve.snapshots, &out_of_sample_transactions);
+        let (status, equity_curve, performance) = match (serde_json::to_value(&equity_curve), serde_json::to_value(&performance)) {
+            (Ok(equity_curve), Ok(performance)) => (BacktestWalkForwardStatus::Completed, Some(equity_curve), Some(performance)),
+            (Err(e), _) | (_, Err(e)) => {
+                tracing::error!("[{}] serialize walk-forward result failed: {}", strategy_name, e);
+                (BacktestWalkForwardStatus::Failed, None, None)
+            }
+        };
+        if let Err(e) = BacktestWalkForwardMutation::finish_backtest_walk_forward(
+            &self.database,
+            self.walk_forward_id,
+            status,
+            equity_curve,
+            performance,
+        )
+        .await
+        {
+            tracing::error!("[{}] finish walk-forward failed: {}", strategy_name, e);
+        }
+        tracing::info!("[{}] walk-forward {} finished", strategy_name, self.walk_forward_id);
+    }
+
+    // A window without a successful in-sample trial or out-of-sample run is reported with its error
+    async fn run_window(&self, window: WalkForwardWindow) -> (WalkForwardWindowReport, Option<TrialResult>) {
+        let metric = &self.plan.config.optimization.metric;
+        let mut report = WalkForwardWindowReport::new(window.clone());
+
+        let in_sample_trials = match self
+            .plan
+            .parameter_sets
+            .iter()
+            .map(|parameter_set| self.build_trial(parameter_set, window.in_sample_start, window.in_sample_end))
+            .collect::<Result<Vec<OptimizationTrial>, BacktestEngineError>>()
+        {
+            Ok(trials) => trials,
+            Err(e) => {
+                e.report_log();
+                report.error = Some(e.to_string());
+                return (report, None);
+            }
+        };
+
+        // (trial index, score, parameters), the earlier trial wins a tie so the result does not depend on completion order
+        let mut best: Option<(usize, f64, ParameterSet)> = None;
+        let mut last_error = None;
+        let mut results = std::pin::pin!(run_trials(
+            in_sample_trials,
+            self.plan.max_concurrency,
+            self.database.clone(),
+            self.heartbeat.clone(),
+        ));
+        while let Some((index, parameter_set, result)) = results.next().await {
+            match result {
+                Ok(result) => {
+                    let score = result.headless_result.trading_performance.score(metric);
+                    let is_better = match &best {
+                        None => true,
+                        Some((best_index, best_score, _)) => score > *best_score || (score == *best_score && index < *best_index),
+                    };
+                    if is_better {
+                        best = Some((index, score, parameter_set));
+                    }
+                }
+                Err(e) => {
+                    e.report_log();
+                    report.failed_trial_count += 1;
+                    last_error = Some(e.to_string());
+                }
+            }
+        }
+
+        let Some((_, in_sample_score, best_parameters)) = best else {
+            report.error = last_error.or(Some("no in-sample trial".to_string()));
+            return (report, None);
+        };
+        report.in_sample_score = Some(in_sample_score);
+        report.best_parameters = Some(best_parameters.clone());
+
+        let out_of_sample_trial = match self.build_trial(&best_parameters, window.out_of_sample_start, window.out_of_sample_end) {
+            Ok(trial) => trial,
+            Err(e) => {
+                e.report_log();
+                report.error = Some(e.to_string());
+                return (report, None);
+            }
+        };
+        let mut results = std::pin::pin!(run_trials(
+            vec![out_of_sample_trial],
+            1,
+            self.database.clone(),
+            self.heartbeat.clone()
+        ));
+        match results.next().await {
+            Some((_, _, Ok(result))) => {
+                let performance = result.headless_result.trading_performance.clone();
+                report.out_of_sample_score = Some(performance.score(metric));
+                report.out_of_sample_performance = Some(performance);
+                (report, Some(result))
+            }
+            Some((_, _, Err(e))) => {
+                e.report_log();
+                report.error = Some(e.to_string());
+                (report, None)
+            }
+            None => (report, None),
+        }
+    }
+
+    fn build_trial(
+        &self,
+        parameter_set: &ParameterSet,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<OptimizationTrial, BacktestEngineError> {
+        let mut strategy_config = self.plan.strategy_config.clone();
+        let mut nodes = strategy_config.nodes.take().unwrap_or_default();
+        apply_parameter_set(&mut nodes, parameter_set)?;
+        // The lookback depends on the applied parameters, data before the backtest time range may not exist
+        let data_start = (start - indicator_warm_up(&nodes)).max(self.plan.start_time);
+        apply_time_range(&mut nodes, data_start, start, end);
+        strategy_config.nodes = Some(nodes);
+        OptimizationTrial::new(parameter_set.clone(), strategy_config)
+    }
+}
+
+fn is_start_node(node: &Value) -> bool {
+    node.get("id").and_then(Value::as_str) == Some(START_NODE_ID)
+}
+
+/// Replace the backtest time range of every node that has one
+///
+/// The start node plays from `start`, the other nodes load their data from `data_start`,
+/// so indicators already have a value on the first played bar
+pub fn apply_time_range(nodes: &mut Value, data_start: DateTime<Utc>, start: DateTime<Utc>, end: DateTime<Utc>) {
+    let time_range = |start: DateTime<Utc>| {
+        serde_json::json!({
+            "startDate": start.format(TIME_RANGE_FORMAT).to_string(),
+            "endDate": end.format(TIME_RANGE_FORMAT).to_string(),
+        })
+    };
+    for node in nodes.as_array_mut().into_iter().flatten() {
+        let range_start = if is_start_node(node) { start } else { data_start };
+        if let Some(target) = node.pointer_mut(TIME_RANGE_POINTER) {
+            *target = time_range(range_start);
+        }
+    }
+}
+
+/// Longest time an indicator of the strategy needs before its first value, `lookback` bars of its interval
+fn indicator_warm_up(nodes: &Value) -> Duration {
+    nodes
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|node| node.pointer(EXCHANGE_MODE_CONFIG_POINTER))
+        .flat_map(|config| {
+            let interval = config
+                .pointer("/selectedSymbol/interval")
+                .and_then(|interval| serde_json::from_value::<KlineInterval>(interval.clone()).ok());
+            config
+                .get("selectedIndicators")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(move |indicator| {
+                    let interval = interval.as_ref()?;
+                    let indicator_type = indicator.get("indicatorType")?.as_str()?;
+                    let indicator_config = IndicatorConfig::new(indicator_type, indicator.get("indicatorConfig")?).ok()?;
+                    Some(Duration::seconds(
+                        (TALib::lookback(&indicator_config) as u64 * interval.to_seconds()) as i64,
+                    ))
+                })
+        })
+        .max()
+        .unwrap_or_else(Duration::zero)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use serde_json::json;
+    use star_river_core::system::deserialize_time_range;
+
+    use super::*;
+
+    fn day(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, day, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_split_windows() {
+        let rolling = WalkForwardWindow::split(day(1), day(20), WindowMode::Rolling, Duration::days(7), Duration::days(5)).unwrap();
+        let ranges = rolling
+            .iter()
+            .map(|window| (window.in_sample_start, window.out_of_sample_start, window.out_of_sample_end))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranges,
+            vec![(day(1), day(8), day(13)), (day(6), day(13), day(18)), (day(11), day(18), day(20))]
+        );
+
+        let anchored = WalkForwardWindow::split(day(1), day(20), WindowMode::Anchored, Duration::days(7), Duration::days(5)).unwrap();
+        assert!(anchored.iter().all(|window| window.in_sample_start == day(1)));
+        assert_eq!(anchored[2].in_sample_end, day(18));
+
+        assert!(WalkForwardWindow::split(day(1), day(5), WindowMode::Rolling, Duration::days(7), Duration::days(5)).is_err());
+    }
+
+    #[test]
+    fn test_apply_time_range() {
+        let mut nodes = json!([
+            {"id": "start_node", "data": {"backtestConfig": {"exchangeModeConfig": {"timeRange": {}}}}},
+            {"id": "kline_node", "data": {"backtestConfig": {"exchangeModeConfig": {"timeRange": {}}}}},
+            {"id": "if_else_node", "data": {"backtestConfig": {}}},
+        ]);
+        apply_time_range(&mut nodes, day(1), day(2), day(9));
+
+        let time_range = |pointer: &str| {
+            let time_range = deserialize_time_range(nodes.pointer(pointer).unwrap().clone()).unwrap();
+            (time_range.start_date, time_range.end_date)
+        };
+        // The start node plays the window, the kline node also loads the warm-up bars before it
+        assert_eq!(time_range("/0/data/backtestConfig/exchangeModeConfig/timeRange"), (day(2), day(9)));
+        assert_eq!(time_range("/1/data/backtestConfig/exchangeModeConfig/timeRange"), (day(1), day(9)));
+        assert!(nodes.pointer("/2/data/backtestConfig/exchangeModeConfig").is_none());
+    }
+
+    #[test]
+    fn test_indicator_warm_up() {
+        let nodes = json!([
+            {"id": "start_node", "data": {"backtestConfig": {"exchangeModeConfig": {"timeRange": {}}}}},
+            {"id": "indicator_node_1", "data": {"backtestConfig": {"exchangeModeConfig": {
+                "selectedSymbol": {"configId": 1, "outputHandleId": "kline_node_output_1", "symbol": "BTCUSDT", "interval": "1m"},
+                "selectedIndicators": [
+                    {"configId": 1, "indicatorType": "ma", "indicatorConfig": {"maType": "SMA", "priceSource": "CLOSE", "timePeriod": 60}},
+                ],
+            }}}},
+            {"id": "indicator_node_2", "data": {"backtestConfig": {"exchangeModeConfig": {
+                "selectedSymbol": {"configId": 2, "outputHandleId": "kline_node_output_2", "symbol": "BTCUSDT", "interval": "15m"},
+                "selectedIndicators": [
+                    {"configId": 1, "indicatorType": "ma", "indicatorConfig": {"maType": "SMA", "priceSource": "CLOSE", "timePeriod": 14}},
+                ],
+            }}}},
+        ]);
+        // SMA(14) on 15m needs 13 bars, longer than the 59 minutes of SMA(60) on 1m
+        assert_eq!(indicator_warm_up(&nodes), Duration::minutes(13 * 15));
+        assert_eq!(indicator_warm_up(&json!([])), Duration::zero());
+    }
+}
@@ -1,4 +1,5 @@
 mod signal_generator;
+pub(crate) mod signal_schedule;
 pub(crate) mod strategy_command;
 pub(crate) mod strategy_config;
 pub(crate) mod strategy_context;
@@ -14,11 +15,17 @@ use std::sync::Arc;
 // External crate imports
 use heartbeat::Heartbeat;
 use sea_orm::DatabaseConnection;
+use snafu::ResultExt;
+use star_river_core::custom_type::StrategyName;
 // Current crate imports
-use strategy_context::BacktestStrategyContext;
+use strategy_command::{BacktestStrategyCommand, RunHeadlessCmdPayload, RunHeadlessCommand, SeekCmdPayload, SeekCommand};
+use strategy_context::{BacktestStrategyContext, HeadlessRunResult, SeekResult, SeekTarget};
+use strategy_error::{BacktestStrategyError, HeadlessRunFailedSnafu, HeadlessRunInterruptedSnafu, SeekFailedSnafu, SeekInterruptedSnafu};
 // Workspace crate imports
 use strategy_core::strategy::{StrategyConfig, strategy_trait::StrategyContextAccessor};
-use tokio::sync::{Mutex, RwLock};
+use tokio::sync::{Mutex, RwLock, mpsc, oneshot};
+
+use crate::optimizer::KlineHistoryCache;
 
 pub type PlayIndex = i32;
 
@@ -42,4 +49,54 @@ impl BacktestStrategy {
             context: Arc::new(RwLock::new(context)),
         }
     }
+
+    // A detached instance used by the optimizer, the stored strategy and its subscribers are left untouched
+    pub(crate) fn new_trial(
+        strategy_config: StrategyConfig,
+        database: DatabaseConnection,
+        heartbeat: Arc<Mutex<Heartbeat>>,
+        kline_history_cache: KlineHistoryCache,
+    ) -> Self {
+        let mut context = BacktestStrategyContext::new(strategy_config, database, heartbeat);
+        context.set_trial(kline_history_cache);
+        Self {
+            context: Arc::new(RwLock::new(context)),
+        }
+    }
+
+    // Send a headless run command and wait for its result, the strategy stays unlocked while the run plays
+    pub(crate) async fn request_headless_run(
+        strategy_name: StrategyName,
+        command_sender: mpsc::Sender<BacktestStrategyCommand>,
+    ) -> Result<HeadlessRunResult, BacktestStrategyError> {
+        let (resp_tx, resp_rx) = oneshot::channel();
+        // Not issued by a node, the engine is recorded as the sender
+        let command = RunHeadlessCommand::new("backtest_engine".to_string(), resp_tx, RunHeadlessCmdPayload);
+        if command_sender.send(command.into()).await.is_err() {
+            return Err(HeadlessRunInterruptedSnafu { strategy_name }.build());
+        }
+        let Ok(response) = resp_rx.await else {
+            return Err(HeadlessRunInterruptedSnafu { strategy_name }.build());
+        };
+        let payload = response.into_payload().context(HeadlessRunFailedSnafu { strategy_name })?;
+        Ok(payload.result)
+    }
+
+    // Send a seek command and wait until the target has been reached, like headless runs the replay plays unlocked
+    pub(crate) async fn request_seek(
+        strategy_name: StrategyName,
+        command_sender: mpsc::Sender<BacktestStrategyCommand>,
+        target: SeekTarget,
+    ) -> Result<SeekResult, BacktestStrategyError> {
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let command = SeekCommand::new("backtest_engine".to_string(), resp_tx, SeekCmdPayload::new(target));
+        if command_sender.send(command.into()).await.is_err() {
+            return Err(SeekInterruptedSnafu { strategy_name }.build());
+        }
+        let Ok(response) = resp_rx.await else {
+            return Err(SeekInterruptedSnafu { strategy_name }.build());
+        };
+        let payload = response.into_payload().context(SeekFailedSnafu { strategy_name })?;
+        Ok(payload.result)
+    }
 }
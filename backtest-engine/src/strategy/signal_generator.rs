@@ -1,7 +1,13 @@
 #![allow(unused)]
-use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use star_river_core::kline::KlineInterval;
 
+use super::signal_schedule::{interval_index_at, interval_time_at};
+
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SignalGenerator {
     pub current_index: u64,
     pub current_time: DateTime<Utc>,
@@ -10,6 +16,9 @@ pub struct SignalGenerator {
     pub min_interval: KlineInterval,
     pub finished: bool,
     total_signal_count: u64, // Total signal count
+    // Explicit signal times of calendar and data driven schedules, shared by the checkpoint copies
+    #[serde(skip)]
+    signal_times: Option<Arc<Vec<DateTime<Utc>>>>,
 }
 
 impl SignalGenerator {
@@ -22,6 +31,7 @@ impl SignalGenerator {
             min_interval: KlineInterval::Minutes1,
             finished: false,
             total_signal_count: 0,
+            signal_times: None,
         }
     }
 
@@ -36,6 +46,25 @@ impl SignalGenerator {
         self.current_time = start_time;
         self.finished = false;
         self.total_signal_count = total_signal_count;
+        self.signal_times = None;
+    }
+
+    /// Play the given signal times instead of stepping by the min interval
+    pub fn init_with_signal_times(
+        &mut self,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        min_interval: KlineInterval,
+        signal_times: Vec<DateTime<Utc>>,
+    ) {
+        self.start_time = start_time;
+        self.end_time = end_time;
+        self.min_interval = min_interval;
+        self.current_index = 0;
+        self.current_time = signal_times.first().copied().unwrap_or(start_time);
+        self.finished = false;
+        self.total_signal_count = signal_times.len() as u64;
+        self.signal_times = Some(Arc::new(signal_times));
     }
 
     pub fn next(&mut self) -> Option<(u64, DateTime<Utc>)> {
@@ -44,8 +73,7 @@ impl SignalGenerator {
             return None;
         }
 
-        // Check if current time has exceeded end time
-        if self.current_time > self.end_time {
+        if self.current_index >= self.total_signal_count {
             self.finished = true;
             return None;
         }
@@ -53,16 +81,15 @@ impl SignalGenerator {
         // Save current values to return
         let result = (self.current_index, self.current_time);
 
-        // Check if this is the last item (current_time == end_time)
-        if self.current_time == self.end_time {
+        // The last signal does not advance the index
+        if self.current_index + 1 == self.total_signal_count {
             self.finished = true;
             return Some(result);
         }
 
         // Update state for next iteration
         self.current_index += 1;
-        let duration = Duration::from_std(self.min_interval.to_duration()).unwrap();
-        self.current_time = self.current_time + duration;
+        self.current_time = self.signal_time_at(self.current_index);
 
         Some(result)
     }
@@ -73,10 +100,19 @@ impl SignalGenerator {
 
     pub fn reset(&mut self) {
         self.current_index = 0;
-        self.current_time = self.start_time;
+        self.current_time = self.signal_time_at(0);
         self.finished = false;
     }
 
+    /// Move to the position of another generator over the same signals, e.g. a checkpoint copy
+    ///
+    /// Signal times are not serialized, so only the position is taken over
+    pub fn restore_position(&mut self, other: &SignalGenerator) {
+        self.current_index = other.current_index;
+        self.current_time = other.current_time;
+        self.finished = other.finished;
+    }
+
     /// Calculate total signal count
     ///
     /// # Arguments
@@ -91,18 +127,8 @@ impl SignalGenerator {
             return 0;
         }
 
-        if start_time == end_time {
-            return 1;
-        }
-
-        // Calculate time difference
-        let time_diff = end_time.signed_duration_since(start_time);
-        let interval_duration = Duration::from_std(min_interval.to_duration()).unwrap();
-
-        // Calculate signal count: (time_diff / interval) + 1
         // +1 because it includes the starting point
-        let count = time_diff.num_milliseconds() / interval_duration.num_milliseconds();
-        (count + 1) as u64
+        interval_index_at(start_time, min_interval, end_time) + 1
     }
 
     /// Get total signal count
@@ -115,6 +141,39 @@ impl SignalGenerator {
         self.current_index
     }
 
+    /// Index of the last played signal, None if no signal has been played yet
+    pub fn last_played_index(&self) -> Option<u64> {
+        // The last signal does not advance the index, it only marks the generator as finished
+        if self.finished && self.total_signal_count > 0 {
+            Some(self.current_index)
+        } else {
+            self.current_index.checked_sub(1)
+        }
+    }
+
+    /// Index of the signal covering the datetime, None if it is outside of the time range
+    pub fn signal_index_at(&self, datetime: DateTime<Utc>) -> Option<u64> {
+        if datetime < self.start_time || datetime > self.end_time {
+            return None;
+        }
+        match &self.signal_times {
+            // Last signal at or before the datetime
+            Some(signal_times) => signal_times
+                .partition_point(|signal_time| *signal_time <= datetime)
+                .checked_sub(1)
+                .map(|index| index as u64),
+            None => Some(interval_index_at(self.start_time, &self.min_interval, datetime)),
+        }
+    }
+
+    /// Datetime of the signal at the index
+    pub fn signal_time_at(&self, index: u64) -> DateTime<Utc> {
+        match &self.signal_times {
+            Some(signal_times) => signal_times.get(index as usize).copied().unwrap_or(self.end_time),
+            None => interval_time_at(self.start_time, &self.min_interval, index),
+        }
+    }
+
     /// Get playback progress (0.0 ~ 1.0)
     ///
     /// # Returns
@@ -173,3 +232,84 @@ impl std::fmt::Debug for SignalGenerator {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_seek_positions() {
+        let start_time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end_time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 4, 0).unwrap();
+        let mut signal_generator = SignalGenerator::new();
+        signal_generator.init(start_time, end_time, KlineInterval::Minutes1);
+
+        assert_eq!(signal_generator.last_played_index(), None);
+        assert_eq!(
+            signal_generator.signal_index_at(Utc.with_ymd_and_hms(2024, 1, 1, 0, 2, 30).unwrap()),
+            Some(2)
+        );
+        assert_eq!(
+            signal_generator.signal_index_at(Utc.with_ymd_and_hms(2024, 1, 1, 0, 5, 0).unwrap()),
+            None
+        );
+        assert_eq!(
+            signal_generator.signal_time_at(3),
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 3, 0).unwrap()
+        );
+
+        signal_generator.next();
+        signal_generator.next();
+        assert_eq!(signal_generator.last_played_index(), Some(1));
+
+        // The last signal marks the generator as finished without advancing the index
+        while signal_generator.next().is_some() {}
+        assert_eq!(signal_generator.last_played_index(), Some(4));
+    }
+
+    #[test]
+    fn test_signal_times_and_calendar_months() {
+        // Weekend bars are not in the loaded klines
+        let signal_times = vec![
+            Utc.with_ymd_and_hms(2024, 1, 4, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 5, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 8, 0, 0, 0).unwrap(),
+        ];
+        let mut signal_generator = SignalGenerator::new();
+        signal_generator.init_with_signal_times(
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap(),
+            KlineInterval::Days1,
+            signal_times.clone(),
+        );
+        assert_eq!(signal_generator.total_signal_count(), 3);
+        assert_eq!(
+            signal_generator.signal_index_at(Utc.with_ymd_and_hms(2024, 1, 6, 12, 0, 0).unwrap()),
+            Some(1)
+        );
+        assert_eq!(
+            signal_generator.signal_index_at(Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap()),
+            None
+        );
+        let played: Vec<_> = std::iter::from_fn(|| signal_generator.next()).collect();
+        assert_eq!(
+            played,
+            signal_times.into_iter().enumerate().map(|(i, t)| (i as u64, t)).collect::<Vec<_>>()
+        );
+        assert_eq!(signal_generator.last_played_index(), Some(2));
+
+        let mut signal_generator = SignalGenerator::new();
+        signal_generator.init(
+            Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 4, 30, 0, 0, 0).unwrap(),
+            KlineInterval::Months1,
+        );
+        // Jan 31, Feb 29, Mar 31, Apr 30
+        assert_eq!(signal_generator.total_signal_count(), 4);
+        let played: Vec<_> = std::iter::from_fn(|| signal_generator.next()).map(|(_, t)| t).collect();
+        assert_eq!(played[1], Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap());
+        assert_eq!(played[3], Utc.with_ymd_and_hms(2024, 4, 30, 0, 0, 0).unwrap());
+    }
+}
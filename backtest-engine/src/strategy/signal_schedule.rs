@@ -0,0 +1,168 @@
+use chrono::{DateTime, Datelike, Duration, Months, Utc};
+use serde::{Deserialize, Serialize};
+use star_river_core::kline::KlineInterval;
+use virtual_trading::market_session::TradingCalendar;
+
+/// Where the signals of a backtest come from
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum SignalSchedule {
+    /// One signal every min interval between start and end time, weeks and months step by calendar
+    #[default]
+    FixedInterval,
+
+    /// One signal per timestamp of the loaded min interval klines, merged over all symbols
+    LoadedKlines,
+
+    /// Fixed interval signals that fall inside the trading sessions of an exchange calendar
+    SessionCalendar(TradingCalendar),
+}
+
+impl SignalSchedule {
+    /// Signal times of the schedule, None when signals are stepped by the min interval
+    ///
+    /// `loaded_kline_times` only has to be provided for `LoadedKlines`, it does not need to be sorted
+    pub fn signal_times(
+        &self,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        min_interval: &KlineInterval,
+        loaded_kline_times: impl Iterator<Item = DateTime<Utc>>,
+    ) -> Option<Vec<DateTime<Utc>>> {
+        match self {
+            SignalSchedule::FixedInterval => None,
+            SignalSchedule::LoadedKlines => {
+                let mut signal_times: Vec<DateTime<Utc>> = loaded_kline_times
+                    .filter(|datetime| *datetime >= start_time && *datetime <= end_time)
+                    .collect();
+                signal_times.sort();
+                signal_times.dedup();
+                Some(signal_times)
+            }
+            SignalSchedule::SessionCalendar(calendar) => {
+                let mut signal_times = vec![];
+                let mut index = 0;
+                loop {
+                    let datetime = interval_time_at(start_time, min_interval, index);
+                    if datetime > end_time {
+                        break;
+                    }
+                    if calendar.is_open(datetime) {
+                        signal_times.push(datetime);
+                    }
+                    index += 1;
+                }
+                Some(signal_times)
+            }
+        }
+    }
+
+    /// Exchange calendar of a session calendar schedule
+    pub fn trading_calendar(&self) -> Option<&TradingCalendar> {
+        match self {
+            SignalSchedule::SessionCalendar(calendar) => Some(calendar),
+            _ => None,
+        }
+    }
+}
+
+/// Datetime of the `index`-th interval after `start_time`
+///
+/// Months are added by calendar from the start time, a start on the 31st falls back to the last day of shorter months
+pub(crate) fn interval_time_at(start_time: DateTime<Utc>, interval: &KlineInterval, index: u64) -> DateTime<Utc> {
+    match interval {
+        KlineInterval::Months1 => start_time
+            .checked_add_months(Months::new(index as u32))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC),
+        _ => {
+            let interval_seconds = interval.to_seconds() as i64;
+            start_time + Duration::seconds(interval_seconds * index as i64)
+        }
+    }
+}
+
+/// Number of whole intervals from `start_time` to `datetime`, `datetime` must not be before `start_time`
+pub(crate) fn interval_index_at(start_time: DateTime<Utc>, interval: &KlineInterval, datetime: DateTime<Utc>) -> u64 {
+    match interval {
+        KlineInterval::Months1 => {
+            let months = (datetime.year() - start_time.year()) * 12 + datetime.month() as i32 - start_time.month() as i32;
+            let months = months.max(0) as u64;
+            // The start day may not have been reached in the last month yet
+            if months > 0 && interval_time_at(start_time, interval, months) > datetime {
+                months - 1
+            } else {
+                months
+            }
+        }
+        _ => {
+            let interval_seconds = interval.to_seconds() as i64;
+            (datetime.signed_duration_since(start_time).num_seconds() / interval_seconds) as u64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveTime, TimeZone, Weekday};
+    use virtual_trading::market_session::TradingSession;
+
+    use super::*;
+
+    #[test]
+    fn test_session_calendar_skips_weekends_and_holidays() {
+        // Forex style week: opens Sunday 17:00 New York, closes Friday 17:00
+        let schedule = SignalSchedule::SessionCalendar(TradingCalendar {
+            timezone: chrono_tz::America::New_York,
+            sessions: vec![TradingSession {
+                weekdays: vec![Weekday::Sun, Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu],
+                open: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+                close: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            }],
+            holidays: vec![NaiveDate::from_ymd_opt(2024, 1, 10).unwrap()],
+        });
+        // Friday 2024-01-05 00:00 UTC to Tuesday 2024-01-16 00:00 UTC
+        let start_time = Utc.with_ymd_and_hms(2024, 1, 5, 0, 0, 0).unwrap();
+        let end_time = Utc.with_ymd_and_hms(2024, 1, 16, 0, 0, 0).unwrap();
+        let signal_times = schedule
+            .signal_times(start_time, end_time, &KlineInterval::Hours1, std::iter::empty())
+            .unwrap();
+
+        // Friday 16:00 New York is the last bar of the week, Sunday 17:00 the first of the next one
+        assert!(signal_times.contains(&Utc.with_ymd_and_hms(2024, 1, 5, 21, 0, 0).unwrap()));
+        assert!(!signal_times.contains(&Utc.with_ymd_and_hms(2024, 1, 5, 22, 0, 0).unwrap()));
+        assert!(!signal_times.contains(&Utc.with_ymd_and_hms(2024, 1, 6, 12, 0, 0).unwrap()));
+        assert!(signal_times.contains(&Utc.with_ymd_and_hms(2024, 1, 7, 22, 0, 0).unwrap()));
+        // The session opening on the holiday does not trade
+        assert!(!signal_times.contains(&Utc.with_ymd_and_hms(2024, 1, 11, 0, 0, 0).unwrap()));
+        assert!(signal_times.contains(&Utc.with_ymd_and_hms(2024, 1, 10, 21, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn test_calendar_month_stepping() {
+        let start_time = Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap();
+        assert_eq!(
+            interval_time_at(start_time, &KlineInterval::Months1, 1),
+            Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            interval_time_at(start_time, &KlineInterval::Months1, 2),
+            Utc.with_ymd_and_hms(2024, 3, 31, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            interval_index_at(
+                start_time,
+                &KlineInterval::Months1,
+                Utc.with_ymd_and_hms(2024, 3, 30, 0, 0, 0).unwrap()
+            ),
+            1
+        );
+        assert_eq!(
+            interval_index_at(
+                start_time,
+                &KlineInterval::Months1,
+                Utc.with_ymd_and_hms(2024, 3, 31, 0, 0, 0).unwrap()
+            ),
+            2
+        );
+    }
+}
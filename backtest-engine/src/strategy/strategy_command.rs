@@ -18,6 +18,8 @@ use strategy_core::{
 };
 use ta_lib::Indicator;
 
+use crate::strategy::strategy_context::{HeadlessRunResult, SeekResult, SeekTarget};
+
 #[derive(Debug, From)]
 pub enum BacktestStrategyCommand {
     GetStrategyKeys(GetStrategyKeysCommand),
@@ -35,6 +37,8 @@ pub enum BacktestStrategyCommand {
     ResetCustomVariableValue(ResetCustomVarValueCommand),
     UpdateSysVariableValue(UpdateSysVarValueCommand),
     AddNodeCycleTracker(AddNodeCycleTrackerCommand),
+    RunHeadless(RunHeadlessCommand),
+    Seek(SeekCommand),
 }
 
 impl BacktestStrategyCommand {
@@ -55,6 +59,8 @@ impl BacktestStrategyCommand {
             BacktestStrategyCommand::ResetCustomVariableValue(command) => command.node_id(),
             BacktestStrategyCommand::UpdateSysVariableValue(command) => command.node_id(),
             BacktestStrategyCommand::AddNodeCycleTracker(command) => command.node_id(),
+            BacktestStrategyCommand::RunHeadless(command) => command.node_id(),
+            BacktestStrategyCommand::Seek(command) => command.node_id(),
         }
     }
 }
@@ -111,6 +117,14 @@ pub type UpdateSysVarValueResponse = StrategyResponse<UpdateSysVarRespPayload>;
 pub type AddNodeCycleTrackerCommand = StrategyCommand<AddNodeCycleTrackerCmdPayload, AddNodeCycleTrackerRespPayload>;
 pub type AddNodeCycleTrackerResponse = StrategyResponse<AddNodeCycleTrackerRespPayload>;
 
+// run headless
+pub type RunHeadlessCommand = StrategyCommand<RunHeadlessCmdPayload, RunHeadlessRespPayload>;
+pub type RunHeadlessResponse = StrategyResponse<RunHeadlessRespPayload>;
+
+// seek
+pub type SeekCommand = StrategyCommand<SeekCmdPayload, SeekRespPayload>;
+pub type SeekResponse = StrategyResponse<SeekRespPayload>;
+
 // ============ Get Strategy Keys ============
 #[derive(Debug, From)]
 pub struct GetStrategyKeysCmdPayload;
@@ -427,3 +441,43 @@ impl AddNodeCycleTrackerCmdPayload {
 
 #[derive(Debug)]
 pub struct AddNodeCycleTrackerRespPayload;
+
+// ============ Run Headless ============
+// Sent by the engine on behalf of the api, the response arrives once all signals have been played
+#[derive(Debug)]
+pub struct RunHeadlessCmdPayload;
+
+#[derive(Debug)]
+pub struct RunHeadlessRespPayload {
+    pub result: HeadlessRunResult,
+}
+
+impl RunHeadlessRespPayload {
+    pub fn new(result: HeadlessRunResult) -> Self {
+        Self { result }
+    }
+}
+
+// ============ Seek ============
+// Sent by the engine on behalf of the api, the response arrives once the target kline has been played
+#[derive(Debug)]
+pub struct SeekCmdPayload {
+    pub target: SeekTarget,
+}
+
+impl SeekCmdPayload {
+    pub fn new(target: SeekTarget) -> Self {
+        Self { target }
+    }
+}
+
+#[derive(Debug)]
+pub struct SeekRespPayload {
+    pub result: SeekResult,
+}
+
+impl SeekRespPayload {
+    pub fn new(result: SeekResult) -> Self {
+        Self { result }
+    }
+}
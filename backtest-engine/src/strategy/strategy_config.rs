@@ -8,6 +8,11 @@ use star_river_core::{
 };
 use strategy_core::{strategy::SelectedAccount, variable::custom_variable::CustomVariable};
 use strum::{Display, EnumString};
+use virtual_trading::{
+    fee_schedule::FeeSchedule, fill_model::FillModel, funding::FundingConfig, margin::MarginConfig, types::ScaleInLimit,
+};
+
+use super::signal_schedule::SignalSchedule;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Display, EnumString, Eq, PartialEq, Hash)]
 #[serde(rename_all = "lowercase")]
@@ -34,6 +39,24 @@ pub struct BacktestStrategyConfig {
     #[serde(rename = "feeRate")]
     pub fee_rate: FeeRate, // Fee rate
 
+    #[serde(rename = "feeSchedule", default)]
+    pub fee_schedule: Option<FeeSchedule>, // Maker/taker rates, fee_rate is used for both when not configured
+
+    #[serde(rename = "funding", default)]
+    pub funding: FundingConfig, // Funding rate of perpetual futures
+
+    #[serde(rename = "margin", default)]
+    pub margin: MarginConfig, // Margin mode, maintenance margin tiers and liquidation fee
+
+    #[serde(rename = "scaleInLimit", default)]
+    pub scale_in_limit: ScaleInLimit, // Max quantity and adds of a position
+
+    #[serde(rename = "fillModel", default)]
+    pub fill_model: FillModel, // Slippage and execution mode of virtual orders
+
+    #[serde(rename = "signalSchedule", default)]
+    pub signal_schedule: SignalSchedule, // Fixed interval, loaded kline timestamps or an exchange session calendar
+
     #[serde(rename = "playSpeed")]
     pub play_speed: i32, // Playback speed
 
@@ -1,3 +1,5 @@
+mod backtest_run_handler;
+mod checkpoint_handler;
 mod command_handler;
 mod data_handler;
 mod event_handler;
@@ -6,7 +8,13 @@ mod node_operation;
 mod playback_handler;
 mod workflow_builder;
 
-use std::{collections::HashMap, sync::Arc};
+pub use checkpoint_handler::StrategyCheckpoint;
+pub use playback_handler::{HeadlessRunResult, SeekResult, SeekTarget};
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
 
 use chrono::{DateTime, Utc};
 use heartbeat::Heartbeat;
@@ -31,6 +39,7 @@ use super::{
 };
 use crate::{
     node::{BacktestNode, node_command::BacktestNodeCommand, node_event::BacktestNodeEvent},
+    optimizer::KlineHistoryCache,
     strategy::{strategy_command::BacktestStrategyCommand, strategy_error::BacktestStrategyError},
     strategy_stats::BacktestStrategyStats,
     virtual_trading_system::{BacktestVts, BacktestVtsContext},
@@ -48,6 +57,13 @@ pub type BacktestStrategyMetadata = StrategyMetadata<
 pub struct BacktestStrategyContext {
     metadata: BacktestStrategyMetadata,
     is_playing: Arc<RwLock<bool>>,
+    is_headless: Arc<RwLock<bool>>,
+    // Set once the completed run is saved, cleared on reset
+    is_run_completed: Arc<RwLock<bool>>,
+    // Optimization trial: nothing is stored to the database and no events are published
+    is_trial: bool,
+    // Kline history shared with the other trials of the optimization
+    kline_history_cache: Option<KlineHistoryCache>,
     initial_play_speed: Arc<RwLock<u32>>,
     cancel_play_token: CancellationToken,
     pub(crate) batch_id: Uuid,
@@ -60,6 +76,8 @@ pub struct BacktestStrategyContext {
     keys: Arc<RwLock<HashMap<Key, NodeId>>>,
     pub(crate) vts: Arc<BacktestVts>,
     pub(crate) signal_generator: Arc<Mutex<SignalGenerator>>,
+    // Checkpoints by play index, kept across resets since replaying the same signals leads to the same state
+    checkpoints: Arc<RwLock<BTreeMap<u64, StrategyCheckpoint>>>,
 }
 
 impl BacktestStrategyContext {
@@ -93,6 +111,10 @@ impl BacktestStrategyContext {
         Self {
             metadata,
             is_playing: Arc::new(RwLock::new(false)),
+            is_headless: Arc::new(RwLock::new(false)),
+            is_run_completed: Arc::new(RwLock::new(false)),
+            is_trial: false,
+            kline_history_cache: None,
             initial_play_speed: Arc::new(RwLock::new(0)),
             cancel_play_token: CancellationToken::new(),
             batch_id: Uuid::new_v4(),
@@ -105,6 +127,7 @@ impl BacktestStrategyContext {
             keys: Arc::new(RwLock::new(HashMap::new())),
             vts,
             signal_generator: Arc::new(Mutex::new(SignalGenerator::new())),
+            checkpoints: Arc::new(RwLock::new(BTreeMap::new())),
         }
     }
 }
@@ -176,6 +199,27 @@ impl BacktestStrategyContext {
         *self.is_playing.write().await = playing;
     }
 
+    /// Whether a headless run is in progress, per-bar events are not published meanwhile
+    pub async fn is_headless(&self) -> bool {
+        *self.is_headless.read().await
+    }
+
+    /// Whether the strategy is a trial instance of a parameter optimization
+    pub fn is_trial(&self) -> bool {
+        self.is_trial
+    }
+
+    /// Mark the strategy as an optimization trial, must be set before the strategy is initialized
+    pub(crate) fn set_trial(&mut self, kline_history_cache: KlineHistoryCache) {
+        self.is_trial = true;
+        self.kline_history_cache = Some(kline_history_cache);
+    }
+
+    /// Kline history shared by the trials of an optimization, None outside of optimizations
+    pub(crate) fn kline_history_cache(&self) -> Option<&KlineHistoryCache> {
+        self.kline_history_cache.as_ref()
+    }
+
     // ========================================================================
     // 5. Playback Control - Speed
     // ========================================================================
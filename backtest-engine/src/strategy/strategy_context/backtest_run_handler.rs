@@ -0,0 +1,240 @@
+// std
+use std::sync::Arc;
+
+// third-party
+use sea_orm::DatabaseConnection;
+use snafu::{OptionExt, ResultExt};
+// workspace crate
+use database::mutation::backtest_run_mutation::{
+    BacktestRunMutation, BacktestRunOrder, BacktestRunPosition, BacktestRunTransaction, NewBacktestRun,
+};
+use star_river_core::{
+    backtest_run::{BacktestRunEquity, BacktestRunInfo, BacktestRunStatus},
+    custom_type::{StrategyId, StrategyName},
+    transaction::TransactionType,
+};
+use strategy_core::strategy::context_trait::{StrategyIdentityExt, StrategyInfoExt, StrategyInfraExt};
+use strategy_stats::strategy_stats::{StrategyStatsAccessor, StrategyStatsInfoExt};
+use tokio::sync::RwLock;
+use virtual_trading::vts_trait::VtsCtxAccessor;
+
+// current crate
+use super::BacktestStrategyContext;
+use crate::{
+    strategy::{
+        strategy_config::BacktestStrategyConfig,
+        strategy_error::{BacktestStrategyError, SaveBacktestRunFailedSnafu, SerializeBacktestRunFailedSnafu, TimeRangeNotConfiguredSnafu},
+    },
+    strategy_stats::BacktestStrategyStats,
+    virtual_trading_system::BacktestVts,
+};
+
+// Everything needed to persist a backtest run, detached from the strategy context so the play task can own it
+#[derive(Debug, Clone)]
+pub(super) struct BacktestRunRecorder {
+    strategy_id: StrategyId,
+    strategy_name: StrategyName,
+    database: DatabaseConnection,
+    config_snapshot: Option<serde_json::Value>,
+    backtest_config: BacktestStrategyConfig,
+    vts: Arc<BacktestVts>,
+    strategy_stats: BacktestStrategyStats,
+    is_run_completed: Arc<RwLock<bool>>,
+}
+
+impl BacktestRunRecorder {
+    // Mark the run as completed, false if it already was
+    // Reaching the last kline again after a seek or step back is the same run, it is only saved once until reset
+    pub(super) async fn mark_completed(&self) -> bool {
+        let mut is_run_completed_guard = self.is_run_completed.write().await;
+        !std::mem::replace(&mut *is_run_completed_guard, true)
+    }
+
+    // Write the run and its orders/positions/transactions/equity curve to the database
+    pub(super) async fn save(&self, status: BacktestRunStatus) -> Result<BacktestRunInfo, BacktestStrategyError> {
+        let (start_time, end_time) =
+            self.backtest_config
+                .start_time()
+                .zip(self.backtest_config.end_time())
+                .context(TimeRangeNotConfiguredSnafu {
+                    strategy_name: self.strategy_name.clone(),
+                })?;
+
+        let (orders, positions, transactions) = self
+            .vts
+            .with_ctx_read(|ctx| -> Result<_, serde_json::Error> {
+                let orders = ctx
+                    .history_orders
+                    .iter()
+                    .chain(ctx.unfilled_orders.iter())
+                    .map(|order| {
+                        Ok(BacktestRunOrder {
+                            order_id: order.order_id as i64,
+                            node_id: order.node_id.clone(),
+                            exchange: order.exchange.to_string(),
+                            symbol: order.symbol.clone(),
+                            order_side: order.order_side.to_string(),
+                            order_status: order.order_status.to_string(),
+                            order_type: order.order_type.to_string(),
+                            quantity: order.quantity,
+                            price: order.open_price,
+                            sl: order.sl,
+                            tp: order.tp,
+                            extra_info: serde_json::to_value(order)?,
+                            created_time: order.create_time,
+                            updated_time: order.update_time,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let positions = ctx
+                    .history_positions
+                    .iter()
+                    .chain(ctx.current_positions.iter())
+                    .map(|position| {
+                        // Positions have no node, they are opened by the node of their first transaction
+                        let node_id = ctx
+                            .transactions
+                            .iter()
+                            .find(|transaction| transaction.position_id == position.position_id)
+                            .map(|transaction| transaction.node_id.clone())
+                            .unwrap_or_default();
+                        Ok(BacktestRunPosition {
+                            position_id: position.position_id as i64,
+                            node_id,
+                            exchange: position.exchange.to_string(),
+                            symbol: position.symbol.clone(),
+                            position_side: position.position_side.to_string(),
+                            position_state: position.position_state.to_string(),
+                            quantity: position.quantity,
+                            open_price: position.open_price,
+                            unrealized_profit: position.unrealized_profit,
+                            extra_info: serde_json::to_value(position)?,
+                            created_time: position.create_time,
+                            updated_time: position.update_time,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let transactions = ctx
+                    .transactions
+                    .iter()
+                    .map(|transaction| {
+                        // Only closing transactions realize a profit
+                        let transaction_type = if transaction.profit.is_some() {
+                            TransactionType::Close
+                        } else {
+                            TransactionType::Open
+                        };
+                        Ok(BacktestRunTransaction {
+                            transaction_id: transaction.transaction_id as i64,
+                            order_id: transaction.order_id as i64,
+                            position_id: transaction.position_id as i64,
+                            node_id: transaction.node_id.clone(),
+                            exchange: transaction.exchange.to_string(),
+                            symbol: transaction.symbol.clone(),
+                            transaction_type: transaction_type.to_string(),
+                            transaction_side: transaction.transaction_side.to_string(),
+                            quantity: transaction.quantity,
+                            price: transaction.price,
+                            extra_info: serde_json::to_value(transaction)?,
+                            created_time: transaction.create_time,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok((orders, positions, transactions))
+            })
+            .await
+            .context(SerializeBacktestRunFailedSnafu {
+                strategy_name: self.strategy_name.clone(),
+            })?;
+
+        let equity_curve = self
+            .strategy_stats
+            .with_ctx_read(|ctx| {
+                ctx.asset_snapshot_history()
+                    .snapshots
+                    .iter()
+                    .map(|snapshot| BacktestRunEquity {
+                        datetime: snapshot.datetime,
+                        balance: snapshot.balance,
+                        available_balance: snapshot.available_balance,
+                        unrealized_pnl: snapshot.unrealized_pnl,
+                        equity: snapshot.equity,
+                        cumulative_return: snapshot.cumulative_return,
+                        realized_pnl: snapshot.realized_pnl,
+                        total_fee: snapshot.total_fee,
+                        total_funding: snapshot.total_funding,
+                    })
+                    .collect::<Vec<BacktestRunEquity>>()
+            })
+            .await;
+
+        let new_backtest_run = NewBacktestRun {
+            strategy_id: self.strategy_id,
+            strategy_name: self.strategy_name.clone(),
+            config_snapshot: self.config_snapshot.clone(),
+            start_time,
+            end_time,
+            initial_balance: self.backtest_config.initial_balance,
+            fee_rate: self.backtest_config.fee_rate,
+            leverage: self.backtest_config.leverage,
+            status,
+            orders,
+            positions,
+            transactions,
+            equity_curve,
+        };
+
+        let backtest_run = BacktestRunMutation::create_backtest_run(&self.database, new_backtest_run)
+            .await
+            .context(SaveBacktestRunFailedSnafu {
+                strategy_name: self.strategy_name.clone(),
+            })?;
+        tracing::info!(
+            "[{}] backtest run {} saved, status: {}",
+            self.strategy_name,
+            backtest_run.id,
+            backtest_run.status
+        );
+        Ok(backtest_run)
+    }
+}
+
+impl BacktestStrategyContext {
+    pub(super) async fn backtest_run_recorder(&self) -> Result<BacktestRunRecorder, BacktestStrategyError> {
+        let backtest_config = self.get_strategy_config().await?;
+        let config_snapshot = serde_json::to_value(self.strategy_config()).context(SerializeBacktestRunFailedSnafu {
+            strategy_name: self.strategy_name().clone(),
+        })?;
+        Ok(BacktestRunRecorder {
+            strategy_id: self.strategy_id(),
+            strategy_name: self.strategy_name().clone(),
+            database: self.database().clone(),
+            config_snapshot: Some(config_snapshot),
+            backtest_config,
+            vts: self.vts.clone(),
+            strategy_stats: self.strategy_stats().clone(),
+            is_run_completed: self.is_run_completed.clone(),
+        })
+    }
+
+    // Persist the current run outside of the play task
+    pub async fn save_backtest_run(&self, status: BacktestRunStatus) -> Result<BacktestRunInfo, BacktestStrategyError> {
+        self.backtest_run_recorder().await?.save(status).await
+    }
+
+    // Called on stop: a run that was started but not played to the end is saved as stopped
+    // A run already saved as completed is not saved again after a step back
+    pub async fn save_unfinished_backtest_run(&self) {
+        let (current_index, is_finished) = {
+            let signal_generator_guard = self.signal_generator.lock().await;
+            (signal_generator_guard.current_index(), signal_generator_guard.is_finished())
+        };
+        if current_index == 0 || is_finished || self.is_trial() || *self.is_run_completed.read().await {
+            return;
+        }
+
+        if let Err(e) = self.save_backtest_run(BacktestRunStatus::Stopped).await {
+            tracing::error!("[{}] save stopped backtest run failed: {}", self.strategy_name(), e);
+        }
+    }
+}
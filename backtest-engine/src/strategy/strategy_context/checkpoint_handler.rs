@@ -0,0 +1,388 @@
+// std
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use chrono::{DateTime, Utc};
+use database::{
+    mutation::backtest_checkpoint_mutation::{BacktestCheckpointMutation, NewBacktestCheckpoint},
+    query::backtest_checkpoint_query::BacktestCheckpointQuery,
+};
+use key::{IndicatorKey, KeyTrait, KlineKey};
+use serde::{Deserialize, Serialize};
+use snafu::{IntoError, OptionExt, ResultExt};
+use star_river_core::{
+    backtest_checkpoint::BacktestCheckpointInfo,
+    custom_type::{NodeId, NodeName, StrategyName},
+    kline::{Kline, KlineInterval},
+    system::map_entries,
+};
+use strategy_core::{
+    error::strategy_error::{NodeCmdRespRecvFailedSnafu, NodeCmdSendFailedSnafu},
+    event::node_common_event::NodeRunningLogEvent,
+    node::NodeTrait,
+    strategy::{
+        context_trait::{
+            StrategyCommunicationExt, StrategyIdentityExt, StrategyInfoExt, StrategyInfraExt, StrategyMetaDataExt, StrategyWorkflowExt,
+        },
+        cycle::Cycle,
+    },
+    variable::{
+        custom_variable::CustomVariable,
+        sys_varibale::{SysVariable, SysVariableType},
+        tagged_variable::{custom_variables, sys_variables},
+    },
+};
+use strategy_stats::{snapshot::StatsSnapshotHistory, strategy_stats::StrategyStatsInfoExt};
+use ta_lib::Indicator;
+// third-party
+use tokio::sync::{Mutex, RwLock, mpsc, oneshot};
+use virtual_trading::{context::snapshot_handler::VtsSnapshot, vts_trait::VtsCtxAccessor};
+
+// current crate
+use super::BacktestStrategyContext;
+// workspace crate
+use crate::{
+    node::{
+        node_command::{
+            BacktestNodeCommand, GetNodeSnapshotCmdPayload, GetNodeSnapshotCommand, RestoreNodeSnapshotCmdPayload,
+            RestoreNodeSnapshotCommand,
+        },
+        node_snapshot::NodeSnapshot,
+    },
+    strategy::{
+        signal_generator::SignalGenerator,
+        strategy_error::{
+            AlreadyPlayingSnafu, BacktestStrategyError, CheckpointMismatchSnafu, DeserializeCheckpointFailedSnafu,
+            LoadCheckpointFailedSnafu, NothingToSaveSnafu, SaveCheckpointFailedSnafu, SerializeCheckpointFailedSnafu,
+        },
+        strategy_state_machine::BacktestStrategyRunState,
+    },
+    strategy_stats::BacktestStrategyStats,
+    virtual_trading_system::BacktestVts,
+};
+use strategy_stats::strategy_stats::StrategyStatsAccessor;
+
+// Signals played between two checkpoints, long backtests use a wider interval so at most MAX_CHECKPOINTS are kept
+const CHECKPOINT_INTERVAL: u64 = 500;
+const MAX_CHECKPOINTS: u64 = 200;
+
+/// Whole strategy state right after a signal has been played
+///
+/// Serialized as json when saved to the database, see [`BacktestStrategyContext::save_checkpoint`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StrategyCheckpoint {
+    pub play_index: u64,
+    pub strategy_time: DateTime<Utc>,
+    pub signal_generator: SignalGenerator,
+    pub vts: VtsSnapshot,
+    pub stats_history: StatsSnapshotHistory,
+    #[serde(with = "custom_variables")]
+    pub custom_variables: HashMap<String, CustomVariable>,
+    #[serde(with = "sys_variables")]
+    pub sys_variables: HashMap<SysVariableType, SysVariable>,
+    // Min interval series are loaded on initialization, only the series built while playing are kept
+    #[serde(with = "map_entries")]
+    pub kline_data: HashMap<KlineKey, Vec<Kline>>,
+    #[serde(with = "map_entries")]
+    pub indicator_data: HashMap<IndicatorKey, Vec<Indicator>>,
+    pub running_log_count: usize,
+    pub node_snapshots: HashMap<NodeId, NodeSnapshot>,
+}
+
+// Everything needed to capture and restore checkpoints, detached from the strategy context so the play task can own it
+#[derive(Debug, Clone)]
+pub(super) struct CheckpointRecorder {
+    strategy_name: StrategyName,
+    min_interval: KlineInterval,
+    signal_generator: Arc<Mutex<SignalGenerator>>,
+    vts: Arc<BacktestVts>,
+    strategy_stats: BacktestStrategyStats,
+    custom_variable: Arc<RwLock<HashMap<String, CustomVariable>>>,
+    sys_variable: Arc<RwLock<HashMap<SysVariableType, SysVariable>>>,
+    kline_data: Arc<RwLock<HashMap<KlineKey, Vec<Kline>>>>,
+    indicator_data: Arc<RwLock<HashMap<IndicatorKey, Vec<Indicator>>>>,
+    running_log: Arc<RwLock<Vec<NodeRunningLogEvent>>>,
+    // Nodes in topological order
+    nodes: Vec<(NodeId, NodeName, mpsc::Sender<BacktestNodeCommand>)>,
+    checkpoints: Arc<RwLock<BTreeMap<u64, StrategyCheckpoint>>>,
+}
+
+impl CheckpointRecorder {
+    // Called by the play task once all nodes have executed the signal
+    pub(super) async fn capture_if_due(&self, play_index: u64, strategy_time: DateTime<Utc>, total_signal_count: u64) {
+        let interval = CHECKPOINT_INTERVAL.max(total_signal_count.div_ceil(MAX_CHECKPOINTS));
+        if !(play_index + 1).is_multiple_of(interval) || self.checkpoints.read().await.contains_key(&play_index) {
+            return;
+        }
+
+        match self.capture(play_index, strategy_time).await {
+            Ok(checkpoint) => {
+                self.checkpoints.write().await.insert(play_index, checkpoint);
+                tracing::debug!("[{}]: checkpoint captured at play index {}", self.strategy_name, play_index);
+            }
+            Err(e) => tracing::error!(
+                "[{}]: capture checkpoint at play index {} failed: {}",
+                self.strategy_name,
+                play_index,
+                e
+            ),
+        }
+    }
+
+    pub(super) async fn capture(&self, play_index: u64, strategy_time: DateTime<Utc>) -> Result<StrategyCheckpoint, BacktestStrategyError> {
+        let signal_generator = self.signal_generator.lock().await.clone();
+        let vts = self.vts.with_ctx_read(|ctx| ctx.snapshot()).await;
+        let stats_history = self.strategy_stats.with_ctx_read(|ctx| ctx.asset_snapshot_history().clone()).await;
+        let custom_variables = self.custom_variable.read().await.clone();
+        let sys_variables = self.sys_variable.read().await.clone();
+        let kline_data = self
+            .kline_data
+            .read()
+            .await
+            .iter()
+            .filter(|(key, _)| key.interval() != self.min_interval)
+            .map(|(key, data)| (key.clone(), data.clone()))
+            .collect();
+        let indicator_data = self
+            .indicator_data
+            .read()
+            .await
+            .iter()
+            .filter(|(key, _)| key.interval() != self.min_interval)
+            .map(|(key, data)| (key.clone(), data.clone()))
+            .collect();
+        let running_log_count = self.running_log.read().await.len();
+
+        let mut node_snapshots = HashMap::new();
+        for (node_id, node_name, command_sender) in self.nodes.iter() {
+            let (resp_tx, resp_rx) = oneshot::channel();
+            let command = GetNodeSnapshotCommand::new(node_id.clone(), node_name.clone(), resp_tx, GetNodeSnapshotCmdPayload);
+            self.send_node_command(command_sender, node_name, command.into()).await?;
+            let response = resp_rx.await.context(NodeCmdRespRecvFailedSnafu {
+                strategy_name: self.strategy_name.clone(),
+                node_name: node_name.clone(),
+            })?;
+            if let Some(payload) = response.payload() {
+                node_snapshots.insert(node_id.clone(), payload.snapshot.clone());
+            }
+        }
+
+        Ok(StrategyCheckpoint {
+            play_index,
+            strategy_time,
+            signal_generator,
+            vts,
+            stats_history,
+            custom_variables,
+            sys_variables,
+            kline_data,
+            indicator_data,
+            running_log_count,
+            node_snapshots,
+        })
+    }
+
+    pub(super) async fn restore(&self, checkpoint: StrategyCheckpoint) -> Result<(), BacktestStrategyError> {
+        for (node_id, node_name, command_sender) in self.nodes.iter() {
+            let Some(snapshot) = checkpoint.node_snapshots.get(node_id) else {
+                continue;
+            };
+            let (resp_tx, resp_rx) = oneshot::channel();
+            let payload = RestoreNodeSnapshotCmdPayload::new(snapshot.clone());
+            let command = RestoreNodeSnapshotCommand::new(node_id.clone(), node_name.clone(), resp_tx, payload);
+            self.send_node_command(command_sender, node_name, command.into()).await?;
+            resp_rx.await.context(NodeCmdRespRecvFailedSnafu {
+                strategy_name: self.strategy_name.clone(),
+                node_name: node_name.clone(),
+            })?;
+        }
+
+        self.signal_generator.lock().await.restore_position(&checkpoint.signal_generator);
+        self.vts.with_ctx_write(|ctx| ctx.restore(checkpoint.vts)).await;
+        self.strategy_stats
+            .with_ctx_write(|ctx| *ctx.asset_snapshot_history_mut() = checkpoint.stats_history)
+            .await;
+        *self.custom_variable.write().await = checkpoint.custom_variables;
+        *self.sys_variable.write().await = checkpoint.sys_variables;
+
+        let mut kline_data_guard = self.kline_data.write().await;
+        kline_data_guard.retain(|key, _| key.interval() == self.min_interval);
+        kline_data_guard.extend(checkpoint.kline_data);
+        drop(kline_data_guard);
+        let mut indicator_data_guard = self.indicator_data.write().await;
+        indicator_data_guard.retain(|key, _| key.interval() == self.min_interval);
+        indicator_data_guard.extend(checkpoint.indicator_data);
+        drop(indicator_data_guard);
+
+        self.running_log.write().await.truncate(checkpoint.running_log_count);
+        Ok(())
+    }
+
+    // The nearest checkpoint at or before the play index
+    pub(super) async fn nearest(&self, play_index: u64) -> Option<StrategyCheckpoint> {
+        self.checkpoints
+            .read()
+            .await
+            .range(..=play_index)
+            .next_back()
+            .map(|(_, checkpoint)| checkpoint.clone())
+    }
+
+    async fn send_node_command(
+        &self,
+        command_sender: &mpsc::Sender<BacktestNodeCommand>,
+        node_name: &NodeName,
+        command: BacktestNodeCommand,
+    ) -> Result<(), BacktestStrategyError> {
+        command_sender.send(command).await.map_err(|e| {
+            NodeCmdSendFailedSnafu {
+                strategy_name: self.strategy_name.clone(),
+                node_name: node_name.clone(),
+            }
+            .into_error(Arc::new(e))
+        })?;
+        Ok(())
+    }
+}
+
+impl BacktestStrategyContext {
+    pub(super) async fn checkpoint_recorder(&self) -> CheckpointRecorder {
+        let mut nodes = vec![];
+        for node in self.topological_sort().unwrap_or_default() {
+            let node_id = node.node_id().await;
+            let node_name = node.node_name().await;
+            let command_sender = self.node_command_sender(&node_id).clone();
+            nodes.push((node_id, node_name, command_sender));
+        }
+
+        CheckpointRecorder {
+            strategy_name: self.strategy_name().clone(),
+            min_interval: self.min_interval.clone(),
+            signal_generator: self.signal_generator.clone(),
+            vts: self.vts.clone(),
+            strategy_stats: self.strategy_stats().clone(),
+            custom_variable: self.metadata().custom_variable(),
+            sys_variable: self.metadata().sys_variable(),
+            kline_data: self.kline_data.clone(),
+            indicator_data: self.indicator_data.clone(),
+            running_log: self.running_log.clone(),
+            nodes,
+            checkpoints: self.checkpoints.clone(),
+        }
+    }
+
+    // Put the strategy back to the state captured by the checkpoint, the next played signal follows the checkpoint
+    pub(super) async fn restore_checkpoint(&self, checkpoint: StrategyCheckpoint) -> Result<(), BacktestStrategyError> {
+        let play_index = checkpoint.play_index;
+        let strategy_time = checkpoint.strategy_time;
+        self.checkpoint_recorder().await.restore(checkpoint).await?;
+
+        // Receivers are not notified, a changed cycle would make the start node play the signal again
+        self.strategy_time_watch_tx().send_if_modified(|time| {
+            *time = strategy_time;
+            false
+        });
+        self.cycle_watch_tx().send_if_modified(|cycle| {
+            *cycle = Cycle::Id(play_index);
+            false
+        });
+        tracing::info!("[{}]: checkpoint at play index {} restored", self.strategy_name(), play_index);
+        Ok(())
+    }
+
+    // Save the state after the last played kline, the playback must be paused
+    pub async fn save_checkpoint(&self) -> Result<BacktestCheckpointInfo, BacktestStrategyError> {
+        if self.is_playing().await {
+            return Err(AlreadyPlayingSnafu {}.build());
+        }
+        let strategy_name = self.strategy_name().clone();
+        let play_index = self.signal_generator.lock().await.last_played_index().context(NothingToSaveSnafu {
+            strategy_name: strategy_name.clone(),
+        })?;
+
+        let checkpoint = self.checkpoint_recorder().await.capture(play_index, self.strategy_time()).await?;
+        let new_checkpoint = NewBacktestCheckpoint {
+            strategy_id: self.strategy_id(),
+            strategy_name: strategy_name.clone(),
+            play_index: play_index as i64,
+            strategy_datetime: checkpoint.strategy_time,
+            checkpoint: serde_json::to_value(&checkpoint).context(SerializeCheckpointFailedSnafu {
+                strategy_name: strategy_name.clone(),
+            })?,
+        };
+        let checkpoint_info = BacktestCheckpointMutation::create_backtest_checkpoint(self.database(), new_checkpoint)
+            .await
+            .context(SaveCheckpointFailedSnafu {
+                strategy_name: strategy_name.clone(),
+            })?;
+        tracing::info!(
+            "[{}]: checkpoint {} saved at play index {}",
+            strategy_name,
+            checkpoint_info.id,
+            play_index
+        );
+        Ok(checkpoint_info)
+    }
+
+    // Put the strategy back to a saved checkpoint, the playback stays paused on it.
+    // The node parameters may have been changed since the checkpoint was saved, the time range and the nodes may not
+    pub async fn load_checkpoint(&mut self, checkpoint_id: i32) -> Result<BacktestCheckpointInfo, BacktestStrategyError> {
+        if self.is_playing().await {
+            return Err(AlreadyPlayingSnafu {}.build());
+        }
+        let strategy_name = self.strategy_name().clone();
+        let (checkpoint_info, checkpoint) = BacktestCheckpointQuery::get_backtest_checkpoint_by_id(self.database(), checkpoint_id)
+            .await
+            .context(LoadCheckpointFailedSnafu {
+                strategy_name: strategy_name.clone(),
+                checkpoint_id,
+            })?;
+        let mut checkpoint = serde_json::from_value::<StrategyCheckpoint>(checkpoint).context(DeserializeCheckpointFailedSnafu {
+            strategy_name: strategy_name.clone(),
+            checkpoint_id,
+        })?;
+
+        let mismatch = |reason: &str| {
+            CheckpointMismatchSnafu {
+                strategy_name: strategy_name.clone(),
+                checkpoint_id,
+                reason: reason.to_string(),
+            }
+            .build()
+        };
+        if checkpoint_info.strategy_id != self.strategy_id() {
+            return Err(mismatch("saved by another strategy"));
+        }
+        {
+            let signal_generator_guard = self.signal_generator.lock().await;
+            let saved = &checkpoint.signal_generator;
+            if saved.start_time != signal_generator_guard.start_time
+                || saved.end_time != signal_generator_guard.end_time
+                || saved.min_interval != signal_generator_guard.min_interval
+            {
+                return Err(mismatch("the backtest time range or the min interval has changed"));
+            }
+            if saved.total_signal_count() != signal_generator_guard.total_signal_count() {
+                return Err(mismatch("the signal schedule has changed"));
+            }
+        }
+        let mut node_ids = vec![];
+        for node in self.topological_sort().unwrap_or_default() {
+            node_ids.push(node.node_id().await);
+        }
+        if node_ids.len() != checkpoint.node_snapshots.len()
+            || node_ids.iter().any(|node_id| !checkpoint.node_snapshots.contains_key(node_id))
+        {
+            return Err(mismatch("nodes have been added or removed"));
+        }
+
+        // The cached drawdown is not serialized
+        checkpoint.stats_history.recalculate_max_drawdown();
+        self.restore_checkpoint(checkpoint).await?;
+        self.store_strategy_status(BacktestStrategyRunState::Pausing.to_string()).await?;
+        Ok(checkpoint_info)
+    }
+}
@@ -1,8 +1,11 @@
 // workspace crate
 use chrono::{DateTime, Utc};
 use key::{IndicatorKey, KeyTrait, KlineKey};
-use star_river_core::kline::Kline;
-use strategy_core::strategy::context_trait::StrategyIdentityExt;
+use star_river_core::{
+    core_error::CoreError,
+    kline::{Kline, KlineInterval, resample_klines},
+};
+use strategy_core::strategy::context_trait::{StrategyIdentityExt, StrategyInfoExt};
 use ta_lib::Indicator;
 
 // current crate
@@ -124,6 +127,37 @@ where
     }
 }
 
+/// Resample min interval klines up to and including `datetime` into `interval` klines
+/// Klines after `datetime` are never touched, so the last period is a partially-formed bar (no look-ahead)
+/// When `limit` is provided, only the last `limit` periods are resampled
+fn resample_kline_data(
+    data: &[Kline],
+    interval: &KlineInterval,
+    datetime: DateTime<Utc>,
+    limit: Option<i32>,
+) -> Result<Vec<Kline>, CoreError> {
+    let end = data.partition_point(|kline| kline.datetime() <= datetime);
+    let mut start = 0;
+    if let Some(limit) = limit {
+        let mut periods = 0;
+        let mut current_open_time = None;
+        start = end;
+        // Walk backwards until `limit` periods are collected
+        for (i, kline) in data[..end].iter().enumerate().rev() {
+            let open_time = interval.open_time(kline.datetime())?;
+            if current_open_time != Some(open_time) {
+                if periods == limit {
+                    break;
+                }
+                periods += 1;
+                current_open_time = Some(open_time);
+            }
+            start = i;
+        }
+    }
+    resample_klines(&data[start..end], interval)
+}
+
 mod kline {
     use std::collections::hash_map::Entry;
 
@@ -200,13 +234,27 @@ mod kline {
             limit: Option<i32>,
         ) -> Result<(Vec<Kline>, Option<u64>), BacktestStrategyError> {
             let kline_data_guard = self.kline_data.read().await;
-            let data = kline_data_guard.get(kline_key).context(KeyNotFoundSnafu {
-                strategy_name: self.strategy_name(),
-                key: kline_key.key_str(),
-            })?;
+            if let Some(data) = kline_data_guard.get(kline_key) {
+                // Use generic helper method with kline datetime extractor
+                return slice_time_series_data(data, datetime, index, limit, |k| k.datetime());
+            }
 
-            // Use generic helper method with kline datetime extractor
-            slice_time_series_data(data, datetime, index, limit, |k| k.datetime())
+            // Interval not maintained by any kline node, resample it from the min interval klines on the fly
+            let mut min_interval_kline_key = kline_key.clone();
+            min_interval_kline_key.interval = self.min_interval.clone();
+            let data = kline_data_guard
+                .get(&min_interval_kline_key)
+                .filter(|_| kline_key.interval() > self.min_interval)
+                .context(KeyNotFoundSnafu {
+                    strategy_name: self.strategy_name(),
+                    key: kline_key.key_str(),
+                })?;
+
+            // Without datetime, only klines up to the current strategy time are visible
+            let resampled_data = resample_kline_data(data, &kline_key.interval(), datetime.unwrap_or_else(|| self.strategy_time()), limit)?;
+            // Index hint and correct index refer to the min interval series, so they are not used here
+            let (kline_series, _) = slice_time_series_data(&resampled_data, datetime, None, limit, |k| k.datetime())?;
+            Ok((kline_series, None))
         }
 
         pub async fn update_kline_data(&mut self, kline_key: &KlineKey, kline: &Kline) -> Kline {
@@ -313,3 +361,45 @@ mod indicator {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDateTime};
+
+    use super::*;
+
+    #[test]
+    fn test_resample_kline_data_without_look_ahead() {
+        let start = NaiveDateTime::parse_from_str("2024-01-01 00:00:00", "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_utc();
+        // 3 hours of 1m klines
+        let data: Vec<Kline> = (0..180)
+            .map(|i| {
+                Kline::new(
+                    start + Duration::minutes(i),
+                    i as f64,
+                    i as f64 + 1.0,
+                    i as f64 - 1.0,
+                    i as f64,
+                    1.0,
+                )
+            })
+            .collect();
+
+        // At 01:29 the 1h kline of 01:00 is partially formed from 30 min klines
+        let now = start + Duration::minutes(89);
+        let resampled = resample_kline_data(&data, &KlineInterval::Hours1, now, None).unwrap();
+        assert_eq!(resampled.len(), 2);
+        let last = resampled.last().unwrap();
+        assert_eq!(last.datetime(), start + Duration::hours(1));
+        assert_eq!((last.open(), last.high(), last.close(), last.volume()), (60.0, 90.0, 89.0, 30.0));
+
+        // Limit only resamples the last periods
+        let resampled = resample_kline_data(&data, &KlineInterval::Minutes15, now, Some(2)).unwrap();
+        assert_eq!(resampled.len(), 2);
+        assert_eq!(resampled[0].datetime(), start + Duration::minutes(60));
+        assert_eq!(resampled[0].volume(), 15.0);
+        assert_eq!(resampled[1].volume(), 15.0);
+    }
+}
@@ -3,7 +3,7 @@ use chrono::{DateTime, Utc};
 use key::{Key, KeyTrait};
 use strategy_core::strategy::context_trait::{StrategyIdentityExt, StrategyInfoExt};
 use strategy_stats::{
-    StatsSnapshot,
+    StatsSnapshot, TradingPerformanceReport,
     strategy_stats::{StrategyStatsAccessor, StrategyStatsInfoExt},
 };
 use virtual_trading::{
@@ -48,6 +48,15 @@ impl BacktestStrategyContext {
         stats_history
     }
 
+    pub async fn get_trading_performance(&self) -> TradingPerformanceReport {
+        let (initial_balance, transactions) = self.vts.with_ctx_read(|ctx| (ctx.initial_balance, ctx.transactions.clone())).await;
+        let trading_performance = self
+            .strategy_stats()
+            .with_ctx_read(|ctx| TradingPerformanceReport::new(initial_balance, &ctx.asset_snapshot_history().snapshots, &transactions))
+            .await;
+        trading_performance
+    }
+
     pub async fn get_strategy_data(
         &self,
         datetime: Option<DateTime<Utc>>,
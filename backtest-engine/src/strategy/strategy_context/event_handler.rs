@@ -27,7 +27,7 @@ use crate::{
         strategy_error::BacktestStrategyError,
         strategy_log_message::{
             FuturesOrderCanceledMsg, FuturesOrderCreatedMsg, FuturesOrderFilledMsg, LongLimitOrderExecutedDirectlyMsg,
-            ShortLimitOrderExecutedDirectlyMsg,
+            PositionLiquidatedMsg, ShortLimitOrderExecutedDirectlyMsg,
         },
     },
 };
@@ -205,6 +205,12 @@ impl StrategyEventHandlerExt for BacktestStrategyContext {
                     cmd.respond(resp);
                 }
             }
+            BacktestStrategyCommand::RunHeadless(cmd) => {
+                self.run_headless(cmd).await;
+            }
+            BacktestStrategyCommand::Seek(cmd) => {
+                self.seek(cmd).await;
+            }
         }
     }
 
@@ -272,25 +278,35 @@ impl StrategyEventHandlerExt for BacktestStrategyContext {
                                 let mut strategy_benchmark_guard = self.benchmark().write().await;
                                 strategy_benchmark_guard.add_cycle_tracker(tracker);
                             }
-                            let benchmark_clone = Arc::clone(&self.benchmark());
-
-                            let strategy_id = self.strategy_id();
-                            tokio::spawn(async move {
-                                let strategy_benchmark_guard = benchmark_clone.read().await;
-                                let report = strategy_benchmark_guard.report();
-                                let event: BacktestStrategyEvent = StrategyPerformanceUpdateEvent::new(strategy_id, report.clone()).into();
-                                let _ = EventCenterSingleton::publish(event.into()).await;
-                            });
+                            // Per-cycle performance updates are not published during a headless run
+                            if !self.is_headless().await {
+                                let benchmark_clone = Arc::clone(&self.benchmark());
+
+                                let strategy_id = self.strategy_id();
+                                tokio::spawn(async move {
+                                    let strategy_benchmark_guard = benchmark_clone.read().await;
+                                    let report = strategy_benchmark_guard.report();
+                                    let event: BacktestStrategyEvent =
+                                        StrategyPerformanceUpdateEvent::new(strategy_id, report.clone()).into();
+                                    let _ = EventCenterSingleton::publish(event.into()).await;
+                                });
+                            }
                         }
                     }
                 }
                 CommonEvent::NodeRunningLog(running_log_event) => {
                     self.add_running_log(running_log_event.clone()).await;
+                    if self.is_trial() {
+                        return Ok(());
+                    }
                     let backtest_strategy_event: BacktestStrategyEvent = running_log_event.clone().into();
                     let event: Event = backtest_strategy_event.into();
                     EventCenterSingleton::publish(event).await?;
                 }
                 CommonEvent::RunStateLog(state_log_event) => {
+                    if self.is_trial() {
+                        return Ok(());
+                    }
                     let backtest_strategy_event: BacktestStrategyEvent = state_log_event.clone().into();
                     let event: Event = backtest_strategy_event.into();
                     EventCenterSingleton::publish(event).await?;
@@ -299,6 +315,11 @@ impl StrategyEventHandlerExt for BacktestStrategyContext {
             }
         }
 
+        // Per-bar updates are not published during a headless run
+        if self.is_headless().await {
+            return Ok(());
+        }
+
         if let BacktestNodeEvent::KlineNode(kline_node_event) = &node_event {
             match kline_node_event {
                 KlineNodeEvent::KlineUpdate(kline_update_event) => {
@@ -337,6 +358,10 @@ impl StrategyEventHandlerExt for BacktestStrategyContext {
 
 impl BacktestStrategyContext {
     pub async fn handle_vts_event(&mut self, event: VtsEvent) -> Result<(), BacktestStrategyError> {
+        // Trials share the strategy id with the stored strategy, their trades must not reach its subscribers
+        if self.is_trial() {
+            return Ok(());
+        }
         match event {
             VtsEvent::LimitOrderExecutedDirectly { limit_price, order } => {
                 let log_message = if order.order_side == FuturesOrderSide::Long {
@@ -365,6 +390,9 @@ impl BacktestStrategyContext {
                 EventCenterSingleton::publish(event.into()).await?;
             }
             VtsEvent::PositionUpdated(position) => {
+                if self.is_headless().await {
+                    return Ok(());
+                }
                 let event = BacktestStrategyEvent::PositionUpdated {
                     virtual_position: position,
                 };
@@ -376,8 +404,37 @@ impl BacktestStrategyContext {
                 };
                 EventCenterSingleton::publish(event.into()).await?;
             }
+            VtsEvent::PositionLiquidated {
+                liquidation_price,
+                liquidation_fee,
+                position,
+            } => {
+                let log_message = PositionLiquidatedMsg::new(
+                    self.strategy_name().clone(),
+                    position.position_id,
+                    position.symbol.clone(),
+                    liquidation_price,
+                    liquidation_fee,
+                );
+                let log_event: BacktestStrategyEvent = StrategyRunningLogEvent::warn_with_time(
+                    self.cycle_id(),
+                    self.strategy_id().clone(),
+                    log_message.to_string(),
+                    None,
+                    None,
+                    self.strategy_time(),
+                )
+                .into();
+                let event = BacktestStrategyEvent::PositionLiquidated {
+                    liquidation_price,
+                    liquidation_fee,
+                    virtual_position: position,
+                };
+                EventCenterSingleton::publish(log_event.into()).await?;
+                EventCenterSingleton::publish(event.into()).await?;
+            }
             VtsEvent::FuturesOrderCreated(order) => {
-                if let OrderType::Limit = order.order_type {
+                if let OrderType::Limit | OrderType::StopMarket | OrderType::StopLimit = order.order_type {
                     let log_message = FuturesOrderCreatedMsg::new(
                         self.strategy_name().clone(),
                         order.order_id,
@@ -430,6 +487,14 @@ impl BacktestStrategyContext {
                 EventCenterSingleton::publish(log_event.into()).await?;
                 EventCenterSingleton::publish(event.into()).await?;
             }
+            VtsEvent::FuturesOrderModified(order) => {
+                let event = BacktestStrategyEvent::FuturesOrderModified { futures_order: order };
+                EventCenterSingleton::publish(event.into()).await?;
+            }
+            VtsEvent::OrderGroupLegActivated(order) => {
+                let event = BacktestStrategyEvent::OrderGroupLegActivated { virtual_order: order };
+                EventCenterSingleton::publish(event.into()).await?;
+            }
             VtsEvent::TakeProfitOrderCreated(order) => {
                 let log_message = FuturesOrderCreatedMsg::new(
                     self.strategy_name().clone(),
@@ -530,10 +595,18 @@ impl BacktestStrategyContext {
                 EventCenterSingleton::publish(log_event.into()).await?;
                 EventCenterSingleton::publish(event.into()).await?;
             }
+            VtsEvent::StopLossOrderUpdated(order) => {
+                let event = BacktestStrategyEvent::StopLossOrderUpdated { stop_loss_order: order };
+                EventCenterSingleton::publish(event.into()).await?;
+            }
             VtsEvent::TransactionCreated(transaction) => {
                 let event = BacktestStrategyEvent::TransactionCreated { transaction: transaction };
                 EventCenterSingleton::publish(event.into()).await?;
             }
+            VtsEvent::FundingSettled(funding_payment) => {
+                let event = BacktestStrategyEvent::FundingSettled { funding_payment };
+                EventCenterSingleton::publish(event.into()).await?;
+            }
             VtsEvent::UpdateFinished => {}
         }
         Ok(())
@@ -542,6 +615,9 @@ impl BacktestStrategyContext {
     pub async fn handle_strategy_stats_event(&mut self, event: StrategyStatsEvent) -> Result<(), BacktestStrategyError> {
         match event {
             StrategyStatsEvent::StrategyStatsUpdated(snp_event) => {
+                if self.is_headless().await {
+                    return Ok(());
+                }
                 let event: BacktestStrategyEvent = snp_event.into();
                 EventCenterSingleton::publish(event.into()).await?;
             }
@@ -196,6 +196,10 @@ impl BacktestStrategyContext {
     }
 
     pub async fn store_strategy_status(&mut self, status: String) -> Result<(), BacktestStrategyError> {
+        // The status of the stored strategy belongs to its own instance, not to optimization trials
+        if self.is_trial() {
+            return Ok(());
+        }
         let strategy_id = self.strategy_id();
         let strategy_name = self.strategy_name().clone();
         let database = self.database();
@@ -1,11 +1,15 @@
 // std
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 use chrono::{DateTime, Utc};
 use event_center::EventCenterSingleton;
-use snafu::ResultExt;
-use star_river_core::custom_type::StrategyId;
-use star_river_event::backtest_strategy::strategy_event::{BacktestStrategyEvent, PlayFinishedEvent};
+use serde::{Deserialize, Serialize};
+use snafu::{OptionExt, ResultExt};
+use star_river_core::{
+    backtest_run::BacktestRunStatus,
+    custom_type::{StrategyId, StrategyName},
+};
+use star_river_event::backtest_strategy::strategy_event::{BacktestStrategyEvent, HeadlessRunProgressEvent, PlayFinishedEvent};
 use strategy_core::{
     benchmark::strategy_benchmark::StrategyCycleTracker,
     error::strategy_error::NodeCmdRespRecvFailedSnafu,
@@ -17,15 +21,19 @@ use strategy_core::{
         cycle::Cycle,
     },
 };
-use strategy_stats::strategy_stats::StrategyStatsAccessor;
+use strategy_stats::{
+    StatsSnapshot, TradingPerformanceReport,
+    strategy_stats::{StrategyStatsAccessor, StrategyStatsInfoExt},
+};
 // third-party
 use tokio::sync::{Mutex, Notify, RwLock, oneshot, watch};
 use tokio_util::sync::CancellationToken;
+use utoipa::ToSchema;
 use uuid::Uuid;
-use virtual_trading::vts_trait::VtsCtxAccessor;
+use virtual_trading::{types::VirtualTransaction, vts_trait::VtsCtxAccessor};
 
 // current crate
-use super::BacktestStrategyContext;
+use super::{BacktestStrategyContext, backtest_run_handler::BacktestRunRecorder, checkpoint_handler::CheckpointRecorder};
 // workspace crate
 use crate::{
     node::BacktestNode,
@@ -34,17 +42,103 @@ use crate::{
 use crate::{
     node::node_command::{NodeResetCmdPayload, NodeResetCommand},
     strategy::{
-        strategy_error::{AlreadyPausingSnafu, AlreadyPlayingSnafu, BacktestStrategyError, PlayFinishedSnafu},
+        strategy_command::{RunHeadlessCommand, RunHeadlessRespPayload, RunHeadlessResponse, SeekCommand, SeekRespPayload, SeekResponse},
+        strategy_error::{
+            AlreadyPausingSnafu, AlreadyPlayingSnafu, BacktestStrategyError, HeadlessRunInterruptedSnafu, NothingToStepBackSnafu,
+            PlayFinishedSnafu, SeekInterruptedSnafu, SeekTargetOutOfRangeSnafu,
+        },
         strategy_state_machine::BacktestStrategyRunState,
     },
+    strategy_stats::BacktestStrategyStats,
+    virtual_trading_system::BacktestVts,
 };
 
+/// Final stats and trade list of a headless run
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeadlessRunResult {
+    pub strategy_id: StrategyId,
+    pub strategy_name: StrategyName,
+    pub played_signal_count: u64,
+    pub elapsed_millis: u64,
+    pub final_stats: Option<StatsSnapshot>,
+    pub trading_performance: TradingPerformanceReport,
+    pub transactions: Vec<VirtualTransaction>,
+}
+
+impl HeadlessRunResult {
+    async fn collect(
+        strategy_id: StrategyId,
+        strategy_name: StrategyName,
+        played_signal_count: u64,
+        elapsed_millis: u64,
+        vts: &BacktestVts,
+        strategy_stats: &BacktestStrategyStats,
+    ) -> Self {
+        let (initial_balance, transactions) = vts.with_ctx_read(|ctx| (ctx.initial_balance, ctx.transactions.clone())).await;
+        let (final_stats, trading_performance) = strategy_stats
+            .with_ctx_read(|ctx| {
+                let snapshots = &ctx.asset_snapshot_history().snapshots;
+                (
+                    snapshots.last().cloned(),
+                    TradingPerformanceReport::new(initial_balance, snapshots, &transactions),
+                )
+            })
+            .await;
+        Self {
+            strategy_id,
+            strategy_name,
+            played_signal_count,
+            elapsed_millis,
+            final_stats,
+            trading_performance,
+            transactions,
+        }
+    }
+}
+
+/// Where a seek moves the playback to
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum SeekTarget {
+    /// Play index of the kline, starting from 0
+    PlayIndex { play_index: u64 },
+    /// The kline covering the datetime
+    Datetime { datetime: DateTime<Utc> },
+    /// The kline before the last played one
+    StepBack,
+}
+
+/// Playback position once a seek has finished, the kline at the play index is the last one played
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeekResult {
+    pub strategy_id: StrategyId,
+    pub strategy_name: StrategyName,
+    pub play_index: PlayIndex,
+    pub strategy_datetime: DateTime<Utc>,
+    /// Play index of the checkpoint the replay started from
+    pub restored_checkpoint: Option<PlayIndex>,
+    pub replayed_signal_count: u64,
+    pub elapsed_millis: u64,
+}
+
+// Position a seek replays from, no play context when the target is already the last played kline
+struct SeekPlan {
+    target_index: u64,
+    restored_checkpoint: Option<u64>,
+    replayed_signal_count: u64,
+    play_context: Option<PlayContext>,
+}
+
 #[derive(Debug)]
 struct PlayContext {
     strategy_id: StrategyId,
     strategy_name: String,
     node: BacktestNode,
     is_playing: Arc<RwLock<bool>>,
+    is_headless: Arc<RwLock<bool>>,
+    is_trial: bool,
     initial_play_speed: Arc<RwLock<u32>>,
     child_cancel_play_token: CancellationToken,
     execute_over_notify: Arc<Notify>,
@@ -52,6 +146,11 @@ struct PlayContext {
     signal_generator: Arc<Mutex<SignalGenerator>>,
     current_time_watch_tx: watch::Sender<DateTime<Utc>>,
     cycle_watch_tx: watch::Sender<Cycle>,
+    backtest_run_recorder: BacktestRunRecorder,
+    // None for optimization trials, they are never stepped back
+    checkpoint_recorder: Option<CheckpointRecorder>,
+    // Set by seeks, the play task stops once the signal at this index has been played
+    stop_at: Option<u64>,
 }
 
 impl BacktestStrategyContext {
@@ -65,14 +164,21 @@ impl BacktestStrategyContext {
         true
     }
 
-    async fn create_play_context(&self) -> PlayContext {
+    async fn create_play_context(&self, backtest_run_recorder: BacktestRunRecorder) -> PlayContext {
         let node = self.get_node("start_node").unwrap();
+        let checkpoint_recorder = if self.is_trial {
+            None
+        } else {
+            Some(self.checkpoint_recorder().await)
+        };
 
         PlayContext {
             strategy_id: self.strategy_id(),
             strategy_name: self.strategy_name().clone(),
             node: node.clone(),
             is_playing: self.is_playing.clone(),
+            is_headless: self.is_headless.clone(),
+            is_trial: self.is_trial,
             initial_play_speed: self.initial_play_speed.clone(),
             child_cancel_play_token: self.cancel_play_token.child_token(),
             execute_over_notify: self.execute_over_notify.clone(),
@@ -80,29 +186,33 @@ impl BacktestStrategyContext {
             signal_generator: self.signal_generator.clone(),
             current_time_watch_tx: self.strategy_time_watch_tx().clone(),
             cycle_watch_tx: self.cycle_watch_tx().clone(),
+            backtest_run_recorder,
+            checkpoint_recorder,
+            stop_at: None,
         }
     }
 
-    async fn run_play_loop(context: PlayContext) {
+    // Returns true once all signals or the seek target have been played, false if the play task was cancelled
+    async fn run_play_loop(context: PlayContext) -> bool {
+        // Headless runs play without delay and report progress instead of per-bar updates
+        let headless = *context.is_headless.read().await;
+        let mut reported_progress = 0;
         loop {
             // Check cancellation status
             if context.child_cancel_play_token.is_cancelled() {
                 tracing::info!("[{}]: received cancel signal, exit play task", context.strategy_name.clone());
                 *context.is_playing.write().await = false;
-                break;
+                return false;
             }
 
             // Check pause status
             if let Some(should_break) = Self::handle_pause_state(&context, &context.strategy_name).await {
                 if should_break {
-                    break;
+                    return false;
                 }
                 continue;
             }
 
-            // Get play speed
-            let play_speed = Self::get_play_speed(&context).await;
-
             // Check if signal generator has finished
             let mut signal_generator_guard = context.signal_generator.lock().await;
             let is_finished = signal_generator_guard.is_finished();
@@ -111,12 +221,14 @@ impl BacktestStrategyContext {
                 drop(signal_generator_guard);
                 tracing::info!("[{}]: all signals played, exit play task", context.strategy_name);
                 *context.is_playing.write().await = false;
-                break;
+                return true;
             }
 
             // Get next signal
             let (signal_index, signal_time) = signal_generator_guard.next().unwrap();
             let is_finished_after_next = signal_generator_guard.is_finished();
+            let total_signal_count = signal_generator_guard.total_signal_count();
+            let progress_percentage = signal_generator_guard.progress_percentage();
             drop(signal_generator_guard);
 
             // tracing::debug!(
@@ -131,6 +243,11 @@ impl BacktestStrategyContext {
             let mut strategy_cycle_tracker = StrategyCycleTracker::new(signal_index);
             strategy_cycle_tracker.start_phase("increment play index");
 
+            // execute_over_notify does not store a permit, register the waiter before the cycle is sent
+            let notified = context.execute_over_notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
             context.current_time_watch_tx.send(signal_time).unwrap();
             context.cycle_watch_tx.send(Cycle::Id(signal_index)).unwrap();
 
@@ -141,17 +258,52 @@ impl BacktestStrategyContext {
                 *cycle_tracker_guard = Some(strategy_cycle_tracker); // Share to strategy context
             }
             // After sending, wait for all leaf nodes to complete execution
-            context.execute_over_notify.notified().await;
+            notified.await;
 
             // Check if playback finished
             if is_finished_after_next {
                 Self::handle_play_finished(&context, &context.strategy_name, signal_index as i32).await;
-                break;
+                return true;
+            }
+
+            if let Some(checkpoint_recorder) = &context.checkpoint_recorder {
+                checkpoint_recorder
+                    .capture_if_due(signal_index, signal_time, total_signal_count)
+                    .await;
+            }
+
+            // Seek target reached, the playback stays paused on it
+            if context.stop_at == Some(signal_index) {
+                *context.is_playing.write().await = false;
+                return true;
+            }
+
+            if headless {
+                // Seeks are answered once the target is reached, no progress is reported meanwhile
+                if context.is_trial || context.stop_at.is_some() {
+                    continue;
+                }
+                // One progress event per whole percent
+                let progress = progress_percentage.floor() as u32;
+                if progress > reported_progress {
+                    reported_progress = progress;
+                    let progress_event: BacktestStrategyEvent = HeadlessRunProgressEvent::new(
+                        context.strategy_id,
+                        context.strategy_name.clone(),
+                        signal_index as i32,
+                        total_signal_count,
+                        progress_percentage,
+                    )
+                    .into();
+                    let _ = EventCenterSingleton::publish(progress_event.into()).await;
+                }
+                continue;
             }
 
             // Playback delay
             // play_speed represents how many klines to play per second, 100 means 100 klines per second
             // 1000 / 100 = 10ms
+            let play_speed = Self::get_play_speed(&context).await;
             let delay_millis = 1000 / play_speed as u64;
             tokio::time::sleep(tokio::time::Duration::from_millis(delay_millis)).await;
         }
@@ -200,9 +352,16 @@ impl BacktestStrategyContext {
 
     // Handle playback finished, send playback finished event
     async fn handle_play_finished(context: &PlayContext, strategy_name: &str, play_index: PlayIndex) {
-        let finish_event: BacktestStrategyEvent =
-            PlayFinishedEvent::new(context.strategy_id, context.strategy_name.clone(), play_index).into();
-        let _ = EventCenterSingleton::publish(finish_event.into()).await;
+        // Optimization trials are collected by the optimizer instead
+        if !context.is_trial && context.backtest_run_recorder.mark_completed().await {
+            if let Err(e) = context.backtest_run_recorder.save(BacktestRunStatus::Completed).await {
+                tracing::error!("[{}]: save backtest run failed: {}", strategy_name, e);
+            }
+
+            let finish_event: BacktestStrategyEvent =
+                PlayFinishedEvent::new(context.strategy_id, context.strategy_name.clone(), play_index).into();
+            let _ = EventCenterSingleton::publish(finish_event.into()).await;
+        }
 
         tracing::info!("[{}]: kline playback finished, exiting play task normally", strategy_name);
         *context.is_playing.write().await = false;
@@ -210,6 +369,76 @@ impl BacktestStrategyContext {
 
     // Play klines
     pub async fn play(&mut self) -> Result<(), BacktestStrategyError> {
+        let play_context = self.prepare_play().await?;
+
+        tokio::spawn(async move {
+            Self::run_play_loop(play_context).await;
+        });
+        Ok(())
+    }
+
+    // Play all remaining signals as fast as the node graph completes them, the command is answered once the run ends
+    pub async fn run_headless(&mut self, command: RunHeadlessCommand) {
+        let play_context = match self.prepare_play().await {
+            Ok(play_context) => play_context,
+            Err(e) => {
+                command.respond(RunHeadlessResponse::fail(Arc::new(e)));
+                return;
+            }
+        };
+        // Set before the play task starts, the loop reads it once
+        *self.is_headless.write().await = true;
+        tracing::info!("[{}]: start headless run", self.strategy_name());
+
+        let strategy_id = self.strategy_id();
+        let strategy_name = self.strategy_name().clone();
+        let is_headless = self.is_headless.clone();
+        let signal_generator = self.signal_generator.clone();
+        let vts = self.vts.clone();
+        let strategy_stats = self.strategy_stats().clone();
+
+        tokio::spawn(async move {
+            let start = Instant::now();
+            let finished = Self::run_play_loop(play_context).await;
+            *is_headless.write().await = false;
+
+            let response = if finished {
+                let played_signal_count = signal_generator.lock().await.total_signal_count();
+                let elapsed_millis = start.elapsed().as_millis() as u64;
+                tracing::info!(
+                    "[{}]: headless run finished, {} signals played in {} ms",
+                    strategy_name,
+                    played_signal_count,
+                    elapsed_millis
+                );
+                let result = HeadlessRunResult::collect(
+                    strategy_id,
+                    strategy_name,
+                    played_signal_count,
+                    elapsed_millis,
+                    &vts,
+                    &strategy_stats,
+                )
+                .await;
+                RunHeadlessResponse::success(RunHeadlessRespPayload::new(result))
+            } else {
+                RunHeadlessResponse::fail(Arc::new(HeadlessRunInterruptedSnafu { strategy_name }.build()))
+            };
+            command.respond(response);
+        });
+    }
+
+    // Check that the strategy can play and mark it as playing
+    async fn prepare_play(&mut self) -> Result<PlayContext, BacktestStrategyError> {
+        let play_context = self.enter_play().await?;
+
+        // Update strategy status to playing
+        self.store_strategy_status(BacktestStrategyRunState::Playing.to_string()).await?;
+        Ok(play_context)
+    }
+
+    // Mark the strategy as playing without touching the stored status, seeks leave the strategy paused
+    async fn enter_play(&mut self) -> Result<PlayContext, BacktestStrategyError> {
         // Check if playback already finished
         let signal_generator_guard = self.signal_generator.lock().await;
         let is_finished = signal_generator_guard.is_finished();
@@ -224,24 +453,142 @@ impl BacktestStrategyContext {
             .build());
         }
 
+        let backtest_run_recorder = self.backtest_run_recorder().await?;
+
         // Check if playing state is true
         if !self.check_and_set_playing_state().await {
             return Err(AlreadyPlayingSnafu {}.build());
         }
 
-        let play_context = self.create_play_context().await;
+        let play_context = self.create_play_context(backtest_run_recorder).await;
         // Indicates strategy just started, reset batch_id
         if current_index == 0 {
             self.batch_id = Uuid::new_v4();
         }
+        Ok(play_context)
+    }
 
-        // Update strategy status to playing
-        self.store_strategy_status(BacktestStrategyRunState::Playing.to_string()).await?;
+    // Move the playback so that the target kline is the last one played. Signals are replayed without delay from the
+    // nearest checkpoint, or from the beginning if there is none, and the command is answered once the target is reached
+    pub async fn seek(&mut self, command: SeekCommand) {
+        let start = Instant::now();
+        let plan = match self.prepare_seek(&command.target).await {
+            Ok(plan) => plan,
+            Err(e) => {
+                command.respond(SeekResponse::fail(Arc::new(e)));
+                return;
+            }
+        };
 
+        let strategy_datetime = self.signal_generator.lock().await.signal_time_at(plan.target_index);
+        let result = SeekResult {
+            strategy_id: self.strategy_id(),
+            strategy_name: self.strategy_name().clone(),
+            play_index: plan.target_index as PlayIndex,
+            strategy_datetime,
+            restored_checkpoint: plan.restored_checkpoint.map(|index| index as PlayIndex),
+            replayed_signal_count: plan.replayed_signal_count,
+            elapsed_millis: 0,
+        };
+        let Some(mut play_context) = plan.play_context else {
+            command.respond(SeekResponse::success(SeekRespPayload::new(result)));
+            return;
+        };
+        play_context.stop_at = Some(plan.target_index);
+        // Set before the play task starts, the loop reads it once
+        *self.is_headless.write().await = true;
+        tracing::info!(
+            "[{}]: seek to play index {}, {} signals to replay",
+            self.strategy_name(),
+            plan.target_index,
+            plan.replayed_signal_count
+        );
+
+        let is_headless = self.is_headless.clone();
         tokio::spawn(async move {
-            Self::run_play_loop(play_context).await;
+            let reached = Self::run_play_loop(play_context).await;
+            *is_headless.write().await = false;
+
+            let response = if reached {
+                let elapsed_millis = start.elapsed().as_millis() as u64;
+                SeekResponse::success(SeekRespPayload::new(SeekResult { elapsed_millis, ..result }))
+            } else {
+                SeekResponse::fail(Arc::new(
+                    SeekInterruptedSnafu {
+                        strategy_name: result.strategy_name,
+                    }
+                    .build(),
+                ))
+            };
+            command.respond(response);
         });
-        Ok(())
+    }
+
+    // Resolve the target and rewind to the nearest checkpoint when the target lies behind the current position
+    async fn prepare_seek(&mut self, target: &SeekTarget) -> Result<SeekPlan, BacktestStrategyError> {
+        if self.is_playing().await {
+            return Err(AlreadyPlayingSnafu {}.build());
+        }
+
+        let strategy_name = self.strategy_name().clone();
+        let (last_played_index, target_index) = {
+            let signal_generator_guard = self.signal_generator.lock().await;
+            let last_played_index = signal_generator_guard.last_played_index();
+            let total_signal_count = signal_generator_guard.total_signal_count();
+            let target_index =
+                match target {
+                    SeekTarget::PlayIndex { play_index } => Some(*play_index),
+                    SeekTarget::Datetime { datetime } => signal_generator_guard.signal_index_at(*datetime),
+                    SeekTarget::StepBack => Some(last_played_index.and_then(|index| index.checked_sub(1)).context(
+                        NothingToStepBackSnafu {
+                            strategy_name: strategy_name.clone(),
+                        },
+                    )?),
+                };
+            let target_index = target_index
+                .filter(|index| *index < total_signal_count)
+                .context(SeekTargetOutOfRangeSnafu {
+                    strategy_name: strategy_name.clone(),
+                    target: format!("{target:?}"),
+                    total_signal_count,
+                })?;
+            (last_played_index, target_index)
+        };
+
+        // A checkpoint ahead of the current position saves replaying, going back needs a checkpoint or a reset
+        let mut from_index = last_played_index;
+        let mut restored_checkpoint = None;
+        let rewind = last_played_index.is_some_and(|index| index > target_index);
+        if last_played_index != Some(target_index) {
+            match self.checkpoint_recorder().await.nearest(target_index).await {
+                Some(checkpoint) if rewind || last_played_index.is_none_or(|index| checkpoint.play_index > index) => {
+                    from_index = Some(checkpoint.play_index);
+                    restored_checkpoint = Some(checkpoint.play_index);
+                    self.restore_checkpoint(checkpoint).await?;
+                }
+                _ if rewind => {
+                    // Replay from the start, the reset only touches the state of this strategy, ids included
+                    from_index = None;
+                    self.reset().await?;
+                    // The seek leaves the playback paused on the target, not back at the start
+                    self.store_strategy_status(BacktestStrategyRunState::Pausing.to_string()).await?;
+                }
+                _ => {}
+            }
+        }
+
+        let replayed_signal_count = target_index + 1 - from_index.map_or(0, |index| index + 1);
+        let play_context = if replayed_signal_count > 0 {
+            Some(self.enter_play().await?)
+        } else {
+            None
+        };
+        Ok(SeekPlan {
+            target_index,
+            restored_checkpoint,
+            replayed_signal_count,
+            play_context,
+        })
     }
 
     // Pause playback
@@ -290,6 +637,8 @@ impl BacktestStrategyContext {
         self.cancel_play_token.cancel();
         // Reset playing state
         *self.is_playing.write().await = false;
+        *self.is_headless.write().await = false;
+        *self.is_run_completed.write().await = false;
 
         self.cycle_watch_tx().send(Cycle::Reset).unwrap();
 
@@ -357,13 +706,27 @@ impl BacktestStrategyContext {
         //     progress
         // );
 
+        // Last cycle: save the run once all leaf nodes have executed it, only the first time the end is reached
+        let is_first_completion = if is_finished_after_next {
+            let backtest_run_recorder = self.backtest_run_recorder().await?;
+            let is_first_completion = backtest_run_recorder.mark_completed().await;
+            if is_first_completion {
+                self.spawn_save_after_execute_over(backtest_run_recorder).await;
+            }
+            is_first_completion
+        } else {
+            false
+        };
+
         // Start single cycle logic
         self.cycle_watch_tx().send(Cycle::Id(signal_index)).unwrap();
         self.strategy_time_watch_tx().send(signal_time).unwrap();
         if is_finished_after_next {
-            let finish_event: BacktestStrategyEvent =
-                PlayFinishedEvent::new(self.strategy_id(), self.strategy_name().clone(), signal_index as i32).into();
-            let _ = EventCenterSingleton::publish(finish_event.into()).await;
+            if is_first_completion {
+                let finish_event: BacktestStrategyEvent =
+                    PlayFinishedEvent::new(self.strategy_id(), self.strategy_name().clone(), signal_index as i32).into();
+                let _ = EventCenterSingleton::publish(finish_event.into()).await;
+            }
 
             tracing::info!("[{}]: kline played finished, exit play task", self.strategy_name());
             self.set_is_playing(false).await;
@@ -376,6 +739,26 @@ impl BacktestStrategyContext {
         Ok(signal_index as i32)
     }
 
+    // execute_over_notify does not store a permit, so the waiter is registered before the cycle is sent
+    async fn spawn_save_after_execute_over(&self, backtest_run_recorder: BacktestRunRecorder) {
+        let execute_over_notify = self.execute_over_notify.clone();
+        let strategy_name = self.strategy_name().clone();
+        let (registered_tx, registered_rx) = oneshot::channel();
+
+        tokio::spawn(async move {
+            let notified = execute_over_notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            let _ = registered_tx.send(());
+
+            notified.await;
+            if let Err(e) = backtest_run_recorder.save(BacktestRunStatus::Completed).await {
+                tracing::error!("[{}]: save backtest run failed: {}", strategy_name, e);
+            }
+        });
+        let _ = registered_rx.await;
+    }
+
     pub async fn send_reset_node_event(&self) -> Result<(), BacktestStrategyError> {
         let nodes = self.topological_sort().unwrap();
         for node in nodes {
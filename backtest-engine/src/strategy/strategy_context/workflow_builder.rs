@@ -141,6 +141,7 @@ impl BacktestStrategyContext {
                     }
 
                     self.add_node_command_sender(node_id, node_command_tx);
+
                     self.add_node(indicator_node.into()).await;
                 }
                 NodeType::IfElseNode => {
@@ -241,6 +242,8 @@ impl BacktestStrategyContext {
         for edge_config in edge_config_list {
             self.build_edge(edge_config).await?;
         }
+        self.check_variable_node_timer_edges().await?;
+        self.subscribe_trailing_stop_indicators().await?;
 
         // check symbol config
         tracing::debug!("workflow build phase 3: check symbol config");
@@ -1,15 +1,27 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use heartbeat::Heartbeat;
 use sea_orm::DatabaseConnection;
-use strategy_core::strategy::context_trait::{StrategyCommunicationExt, StrategyInfoExt};
+use strategy_core::{
+    node::{
+        NodeTrait,
+        context_trait::{NodeHandleExt, NodeInfoExt},
+        node_trait::NodeContextAccessor,
+    },
+    strategy::context_trait::{StrategyCommunicationExt, StrategyInfoExt, StrategyWorkflowExt},
+};
+use ta_lib::IndicatorConfig;
 use tokio::sync::{Mutex, broadcast, mpsc};
-use virtual_trading::{command::VtsCommand, event::VtsEvent};
+use virtual_trading::{command::VtsCommand, event::VtsEvent, vts_trait::VtsCtxAccessor};
 
 use super::BacktestStrategyContext;
 use crate::{
-    node::{node_command::BacktestNodeCommand, node_error::BacktestNodeError},
+    node::{
+        BacktestNode, node_command::BacktestNodeCommand, node_error::BacktestNodeError,
+        node_error::futures_order_node_error::TrailingStopIndicatorNotFoundSnafu,
+    },
     node_catalog::futures_order_node::FuturesOrderNode,
+    strategy::strategy_error::BacktestStrategyError,
 };
 
 impl BacktestStrategyContext {
@@ -38,4 +50,61 @@ impl BacktestStrategyContext {
         )?;
         Ok(node)
     }
+
+    // Indicator nodes only feed the virtual trading system when an ATR trailing stop refers to them
+    pub async fn subscribe_trailing_stop_indicators(&mut self) -> Result<(), BacktestStrategyError> {
+        let nodes = self.topological_sort()?;
+        let mut subscribed_node_ids = HashSet::new();
+        for node in nodes.iter() {
+            let BacktestNode::FuturesOrder(futures_order_node) = node else {
+                continue;
+            };
+            let (node_name, trailing_stop_indicators) = futures_order_node
+                .with_ctx_read(|ctx| (ctx.node_name().clone(), ctx.node_config().trailing_stop_indicators()))
+                .await;
+
+            for (order_config_id, indicator_node_id, config_id) in trailing_stop_indicators {
+                let mut indicator_node = None;
+                for node in nodes.iter() {
+                    if let BacktestNode::Indicator(candidate) = node
+                        && node.node_id().await == indicator_node_id
+                    {
+                        indicator_node = Some(candidate.clone());
+                    }
+                }
+                let is_atr = match &indicator_node {
+                    Some(indicator_node) => {
+                        indicator_node
+                            .with_ctx_read(|ctx| {
+                                ctx.indicator_keys()
+                                    .iter()
+                                    .any(|(key, (id, _))| *id == config_id && matches!(key.indicator_config, IndicatorConfig::ATR(_)))
+                            })
+                            .await
+                    }
+                    None => false,
+                };
+                let Some(indicator_node) = indicator_node.filter(|_| is_atr) else {
+                    let error = TrailingStopIndicatorNotFoundSnafu {
+                        node_name: node_name.clone(),
+                        order_config_id,
+                        indicator_node_id,
+                        config_id,
+                    }
+                    .build();
+                    return Err(BacktestNodeError::from(error).into());
+                };
+
+                if subscribed_node_ids.insert(indicator_node_id) {
+                    let indicator_node_event_receiver = indicator_node
+                        .with_ctx_write(|ctx| ctx.subscribe_strategy_bound_handle("virtual_trading_system".to_string()))
+                        .await;
+                    self.vts
+                        .with_ctx_write(|ctx| ctx.add_kline_node_event_receiver(indicator_node_event_receiver))
+                        .await;
+                }
+            }
+        }
+        Ok(())
+    }
 }
@@ -27,6 +27,7 @@ impl BacktestStrategyContext {
             strategy_command_sender,
             Arc::new(Mutex::new(node_command_rx)),
             strategy_time_watch_rx,
+            self.kline_history_cache().cloned(),
         )?;
         Ok(node)
     }
@@ -1,12 +1,16 @@
 use std::sync::Arc;
 
-use strategy_core::strategy::context_trait::{StrategyCommunicationExt, StrategyInfoExt};
+use strategy_core::{
+    node::{NodeTrait, node_trait::NodeContextAccessor},
+    strategy::context_trait::{StrategyCommunicationExt, StrategyInfoExt, StrategyWorkflowExt},
+};
 use tokio::sync::{Mutex, mpsc};
 
 use super::BacktestStrategyContext;
 use crate::{
-    node::{node_command::BacktestNodeCommand, node_error::BacktestNodeError},
+    node::{BacktestNode, node_command::BacktestNodeCommand, node_error::BacktestNodeError},
     node_catalog::variable_node::VariableNode,
+    strategy::strategy_error::BacktestStrategyError,
     virtual_trading_system::BacktestVts,
 };
 
@@ -29,4 +33,27 @@ impl BacktestStrategyContext {
         )?;
         Ok(node)
     }
+
+    // Must run after the edges are built
+    pub async fn check_variable_node_timer_edges(&self) -> Result<(), BacktestStrategyError> {
+        let nodes = self.topological_sort()?;
+        let mut start_node_id = None;
+        for node in nodes.iter() {
+            if let BacktestNode::Start(_) = node {
+                start_node_id = Some(node.node_id().await);
+            }
+        }
+        let Some(start_node_id) = start_node_id else {
+            return Ok(());
+        };
+        for node in nodes.iter() {
+            if let BacktestNode::Variable(variable_node) = node {
+                variable_node
+                    .with_ctx_read(|ctx| ctx.check_timer_trigger_edge(&start_node_id))
+                    .await
+                    .map_err(BacktestNodeError::from)?;
+            }
+        }
+        Ok(())
+    }
 }
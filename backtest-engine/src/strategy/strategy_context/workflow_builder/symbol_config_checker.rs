@@ -21,6 +21,7 @@ impl BacktestStrategyContext {
 
         let keys = self.keys().await;
 
+        // Only the min interval klines of each symbol are loaded, higher intervals are resampled from them during playback
         let mut min_symbol_map: HashMap<String, KlineKey> = HashMap::new();
 
         for key in keys.keys() {
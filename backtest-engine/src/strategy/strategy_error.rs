@@ -4,6 +4,7 @@ use database::error::DatabaseError;
 use event_center::EventCenterError;
 use snafu::{Backtrace, Snafu};
 use star_river_core::{
+    core_error::CoreError,
     custom_type::NodeName,
     error::{ErrorCode, ErrorLanguage, StarRiverErrorTrait, StatusCode, generate_error_code_chain},
 };
@@ -34,6 +35,9 @@ pub enum BacktestStrategyError {
     #[snafu(transparent)]
     VtsError { source: VtsError, backtrace: Backtrace },
 
+    #[snafu(transparent)]
+    CoreError { source: CoreError, backtrace: Backtrace },
+
     #[snafu(display("[{strategy_name}] update status failed: {source}"))]
     UpdateStrategyStatusFailed {
         strategy_name: String,
@@ -124,6 +128,99 @@ pub enum BacktestStrategyError {
 
     #[snafu(display("#[{strategy_name}] time range not configured"))]
     TimeRangeNotConfigured { strategy_name: String, backtrace: Backtrace },
+
+    #[snafu(display("[{strategy_name}] save backtest run failed: {source}"))]
+    SaveBacktestRunFailed {
+        strategy_name: String,
+        source: DatabaseError,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("[{strategy_name}] serialize backtest run failed: {source}"))]
+    SerializeBacktestRunFailed {
+        strategy_name: String,
+        source: serde_json::Error,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("[{strategy_name}] headless run failed: {source}"))]
+    HeadlessRunFailed {
+        strategy_name: String,
+        source: Arc<dyn StarRiverErrorTrait>,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("[{strategy_name}] headless run interrupted before all signals were played"))]
+    HeadlessRunInterrupted { strategy_name: String, backtrace: Backtrace },
+
+    #[snafu(display("[{strategy_name}] seek target {target} is out of range, total signal count: {total_signal_count}"))]
+    SeekTargetOutOfRange {
+        strategy_name: String,
+        target: String,
+        total_signal_count: u64,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("[{strategy_name}] no kline has been played before the current one, cannot step back"))]
+    NothingToStepBack { strategy_name: String, backtrace: Backtrace },
+
+    #[snafu(display("[{strategy_name}] seek failed: {source}"))]
+    SeekFailed {
+        strategy_name: String,
+        source: Arc<dyn StarRiverErrorTrait>,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("[{strategy_name}] seek interrupted before the target was reached"))]
+    SeekInterrupted { strategy_name: String, backtrace: Backtrace },
+
+    #[snafu(display("[{strategy_name}] no kline has been played yet, nothing to save"))]
+    NothingToSave { strategy_name: String, backtrace: Backtrace },
+
+    #[snafu(display("[{strategy_name}] serialize checkpoint failed: {source}"))]
+    SerializeCheckpointFailed {
+        strategy_name: String,
+        source: serde_json::Error,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("[{strategy_name}] checkpoint {checkpoint_id} is not readable: {source}"))]
+    DeserializeCheckpointFailed {
+        strategy_name: String,
+        checkpoint_id: i32,
+        source: serde_json::Error,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("[{strategy_name}] save checkpoint failed: {source}"))]
+    SaveCheckpointFailed {
+        strategy_name: String,
+        source: DatabaseError,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("[{strategy_name}] load checkpoint {checkpoint_id} failed: {source}"))]
+    LoadCheckpointFailed {
+        strategy_name: String,
+        checkpoint_id: i32,
+        source: DatabaseError,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("[{strategy_name}] checkpoint {checkpoint_id} does not match the strategy: {reason}"))]
+    CheckpointMismatch {
+        strategy_name: String,
+        checkpoint_id: i32,
+        reason: String,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("[{strategy_name}] no signal in the time range with schedule {schedule}"))]
+    EmptySignalSchedule {
+        strategy_name: String,
+        schedule: String,
+        backtrace: Backtrace,
+    },
 }
 
 // Implement the StarRiverErrorTrait for Mt5Error
@@ -135,28 +232,44 @@ impl StarRiverErrorTrait for BacktestStrategyError {
     fn error_code(&self) -> ErrorCode {
         let prefix = self.get_prefix();
         let code = match self {
-            BacktestStrategyError::StrategyError { .. } => 1001,              // Strategy error
-            BacktestStrategyError::StrategyStateMachineError { .. } => 1002,  // Strategy state machine error
-            BacktestStrategyError::BacktestNodeError { .. } => 1003,          // Node error
-            BacktestStrategyError::EventCenterError { .. } => 1004,           // Event center error
-            BacktestStrategyError::VtsError { .. } => 1005,                   // Virtual trading system error
-            BacktestStrategyError::UpdateStrategyStatusFailed { .. } => 1006, // Update strategy status failed
-            BacktestStrategyError::PlayFinished { .. } => 1007,               // All backtest data playback finished
-            BacktestStrategyError::AlreadyPlaying { .. } => 1008,             // Strategy is already playing, cannot play again
-            BacktestStrategyError::AlreadyPausing { .. } => 1009,             // Strategy is already pausing, cannot pause again
-            BacktestStrategyError::IntervalNotSame { .. } => 1010,            // Minimum interval of different symbols not the same
-            BacktestStrategyError::GetDataFailed { .. } => 1011,              // Get data failed
-            BacktestStrategyError::GetDataByDatetimeFailed { .. } => 1012,    // Get data by datetime failed
-            BacktestStrategyError::GetStartNodeConfigFailed { .. } => 1013,   // Get start node config failed
-            BacktestStrategyError::KlineDataLengthNotSame { .. } => 1014,     // Kline data length not the same
-            BacktestStrategyError::KeyNotFound { .. } => 1015,                // Kline key not found
-            BacktestStrategyError::PlayIndexOutOfRange { .. } => 1016,        // Play index out of range
-            BacktestStrategyError::GetNodeConfigFailed { .. } => 1017,        // Get node config failed
-            BacktestStrategyError::MissingDataSource { .. } => 1018,          // Missing data source
-            BacktestStrategyError::MissingStartNode { .. } => 1019,           // Missing start node
-            BacktestStrategyError::SymbolIsNotMinInterval { .. } => 1020,     // Kline key is not minimum interval symbol
-            BacktestStrategyError::NoSymbolConfigured { .. } => 1021,         // No symbol configured
-            BacktestStrategyError::TimeRangeNotConfigured { .. } => 1022,     // Time range not configured
+            BacktestStrategyError::StrategyError { .. } => 1001,               // Strategy error
+            BacktestStrategyError::StrategyStateMachineError { .. } => 1002,   // Strategy state machine error
+            BacktestStrategyError::BacktestNodeError { .. } => 1003,           // Node error
+            BacktestStrategyError::EventCenterError { .. } => 1004,            // Event center error
+            BacktestStrategyError::VtsError { .. } => 1005,                    // Virtual trading system error
+            BacktestStrategyError::UpdateStrategyStatusFailed { .. } => 1006,  // Update strategy status failed
+            BacktestStrategyError::PlayFinished { .. } => 1007,                // All backtest data playback finished
+            BacktestStrategyError::AlreadyPlaying { .. } => 1008,              // Strategy is already playing, cannot play again
+            BacktestStrategyError::AlreadyPausing { .. } => 1009,              // Strategy is already pausing, cannot pause again
+            BacktestStrategyError::IntervalNotSame { .. } => 1010,             // Minimum interval of different symbols not the same
+            BacktestStrategyError::GetDataFailed { .. } => 1011,               // Get data failed
+            BacktestStrategyError::GetDataByDatetimeFailed { .. } => 1012,     // Get data by datetime failed
+            BacktestStrategyError::GetStartNodeConfigFailed { .. } => 1013,    // Get start node config failed
+            BacktestStrategyError::KlineDataLengthNotSame { .. } => 1014,      // Kline data length not the same
+            BacktestStrategyError::KeyNotFound { .. } => 1015,                 // Kline key not found
+            BacktestStrategyError::PlayIndexOutOfRange { .. } => 1016,         // Play index out of range
+            BacktestStrategyError::GetNodeConfigFailed { .. } => 1017,         // Get node config failed
+            BacktestStrategyError::MissingDataSource { .. } => 1018,           // Missing data source
+            BacktestStrategyError::MissingStartNode { .. } => 1019,            // Missing start node
+            BacktestStrategyError::SymbolIsNotMinInterval { .. } => 1020,      // Kline key is not minimum interval symbol
+            BacktestStrategyError::NoSymbolConfigured { .. } => 1021,          // No symbol configured
+            BacktestStrategyError::TimeRangeNotConfigured { .. } => 1022,      // Time range not configured
+            BacktestStrategyError::SaveBacktestRunFailed { .. } => 1023,       // Save backtest run failed
+            BacktestStrategyError::HeadlessRunFailed { .. } => 1024,           // Headless run failed
+            BacktestStrategyError::HeadlessRunInterrupted { .. } => 1025,      // Headless run paused, reset or stopped before finishing
+            BacktestStrategyError::SeekTargetOutOfRange { .. } => 1026,        // Seek target outside of the backtest time range
+            BacktestStrategyError::NothingToStepBack { .. } => 1027,           // Step back before any kline was played
+            BacktestStrategyError::SeekFailed { .. } => 1028,                  // Seek failed
+            BacktestStrategyError::SeekInterrupted { .. } => 1029,             // Seek paused, reset or stopped before reaching the target
+            BacktestStrategyError::NothingToSave { .. } => 1030,               // Checkpoint saved before any kline was played
+            BacktestStrategyError::SerializeCheckpointFailed { .. } => 1031,   // Serialize checkpoint failed
+            BacktestStrategyError::DeserializeCheckpointFailed { .. } => 1032, // Saved checkpoint has an unknown format
+            BacktestStrategyError::SaveCheckpointFailed { .. } => 1033,        // Save checkpoint failed
+            BacktestStrategyError::LoadCheckpointFailed { .. } => 1034,        // Load checkpoint failed
+            BacktestStrategyError::CheckpointMismatch { .. } => 1035,          // Checkpoint saved by another strategy or time range
+            BacktestStrategyError::EmptySignalSchedule { .. } => 1036,         // No loaded kline or session inside the time range
+            BacktestStrategyError::CoreError { .. } => 1037,                   // Core error
+            BacktestStrategyError::SerializeBacktestRunFailed { .. } => 1038,  // Serialize backtest run results failed
         };
         format!("{prefix}_{code:04}")
     }
@@ -169,12 +282,18 @@ impl StarRiverErrorTrait for BacktestStrategyError {
             BacktestStrategyError::BacktestNodeError { source, .. } => source.http_status_code(),
             BacktestStrategyError::EventCenterError { source, .. } => source.http_status_code(),
             BacktestStrategyError::VtsError { source, .. } => source.http_status_code(),
+            BacktestStrategyError::CoreError { source, .. } => source.http_status_code(),
+            BacktestStrategyError::HeadlessRunFailed { source, .. } => source.http_status_code(),
+            BacktestStrategyError::SeekFailed { source, .. } => source.http_status_code(),
             // Server internal error (500)
             BacktestStrategyError::GetDataFailed { .. }
             | BacktestStrategyError::GetDataByDatetimeFailed { .. }
             | BacktestStrategyError::KlineDataLengthNotSame { .. }
             | BacktestStrategyError::PlayIndexOutOfRange { .. }
-            | BacktestStrategyError::GetNodeConfigFailed { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            | BacktestStrategyError::GetNodeConfigFailed { .. }
+            | BacktestStrategyError::SerializeCheckpointFailed { .. }
+            | BacktestStrategyError::SerializeBacktestRunFailed { .. }
+            | BacktestStrategyError::DeserializeCheckpointFailed { .. } => StatusCode::INTERNAL_SERVER_ERROR,
 
             // Client error - configuration/data issues (400)
             BacktestStrategyError::GetStartNodeConfigFailed { .. } | BacktestStrategyError::IntervalNotSame { .. } => {
@@ -185,20 +304,31 @@ impl StarRiverErrorTrait for BacktestStrategyError {
             BacktestStrategyError::KeyNotFound { .. } => StatusCode::NOT_FOUND,
 
             // Client error - conflict/state error (409)
-            BacktestStrategyError::AlreadyPlaying { .. } | BacktestStrategyError::AlreadyPausing { .. } => StatusCode::CONFLICT,
+            BacktestStrategyError::AlreadyPlaying { .. }
+            | BacktestStrategyError::AlreadyPausing { .. }
+            | BacktestStrategyError::HeadlessRunInterrupted { .. }
+            | BacktestStrategyError::NothingToStepBack { .. }
+            | BacktestStrategyError::SeekInterrupted { .. }
+            | BacktestStrategyError::NothingToSave { .. }
+            | BacktestStrategyError::CheckpointMismatch { .. } => StatusCode::CONFLICT,
 
             // Success but completed (200 - although an error, it's a normal completion in business terms)
             BacktestStrategyError::PlayFinished { .. } => StatusCode::OK,
 
             // Service unavailable (503)
-            BacktestStrategyError::UpdateStrategyStatusFailed { .. } => StatusCode::SERVICE_UNAVAILABLE,
+            BacktestStrategyError::UpdateStrategyStatusFailed { .. }
+            | BacktestStrategyError::SaveBacktestRunFailed { .. }
+            | BacktestStrategyError::SaveCheckpointFailed { .. }
+            | BacktestStrategyError::LoadCheckpointFailed { .. } => StatusCode::SERVICE_UNAVAILABLE,
 
             // Client error - configuration/data issues (400)
             BacktestStrategyError::MissingDataSource { .. }
             | BacktestStrategyError::TimeRangeNotConfigured { .. }
             | BacktestStrategyError::MissingStartNode { .. }
             | BacktestStrategyError::SymbolIsNotMinInterval { .. }
-            | BacktestStrategyError::NoSymbolConfigured { .. } => StatusCode::BAD_REQUEST,
+            | BacktestStrategyError::NoSymbolConfigured { .. }
+            | BacktestStrategyError::SeekTargetOutOfRange { .. }
+            | BacktestStrategyError::EmptySignalSchedule { .. } => StatusCode::BAD_REQUEST,
         }
     }
 
@@ -212,6 +342,7 @@ impl StarRiverErrorTrait for BacktestStrategyError {
                 BacktestStrategyError::BacktestNodeError { source, .. } => source.error_message(language),
                 BacktestStrategyError::EventCenterError { source, .. } => source.error_message(language),
                 BacktestStrategyError::VtsError { source, .. } => source.error_message(language),
+                BacktestStrategyError::CoreError { source, .. } => source.error_message(language),
                 BacktestStrategyError::UpdateStrategyStatusFailed { strategy_name, source, .. } => {
                     format!("策略 [{strategy_name}] 更新状态失败: {source}")
                 }
@@ -289,6 +420,73 @@ impl StarRiverErrorTrait for BacktestStrategyError {
                 BacktestStrategyError::TimeRangeNotConfigured { strategy_name, .. } => {
                     format!("#[{strategy_name}] 回测时间范围未配置")
                 }
+                BacktestStrategyError::SaveBacktestRunFailed { strategy_name, source, .. } => {
+                    format!("策略 [{strategy_name}] 保存回测记录失败: {source}")
+                }
+                BacktestStrategyError::SerializeBacktestRunFailed { strategy_name, source, .. } => {
+                    format!("策略 [{strategy_name}] 序列化回测记录失败: {source}")
+                }
+                BacktestStrategyError::HeadlessRunFailed { strategy_name, source, .. } => {
+                    format!("策略 [{strategy_name}] 无头运行失败: {}", source.error_message(language))
+                }
+                BacktestStrategyError::HeadlessRunInterrupted { strategy_name, .. } => {
+                    format!("策略 [{strategy_name}] 无头运行在所有信号播放完成前被中断")
+                }
+                BacktestStrategyError::SeekTargetOutOfRange {
+                    strategy_name,
+                    target,
+                    total_signal_count,
+                    ..
+                } => {
+                    format!("策略 [{strategy_name}] 跳转目标 {target} 超出范围, 信号总数: {total_signal_count}")
+                }
+                BacktestStrategyError::NothingToStepBack { strategy_name, .. } => {
+                    format!("策略 [{strategy_name}] 当前k线之前没有已播放的k线, 无法后退")
+                }
+                BacktestStrategyError::SeekFailed { strategy_name, source, .. } => {
+                    format!("策略 [{strategy_name}] 跳转失败: {}", source.error_message(language))
+                }
+                BacktestStrategyError::SeekInterrupted { strategy_name, .. } => {
+                    format!("策略 [{strategy_name}] 跳转在到达目标前被中断")
+                }
+                BacktestStrategyError::NothingToSave { strategy_name, .. } => {
+                    format!("策略 [{strategy_name}] 尚未播放任何k线, 无法保存检查点")
+                }
+                BacktestStrategyError::SerializeCheckpointFailed { strategy_name, source, .. } => {
+                    format!("策略 [{strategy_name}] 序列化检查点失败: {source}")
+                }
+                BacktestStrategyError::DeserializeCheckpointFailed {
+                    strategy_name,
+                    checkpoint_id,
+                    source,
+                    ..
+                } => {
+                    format!("策略 [{strategy_name}] 检查点 {checkpoint_id} 无法读取: {source}")
+                }
+                BacktestStrategyError::SaveCheckpointFailed { strategy_name, source, .. } => {
+                    format!("策略 [{strategy_name}] 保存检查点失败: {source}")
+                }
+                BacktestStrategyError::LoadCheckpointFailed {
+                    strategy_name,
+                    checkpoint_id,
+                    source,
+                    ..
+                } => {
+                    format!("策略 [{strategy_name}] 加载检查点 {checkpoint_id} 失败: {source}")
+                }
+                BacktestStrategyError::CheckpointMismatch {
+                    strategy_name,
+                    checkpoint_id,
+                    reason,
+                    ..
+                } => {
+                    format!("策略 [{strategy_name}] 检查点 {checkpoint_id} 与策略不匹配: {reason}")
+                }
+                BacktestStrategyError::EmptySignalSchedule {
+                    strategy_name, schedule, ..
+                } => {
+                    format!("策略 [{strategy_name}] 在回测时间范围内没有信号, 信号计划: {schedule}")
+                }
             },
         }
     }
@@ -301,6 +499,7 @@ impl StarRiverErrorTrait for BacktestStrategyError {
             BacktestStrategyError::BacktestNodeError { source, .. } => generate_error_code_chain(source, self.error_code()),
             BacktestStrategyError::EventCenterError { source, .. } => generate_error_code_chain(source, self.error_code()),
             BacktestStrategyError::VtsError { source, .. } => generate_error_code_chain(source, self.error_code()),
+            BacktestStrategyError::CoreError { source, .. } => generate_error_code_chain(source, self.error_code()),
             // Non-transparent errors - return own error code
             _ => vec![self.error_code()],
         }
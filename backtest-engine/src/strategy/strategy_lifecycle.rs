@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use event_center::EventCenterSingleton;
+use key::KeyTrait;
 use star_river_event::backtest_strategy::strategy_event::BacktestStrategyEvent;
 use strategy_core::{
     error::strategy_error::WaitAllNodesStoppedTimeoutSnafu,
@@ -18,7 +19,7 @@ use super::{
     strategy_state_machine::{BacktestStrategyRunState, BacktestStrategyStateAction, BacktestStrategyStateTransTrigger},
 };
 use crate::strategy::{
-    strategy_error::{BacktestStrategyError, TimeRangeNotConfiguredSnafu},
+    strategy_error::{BacktestStrategyError, EmptySignalScheduleSnafu, TimeRangeNotConfiguredSnafu},
     strategy_log_message::StrategyRunStateLogMsg,
 };
 
@@ -73,6 +74,9 @@ impl StrategyLifecycle for BacktestStrategy {
         if current_state == BacktestStrategyRunState::Stopping {
             return Ok(());
         }
+        // Save the unfinished run while nodes are still alive to answer config queries
+        self.with_ctx_read_async(|ctx| Box::pin(ctx.save_unfinished_backtest_run())).await;
+
         tracing::info!("waiting for all nodes to stop...");
         self.with_ctx_write_async(|ctx| {
             Box::pin(async move { ctx.store_strategy_status(BacktestStrategyRunState::Stopping.to_string()).await })
@@ -160,10 +164,37 @@ impl StrategyLifecycle for BacktestStrategy {
                             let start_time = strategy_config.start_time();
                             let end_time = strategy_config.end_time();
                             if let (Some(start_time), Some(end_time)) = (start_time, end_time) {
-                                ctx.signal_generator
-                                    .lock()
-                                    .await
-                                    .init(start_time, end_time, ctx.min_interval.clone());
+                                // Klines are loaded by the kline nodes on InitNode, before the signal generator
+                                let signal_times = {
+                                    let kline_data_guard = ctx.kline_data.read().await;
+                                    let loaded_kline_times = kline_data_guard
+                                        .iter()
+                                        .filter(|(kline_key, _)| kline_key.interval() == ctx.min_interval)
+                                        .flat_map(|(_, klines)| klines.iter().map(|kline| kline.datetime()));
+                                    strategy_config.signal_schedule.signal_times(
+                                        start_time,
+                                        end_time,
+                                        &ctx.min_interval,
+                                        loaded_kline_times,
+                                    )
+                                };
+                                let mut signal_generator = ctx.signal_generator.lock().await;
+                                match signal_times {
+                                    Some(signal_times) if signal_times.is_empty() => {
+                                        return Err(EmptySignalScheduleSnafu {
+                                            strategy_name: ctx.strategy_name().clone(),
+                                            schedule: format!("{:?}", strategy_config.signal_schedule),
+                                        }
+                                        .build());
+                                    }
+                                    Some(signal_times) => signal_generator.init_with_signal_times(
+                                        start_time,
+                                        end_time,
+                                        ctx.min_interval.clone(),
+                                        signal_times,
+                                    ),
+                                    None => signal_generator.init(start_time, end_time, ctx.min_interval.clone()),
+                                }
                                 Ok::<(), BacktestStrategyError>(())
                             } else {
                                 return Err(TimeRangeNotConfiguredSnafu {
@@ -179,12 +210,23 @@ impl StrategyLifecycle for BacktestStrategy {
                     self.with_ctx_write_async(|ctx| {
                         Box::pin(async move {
                             let strategy_config = ctx.get_strategy_config().await?;
+                            // a funding rate file is read once here, the vts only works with the loaded series
+                            let mut funding_config = strategy_config.funding.clone();
+                            funding_config.load_series_file().await?;
                             {
                                 ctx.vts
                                     .with_ctx_write(|ctx| {
                                         ctx.set_initial_balance(strategy_config.initial_balance);
                                         ctx.set_leverage(strategy_config.leverage as u32);
                                         ctx.set_fee_rate(strategy_config.fee_rate);
+                                        if let Some(fee_schedule) = &strategy_config.fee_schedule {
+                                            ctx.set_fee_schedule(fee_schedule.clone());
+                                        }
+                                        ctx.set_funding_config(funding_config);
+                                        ctx.set_margin_config(strategy_config.margin.clone());
+                                        ctx.set_scale_in_limit(strategy_config.scale_in_limit.clone());
+                                        ctx.set_fill_model(strategy_config.fill_model.clone());
+                                        ctx.set_trading_calendar(strategy_config.signal_schedule.trading_calendar().cloned());
                                     })
                                     .await;
                                 ctx.vts.start().await;
@@ -273,9 +315,12 @@ impl StrategyLifecycle for BacktestStrategy {
                     tracing::error!("#[{}] {}", &strategy_name, error);
                 }
                 BacktestStrategyStateAction::LogStrategyState => {
-                    let (strategy_id, current_state) = self
-                        .with_ctx_read_async(|ctx| Box::pin(async move { (ctx.strategy_id(), ctx.run_state().await) }))
+                    let (strategy_id, current_state, is_trial) = self
+                        .with_ctx_read_async(|ctx| Box::pin(async move { (ctx.strategy_id(), ctx.run_state().await, ctx.is_trial()) }))
                         .await;
+                    if is_trial {
+                        continue;
+                    }
 
                     let log_message = StrategyRunStateLogMsg::new(strategy_name.clone(), current_state.to_string());
                     let log_event: BacktestStrategyEvent = StrategyStateLogEvent::info(
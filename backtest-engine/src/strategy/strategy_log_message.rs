@@ -35,6 +35,19 @@ log_message!(
     zh: "#[{strategy_name}] 订单价格{limit_price}小于当前价格{current_price}, 限价单直接成交, 订单ID: {order_id}"
 );
 
+log_message!(
+    PositionLiquidatedMsg,
+    params: (
+        strategy_name: String,
+        position_id: i32,
+        symbol: String,
+        liquidation_price: f64,
+        liquidation_fee: f64,
+    ),
+    en: "#[{strategy_name}] position liquidated - Position ID: {position_id}, Symbol: {symbol}, Liquidation price: {liquidation_price}, Liquidation fee: {liquidation_fee}",
+    zh: "#[{strategy_name}] 仓位强平 - 仓位ID: {position_id}, 交易对: {symbol}, 强平价格: {liquidation_price}, 强平手续费: {liquidation_fee}"
+);
+
 log_message!(
     FuturesOrderCreatedMsg,
     params: (
@@ -10,7 +10,7 @@ use tokio::sync::{RwLock, watch};
 
 use crate::{strategy_stats::context::BacktestStrategyStatsContext, virtual_trading_system::BacktestVts};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BacktestStrategyStats {
     pub context: Arc<RwLock<BacktestStrategyStatsContext>>,
 }
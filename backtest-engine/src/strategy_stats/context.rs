@@ -77,7 +77,7 @@ impl BacktestStrategyStatsContext {
         // let datetime = trading_system.get_datetime(); // Timestamp
         let datetime = self.current_time();
 
-        let (balance, initial_balance, available_balance, unrealized_pnl, equity, realized_pnl) = self
+        let (balance, initial_balance, available_balance, unrealized_pnl, equity, realized_pnl, total_fee, total_funding) = self
             .vts
             .with_ctx_read(|ctx| {
                 let balance = ctx.balance;
@@ -86,7 +86,18 @@ impl BacktestStrategyStatsContext {
                 let unrealized_pnl = ctx.unrealized_pnl;
                 let equity = ctx.equity;
                 let realized_pnl = ctx.realized_pnl;
-                (balance, initial_balance, available_balance, unrealized_pnl, equity, realized_pnl)
+                let total_fee = ctx.total_fee;
+                let total_funding = ctx.total_funding;
+                (
+                    balance,
+                    initial_balance,
+                    available_balance,
+                    unrealized_pnl,
+                    equity,
+                    realized_pnl,
+                    total_fee,
+                    total_funding,
+                )
             })
             .await;
 
@@ -99,6 +110,8 @@ impl BacktestStrategyStatsContext {
             unrealized_pnl,
             equity,
             realized_pnl,
+            total_fee,
+            total_funding,
         )
         // tracing::debug!("Strategy stats module created asset snapshot: equity={:.2}, cumulative_return={:.2}%, position_count={}",
         //     asset_snapshot_history_guard.get_latest_snapshot().unwrap().equity,
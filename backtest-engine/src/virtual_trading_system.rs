@@ -4,7 +4,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use futures::{StreamExt, stream::select_all};
 use key::KeyTrait;
-use star_river_event::backtest_strategy::node_event::KlineNodeEvent;
+use star_river_event::backtest_strategy::node_event::{IndicatorNodeEvent, KlineNodeEvent};
 use tokio::sync::watch;
 use tokio_stream::wrappers::BroadcastStream;
 use virtual_trading::{
@@ -99,15 +99,23 @@ impl VTSEventHandler for BacktestVtsContext {
         match event {
             BacktestNodeEvent::KlineNode(kline_event) => match kline_event {
                 KlineNodeEvent::KlineUpdate(event) => {
-                    if event.is_min_interval {
-                        self.handle_kline_update(
+                    if event.is_min_interval
+                        && let Err(e) = self.handle_kline_update(
                             event.kline_key.exchange().clone(),
                             event.kline_key.symbol().clone(),
                             event.kline.clone(),
-                        );
+                        )
+                    {
+                        tracing::error!("[BacktestVts] handle kline update error: {}", e);
                     }
                 }
             },
+            // Cache ATR values for ATR based trailing stops
+            BacktestNodeEvent::IndicatorNode(IndicatorNodeEvent::IndicatorUpdate(event)) => {
+                if let Some(atr) = event.indicator_value.get_value("atr") {
+                    self.update_atr_value(event.node_id().clone(), event.config_id, atr);
+                }
+            }
             _ => {}
         }
     }
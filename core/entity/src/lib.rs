@@ -4,8 +4,15 @@ pub mod prelude;
 
 pub mod account_config;
 pub mod account_info;
+pub mod backtest_checkpoint;
+pub mod backtest_optimization;
+pub mod backtest_optimization_trial;
+pub mod backtest_run;
+pub mod backtest_walk_forward;
+pub mod backtest_walk_forward_window;
 pub mod order;
 pub mod position;
 pub mod strategy_config;
+pub mod strategy_statistics;
 pub mod system_config;
 pub mod transaction;
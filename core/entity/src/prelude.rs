@@ -1,6 +1,9 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.19
 
 pub use super::{
-    account_config::Entity as AccountConfig, account_info::Entity as AccountInfo, strategy_config::Entity as StrategyConfig,
+    account_config::Entity as AccountConfig, account_info::Entity as AccountInfo, backtest_checkpoint::Entity as BacktestCheckpoint,
+    backtest_optimization::Entity as BacktestOptimization, backtest_optimization_trial::Entity as BacktestOptimizationTrial,
+    backtest_run::Entity as BacktestRun, backtest_walk_forward::Entity as BacktestWalkForward,
+    backtest_walk_forward_window::Entity as BacktestWalkForwardWindow, strategy_config::Entity as StrategyConfig, strategy_statistics::Entity as StrategyStatistics,
     system_config::Entity as SystemConfig,
 };
@@ -21,11 +21,28 @@ pub struct Model {
     pub quantity: f64,
     #[sea_orm(column_type = "Double")]
     pub price: f64,
+    #[sea_orm(column_name = "create_time")]
     pub created_time: DateTimeUtc,
     pub extra_info: Option<Json>,
+    pub backtest_run_id: Option<i32>, // Backtest run the record belongs to, None for live strategies
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
-pub enum Relation {}
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::backtest_run::Entity",
+        from = "Column::BacktestRunId",
+        to = "super::backtest_run::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    BacktestRun,
+}
+
+impl Related<super::backtest_run::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BacktestRun.def()
+    }
+}
 
 impl ActiveModelBehavior for ActiveModel {}
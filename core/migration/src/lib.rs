@@ -17,6 +17,10 @@ mod m20250610_030125_insert_system_config_init_data;
 // mod m20251117_063612_strategy_config_delete_config_field;
 mod m20251203_014716_insert_exchange_init_data;
 mod m20251205_095239_insert_demo_strategy;
+mod m20261017_000001_create_backtest_run_table; // Backtest run table, its link to the order/position/transaction tables and its equity curve
+mod m20261017_000002_create_backtest_optimization_table; // Parameter optimization table and its trial table
+mod m20261017_000003_create_backtest_checkpoint_table; // Saved backtest playback state table
+mod m20261018_000001_create_backtest_walk_forward_table; // Walk-forward analysis table and its window table
 
 pub struct Migrator;
 
@@ -41,6 +45,10 @@ impl MigratorTrait for Migrator {
             // Box::new(m20251117_063612_strategy_config_delete_config_field::Migration),
             Box::new(m20251203_014716_insert_exchange_init_data::Migration),
             Box::new(m20251205_095239_insert_demo_strategy::Migration),
+            Box::new(m20261017_000001_create_backtest_run_table::Migration),
+            Box::new(m20261017_000002_create_backtest_optimization_table::Migration),
+            Box::new(m20261017_000003_create_backtest_checkpoint_table::Migration),
+            Box::new(m20261018_000001_create_backtest_walk_forward_table::Migration),
         ]
     }
 }
@@ -0,0 +1,191 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 1. Backtest run table (one row per finished backtest)
+        manager
+            .create_table(
+                Table::create()
+                    .table(BacktestRun::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BacktestRun::Id))
+                    .col(integer(BacktestRun::StrategyId))
+                    .col(string(BacktestRun::StrategyName))
+                    .col(json_null(BacktestRun::ConfigSnapshot))
+                    .col(timestamp(BacktestRun::StartTime))
+                    .col(timestamp(BacktestRun::EndTime))
+                    .col(double(BacktestRun::InitialBalance))
+                    .col(double(BacktestRun::FeeRate))
+                    .col(integer(BacktestRun::Leverage))
+                    .col(string(BacktestRun::Status))
+                    .col(timestamp(BacktestRun::CreatedTime).default(SimpleExpr::Custom("CURRENT_TIMESTAMP".to_string())))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-backtest_run-strategy_id")
+                    .table(BacktestRun::Table)
+                    .col(BacktestRun::StrategyId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 2. Orders, positions and transactions of a run go to the existing tables, linked by backtest_run_id
+        add_backtest_run_id_column(manager, Order::Table).await?;
+        add_backtest_run_id_column(manager, Position::Table).await?;
+        add_backtest_run_id_column(manager, Transaction::Table).await?;
+
+        // 3. Equity curve of a run, one row per stats snapshot
+        manager
+            .create_table(
+                Table::create()
+                    .table(StrategyStatistics::Table)
+                    .if_not_exists()
+                    .col(pk_auto(StrategyStatistics::Id))
+                    .col(integer(StrategyStatistics::StrategyId))
+                    .col(integer(StrategyStatistics::BacktestRunId))
+                    .col(timestamp(StrategyStatistics::Datetime))
+                    .col(double(StrategyStatistics::Balance))
+                    .col(double(StrategyStatistics::AvailableBalance))
+                    .col(double(StrategyStatistics::UnrealizedPnl))
+                    .col(double(StrategyStatistics::Equity))
+                    .col(double(StrategyStatistics::CumulativeReturn))
+                    .col(double(StrategyStatistics::RealizedPnl))
+                    .col(double(StrategyStatistics::TotalFee))
+                    .col(double(StrategyStatistics::TotalFunding))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-strategy_statistics-backtest_run_id")
+                            .from(StrategyStatistics::Table, StrategyStatistics::BacktestRunId)
+                            .to(BacktestRun::Table, BacktestRun::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-strategy_statistics-backtest_run_id")
+                    .table(StrategyStatistics::Table)
+                    .col(StrategyStatistics::BacktestRunId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StrategyStatistics::Table).to_owned())
+            .await?;
+        drop_backtest_run_id_column(manager, Transaction::Table).await?;
+        drop_backtest_run_id_column(manager, Position::Table).await?;
+        drop_backtest_run_id_column(manager, Order::Table).await?;
+        manager.drop_table(Table::drop().table(BacktestRun::Table).to_owned()).await
+    }
+}
+
+// Rows of live strategies keep a null backtest_run_id
+async fn add_backtest_run_id_column<T>(manager: &SchemaManager<'_>, table: T) -> Result<(), DbErr>
+where
+    T: Iden + Clone + 'static,
+{
+    let table_name = table.to_string();
+    manager
+        .alter_table(
+            Table::alter()
+                .table(table.clone())
+                .add_column(integer_null(BacktestRunResult::BacktestRunId))
+                .to_owned(),
+        )
+        .await?;
+
+    manager
+        .create_index(
+            Index::create()
+                .name(format!("idx-{table_name}-backtest_run_id"))
+                .table(table)
+                .col(BacktestRunResult::BacktestRunId)
+                .to_owned(),
+        )
+        .await
+}
+
+async fn drop_backtest_run_id_column<T>(manager: &SchemaManager<'_>, table: T) -> Result<(), DbErr>
+where
+    T: Iden + Clone + 'static,
+{
+    let table_name = table.to_string();
+    manager
+        .drop_index(
+            Index::drop()
+                .name(format!("idx-{table_name}-backtest_run_id"))
+                .table(table.clone())
+                .to_owned(),
+        )
+        .await?;
+    manager
+        .alter_table(Table::alter().table(table).drop_column(BacktestRunResult::BacktestRunId).to_owned())
+        .await
+}
+
+#[derive(DeriveIden)]
+enum BacktestRun {
+    Table,
+    Id,
+    StrategyId,
+    StrategyName,
+    ConfigSnapshot,
+    StartTime,
+    EndTime,
+    InitialBalance,
+    FeeRate,
+    Leverage,
+    Status,
+    CreatedTime,
+}
+
+#[derive(DeriveIden)]
+enum BacktestRunResult {
+    BacktestRunId,
+}
+
+#[derive(DeriveIden, Clone)]
+enum Order {
+    Table,
+}
+
+#[derive(DeriveIden, Clone)]
+enum Position {
+    Table,
+}
+
+#[derive(DeriveIden, Clone)]
+enum Transaction {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum StrategyStatistics {
+    Table,
+    Id,
+    StrategyId,
+    BacktestRunId,
+    Datetime,
+    Balance,
+    AvailableBalance,
+    UnrealizedPnl,
+    Equity,
+    CumulativeReturn,
+    RealizedPnl,
+    TotalFee,
+    TotalFunding,
+}
@@ -0,0 +1,107 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 1. Optimization table (one row per parameter sweep)
+        manager
+            .create_table(
+                Table::create()
+                    .table(BacktestOptimization::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BacktestOptimization::Id))
+                    .col(integer(BacktestOptimization::StrategyId))
+                    .col(string(BacktestOptimization::StrategyName))
+                    .col(json(BacktestOptimization::OptimizationConfig))
+                    .col(string(BacktestOptimization::Metric))
+                    .col(string(BacktestOptimization::Status))
+                    .col(integer(BacktestOptimization::TotalTrials))
+                    .col(timestamp(BacktestOptimization::CreatedTime).default(SimpleExpr::Custom("CURRENT_TIMESTAMP".to_string())))
+                    .col(timestamp_null(BacktestOptimization::FinishedTime))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-backtest_optimization-strategy_id")
+                    .table(BacktestOptimization::Table)
+                    .col(BacktestOptimization::StrategyId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 2. Trial table, one row per parameter combination
+        manager
+            .create_table(
+                Table::create()
+                    .table(BacktestOptimizationTrial::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BacktestOptimizationTrial::Id))
+                    .col(integer(BacktestOptimizationTrial::OptimizationId))
+                    .col(json(BacktestOptimizationTrial::Parameters))
+                    .col(double_null(BacktestOptimizationTrial::Score))
+                    .col(json_null(BacktestOptimizationTrial::Performance))
+                    .col(string_null(BacktestOptimizationTrial::Error))
+                    .col(timestamp(BacktestOptimizationTrial::CreatedTime).default(SimpleExpr::Custom("CURRENT_TIMESTAMP".to_string())))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-backtest_optimization_trial-optimization_id")
+                            .from(BacktestOptimizationTrial::Table, BacktestOptimizationTrial::OptimizationId)
+                            .to(BacktestOptimization::Table, BacktestOptimization::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-backtest_optimization_trial-optimization_id")
+                    .table(BacktestOptimizationTrial::Table)
+                    .col(BacktestOptimizationTrial::OptimizationId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BacktestOptimizationTrial::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(BacktestOptimization::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum BacktestOptimization {
+    Table,
+    Id,
+    StrategyId,
+    StrategyName,
+    OptimizationConfig,
+    Metric,
+    Status,
+    TotalTrials,
+    CreatedTime,
+    FinishedTime,
+}
+
+#[derive(DeriveIden)]
+enum BacktestOptimizationTrial {
+    Table,
+    Id,
+    OptimizationId,
+    Parameters,
+    Score,
+    Performance,
+    Error,
+    CreatedTime,
+}
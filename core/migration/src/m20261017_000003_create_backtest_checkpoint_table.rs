@@ -0,0 +1,52 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // One row per saved playback position, the whole strategy state is kept as json
+        manager
+            .create_table(
+                Table::create()
+                    .table(BacktestCheckpoint::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BacktestCheckpoint::Id))
+                    .col(integer(BacktestCheckpoint::StrategyId))
+                    .col(string(BacktestCheckpoint::StrategyName))
+                    .col(big_integer(BacktestCheckpoint::PlayIndex))
+                    .col(timestamp(BacktestCheckpoint::StrategyDatetime))
+                    .col(json(BacktestCheckpoint::Checkpoint))
+                    .col(timestamp(BacktestCheckpoint::CreatedTime).default(SimpleExpr::Custom("CURRENT_TIMESTAMP".to_string())))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-backtest_checkpoint-strategy_id")
+                    .table(BacktestCheckpoint::Table)
+                    .col(BacktestCheckpoint::StrategyId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(BacktestCheckpoint::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum BacktestCheckpoint {
+    Table,
+    Id,
+    StrategyId,
+    StrategyName,
+    PlayIndex,
+    StrategyDatetime,
+    Checkpoint,
+    CreatedTime,
+}
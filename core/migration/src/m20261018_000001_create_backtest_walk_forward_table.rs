@@ -0,0 +1,125 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 1. Walk-forward table (one row per analysis, the stitched out-of-sample result is filled in when it finishes)
+        manager
+            .create_table(
+                Table::create()
+                    .table(BacktestWalkForward::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BacktestWalkForward::Id))
+                    .col(integer(BacktestWalkForward::StrategyId))
+                    .col(string(BacktestWalkForward::StrategyName))
+                    .col(json(BacktestWalkForward::WalkForwardConfig))
+                    .col(string(BacktestWalkForward::Metric))
+                    .col(string(BacktestWalkForward::WindowMode))
+                    .col(string(BacktestWalkForward::Status))
+                    .col(integer(BacktestWalkForward::TotalWindows))
+                    .col(json_null(BacktestWalkForward::EquityCurve))
+                    .col(json_null(BacktestWalkForward::Performance))
+                    .col(timestamp(BacktestWalkForward::CreatedTime).default(SimpleExpr::Custom("CURRENT_TIMESTAMP".to_string())))
+                    .col(timestamp_null(BacktestWalkForward::FinishedTime))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-backtest_walk_forward-strategy_id")
+                    .table(BacktestWalkForward::Table)
+                    .col(BacktestWalkForward::StrategyId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 2. Window table, one row per in-sample/out-of-sample step
+        manager
+            .create_table(
+                Table::create()
+                    .table(BacktestWalkForwardWindow::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BacktestWalkForwardWindow::Id))
+                    .col(integer(BacktestWalkForwardWindow::WalkForwardId))
+                    .col(integer(BacktestWalkForwardWindow::WindowIndex))
+                    .col(timestamp(BacktestWalkForwardWindow::InSampleStart))
+                    .col(timestamp(BacktestWalkForwardWindow::InSampleEnd))
+                    .col(timestamp(BacktestWalkForwardWindow::OutOfSampleStart))
+                    .col(timestamp(BacktestWalkForwardWindow::OutOfSampleEnd))
+                    .col(json_null(BacktestWalkForwardWindow::BestParameters))
+                    .col(double_null(BacktestWalkForwardWindow::InSampleScore))
+                    .col(double_null(BacktestWalkForwardWindow::OutOfSampleScore))
+                    .col(json_null(BacktestWalkForwardWindow::OutOfSamplePerformance))
+                    .col(integer(BacktestWalkForwardWindow::FailedTrialCount))
+                    .col(string_null(BacktestWalkForwardWindow::Error))
+                    .col(timestamp(BacktestWalkForwardWindow::CreatedTime).default(SimpleExpr::Custom("CURRENT_TIMESTAMP".to_string())))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-backtest_walk_forward_window-walk_forward_id")
+                            .from(BacktestWalkForwardWindow::Table, BacktestWalkForwardWindow::WalkForwardId)
+                            .to(BacktestWalkForward::Table, BacktestWalkForward::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-backtest_walk_forward_window-walk_forward_id")
+                    .table(BacktestWalkForwardWindow::Table)
+                    .col(BacktestWalkForwardWindow::WalkForwardId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BacktestWalkForwardWindow::Table).to_owned())
+            .await?;
+        manager.drop_table(Table::drop().table(BacktestWalkForward::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum BacktestWalkForward {
+    Table,
+    Id,
+    StrategyId,
+    StrategyName,
+    WalkForwardConfig,
+    Metric,
+    WindowMode,
+    Status,
+    TotalWindows,
+    EquityCurve,
+    Performance,
+    CreatedTime,
+    FinishedTime,
+}
+
+#[derive(DeriveIden)]
+enum BacktestWalkForwardWindow {
+    Table,
+    Id,
+    WalkForwardId,
+    WindowIndex,
+    InSampleStart,
+    InSampleEnd,
+    OutOfSampleStart,
+    OutOfSampleEnd,
+    BestParameters,
+    InSampleScore,
+    OutOfSampleScore,
+    OutOfSamplePerformance,
+    FailedTrialCount,
+    Error,
+    CreatedTime,
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    custom_type::{StrategyId, StrategyName},
+    system::DateTimeUtc,
+};
+
+// Summary of a saved backtest playback position, the strategy state itself is only read when it is loaded
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BacktestCheckpointInfo {
+    pub id: i32,
+    pub strategy_id: StrategyId,
+    pub strategy_name: StrategyName,
+    pub play_index: i64, // Last played kline
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub strategy_datetime: DateTimeUtc,
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub created_time: DateTimeUtc,
+}
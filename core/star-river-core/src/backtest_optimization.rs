@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+use utoipa::ToSchema;
+
+use crate::{
+    custom_type::{StrategyId, StrategyName},
+    system::DateTimeUtc,
+};
+
+// Backtest optimization status
+#[derive(Debug, Clone, Serialize, Deserialize, Display, EnumString, Eq, PartialEq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum BacktestOptimizationStatus {
+    Running,   // Trials are still being played
+    Completed, // All trials finished, failed trials included
+    Failed,    // The optimization could not run, e.g. the parameter space is invalid
+}
+
+// Summary of a persisted parameter optimization
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BacktestOptimizationInfo {
+    pub id: i32,
+    pub strategy_id: StrategyId,
+    pub strategy_name: StrategyName,
+    pub metric: String, // Metric the trials are ranked by
+    pub status: String,
+    pub total_trials: i32,
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub created_time: DateTimeUtc,
+    #[schema(value_type = Option<String>, example = "2021-01-01 00:00:00")]
+    pub finished_time: Option<DateTimeUtc>,
+}
+
+// Result of one parameter combination
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BacktestOptimizationTrialInfo {
+    pub id: i32,
+    pub rank: Option<u32>,                      // 1 is the best score, None for failed trials
+    pub parameters: serde_json::Value,          // Parameter values applied to the strategy config
+    pub score: Option<f64>,                     // Value of the ranking metric, higher is better
+    pub performance: Option<serde_json::Value>, // Trading performance report
+    pub error: Option<String>,                  // Error message of a failed trial
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub created_time: DateTimeUtc,
+}
+
+// Persisted optimization with its trials ranked by score
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BacktestOptimizationDetail {
+    #[serde(flatten)]
+    pub info: BacktestOptimizationInfo,
+    pub optimization_config: serde_json::Value, // Parameter ranges, search method and metric
+    pub trials: Vec<BacktestOptimizationTrialInfo>,
+}
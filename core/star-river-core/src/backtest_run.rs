@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+use utoipa::ToSchema;
+
+use crate::{
+    custom_type::{Balance, Equity, FeeRate, Pnl, StrategyId, StrategyName},
+    system::DateTimeUtc,
+};
+
+// Backtest run status
+#[derive(Debug, Clone, Serialize, Deserialize, Display, EnumString, Eq, PartialEq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum BacktestRunStatus {
+    Completed, // All klines played
+    Stopped,   // Strategy stopped before all klines were played
+}
+
+// Summary of a persisted backtest run
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BacktestRunInfo {
+    pub id: i32,
+    pub strategy_id: StrategyId,
+    pub strategy_name: StrategyName,
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub start_time: DateTimeUtc,
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub end_time: DateTimeUtc,
+    pub initial_balance: Balance,
+    pub fee_rate: FeeRate,
+    pub leverage: i32,
+    pub status: String,
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub created_time: DateTimeUtc,
+}
+
+// Persisted backtest run with its results
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BacktestRunDetail {
+    #[serde(flatten)]
+    pub info: BacktestRunInfo,
+    pub config_snapshot: Option<serde_json::Value>, // Strategy config (nodes and edges) when the run finished
+    pub orders: Vec<serde_json::Value>,
+    pub positions: Vec<serde_json::Value>,
+    pub transactions: Vec<serde_json::Value>,
+    pub equity_curve: Vec<BacktestRunEquity>,
+}
+
+// Stats snapshot of a backtest run, one point of its equity curve
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BacktestRunEquity {
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub datetime: DateTimeUtc,
+    pub balance: Balance,
+    pub available_balance: Balance,
+    pub unrealized_pnl: Pnl,
+    pub equity: Equity,
+    pub cumulative_return: f64,
+    pub realized_pnl: Pnl,
+    pub total_fee: f64,
+    pub total_funding: Pnl,
+}
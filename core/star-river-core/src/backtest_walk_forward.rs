@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+use utoipa::ToSchema;
+
+use crate::{
+    custom_type::{StrategyId, StrategyName},
+    system::DateTimeUtc,
+};
+
+// Walk-forward analysis status
+#[derive(Debug, Clone, Serialize, Deserialize, Display, EnumString, Eq, PartialEq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum BacktestWalkForwardStatus {
+    Running,   // Windows are still being played
+    Completed, // All windows played, windows without an out-of-sample result included
+    Failed,    // The stitched out-of-sample result could not be stored
+}
+
+// Summary of a persisted walk-forward analysis
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BacktestWalkForwardInfo {
+    pub id: i32,
+    pub strategy_id: StrategyId,
+    pub strategy_name: StrategyName,
+    pub metric: String,      // Metric the in-sample trials are ranked by
+    pub window_mode: String, // Rolling or anchored in-sample window
+    pub status: String,
+    pub total_windows: i32,
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub created_time: DateTimeUtc,
+    #[schema(value_type = Option<String>, example = "2021-01-01 00:00:00")]
+    pub finished_time: Option<DateTimeUtc>,
+}
+
+// Result of one in-sample/out-of-sample step
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BacktestWalkForwardWindowInfo {
+    pub id: i32,
+    pub window_index: i32,
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub in_sample_start: DateTimeUtc,
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub in_sample_end: DateTimeUtc,
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub out_of_sample_start: DateTimeUtc,
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub out_of_sample_end: DateTimeUtc,
+    pub best_parameters: Option<serde_json::Value>, // Best in-sample parameters, applied to the out-of-sample window
+    pub in_sample_score: Option<f64>,
+    pub out_of_sample_score: Option<f64>,
+    pub out_of_sample_performance: Option<serde_json::Value>, // Trading performance report of the out-of-sample window
+    pub failed_trial_count: i32,
+    pub error: Option<String>, // Why the window has no out-of-sample result
+    #[schema(value_type = String, example = "2021-01-01 00:00:00")]
+    pub created_time: DateTimeUtc,
+}
+
+// Persisted walk-forward analysis with its windows in time order
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BacktestWalkForwardDetail {
+    #[serde(flatten)]
+    pub info: BacktestWalkForwardInfo,
+    pub walk_forward_config: serde_json::Value, // Parameter ranges, search method, metric and window lengths
+    pub windows: Vec<BacktestWalkForwardWindowInfo>,
+    pub equity_curve: Option<serde_json::Value>, // Out-of-sample equity curves compounded into one, None until finished
+    pub performance: Option<serde_json::Value>,  // Performance of the stitched out-of-sample curve, None until finished
+}
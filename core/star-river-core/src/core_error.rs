@@ -17,6 +17,13 @@ pub enum CoreError {
 
     #[snafu(display("parse exchange failed: {exchange}"))]
     ParseExchangeFailed { exchange: String, backtrace: Backtrace },
+
+    #[snafu(display("open time of the {interval} period containing {datetime} is out of range"))]
+    KlineOpenTimeOutOfRange {
+        interval: String,
+        datetime: String,
+        backtrace: Backtrace,
+    },
 }
 
 // Implement the StarRiverErrorTrait for StarRiverError
@@ -31,6 +38,7 @@ impl StarRiverErrorTrait for CoreError {
             CoreError::UpdateSystemConfigFailed { .. } => 1001, // Update system config failed
             CoreError::GetSystemConfigFailed { .. } => 1002,    // Get system config failed
             CoreError::ParseExchangeFailed { .. } => 1003,      // Parse exchange failed
+            CoreError::KlineOpenTimeOutOfRange { .. } => 1004,  // Kline period open time out of range
         };
         format!("{}_{:04}", prefix, code)
     }
@@ -48,6 +56,9 @@ impl StarRiverErrorTrait for CoreError {
                 CoreError::ParseExchangeFailed { exchange, .. } => {
                     format!("解析交易所失败: {}", exchange)
                 }
+                CoreError::KlineOpenTimeOutOfRange { interval, datetime, .. } => {
+                    format!("{datetime}所在的{interval}周期开盘时间超出范围")
+                }
             },
         }
     }
@@ -56,7 +67,8 @@ impl StarRiverErrorTrait for CoreError {
         match self {
             CoreError::UpdateSystemConfigFailed { .. }
             | CoreError::GetSystemConfigFailed { .. }
-            | CoreError::ParseExchangeFailed { .. } => vec![self.error_code()],
+            | CoreError::ParseExchangeFailed { .. }
+            | CoreError::KlineOpenTimeOutOfRange { .. } => vec![self.error_code()],
         }
     }
 }
@@ -1,12 +1,17 @@
 use std::time::Duration;
 
+use chrono::{Datelike, TimeZone, Utc};
 use deepsize::DeepSizeOf;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use snafu::OptionExt;
 use strum::{Display, EnumString};
 use utoipa::ToSchema;
 
-use crate::system::DateTimeUtc;
+use crate::{
+    core_error::{CoreError, KlineOpenTimeOutOfRangeSnafu},
+    system::DateTimeUtc,
+};
 
 // Kline interval
 #[derive(Clone, Serialize, Deserialize, Display, EnumString, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, ToSchema)]
@@ -106,6 +111,28 @@ impl KlineInterval {
     pub fn to_duration(&self) -> Duration {
         Duration::from_secs(self.to_seconds())
     }
+
+    // Open time of the period (UTC aligned) that contains `datetime`
+    // Example: interval=1h, 03:15:00 -> 03:00:00; interval=1w -> Monday 00:00:00; interval=1M -> 1st 00:00:00
+    pub fn open_time(&self, datetime: DateTimeUtc) -> Result<DateTimeUtc, CoreError> {
+        let open_time = match self {
+            KlineInterval::Weeks1 => {
+                let date = datetime.date_naive() - chrono::Duration::days(datetime.weekday().num_days_from_monday() as i64);
+                date.and_hms_opt(0, 0, 0).map(|open_time| open_time.and_utc())
+            }
+            KlineInterval::Months1 => Utc.with_ymd_and_hms(datetime.year(), datetime.month(), 1, 0, 0, 0).single(),
+            // Minute, hour and day periods all divide a UTC day evenly, so align on the unix epoch
+            _ => {
+                let interval_seconds = self.to_seconds() as i64;
+                let timestamp = datetime.timestamp();
+                Utc.timestamp_opt(timestamp - timestamp.rem_euclid(interval_seconds), 0).single()
+            }
+        };
+        open_time.context(KlineOpenTimeOutOfRangeSnafu {
+            interval: self.to_string(),
+            datetime: datetime.to_string(),
+        })
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, DeepSizeOf)]
@@ -187,6 +214,18 @@ impl Kline {
         )
     }
 
+    // Merge a later kline of a smaller interval into this one (this kline keeps its datetime and open)
+    pub fn merge(&self, next: &Kline) -> Kline {
+        Kline::new(
+            self.datetime,
+            self.open,
+            self.high.max(next.high),
+            self.low.min(next.low),
+            next.close,
+            self.volume + next.volume,
+        )
+    }
+
     pub fn get_value(&self, key: &str) -> Option<f64> {
         match key {
             "datetime" => Some(self.datetime().timestamp_millis() as f64),
@@ -199,3 +238,68 @@ impl Kline {
         }
     }
 }
+
+// Resample a time-ordered kline series into `interval` klines
+// Each output kline is stamped with its period open time; the last one may be a partially-formed bar
+pub fn resample_klines(klines: &[Kline], interval: &KlineInterval) -> Result<Vec<Kline>, CoreError> {
+    let mut resampled: Vec<Kline> = Vec::new();
+    for kline in klines {
+        let open_time = interval.open_time(kline.datetime())?;
+        match resampled.last_mut() {
+            Some(last) if last.datetime() == open_time => *last = last.merge(kline),
+            _ => resampled.push(Kline {
+                datetime: open_time,
+                ..kline.clone()
+            }),
+        }
+    }
+    Ok(resampled)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+
+    fn datetime(s: &str) -> DateTimeUtc {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap().and_utc()
+    }
+
+    #[test]
+    fn test_open_time() {
+        let dt = datetime("2024-01-03 13:47:00"); // Wednesday
+        assert_eq!(KlineInterval::Minutes15.open_time(dt).unwrap(), datetime("2024-01-03 13:45:00"));
+        assert_eq!(KlineInterval::Hours4.open_time(dt).unwrap(), datetime("2024-01-03 12:00:00"));
+        assert_eq!(KlineInterval::Days1.open_time(dt).unwrap(), datetime("2024-01-03 00:00:00"));
+        assert_eq!(KlineInterval::Weeks1.open_time(dt).unwrap(), datetime("2024-01-01 00:00:00"));
+        assert_eq!(KlineInterval::Months1.open_time(dt).unwrap(), datetime("2024-01-01 00:00:00"));
+    }
+
+    #[test]
+    fn test_resample_klines_with_gap_and_partial_bar() {
+        let klines = vec![
+            Kline::new(datetime("2024-01-01 00:58:00"), 1.0, 2.0, 0.5, 1.5, 1.0),
+            Kline::new(datetime("2024-01-01 00:59:00"), 1.5, 3.0, 1.0, 2.5, 2.0),
+            // 01:00 bar is missing, 01:01 must still open a new hour
+            Kline::new(datetime("2024-01-01 01:01:00"), 2.5, 2.6, 2.0, 2.2, 4.0),
+        ];
+        let resampled = resample_klines(&klines, &KlineInterval::Hours1).unwrap();
+
+        assert_eq!(resampled.len(), 2);
+        assert_eq!(resampled[0].datetime(), datetime("2024-01-01 00:00:00"));
+        assert_eq!(
+            (
+                resampled[0].open(),
+                resampled[0].high(),
+                resampled[0].low(),
+                resampled[0].close(),
+                resampled[0].volume()
+            ),
+            (1.0, 3.0, 0.5, 2.5, 3.0)
+        );
+        assert_eq!(resampled[1].datetime(), datetime("2024-01-01 01:00:00"));
+        assert_eq!(resampled[1].open(), 2.5);
+        assert_eq!(resampled[1].volume(), 4.0);
+    }
+}
@@ -1,4 +1,8 @@
 pub mod account;
+pub mod backtest_checkpoint;
+pub mod backtest_optimization;
+pub mod backtest_run;
+pub mod backtest_walk_forward;
 pub mod core_error;
 pub mod custom_type;
 pub mod engine;
@@ -0,0 +1,48 @@
+//! Serialize a map as a list of `[key, value]` entries
+//!
+//! JSON object keys must be strings, maps keyed by tuples or structs are stored with
+//! `#[serde(with = "star_river_core::system::map_entries")]`
+
+use std::{collections::HashMap, hash::Hash};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub fn serialize<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Serialize,
+    V: Serialize,
+    S: Serializer,
+{
+    serializer.collect_seq(map.iter())
+}
+
+pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let entries = Vec::<(K, V)>::deserialize(deserializer)?;
+    Ok(entries.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Prices {
+        #[serde(with = "super")]
+        prices: HashMap<(String, String), f64>,
+    }
+
+    #[test]
+    fn test_tuple_keys_roundtrip() {
+        let prices = Prices {
+            prices: HashMap::from([(("binance".to_string(), "BTCUSDT".to_string()), 100.0)]),
+        };
+        let json = serde_json::to_string(&prices).unwrap();
+        assert_eq!(json, r#"{"prices":[[["binance","BTCUSDT"],100.0]]}"#);
+        assert_eq!(serde_json::from_str::<Prices>(&json).unwrap(), prices);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod map_entries;
 pub mod system_config;
 
 use std::{fmt, str::FromStr};
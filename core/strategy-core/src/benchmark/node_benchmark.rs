@@ -18,13 +18,24 @@ pub struct CycleTracker {
 
 impl CycleTracker {
     pub fn new(cycle_id: CycleId) -> Self {
+        Self::new_at(cycle_id, Instant::now())
+    }
+
+    // Start the cycle at an earlier instant, e.g. when the work was triggered
+    pub fn new_at(cycle_id: CycleId, start_time: Instant) -> Self {
         Self {
             cycle_id,
-            start_time: Instant::now(),
+            start_time,
             phase_durations: Vec::new(),
         }
     }
 
+    // Add a phase measured outside the tracker
+    #[inline]
+    pub fn record_phase(&mut self, phase_name: impl Into<String>, duration: Duration) {
+        self.phase_durations.push((phase_name.into(), duration));
+    }
+
     #[inline]
     pub fn start_phase(&mut self, phase_name: impl Into<String>) {
         // Record current time as phase start
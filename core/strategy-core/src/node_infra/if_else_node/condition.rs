@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, ser::SerializeMap};
 use strum::EnumString;
 use strum_macros::Display;
 
@@ -63,6 +63,36 @@ pub enum ComparisonSymbol {
     #[serde(rename = "is not empty")]
     #[strum(serialize = "is not empty")]
     IsNotEmpty,
+
+    // Left was <= right on the previous bar and is > right now
+    #[serde(rename = "crosses above")]
+    #[strum(serialize = "crosses above")]
+    CrossesAbove,
+
+    // Left was >= right on the previous bar and is < right now
+    #[serde(rename = "crosses below")]
+    #[strum(serialize = "crosses below")]
+    CrossesBelow,
+
+    // Left increased on each of the last N bars, N is the right value
+    #[serde(rename = "rising for")]
+    #[strum(serialize = "rising for")]
+    RisingFor,
+
+    // Left decreased on each of the last N bars, N is the right value
+    #[serde(rename = "falling for")]
+    #[strum(serialize = "falling for")]
+    FallingFor,
+}
+
+impl ComparisonSymbol {
+    // Compared over the recent values of the variables instead of the values of one bar
+    pub fn is_series(&self) -> bool {
+        matches!(
+            self,
+            ComparisonSymbol::CrossesAbove | ComparisonSymbol::CrossesBelow | ComparisonSymbol::RisingFor | ComparisonSymbol::FallingFor
+        )
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -101,6 +131,9 @@ pub struct Variable {
     pub var_value_type: VariableValueType,
     pub var_display_name: String,
     pub var_name: String,
+    // Compare the value of `bars_ago` bars before the current one, value[t - k]
+    #[serde(default)]
+    pub bars_ago: u32,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -145,6 +178,39 @@ pub struct Condition {
     pub right: FormulaRight,
 }
 
+impl Condition {
+    /// Bars before the compared one that the comparison symbol needs
+    ///
+    /// `rising for` and `falling for` read N from a constant right value, N = 0 when it is not a positive integer
+    pub fn series_length(&self) -> usize {
+        match self.comparison_symbol {
+            ComparisonSymbol::CrossesAbove | ComparisonSymbol::CrossesBelow => 1,
+            ComparisonSymbol::RisingFor | ComparisonSymbol::FallingFor => match &self.right {
+                FormulaRight::Constant(constant) => constant.var_value.as_bar_count().unwrap_or(0),
+                // Rejected when the node config is checked, the history cannot be sized from a variable
+                FormulaRight::Variable(_) => 0,
+            },
+            _ => 0,
+        }
+    }
+
+    /// Number of recent values to keep for each variable of the condition, the current one included
+    pub fn history_depth(&self) -> Vec<(&Variable, usize)> {
+        let series_length = self.series_length();
+        let mut depths = vec![(&self.left, self.left.bars_ago as usize + series_length + 1)];
+        if let FormulaRight::Variable(variable) = &self.right {
+            // The right value of `rising for` / `falling for` is the bar count, only its current value is read
+            let depth = if matches!(self.comparison_symbol, ComparisonSymbol::RisingFor | ComparisonSymbol::FallingFor) {
+                variable.bars_ago as usize + 1
+            } else {
+                variable.bars_ago as usize + series_length + 1
+            };
+            depths.push((variable, depth));
+        }
+        depths
+    }
+}
+
 // Condition result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -181,13 +247,205 @@ pub enum LogicalSymbol {
     Or,
 }
 
+/// Node of a case's condition tree
+///
+/// A plain condition object is a `Condition`, an object with `conditions` a `Group`
+/// and `{"not": ...}` negates the expression inside it
+// Conditions are by far the most common node, they are not boxed
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub enum ConditionExpr {
+    Condition(Condition),
+    Group(ConditionGroup),
+    Not(Box<ConditionExpr>),
+}
+
+/// Parenthesized sub-expression, its conditions are joined by `logical_symbol`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConditionGroup {
+    pub group_id: i32,
+    pub logical_symbol: LogicalSymbol,
+    pub conditions: Vec<ConditionExpr>,
+}
+
+impl Serialize for ConditionExpr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ConditionExpr::Condition(condition) => condition.serialize(serializer),
+            ConditionExpr::Group(group) => group.serialize(serializer),
+            ConditionExpr::Not(expr) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("not", expr)?;
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ConditionExpr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        #[derive(Deserialize)]
+        struct NotHelper {
+            not: ConditionExpr,
+        }
+
+        // Dispatch on the keys so that errors point at the fields of the intended node
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let expr = if value.get("not").is_some() {
+            let helper: NotHelper = serde_json::from_value(value).map_err(D::Error::custom)?;
+            ConditionExpr::Not(Box::new(helper.not))
+        } else if value.get("conditions").is_some() {
+            ConditionExpr::Group(serde_json::from_value(value).map_err(D::Error::custom)?)
+        } else {
+            ConditionExpr::Condition(serde_json::from_value(value).map_err(D::Error::custom)?)
+        };
+        Ok(expr)
+    }
+}
+
+impl ConditionExpr {
+    /// Evaluates the expression with `evaluate_condition` for its conditions
+    ///
+    /// Groups short-circuit, conditions after the deciding one are not evaluated
+    pub fn evaluate<F>(&self, evaluate_condition: &mut F) -> ExpressionResult
+    where
+        F: FnMut(&Condition) -> ConditionResult,
+    {
+        match self {
+            ConditionExpr::Condition(condition) => ExpressionResult::Condition(evaluate_condition(condition)),
+            ConditionExpr::Group(group) => ExpressionResult::Group(evaluate_group(
+                Some(group.group_id),
+                &group.logical_symbol,
+                &group.conditions,
+                evaluate_condition,
+            )),
+            ConditionExpr::Not(expr) => {
+                let result = expr.evaluate(evaluate_condition);
+                ExpressionResult::Not(NotResult {
+                    not_result: !result.result(),
+                    result: Box::new(result),
+                })
+            }
+        }
+    }
+
+    fn collect_conditions<'a>(&'a self, conditions: &mut Vec<&'a Condition>) {
+        match self {
+            ConditionExpr::Condition(condition) => conditions.push(condition),
+            ConditionExpr::Group(group) => group.conditions.iter().for_each(|expr| expr.collect_conditions(conditions)),
+            ConditionExpr::Not(expr) => expr.collect_conditions(conditions),
+        }
+    }
+}
+
+fn evaluate_group<F>(
+    group_id: Option<i32>,
+    logical_symbol: &LogicalSymbol,
+    conditions: &[ConditionExpr],
+    evaluate_condition: &mut F,
+) -> GroupResult
+where
+    F: FnMut(&Condition) -> ConditionResult,
+{
+    // Empty AND group is true, empty OR group is false
+    let mut group_result = matches!(logical_symbol, LogicalSymbol::And);
+    let mut results = vec![];
+    let mut decided_by = None;
+    for (index, expr) in conditions.iter().enumerate() {
+        let result = expr.evaluate(evaluate_condition);
+        group_result = result.result();
+        results.push(result);
+        decided_by = Some(index);
+        let short_circuit = match logical_symbol {
+            LogicalSymbol::And => !group_result,
+            LogicalSymbol::Or => group_result,
+        };
+        if short_circuit {
+            break;
+        }
+    }
+    GroupResult {
+        group_id,
+        logical_symbol: logical_symbol.clone(),
+        results,
+        decided_by,
+        group_result,
+    }
+}
+
+/// Result of a condition tree, only the evaluated part of it is included
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(clippy::large_enum_variant)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ExpressionResult {
+    Condition(ConditionResult),
+    Group(GroupResult),
+    Not(NotResult),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupResult {
+    // None for the top level conditions of a case
+    pub group_id: Option<i32>,
+    pub logical_symbol: LogicalSymbol,
+    pub results: Vec<ExpressionResult>,
+    // Index in `results` of the sub-expression that decided the group, None for an empty group
+    pub decided_by: Option<usize>,
+    pub group_result: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotResult {
+    pub result: Box<ExpressionResult>,
+    pub not_result: bool,
+}
+
+impl ExpressionResult {
+    pub fn result(&self) -> bool {
+        match self {
+            ExpressionResult::Condition(result) => result.condition_result,
+            ExpressionResult::Group(result) => result.group_result,
+            ExpressionResult::Not(result) => result.not_result,
+        }
+    }
+
+    fn collect_condition_results<'a>(&'a self, condition_results: &mut Vec<&'a ConditionResult>) {
+        match self {
+            ExpressionResult::Condition(result) => condition_results.push(result),
+            ExpressionResult::Group(result) => result.collect_condition_results(condition_results),
+            ExpressionResult::Not(result) => result.result.collect_condition_results(condition_results),
+        }
+    }
+}
+
+impl GroupResult {
+    /// Results of all evaluated conditions, nested ones included
+    pub fn condition_results(&self) -> Vec<&ConditionResult> {
+        let mut condition_results = vec![];
+        self.collect_condition_results(&mut condition_results);
+        condition_results
+    }
+
+    fn collect_condition_results<'a>(&'a self, condition_results: &mut Vec<&'a ConditionResult>) {
+        self.results
+            .iter()
+            .for_each(|result| result.collect_condition_results(condition_results));
+    }
+}
+
 // Case branch
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Case {
     pub case_id: i32,
     pub output_handle_id: String,
-    pub conditions: Vec<Condition>,
+    // Top level expressions, plain conditions or nested groups
+    pub conditions: Vec<ConditionExpr>,
     pub logical_symbol: LogicalSymbol,
 }
 
@@ -196,4 +454,72 @@ impl Case {
     pub fn from_json(json_str: &str) -> Result<Case, serde_json::Error> {
         serde_json::from_str(json_str)
     }
+
+    pub fn evaluate<F>(&self, mut evaluate_condition: F) -> GroupResult
+    where
+        F: FnMut(&Condition) -> ConditionResult,
+    {
+        evaluate_group(None, &self.logical_symbol, &self.conditions, &mut evaluate_condition)
+    }
+
+    /// All conditions of the case, nested ones included
+    pub fn all_conditions(&self) -> Vec<&Condition> {
+        let mut conditions = vec![];
+        self.conditions.iter().for_each(|expr| expr.collect_conditions(&mut conditions));
+        conditions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn condition_json(condition_id: i32) -> String {
+        format!(
+            r#"{{"conditionId":{condition_id},"comparisonSymbol":">","left":{{"nodeId":"n","nodeName":"n","nodeType":"variableNode","outputHandleId":"o","varConfigId":{condition_id},"varValueType":"number","varDisplayName":"v","varName":"v"}},"right":{{"varType":"constant","varValueType":"number","varValue":0}}}}"#
+        )
+    }
+
+    #[test]
+    fn test_nested_groups_evaluate_with_short_circuit() {
+        // (A and B) or (C and not D) with A and C true
+        let json = format!(
+            r#"{{"caseId":1,"outputHandleId":"o","logicalSymbol":"or","conditions":[
+                {{"groupId":1,"logicalSymbol":"and","conditions":[{},{}]}},
+                {{"groupId":2,"logicalSymbol":"and","conditions":[{},{{"not":{}}}]}}
+            ]}}"#,
+            condition_json(1),
+            condition_json(2),
+            condition_json(3),
+            condition_json(4),
+        );
+        let case = Case::from_json(&json).unwrap();
+        assert_eq!(case.all_conditions().len(), 4);
+
+        let mut evaluated = vec![];
+        let result = case.evaluate(|condition| {
+            evaluated.push(condition.condition_id);
+            let condition_result = [1, 3].contains(&condition.condition_id);
+            ConditionResult::new(condition, VariableValue::Null, VariableValue::Null, condition_result)
+        });
+
+        // B is false, so group 1 stops there and group 2 decides the case
+        assert!(result.group_result);
+        assert_eq!(evaluated, vec![1, 2, 3, 4]);
+        assert_eq!(result.decided_by, Some(1));
+        let ExpressionResult::Group(group) = &result.results[0] else {
+            panic!("expected a group result");
+        };
+        assert_eq!(group.decided_by, Some(1));
+        assert!(!group.group_result);
+
+        // The plain condition list of a case still round-trips
+        let flat = Case::from_json(&format!(
+            r#"{{"caseId":2,"outputHandleId":"o","logicalSymbol":"and","conditions":[{}]}}"#,
+            condition_json(1)
+        ))
+        .unwrap();
+        let reserialized = serde_json::to_value(&flat).unwrap();
+        assert!(reserialized["conditions"][0]["conditionId"] == 1);
+    }
 }
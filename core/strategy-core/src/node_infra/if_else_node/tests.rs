@@ -105,7 +105,7 @@ mod tests {
                 case_id: 1,
                 output_handle_id: "output_1".to_string(),
                 logical_symbol: LogicalSymbol::And,
-                conditions: vec![Condition {
+                conditions: vec![ConditionExpr::Condition(Condition {
                     condition_id: 1,
                     left: Variable {
                         node_id: "node_1".to_string(),
@@ -116,13 +116,14 @@ mod tests {
                         var_value_type: VariableValueType::Number,
                         var_display_name: "Variable 1".to_string(),
                         var_name: "var1".to_string(),
+                        bars_ago: 0,
                     },
                     comparison_symbol: ComparisonSymbol::GreaterThan,
                     right: FormulaRight::Constant(Constant {
                         // var_value_type: VariableValueType::Number,
                         var_value: VariableValue::Number(Decimal::from(100)),
                     }),
-                }],
+                })],
             }],
         };
 
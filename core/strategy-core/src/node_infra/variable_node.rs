@@ -1,3 +1,4 @@
+pub mod timer_schedule;
 pub mod trigger;
 pub mod variable_config;
 pub mod variable_operation;
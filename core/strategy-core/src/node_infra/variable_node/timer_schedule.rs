@@ -0,0 +1,331 @@
+//! Timer triggers evaluated against strategy time
+//!
+//! Schedules are in UTC like the strategy time. Strategy time moves by whole klines, so a timer that came
+//! due between two klines fires once on the later kline, missed occurrences are not replayed
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Timelike, Utc};
+
+use super::trigger::timer::{DayOfMonth, MonthlyFallbackStrategy, MonthlyScheduledConfig, ScheduledTimerConfig, TimeUnit, TimerTrigger};
+
+// Longest gap between two fire times searched for, 29th of February is at most 8 years away
+const MAX_SEARCH_DAYS: i64 = 366 * 8;
+
+/// When a timer trigger fires
+#[derive(Debug, Clone)]
+pub enum TimerSchedule {
+    /// Every interval, counted from the first strategy time
+    Interval(Duration),
+    Cron(CronSchedule),
+    Monthly {
+        day_of_month: DayOfMonth,
+        fallback: MonthlyFallbackStrategy,
+        time: NaiveTime,
+    },
+}
+
+impl TimerSchedule {
+    /// First fire time of a timer started at `start_time`, a calendar schedule matching `start_time` fires on it
+    pub fn first_fire_time(&self, start_time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            TimerSchedule::Interval(interval) => Some(start_time + *interval),
+            _ => self.next_fire_time(start_time - Duration::seconds(1), start_time - Duration::seconds(1)),
+        }
+    }
+
+    /// Fire time following the due `fire_time`, the first one after `now`. None when the timer never fires again
+    pub fn next_fire_time(&self, fire_time: DateTime<Utc>, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            TimerSchedule::Interval(interval) => {
+                // Keep the interval grid, occurrences skipped by a long kline are dropped
+                let interval_seconds = interval.num_seconds();
+                let elapsed_seconds = (now - fire_time).num_seconds().max(0);
+                Some(fire_time + Duration::seconds((elapsed_seconds / interval_seconds + 1) * interval_seconds))
+            }
+            TimerSchedule::Cron(cron_schedule) => cron_schedule.next_after(now),
+            TimerSchedule::Monthly {
+                day_of_month,
+                fallback,
+                time,
+            } => {
+                let mut date = NaiveDate::from_ymd_opt(now.year(), now.month(), 1)?;
+                for _ in 0..=MAX_SEARCH_DAYS / 28 {
+                    let last_day = last_day_of_month(date);
+                    let day = match day_of_month {
+                        DayOfMonth::First => Some(1),
+                        DayOfMonth::Last => Some(last_day),
+                        DayOfMonth::Number(day) if *day <= last_day => Some(*day),
+                        DayOfMonth::Number(_) => match fallback {
+                            MonthlyFallbackStrategy::LastDay => Some(last_day),
+                            MonthlyFallbackStrategy::Skip => None,
+                        },
+                    };
+                    if let Some(day) = day {
+                        let candidate = date.with_day(day)?.and_time(*time).and_utc();
+                        if candidate > now {
+                            return Some(candidate);
+                        }
+                    }
+                    date = date.checked_add_months(chrono::Months::new(1))?;
+                }
+                None
+            }
+        }
+    }
+}
+
+impl TimeUnit {
+    pub fn duration(&self, amount: i64) -> Duration {
+        match self {
+            TimeUnit::Second => Duration::seconds(amount),
+            TimeUnit::Minute => Duration::minutes(amount),
+            TimeUnit::Hour => Duration::hours(amount),
+            TimeUnit::Day => Duration::days(amount),
+        }
+    }
+}
+
+impl TimerTrigger {
+    /// Schedule of the trigger
+    ///
+    /// Hourly, daily and weekly timers run their cron expression, the fields are used when it is empty.
+    /// Monthly timers run from their fields, cron cannot express the fallback of a day missing in a month
+    pub fn schedule(&self) -> Result<TimerSchedule, String> {
+        match self {
+            TimerTrigger::Interval(config) => {
+                if config.interval <= 0 {
+                    return Err(format!("interval must be positive, got {}", config.interval));
+                }
+                Ok(TimerSchedule::Interval(config.unit.duration(config.interval as i64)))
+            }
+            TimerTrigger::Scheduled(ScheduledTimerConfig::Monthly(config)) => monthly_schedule(config),
+            TimerTrigger::Scheduled(config) => {
+                let cron_expression = if config.cron_expression().trim().is_empty() {
+                    cron_expression_from_fields(config)?
+                } else {
+                    config.cron_expression().to_string()
+                };
+                Ok(TimerSchedule::Cron(CronSchedule::parse(&cron_expression)?))
+            }
+        }
+    }
+}
+
+fn monthly_schedule(config: &MonthlyScheduledConfig) -> Result<TimerSchedule, String> {
+    if let DayOfMonth::Number(day) = config.day_of_month
+        && !(1..=31).contains(&day)
+    {
+        return Err(format!("day of month must be between 1 and 31, got {day}"));
+    }
+    Ok(TimerSchedule::Monthly {
+        day_of_month: config.day_of_month.clone(),
+        // Like cron, months without the day are skipped unless told otherwise
+        fallback: config.monthly_fallback.clone().unwrap_or(MonthlyFallbackStrategy::Skip),
+        time: parse_time(&config.time)?,
+    })
+}
+
+fn cron_expression_from_fields(config: &ScheduledTimerConfig) -> Result<String, String> {
+    match config {
+        ScheduledTimerConfig::Hourly(config) => {
+            if config.hourly_interval <= 0 {
+                return Err(format!("hourly interval must be positive, got {}", config.hourly_interval));
+            }
+            Ok(format!("{} */{} * * *", config.minute_of_hour, config.hourly_interval))
+        }
+        ScheduledTimerConfig::Daily(config) => {
+            let time = parse_time(&config.time)?;
+            let days_of_week = if config.days_of_week.is_empty() {
+                "*".to_string()
+            } else {
+                config.days_of_week.iter().map(|day| day.to_string()).collect::<Vec<_>>().join(",")
+            };
+            Ok(format!("{} {} * * {}", time.minute(), time.hour(), days_of_week))
+        }
+        ScheduledTimerConfig::Weekly(config) => {
+            let time = parse_time(&config.time)?;
+            Ok(format!("{} {} * * {}", time.minute(), time.hour(), config.day_of_week))
+        }
+        ScheduledTimerConfig::Monthly(_) => Err("monthly timers are not scheduled by cron".to_string()),
+    }
+}
+
+fn parse_time(time: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(time, "%H:%M")
+        .or_else(|_| NaiveTime::parse_from_str(time, "%H:%M:%S"))
+        .map_err(|_| format!("invalid time: {time}, expected HH:MM"))
+}
+
+fn last_day_of_month(date: NaiveDate) -> u32 {
+    let first_of_next_month = date.with_day(1).and_then(|first| first.checked_add_months(chrono::Months::new(1)));
+    first_of_next_month
+        .and_then(|first| first.pred_opt())
+        .map(|last| last.day())
+        .unwrap_or(31)
+}
+
+/// Standard cron expression: minute hour day-of-month month day-of-week
+///
+/// Supports `*`, `?`, lists, ranges and steps, `L` as day of month for the last day.
+/// A leading seconds field is accepted and ignored
+#[derive(Debug, Clone)]
+pub struct CronSchedule {
+    minutes: Vec<u32>,
+    hours: Vec<u32>,
+    days_of_month: Vec<u32>,
+    last_day_of_month: bool,
+    months: Vec<u32>,
+    // 0 = Sunday
+    days_of_week: Vec<u32>,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let fields = match fields.len() {
+            5 => &fields[..],
+            6 => &fields[1..],
+            count => return Err(format!("invalid cron expression: {expression}, expected 5 fields, got {count}")),
+        };
+        let parse = |field: &str, min: u32, max: u32| {
+            parse_cron_field(field, min, max).map_err(|reason| format!("invalid cron expression: {expression}, {reason}"))
+        };
+
+        let last_day_of_month = fields[2].eq_ignore_ascii_case("L");
+        let days_of_month = if last_day_of_month { vec![] } else { parse(fields[2], 1, 31)? };
+        // Sunday is both 0 and 7
+        let mut days_of_week: Vec<u32> = parse(fields[4], 0, 7)?.into_iter().map(|day| day % 7).collect();
+        days_of_week.sort_unstable();
+        days_of_week.dedup();
+
+        Ok(Self {
+            minutes: parse(fields[0], 0, 59)?,
+            hours: parse(fields[1], 0, 23)?,
+            days_of_month,
+            last_day_of_month,
+            months: parse(fields[3], 1, 12)?,
+            days_of_week,
+            day_of_month_restricted: !is_cron_wildcard(fields[2]),
+            day_of_week_restricted: !is_cron_wildcard(fields[4]),
+        })
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if !self.months.contains(&date.month()) {
+            return false;
+        }
+        let day_of_month_matches =
+            self.days_of_month.contains(&date.day()) || (self.last_day_of_month && date.day() == last_day_of_month(date));
+        let day_of_week_matches = self.days_of_week.contains(&date.weekday().num_days_from_sunday());
+        // Like cron, a day matching either field matches when both are restricted
+        match (self.day_of_month_restricted, self.day_of_week_restricted) {
+            (true, true) => day_of_month_matches || day_of_week_matches,
+            (true, false) => day_of_month_matches,
+            (false, true) => day_of_week_matches,
+            (false, false) => true,
+        }
+    }
+
+    /// First matching minute after `after`
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start_date = after.date_naive();
+        (0..=MAX_SEARCH_DAYS)
+            .filter_map(|days| start_date.checked_add_signed(Duration::days(days)))
+            .filter(|date| self.matches_date(*date))
+            .find_map(|date| {
+                self.hours.iter().find_map(|hour| {
+                    self.minutes.iter().find_map(|minute| {
+                        let candidate = date.and_hms_opt(*hour, *minute, 0)?.and_utc();
+                        (candidate > after).then_some(candidate)
+                    })
+                })
+            })
+    }
+}
+
+fn is_cron_wildcard(field: &str) -> bool {
+    field.starts_with('*') || field == "?"
+}
+
+fn parse_cron_field(field: &str, min: u32, max: u32) -> Result<Vec<u32>, String> {
+    let parse_number = |value: &str| value.parse::<u32>().map_err(|_| format!("invalid value {value} in field {field}"));
+
+    let mut values = vec![];
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, Some(parse_number(step)?)),
+            None => (part, None),
+        };
+        if step == Some(0) {
+            return Err(format!("step of field {field} must be positive"));
+        }
+        let (start, end) = if range == "*" || range == "?" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (parse_number(start)?, parse_number(end)?)
+        } else {
+            // `5/15` runs from 5 to the end of the range
+            let start = parse_number(range)?;
+            (start, if step.is_some() { max } else { start })
+        };
+        if start < min || end > max || start > end {
+            return Err(format!("field {field} is out of range {min}-{max}"));
+        }
+        values.extend((start..=end).step_by(step.unwrap_or(1) as usize));
+    }
+    values.sort_unstable();
+    values.dedup();
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_cron_every_monday_and_last_day() {
+        // 2024-01-01 is a Monday
+        let schedule = TimerSchedule::Cron(CronSchedule::parse("30 9 * * 1").unwrap());
+        assert_eq!(schedule.first_fire_time(utc(2024, 1, 1, 9, 30)), Some(utc(2024, 1, 1, 9, 30)));
+        assert_eq!(
+            schedule.next_fire_time(utc(2024, 1, 1, 9, 30), utc(2024, 1, 2, 0, 0)),
+            Some(utc(2024, 1, 8, 9, 30))
+        );
+
+        let last_day = CronSchedule::parse("0 0 0 L * *").unwrap();
+        assert_eq!(last_day.next_after(utc(2024, 2, 1, 0, 0)), Some(utc(2024, 2, 29, 0, 0)));
+        assert!(CronSchedule::parse("61 * * * *").is_err());
+    }
+
+    #[test]
+    fn test_monthly_fallback() {
+        let time = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+        let last_day = TimerSchedule::Monthly {
+            day_of_month: DayOfMonth::Number(31),
+            fallback: MonthlyFallbackStrategy::LastDay,
+            time,
+        };
+        assert_eq!(last_day.first_fire_time(utc(2024, 2, 1, 0, 0)), Some(utc(2024, 2, 29, 0, 0)));
+
+        let skip = TimerSchedule::Monthly {
+            day_of_month: DayOfMonth::Number(31),
+            fallback: MonthlyFallbackStrategy::Skip,
+            time,
+        };
+        assert_eq!(skip.first_fire_time(utc(2024, 2, 1, 0, 0)), Some(utc(2024, 3, 31, 0, 0)));
+
+        // A daily kline past two occurrences of an hourly timer fires once, the grid is kept
+        let interval = TimerSchedule::Interval(Duration::hours(1));
+        assert_eq!(
+            interval.next_fire_time(utc(2024, 1, 1, 1, 0), utc(2024, 1, 1, 2, 30)),
+            Some(utc(2024, 1, 1, 3, 0))
+        );
+    }
+}
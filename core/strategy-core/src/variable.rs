@@ -1,5 +1,6 @@
 pub mod custom_variable;
 pub mod sys_varibale;
+pub mod tagged_variable;
 pub mod variable_operation;
 
 use serde::Serialize;
@@ -99,6 +99,15 @@ impl VariableValue {
     pub fn is_null(&self) -> bool {
         matches!(self, VariableValue::Null)
     }
+
+    /// Positive whole number as a bar count, e.g. the N of `rising for N bars`
+    pub fn as_bar_count(&self) -> Option<usize> {
+        use rust_decimal::prelude::ToPrimitive;
+        match self {
+            VariableValue::Number(number) if number.is_integer() && number.is_sign_positive() && !number.is_zero() => number.to_usize(),
+            _ => None,
+        }
+    }
 }
 
 // PartialEq implementation for VariableValue
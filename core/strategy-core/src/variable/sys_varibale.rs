@@ -37,6 +37,22 @@ pub enum SysVariableType {
     UnrealizedPnl, // Unrealized profit and loss for specified symbol
 
     CurrentRoi, // Current return on investment
+
+    Equity, // Account equity
+
+    AvailableBalance, // Available balance
+
+    MarginRatio, // Used margin / equity
+
+    CurrentDrawdown, // Drawdown of equity from its peak
+
+    PositionEntryPrice, // Entry price of the position for specified symbol
+
+    PositionSide, // Side of the position for specified symbol
+
+    PositionHoldingBars, // Bars the position for specified symbol has been held
+
+    BarsSinceLastTrade, // Bars since the last trade for specified symbol
 }
 
 #[derive(Debug, Clone, Deserialize, ToSchema)]
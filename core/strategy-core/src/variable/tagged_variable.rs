@@ -0,0 +1,213 @@
+//! Lossless serde form of variables, used by strategy checkpoints
+//!
+//! The api form of [`VariableValue`] is untagged, times come back as strings and percentages as numbers.
+//! Fields holding variables are stored with `#[serde(with = "...")]` and one of the modules below
+
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    custom_variable::{CustomVariable, VariableValue},
+    sys_varibale::{SysVariable, SysVariableType},
+};
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+enum TaggedValue {
+    Number(Decimal),
+    String(String),
+    Boolean(bool),
+    Enum(Vec<String>),
+    Time(DateTime<Utc>),
+    Percentage(Decimal),
+    Null,
+}
+
+impl From<VariableValue> for TaggedValue {
+    fn from(value: VariableValue) -> Self {
+        match value {
+            VariableValue::Number(number) => Self::Number(number),
+            VariableValue::String(string) => Self::String(string),
+            VariableValue::Boolean(boolean) => Self::Boolean(boolean),
+            VariableValue::Enum(options) => Self::Enum(options),
+            VariableValue::Time(time) => Self::Time(time),
+            VariableValue::Percentage(percentage) => Self::Percentage(percentage),
+            VariableValue::Null => Self::Null,
+        }
+    }
+}
+
+impl From<TaggedValue> for VariableValue {
+    fn from(value: TaggedValue) -> Self {
+        match value {
+            TaggedValue::Number(number) => Self::Number(number),
+            TaggedValue::String(string) => Self::String(string),
+            TaggedValue::Boolean(boolean) => Self::Boolean(boolean),
+            TaggedValue::Enum(options) => Self::Enum(options),
+            TaggedValue::Time(time) => Self::Time(time),
+            TaggedValue::Percentage(percentage) => Self::Percentage(percentage),
+            TaggedValue::Null => Self::Null,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TaggedCustomVariable {
+    var_name: String,
+    var_display_name: String,
+    initial_value: TaggedValue,
+    previous_value: TaggedValue,
+    var_value: TaggedValue,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TaggedSysVariable {
+    var_name: SysVariableType,
+    var_display_name: String,
+    symbol: Option<String>,
+    var_value: TaggedValue,
+}
+
+/// `HashMap<String, CustomVariable>`, keyed by variable name
+pub mod custom_variables {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(variables: &HashMap<String, CustomVariable>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(variables.values().map(|variable| TaggedCustomVariable {
+            var_name: variable.var_name.clone(),
+            var_display_name: variable.var_display_name.clone(),
+            initial_value: variable.initial_value.clone().into(),
+            previous_value: variable.previous_value.clone().into(),
+            var_value: variable.var_value.clone().into(),
+        }))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HashMap<String, CustomVariable>, D::Error> {
+        let variables = Vec::<TaggedCustomVariable>::deserialize(deserializer)?;
+        Ok(variables
+            .into_iter()
+            .map(|variable| {
+                let custom_variable = CustomVariable {
+                    var_name: variable.var_name.clone(),
+                    var_display_name: variable.var_display_name,
+                    initial_value: variable.initial_value.into(),
+                    previous_value: variable.previous_value.into(),
+                    var_value: variable.var_value.into(),
+                };
+                (variable.var_name, custom_variable)
+            })
+            .collect())
+    }
+}
+
+/// `HashMap<SysVariableType, SysVariable>`, keyed by variable name
+pub mod sys_variables {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(variables: &HashMap<SysVariableType, SysVariable>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(variables.values().map(|variable| TaggedSysVariable {
+            var_name: variable.var_name.clone(),
+            var_display_name: variable.var_display_name.clone(),
+            symbol: variable.symbol.clone(),
+            var_value: variable.var_value.clone().into(),
+        }))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HashMap<SysVariableType, SysVariable>, D::Error> {
+        let variables = Vec::<TaggedSysVariable>::deserialize(deserializer)?;
+        Ok(variables
+            .into_iter()
+            .map(|variable| {
+                let sys_variable = SysVariable::new(
+                    variable.var_name.clone(),
+                    variable.var_display_name,
+                    variable.symbol,
+                    variable.var_value.into(),
+                );
+                (variable.var_name, sys_variable)
+            })
+            .collect())
+    }
+}
+
+/// `HashMap<K, VariableValue>` with any serializable key, stored as `[key, value]` entries
+pub mod value_entries {
+    use super::*;
+
+    pub fn serialize<K, S>(values: &HashMap<K, VariableValue>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(values.iter().map(|(key, value)| (key, TaggedValue::from(value.clone()))))
+    }
+
+    pub fn deserialize<'de, K, D>(deserializer: D) -> Result<HashMap<K, VariableValue>, D::Error>
+    where
+        K: Deserialize<'de> + Eq + Hash,
+        D: Deserializer<'de>,
+    {
+        let entries = Vec::<(K, TaggedValue)>::deserialize(deserializer)?;
+        Ok(entries.into_iter().map(|(key, value)| (key, value.into())).collect())
+    }
+}
+
+/// `VecDeque<VariableValue>`, e.g. the recent values of a condition variable
+pub mod value_list {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(values: &VecDeque<VariableValue>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(values.iter().map(|value| TaggedValue::from(value.clone())))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<VecDeque<VariableValue>, D::Error> {
+        let values = Vec::<TaggedValue>::deserialize(deserializer)?;
+        Ok(values.into_iter().map(VariableValue::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Variables {
+        #[serde(with = "custom_variables")]
+        custom_variables: HashMap<String, CustomVariable>,
+        #[serde(with = "value_entries")]
+        values: HashMap<(String, i32), VariableValue>,
+    }
+
+    #[test]
+    fn test_value_types_survive_roundtrip() {
+        let time = DateTime::parse_from_rfc3339("2025-10-19T12:02:00Z").unwrap().with_timezone(&Utc);
+        let variable = CustomVariable {
+            var_name: "last_entry".to_string(),
+            var_display_name: "Last entry".to_string(),
+            initial_value: VariableValue::Null,
+            previous_value: VariableValue::Null,
+            var_value: VariableValue::Time(time),
+        };
+        let variables = Variables {
+            custom_variables: HashMap::from([(variable.var_name.clone(), variable)]),
+            values: HashMap::from([(("variable_node".to_string(), 1), VariableValue::percentage(0.5))]),
+        };
+
+        let json = serde_json::to_string(&variables).unwrap();
+        let restored = serde_json::from_str::<Variables>(&json).unwrap();
+        assert_eq!(restored.custom_variables["last_entry"].var_value, VariableValue::Time(time));
+        assert!(restored.custom_variables["last_entry"].initial_value.is_null());
+        assert_eq!(
+            restored.values[&("variable_node".to_string(), 1)],
+            VariableValue::Percentage(Decimal::new(5, 1))
+        );
+    }
+}
@@ -62,6 +62,7 @@ fn build_ta_lib_windows() {
     println!("=== Build Script Ending ===");
 }
 
+#[cfg(target_os = "macos")]
 fn build_ta_lib_macos() {
     println!("=== Build Script Starting (macOS) ===");
 
@@ -132,7 +133,41 @@ fn build_ta_lib_macos() {
     println!("=== Build Script Ending (macOS) ===");
 }
 
+#[cfg(target_os = "linux")]
+fn build_ta_lib_linux() {
+    println!("=== Build Script Starting (Linux) ===");
+
+    // talib_c only ships the Windows/macOS prebuilt libraries, not the TA-Lib C sources,
+    // so Linux links a TA-Lib 0.6.4 installation (the official release .deb or tarball build)
+    // Bindings are shipped in src/bindings_linux.rs, only the C library is needed here
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=TA_LIB_DIR");
+
+    let lib_dirs = match env::var("TA_LIB_DIR") {
+        Ok(ta_lib_dir) => vec![PathBuf::from(ta_lib_dir).join("lib")],
+        Err(_) => vec![PathBuf::from("/usr/local/lib"), PathBuf::from("/usr/lib")],
+    };
+    for lib_dir in lib_dirs.iter() {
+        println!("cargo:rustc-link-search=native={}", lib_dir.display());
+    }
+
+    // Prefer the static archive so the binary does not depend on libta-lib.so at runtime
+    let has_static_lib = lib_dirs.iter().any(|lib_dir| lib_dir.join("libta-lib.a").exists());
+    if has_static_lib {
+        println!("cargo:rustc-link-lib=static=ta-lib");
+    } else {
+        println!("cargo:rustc-link-lib=dylib=ta-lib");
+    }
+
+    println!("=== Build Script Ending (Linux) ===");
+}
+
 fn main() {
+    // The pure-Rust backend needs neither the C library nor the generated bindings
+    if env::var_os("CARGO_FEATURE_PURE_RUST").is_some() {
+        return;
+    }
+
     // Build TA-Lib on Windows
     #[cfg(target_os = "windows")]
     {
@@ -145,8 +180,14 @@ fn main() {
         build_ta_lib_macos();
     }
 
+    // Build TA-Lib on Linux
+    #[cfg(target_os = "linux")]
+    {
+        build_ta_lib_linux();
+    }
+
     // Skip build on other platforms
-    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
     {
         println!("cargo:warning=Skipping TA-Lib build on unsupported platform");
     }
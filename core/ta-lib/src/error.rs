@@ -64,10 +64,13 @@ pub enum TaLibError {
     TalibErrorCode {
         #[cfg(target_os = "windows")]
         ret_code: i32,
-        #[cfg(target_os = "macos")]
+        #[cfg(not(target_os = "windows"))]
         ret_code: u32,
         backtrace: Backtrace,
     },
+
+    #[snafu(display("{indicator_name} parameter out of range"))]
+    BadParam { indicator_name: String, backtrace: Backtrace },
 }
 
 // Implement the StarRiverErrorTrait for IndicatorError
@@ -91,6 +94,7 @@ impl TaLibError {
             TaLibError::DataLessThenLookback { .. } => 1010,
             TaLibError::DataLengthNotEqual { .. } => 1011,
             TaLibError::TalibErrorCode { .. } => 1012,
+            TaLibError::BadParam { .. } => 1013,
         };
         format!("{}_{:04}", prefix, code)
     }
@@ -108,6 +112,7 @@ impl TaLibError {
             TaLibError::ParseSpecialParamFailed { .. } => StatusCode::BAD_REQUEST,
             TaLibError::DataLessThenLookback { .. } => StatusCode::BAD_REQUEST,
             TaLibError::DataLengthNotEqual { .. } => StatusCode::BAD_REQUEST,
+            TaLibError::BadParam { .. } => StatusCode::BAD_REQUEST,
 
             // Server errors - INTERNAL_SERVER_ERROR (500)
             TaLibError::CreateIndicatorFailed { .. } => StatusCode::INTERNAL_SERVER_ERROR,
@@ -165,6 +170,9 @@ impl TaLibError {
                 TaLibError::TalibErrorCode { ret_code, .. } => {
                     format!("TA-Lib 错误代码: {}", ret_code)
                 }
+                TaLibError::BadParam { indicator_name, .. } => {
+                    format!("{} 的参数超出范围", indicator_name)
+                }
             },
             _ => "".to_string(),
         }
@@ -183,7 +191,8 @@ impl TaLibError {
             | TaLibError::ParseSpecialParamFailed { .. }
             | TaLibError::DataLessThenLookback { .. }
             | TaLibError::DataLengthNotEqual { .. }
-            | TaLibError::TalibErrorCode { .. } => vec![self.error_code()],
+            | TaLibError::TalibErrorCode { .. }
+            | TaLibError::BadParam { .. } => vec![self.error_code()],
         }
     }
 }
@@ -25,7 +25,7 @@ use crate::{impl_indicator, impl_indicator_config};
 
 #[cfg(target_os = "windows")]
 pub type MaTypeInt = i32;
-#[cfg(target_os = "macos")]
+#[cfg(not(target_os = "windows"))]
 pub type MaTypeInt = u32;
 
 // Price source
@@ -92,7 +92,7 @@ impl Into<MaTypeInt> for MAType {
         }
     }
 
-    #[cfg(target_os = "macos")]
+    #[cfg(not(target_os = "windows"))]
     fn into(self) -> u32 {
         match self {
             MAType::SMA => 0,
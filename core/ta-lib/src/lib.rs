@@ -2,6 +2,7 @@ pub mod error;
 pub mod indicator;
 mod macros;
 mod talib;
+#[cfg(not(feature = "pure-rust"))]
 mod talib_bindings;
 mod utils;
 
@@ -1,6 +1,10 @@
 pub mod fn_define;
+#[cfg(not(feature = "pure-rust"))]
 pub mod lookback;
+#[cfg(any(feature = "pure-rust", test))]
+mod native;
 pub mod talib_macros;
+#[cfg(not(feature = "pure-rust"))]
 use crate::talib_bindings::*;
 
 #[derive(Clone)]
@@ -8,6 +12,7 @@ pub struct TALib;
 
 impl TALib {
     pub fn init() -> Result<Self, String> {
+        #[cfg(not(feature = "pure-rust"))]
         unsafe {
             let ret = TA_Initialize();
             if ret != TA_RetCode_TA_SUCCESS {
@@ -19,6 +24,7 @@ impl TALib {
     }
 
     pub fn shutdown() {
+        #[cfg(not(feature = "pure-rust"))]
         unsafe {
             TA_Shutdown();
         }
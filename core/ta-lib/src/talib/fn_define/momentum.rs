@@ -2,7 +2,9 @@ use chrono::{DateTime, Utc};
 
 // use crate::indicator_engine::talib_error::TalibError;
 use super::MaTypeInt;
-use crate::{Indicator, indicator::momentum::*, talib::TALib, talib_bindings::*, talib_fn, talib_snake_fn};
+use crate::{Indicator, indicator::momentum::*, talib::TALib, talib_fn, talib_snake_fn};
+#[cfg(not(feature = "pure-rust"))]
+use crate::talib_bindings::*;
 
 impl TALib {
     // ADX - Average Directional Movement Index
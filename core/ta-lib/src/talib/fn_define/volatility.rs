@@ -2,7 +2,9 @@ use chrono::{DateTime, Utc};
 
 // use crate::indicator_engine::talib_error::TalibError;
 use crate::{Indicator, indicator::volatility::*, talib_fn};
-use crate::{talib::TALib, talib_bindings::*};
+use crate::talib::TALib;
+#[cfg(not(feature = "pure-rust"))]
+use crate::talib_bindings::*;
 
 impl TALib {
     // ATR                  Average True Range
@@ -15,7 +15,7 @@ impl TALib {
                         bbands_config.ma_type.clone() as i32,
                     );
 
-                    #[cfg(target_os = "macos")]
+                    #[cfg(any(target_os = "macos", target_os = "linux"))]
                     let lookback = TA_BBANDS_Lookback(
                         bbands_config.time_period,
                         bbands_config.dev_up.into(),
@@ -44,7 +44,7 @@ impl TALib {
                 IndicatorConfig::MA(ma_config) => {
                     #[cfg(target_os = "windows")]
                     let lookback = TA_MA_Lookback(ma_config.time_period, ma_config.ma_type.clone() as i32);
-                    #[cfg(target_os = "macos")]
+                    #[cfg(any(target_os = "macos", target_os = "linux"))]
                     let lookback = TA_MA_Lookback(ma_config.time_period, ma_config.ma_type.clone() as u32);
                     return lookback as usize;
                 }
@@ -113,7 +113,7 @@ impl TALib {
                 IndicatorConfig::APO(apo_config) => {
                     #[cfg(target_os = "windows")]
                     let lookback = TA_APO_Lookback(apo_config.fast_period, apo_config.slow_period, apo_config.ma_type.clone() as i32);
-                    #[cfg(target_os = "macos")]
+                    #[cfg(any(target_os = "macos", target_os = "linux"))]
                     let lookback = TA_APO_Lookback(apo_config.fast_period, apo_config.slow_period, apo_config.ma_type.clone() as u32);
                     return lookback as usize;
                 }
@@ -155,7 +155,7 @@ impl TALib {
                         macdext_config.signal_period,
                         macdext_config.signal_ma_type.clone() as i32,
                     );
-                    #[cfg(target_os = "macos")]
+                    #[cfg(any(target_os = "macos", target_os = "linux"))]
                     let lookback = TA_MACDEXT_Lookback(
                         macdext_config.fast_period,
                         macdext_config.fast_ma_type.clone() as u32,
@@ -197,7 +197,7 @@ impl TALib {
                 IndicatorConfig::PPO(ppo_config) => {
                     #[cfg(target_os = "windows")]
                     let lookback = TA_PPO_Lookback(ppo_config.fast_period, ppo_config.slow_period, ppo_config.ma_type.clone() as i32);
-                    #[cfg(target_os = "macos")]
+                    #[cfg(any(target_os = "macos", target_os = "linux"))]
                     let lookback = TA_PPO_Lookback(ppo_config.fast_period, ppo_config.slow_period, ppo_config.ma_type.clone() as u32);
                     return lookback as usize;
                 }
@@ -230,7 +230,7 @@ impl TALib {
                         stoch_config.slow_d_period,
                         stoch_config.slow_d_ma_type.clone() as i32,
                     );
-                    #[cfg(target_os = "macos")]
+                    #[cfg(any(target_os = "macos", target_os = "linux"))]
                     let lookback = TA_STOCH_Lookback(
                         stoch_config.fast_k_period,
                         stoch_config.slow_k_period,
@@ -247,7 +247,7 @@ impl TALib {
                         stochf_config.fast_d_period,
                         stochf_config.fast_d_ma_type.clone() as i32,
                     );
-                    #[cfg(target_os = "macos")]
+                    #[cfg(any(target_os = "macos", target_os = "linux"))]
                     let lookback = TA_STOCHF_Lookback(
                         stochf_config.fast_k_period,
                         stochf_config.fast_d_period,
@@ -263,7 +263,7 @@ impl TALib {
                         stochrsi_config.fast_d_period,
                         stochrsi_config.fast_d_ma_type.clone() as i32,
                     );
-                    #[cfg(target_os = "macos")]
+                    #[cfg(any(target_os = "macos", target_os = "linux"))]
                     let lookback = TA_STOCHRSI_Lookback(
                         stochrsi_config.time_period,
                         stochrsi_config.fast_k_period,
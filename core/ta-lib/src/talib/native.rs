@@ -0,0 +1,87 @@
+//! Pure-Rust port of the TA-Lib functions declared in `fn_define`.
+//!
+//! Each function mirrors its C counterpart: it receives `start_idx`/`end_idx`, returns the index of the
+//! first calculated bar together with the compacted outputs, and the `*_lookback` functions return `-1`
+//! when a parameter is out of range, exactly like `TA_*_Lookback`.
+#![cfg_attr(not(feature = "pure-rust"), allow(dead_code))]
+
+#[cfg(all(test, not(feature = "pure-rust")))]
+#[macro_use]
+mod test_data;
+
+mod cycle;
+mod hilbert;
+#[cfg(feature = "pure-rust")]
+mod lookback;
+mod momentum;
+mod overlap;
+mod pattern_recognition;
+mod price_transform;
+mod volatility;
+mod volume;
+
+#[cfg(feature = "pure-rust")]
+pub(crate) use cycle::*;
+#[cfg(feature = "pure-rust")]
+pub(crate) use momentum::*;
+#[cfg(feature = "pure-rust")]
+pub(crate) use overlap::*;
+#[cfg(feature = "pure-rust")]
+pub(crate) use pattern_recognition::*;
+#[cfg(feature = "pure-rust")]
+pub(crate) use price_transform::*;
+#[cfg(feature = "pure-rust")]
+pub(crate) use volatility::*;
+#[cfg(feature = "pure-rust")]
+pub(crate) use volume::*;
+
+use crate::error::{BadParamSnafu, TaLibError};
+use crate::indicator::MaTypeInt;
+
+const MAX_PERIOD: i32 = 100_000;
+const MAX_REAL: f64 = 3.0e37;
+
+/// Turns the result of a `*_lookback` function into the lookback, `TA_BAD_PARAM` when it is negative.
+fn check_params(lookback: i32, indicator_name: &str) -> Result<usize, TaLibError> {
+    if lookback < 0 {
+        return BadParamSnafu { indicator_name }.fail();
+    }
+    Ok(lookback as usize)
+}
+
+fn check_period(period: i32, min: i32) -> bool {
+    (min..=MAX_PERIOD).contains(&period)
+}
+
+fn check_ma_type(ma_type: MaTypeInt) -> bool {
+    (0..=8).contains(&i64::from(ma_type))
+}
+
+fn check_real(value: f64, min: f64, max: f64) -> bool {
+    (min..=max).contains(&value)
+}
+
+fn is_zero(value: f64) -> bool {
+    -0.00000001 < value && value < 0.00000001
+}
+
+fn is_zero_or_neg(value: f64) -> bool {
+    value < 0.00000001
+}
+
+fn per_to_k(period: usize) -> f64 {
+    2.0 / (period + 1) as f64
+}
+
+fn true_range(high: f64, low: f64, prev_close: f64) -> f64 {
+    let mut greatest = high - low;
+    let value = (prev_close - high).abs();
+    if value > greatest {
+        greatest = value;
+    }
+    let value = (prev_close - low).abs();
+    if value > greatest {
+        greatest = value;
+    }
+    greatest
+}
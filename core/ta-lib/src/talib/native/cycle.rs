@@ -0,0 +1,198 @@
+use super::hilbert::{DominantCycle, PriceWma, SmoothPrice, Trendline, rad2deg_by_atan, rad2deg_by_pi};
+use crate::error::TaLibError;
+
+type Output = Result<(usize, Vec<f64>), TaLibError>;
+
+pub(crate) fn ht_dcperiod_lookback() -> i32 {
+    32
+}
+
+pub(crate) fn ht_dcperiod(start_idx: usize, end_idx: usize, data: &[f64]) -> Output {
+    let lookback = ht_dcperiod_lookback() as usize;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let (mut price_wma, mut today) = PriceWma::warm_up(data, start_idx - lookback, 9);
+    let mut cycle = DominantCycle::new(rad2deg_by_pi());
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    while today <= end_idx {
+        let smoothed = price_wma.next(data, data[today]);
+        cycle.next(today, smoothed);
+        if today >= start_idx {
+            out.push(cycle.smooth_period);
+        }
+        today += 1;
+    }
+    Ok((start_idx, out))
+}
+
+pub(crate) fn ht_dcphase_lookback() -> i32 {
+    63
+}
+
+pub(crate) fn ht_dcphase(start_idx: usize, end_idx: usize, data: &[f64]) -> Output {
+    let lookback = ht_dcphase_lookback() as usize;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let rad2deg = rad2deg_by_atan();
+    let (mut price_wma, mut today) = PriceWma::warm_up(data, start_idx - lookback, 34);
+    let mut cycle = DominantCycle::new(rad2deg);
+    let mut smooth_price = SmoothPrice::new();
+    let mut dc_phase = 0.0;
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    while today <= end_idx {
+        let smoothed = price_wma.next(data, data[today]);
+        smooth_price.set(smoothed);
+        cycle.next(today, smoothed);
+        dc_phase = smooth_price.dc_phase(cycle.smooth_period, dc_phase, rad2deg);
+        if today >= start_idx {
+            out.push(dc_phase);
+        }
+        smooth_price.advance();
+        today += 1;
+    }
+    Ok((start_idx, out))
+}
+
+pub(crate) fn ht_phasor_lookback() -> i32 {
+    32
+}
+
+pub(crate) fn ht_phasor(start_idx: usize, end_idx: usize, data: &[f64]) -> Result<(usize, Vec<f64>, Vec<f64>), TaLibError> {
+    let lookback = ht_phasor_lookback() as usize;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new(), Vec::new()));
+    }
+
+    let (mut price_wma, mut today) = PriceWma::warm_up(data, start_idx - lookback, 9);
+    let mut cycle = DominantCycle::new(rad2deg_by_pi());
+    let mut in_phase = Vec::with_capacity(end_idx - start_idx + 1);
+    let mut quadrature = Vec::with_capacity(end_idx - start_idx + 1);
+    while today <= end_idx {
+        let smoothed = price_wma.next(data, data[today]);
+        let (q1, i1) = cycle.next(today, smoothed);
+        if today >= start_idx {
+            in_phase.push(i1);
+            quadrature.push(q1);
+        }
+        today += 1;
+    }
+    Ok((start_idx, in_phase, quadrature))
+}
+
+pub(crate) fn ht_sine_lookback() -> i32 {
+    63
+}
+
+pub(crate) fn ht_sine(start_idx: usize, end_idx: usize, data: &[f64]) -> Result<(usize, Vec<f64>, Vec<f64>), TaLibError> {
+    let lookback = ht_sine_lookback() as usize;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new(), Vec::new()));
+    }
+
+    let rad2deg = rad2deg_by_atan();
+    let deg2rad = 1.0 / rad2deg;
+    let (mut price_wma, mut today) = PriceWma::warm_up(data, start_idx - lookback, 34);
+    let mut cycle = DominantCycle::new(rad2deg);
+    let mut smooth_price = SmoothPrice::new();
+    let mut dc_phase = 0.0;
+    let mut sine = Vec::with_capacity(end_idx - start_idx + 1);
+    let mut lead_sine = Vec::with_capacity(end_idx - start_idx + 1);
+    while today <= end_idx {
+        let smoothed = price_wma.next(data, data[today]);
+        smooth_price.set(smoothed);
+        cycle.next(today, smoothed);
+        dc_phase = smooth_price.dc_phase(cycle.smooth_period, dc_phase, rad2deg);
+        if today >= start_idx {
+            sine.push((dc_phase * deg2rad).sin());
+            lead_sine.push(((dc_phase + 45.0) * deg2rad).sin());
+        }
+        smooth_price.advance();
+        today += 1;
+    }
+    Ok((start_idx, sine, lead_sine))
+}
+
+pub(crate) fn ht_trendmode_lookback() -> i32 {
+    63
+}
+
+pub(crate) fn ht_trendmode(start_idx: usize, end_idx: usize, data: &[f64]) -> Result<(usize, Vec<i32>), TaLibError> {
+    let lookback = ht_trendmode_lookback() as usize;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let rad2deg = rad2deg_by_atan();
+    let deg2rad = 1.0 / rad2deg;
+    let (mut price_wma, mut today) = PriceWma::warm_up(data, start_idx - lookback, 34);
+    let mut cycle = DominantCycle::new(rad2deg);
+    let mut smooth_price = SmoothPrice::new();
+    let mut trendline = Trendline::new();
+    let mut days_in_trend = 0;
+    let (mut dc_phase, mut sine, mut lead_sine) = (0.0, 0.0, 0.0);
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    while today <= end_idx {
+        let smoothed = price_wma.next(data, data[today]);
+        smooth_price.set(smoothed);
+        cycle.next(today, smoothed);
+        let smooth_period = cycle.smooth_period;
+
+        let prev_dc_phase = dc_phase;
+        dc_phase = smooth_price.dc_phase(smooth_period, dc_phase, rad2deg);
+        let (prev_sine, prev_lead_sine) = (sine, lead_sine);
+        sine = (dc_phase * deg2rad).sin();
+        lead_sine = ((dc_phase + 45.0) * deg2rad).sin();
+        let trendline = trendline.next(data, today, smooth_period);
+
+        // Trend unless the sine crossed its lead, the trend is younger than half a cycle or the phase advances
+        // at the cycle rate; a price far enough from the trendline always counts as trend.
+        let mut trend = 1;
+        if (sine > lead_sine && prev_sine <= prev_lead_sine) || (sine < lead_sine && prev_sine >= prev_lead_sine) {
+            days_in_trend = 0;
+            trend = 0;
+        }
+        days_in_trend += 1;
+        if (days_in_trend as f64) < 0.5 * smooth_period {
+            trend = 0;
+        }
+        let phase_change = dc_phase - prev_dc_phase;
+        if smooth_period != 0.0 && phase_change > (0.67 * 360.0 / smooth_period) && phase_change < (1.5 * 360.0 / smooth_period) {
+            trend = 0;
+        }
+        if trendline != 0.0 && ((smooth_price.current() - trendline) / trendline).abs() >= 0.015 {
+            trend = 1;
+        }
+
+        if today >= start_idx {
+            out.push(trend);
+        }
+        smooth_price.advance();
+        today += 1;
+    }
+    Ok((start_idx, out))
+}
+
+#[cfg(all(test, not(feature = "pure-rust")))]
+mod tests {
+    use super::super::test_data::ohlcv;
+    use super::*;
+
+    #[test]
+    fn test_hilbert_transform_matches_talib() {
+        let data = ohlcv(500);
+        assert_parity!(HT_DCPERIOD => ht_dcperiod, [data.close], [], [ht_dcperiod: f64]);
+        assert_parity!(HT_DCPHASE => ht_dcphase, [data.close], [], [ht_dcphase: f64]);
+        assert_parity!(HT_PHASOR => ht_phasor, [data.close], [], [in_phase: f64, quadrature: f64]);
+        assert_parity!(HT_SINE => ht_sine, [data.close], [], [sine: f64, lead_sine: f64]);
+        assert_parity!(HT_TRENDMODE => ht_trendmode, [data.close], [], [ht_trendmode: i32]);
+    }
+}
@@ -0,0 +1,289 @@
+//! Building blocks shared by MAMA and the `HT_*` functions.
+//!
+//! The C sources expand the same macros (`DO_PRICE_WMA`, `DO_HILBERT_*`) in every function, the state they
+//! touch is grouped here so each port only keeps its own output logic.
+
+const A: f64 = 0.0962;
+const B: f64 = 0.5769;
+const SMOOTH_PRICE_SIZE: usize = 50;
+
+/// `rad2Deg` as computed by MAMA, HT_DCPERIOD and HT_PHASOR.
+pub(super) fn rad2deg_by_pi() -> f64 {
+    180.0 / (4.0 * 1.0_f64.atan())
+}
+
+/// `rad2Deg` as computed by HT_DCPHASE, HT_SINE, HT_TRENDLINE and HT_TRENDMODE.
+pub(super) fn rad2deg_by_atan() -> f64 {
+    45.0 / 1.0_f64.atan()
+}
+
+/// 4-3-2-1 weighted moving average of the price (`DO_PRICE_WMA`).
+pub(super) struct PriceWma {
+    sub: f64,
+    sum: f64,
+    trailing_value: f64,
+    trailing_idx: usize,
+}
+
+impl PriceWma {
+    /// Seeds the average with the bars from `start` and runs `warm_up` iterations, returns it together with the
+    /// index of the first bar still to be processed.
+    pub(super) fn warm_up(input: &[f64], start: usize, warm_up: usize) -> (Self, usize) {
+        let mut wma = Self {
+            sub: input[start],
+            sum: input[start],
+            trailing_value: 0.0,
+            trailing_idx: start,
+        };
+        wma.sub += input[start + 1];
+        wma.sum += input[start + 1] * 2.0;
+        wma.sub += input[start + 2];
+        wma.sum += input[start + 2] * 3.0;
+
+        let mut today = start + 3;
+        for _ in 0..warm_up {
+            wma.next(input, input[today]);
+            today += 1;
+        }
+        (wma, today)
+    }
+
+    pub(super) fn next(&mut self, input: &[f64], price: f64) -> f64 {
+        self.sub += price;
+        self.sub -= self.trailing_value;
+        self.sum += price * 4.0;
+        self.trailing_value = input[self.trailing_idx];
+        self.trailing_idx += 1;
+        let smoothed = self.sum * 0.1;
+        self.sum -= self.sub;
+        smoothed
+    }
+}
+
+/// Odd/even state of one Hilbert transform (`HILBERT_VARIABLES`).
+#[derive(Default)]
+struct HilbertTransform {
+    odd: [f64; 3],
+    even: [f64; 3],
+    prev_odd: f64,
+    prev_even: f64,
+    prev_input_odd: f64,
+    prev_input_even: f64,
+}
+
+impl HilbertTransform {
+    fn apply(&mut self, input: f64, even: bool, idx: usize, adjusted_prev_period: f64) -> f64 {
+        let (values, prev, prev_input) = if even {
+            (&mut self.even, &mut self.prev_even, &mut self.prev_input_even)
+        } else {
+            (&mut self.odd, &mut self.prev_odd, &mut self.prev_input_odd)
+        };
+        let temp = A * input;
+        let mut value = -values[idx];
+        values[idx] = temp;
+        value += temp;
+        value -= *prev;
+        *prev = B * *prev_input;
+        value += *prev;
+        *prev_input = input;
+        value * adjusted_prev_period
+    }
+}
+
+/// Homodyne discriminator measuring the dominant cycle period.
+pub(super) struct DominantCycle {
+    detrender: HilbertTransform,
+    q1: HilbertTransform,
+    j_i: HilbertTransform,
+    j_q: HilbertTransform,
+    hilbert_idx: usize,
+    prev_q2: f64,
+    prev_i2: f64,
+    re: f64,
+    im: f64,
+    i1_for_odd_prev2: f64,
+    i1_for_odd_prev3: f64,
+    i1_for_even_prev2: f64,
+    i1_for_even_prev3: f64,
+    rad2deg: f64,
+    pub period: f64,
+    pub smooth_period: f64,
+}
+
+impl DominantCycle {
+    pub(super) fn new(rad2deg: f64) -> Self {
+        Self {
+            detrender: HilbertTransform::default(),
+            q1: HilbertTransform::default(),
+            j_i: HilbertTransform::default(),
+            j_q: HilbertTransform::default(),
+            hilbert_idx: 0,
+            prev_q2: 0.0,
+            prev_i2: 0.0,
+            re: 0.0,
+            im: 0.0,
+            i1_for_odd_prev2: 0.0,
+            i1_for_odd_prev3: 0.0,
+            i1_for_even_prev2: 0.0,
+            i1_for_even_prev3: 0.0,
+            rad2deg,
+            period: 0.0,
+            smooth_period: 0.0,
+        }
+    }
+
+    /// Processes the smoothed price of bar `today` and returns its quadrature (Q1) and in-phase (I1)
+    /// components. The period is updated for the next bar.
+    pub(super) fn next(&mut self, today: usize, smoothed: f64) -> (f64, f64) {
+        let adjusted_prev_period = (0.075 * self.period) + 0.54;
+        let idx = self.hilbert_idx;
+        let (q1, i1, q2, i2);
+        if today.is_multiple_of(2) {
+            let detrender = self.detrender.apply(smoothed, true, idx, adjusted_prev_period);
+            q1 = self.q1.apply(detrender, true, idx, adjusted_prev_period);
+            i1 = self.i1_for_even_prev3;
+            let j_i = self.j_i.apply(i1, true, idx, adjusted_prev_period);
+            let j_q = self.j_q.apply(q1, true, idx, adjusted_prev_period);
+            self.hilbert_idx += 1;
+            if self.hilbert_idx == 3 {
+                self.hilbert_idx = 0;
+            }
+            q2 = (0.2 * (q1 + j_i)) + (0.8 * self.prev_q2);
+            i2 = (0.2 * (i1 - j_q)) + (0.8 * self.prev_i2);
+            self.i1_for_odd_prev3 = self.i1_for_odd_prev2;
+            self.i1_for_odd_prev2 = detrender;
+        } else {
+            let detrender = self.detrender.apply(smoothed, false, idx, adjusted_prev_period);
+            q1 = self.q1.apply(detrender, false, idx, adjusted_prev_period);
+            i1 = self.i1_for_odd_prev3;
+            let j_i = self.j_i.apply(i1, false, idx, adjusted_prev_period);
+            let j_q = self.j_q.apply(q1, false, idx, adjusted_prev_period);
+            q2 = (0.2 * (q1 + j_i)) + (0.8 * self.prev_q2);
+            i2 = (0.2 * (i1 - j_q)) + (0.8 * self.prev_i2);
+            self.i1_for_even_prev3 = self.i1_for_even_prev2;
+            self.i1_for_even_prev2 = detrender;
+        }
+
+        self.re = (0.2 * ((i2 * self.prev_i2) + (q2 * self.prev_q2))) + (0.8 * self.re);
+        self.im = (0.2 * ((i2 * self.prev_q2) - (q2 * self.prev_i2))) + (0.8 * self.im);
+        self.prev_q2 = q2;
+        self.prev_i2 = i2;
+        let prev_period = self.period;
+        if self.im != 0.0 && self.re != 0.0 {
+            self.period = 360.0 / ((self.im / self.re).atan() * self.rad2deg);
+        }
+        let limit = 1.5 * prev_period;
+        if self.period > limit {
+            self.period = limit;
+        }
+        let limit = 0.67 * prev_period;
+        if self.period < limit {
+            self.period = limit;
+        }
+        self.period = self.period.clamp(6.0, 50.0);
+        self.period = (0.2 * self.period) + (0.8 * prev_period);
+        self.smooth_period = (0.33 * self.period) + (0.67 * self.smooth_period);
+        (q1, i1)
+    }
+}
+
+/// Circular buffer of the smoothed prices used to measure the dominant cycle phase.
+pub(super) struct SmoothPrice {
+    values: [f64; SMOOTH_PRICE_SIZE],
+    idx: usize,
+}
+
+impl SmoothPrice {
+    pub(super) fn new() -> Self {
+        Self {
+            values: [0.0; SMOOTH_PRICE_SIZE],
+            idx: 0,
+        }
+    }
+
+    pub(super) fn set(&mut self, value: f64) {
+        self.values[self.idx] = value;
+    }
+
+    pub(super) fn current(&self) -> f64 {
+        self.values[self.idx]
+    }
+
+    pub(super) fn advance(&mut self) {
+        self.idx += 1;
+        if self.idx == SMOOTH_PRICE_SIZE {
+            self.idx = 0;
+        }
+    }
+
+    /// Updates `dc_phase` from the last `smooth_period` smoothed prices.
+    pub(super) fn dc_phase(&self, smooth_period: f64, dc_phase: f64, rad2deg: f64) -> f64 {
+        let const_deg2rad_by360 = 1.0_f64.atan() * 8.0;
+        let dc_period_int = (smooth_period + 0.5) as i32;
+        let mut real_part = 0.0;
+        let mut imag_part = 0.0;
+        let mut idx = self.idx;
+        for i in 0..dc_period_int {
+            let angle = (i as f64 * const_deg2rad_by360) / dc_period_int as f64;
+            let value = self.values[idx];
+            real_part += angle.sin() * value;
+            imag_part += angle.cos() * value;
+            idx = if idx == 0 { SMOOTH_PRICE_SIZE - 1 } else { idx - 1 };
+        }
+
+        let mut dc_phase = dc_phase;
+        let abs_imag = imag_part.abs();
+        if abs_imag > 0.0 {
+            dc_phase = (real_part / imag_part).atan() * rad2deg;
+        } else if abs_imag <= 0.01 {
+            if real_part < 0.0 {
+                dc_phase -= 90.0;
+            } else if real_part > 0.0 {
+                dc_phase += 90.0;
+            }
+        }
+        dc_phase += 90.0;
+        dc_phase += 360.0 / smooth_period;
+        if imag_part < 0.0 {
+            dc_phase += 180.0;
+        }
+        if dc_phase > 315.0 {
+            dc_phase -= 360.0;
+        }
+        dc_phase
+    }
+}
+
+/// Instantaneous trendline: the price averaged over the dominant cycle, then smoothed with the previous three
+/// averages.
+pub(super) struct Trendline {
+    i_trend1: f64,
+    i_trend2: f64,
+    i_trend3: f64,
+}
+
+impl Trendline {
+    pub(super) fn new() -> Self {
+        Self {
+            i_trend1: 0.0,
+            i_trend2: 0.0,
+            i_trend3: 0.0,
+        }
+    }
+
+    pub(super) fn next(&mut self, input: &[f64], today: usize, smooth_period: f64) -> f64 {
+        let dc_period_int = (smooth_period + 0.5) as i32;
+        let mut average = 0.0;
+        for value in input[..=today].iter().rev().take(dc_period_int.max(0) as usize) {
+            average += value;
+        }
+        if dc_period_int > 0 {
+            average /= dc_period_int as f64;
+        }
+        let trendline = (4.0 * average + 3.0 * self.i_trend1 + 2.0 * self.i_trend2 + self.i_trend3) / 10.0;
+        self.i_trend3 = self.i_trend2;
+        self.i_trend2 = self.i_trend1;
+        self.i_trend1 = average;
+        trendline
+    }
+}
@@ -0,0 +1,200 @@
+use super::*;
+use crate::indicator::IndicatorConfig;
+use crate::talib::TALib;
+
+impl TALib {
+    pub fn lookback(config: &IndicatorConfig) -> usize {
+        match config {
+            // Overlap
+            IndicatorConfig::BBANDS(bbands_config) => bbands_lookback(
+                bbands_config.time_period,
+                bbands_config.dev_up.into(),
+                bbands_config.dev_down.into(),
+                bbands_config.ma_type.clone().into(),
+            ) as usize,
+            IndicatorConfig::DEMA(dema_config) => dema_lookback(dema_config.time_period) as usize,
+            IndicatorConfig::EMA(ema_config) => ema_lookback(ema_config.time_period) as usize,
+            IndicatorConfig::HtTrendline(_) => ht_trendline_lookback() as usize,
+            IndicatorConfig::KAMA(kama_config) => kama_lookback(kama_config.time_period) as usize,
+            IndicatorConfig::MA(ma_config) => ma_lookback(ma_config.time_period, ma_config.ma_type.clone().into()) as usize,
+            IndicatorConfig::MAMA(mama_config) => mama_lookback(mama_config.fast_limit.into(), mama_config.slow_limit.into()) as usize,
+            IndicatorConfig::MIDPOINT(midpoint_config) => midpoint_lookback(midpoint_config.time_period) as usize,
+            IndicatorConfig::MIDPRICE(midprice_config) => midprice_lookback(midprice_config.time_period) as usize,
+            IndicatorConfig::SAR(sar_config) => sar_lookback(sar_config.acceleration.into(), sar_config.maximum.into()) as usize,
+            IndicatorConfig::SAREXT(sarext_config) => sarext_lookback(
+                sarext_config.start_value.into(),
+                sarext_config.offset_on_reverse.into(),
+                sarext_config.acceleration_init_long.into(),
+                sarext_config.acceleration_long.into(),
+                sarext_config.acceleration_max_long.into(),
+                sarext_config.acceleration_init_short.into(),
+                sarext_config.acceleration_short.into(),
+                sarext_config.acceleration_max_short.into(),
+            ) as usize,
+            IndicatorConfig::SMA(sma_config) => sma_lookback(sma_config.time_period) as usize,
+            IndicatorConfig::T3(t3_config) => t3_lookback(t3_config.time_period, t3_config.v_factor.into()) as usize,
+            IndicatorConfig::TEMA(tema_config) => tema_lookback(tema_config.time_period) as usize,
+            IndicatorConfig::TRIMA(trima_config) => trima_lookback(trima_config.time_period) as usize,
+            IndicatorConfig::WMA(wma_config) => wma_lookback(wma_config.time_period) as usize,
+            // Momentum
+            IndicatorConfig::ADX(adx_config) => adx_lookback(adx_config.time_period) as usize,
+            IndicatorConfig::ADXR(adxr_config) => adxr_lookback(adxr_config.time_period) as usize,
+            IndicatorConfig::APO(apo_config) => {
+                apo_lookback(apo_config.fast_period, apo_config.slow_period, apo_config.ma_type.clone().into()) as usize
+            }
+            IndicatorConfig::AROON(aroon_config) => aroon_lookback(aroon_config.time_period) as usize,
+            IndicatorConfig::AROONOSC(aroonosc_config) => aroonosc_lookback(aroonosc_config.time_period) as usize,
+            IndicatorConfig::BOP(_) => bop_lookback() as usize,
+            IndicatorConfig::CCI(cci_config) => cci_lookback(cci_config.time_period) as usize,
+            IndicatorConfig::CMO(cmo_config) => cmo_lookback(cmo_config.time_period) as usize,
+            IndicatorConfig::DX(dx_config) => dx_lookback(dx_config.time_period) as usize,
+            IndicatorConfig::MACD(macd_config) => {
+                macd_lookback(macd_config.fast_period, macd_config.slow_period, macd_config.signal_period) as usize
+            }
+            IndicatorConfig::MACDEXT(macdext_config) => macdext_lookback(
+                macdext_config.fast_period,
+                macdext_config.fast_ma_type.clone().into(),
+                macdext_config.slow_period,
+                macdext_config.slow_ma_type.clone().into(),
+                macdext_config.signal_period,
+                macdext_config.signal_ma_type.clone().into(),
+            ) as usize,
+            IndicatorConfig::MACDFIX(macdfix_config) => macdfix_lookback(macdfix_config.signal_period) as usize,
+            IndicatorConfig::MFI(mfi_config) => mfi_lookback(mfi_config.time_period) as usize,
+            IndicatorConfig::MinusDi(minus_di_config) => minus_di_lookback(minus_di_config.time_period) as usize,
+            IndicatorConfig::MinusDm(minus_dm_config) => minus_dm_lookback(minus_dm_config.time_period) as usize,
+            IndicatorConfig::MOM(mom_config) => mom_lookback(mom_config.time_period) as usize,
+            IndicatorConfig::PlusDi(plus_di_config) => plus_di_lookback(plus_di_config.time_period) as usize,
+            IndicatorConfig::PlusDm(plus_dm_config) => plus_dm_lookback(plus_dm_config.time_period) as usize,
+            IndicatorConfig::PPO(ppo_config) => {
+                ppo_lookback(ppo_config.fast_period, ppo_config.slow_period, ppo_config.ma_type.clone().into()) as usize
+            }
+            IndicatorConfig::ROC(roc_config) => roc_lookback(roc_config.time_period) as usize,
+            IndicatorConfig::ROCP(rocp_config) => rocp_lookback(rocp_config.time_period) as usize,
+            IndicatorConfig::ROCR(rocr_config) => rocr_lookback(rocr_config.time_period) as usize,
+            IndicatorConfig::ROCR100(rocr100_config) => rocr100_lookback(rocr100_config.time_period) as usize,
+            IndicatorConfig::RSI(rsi_config) => rsi_lookback(rsi_config.time_period) as usize,
+            IndicatorConfig::STOCH(stoch_config) => stoch_lookback(
+                stoch_config.fast_k_period,
+                stoch_config.slow_k_period,
+                stoch_config.slow_k_ma_type.clone().into(),
+                stoch_config.slow_d_period,
+                stoch_config.slow_d_ma_type.clone().into(),
+            ) as usize,
+            IndicatorConfig::STOCHF(stochf_config) => stochf_lookback(
+                stochf_config.fast_k_period,
+                stochf_config.fast_d_period,
+                stochf_config.fast_d_ma_type.clone().into(),
+            ) as usize,
+            IndicatorConfig::STOCHRSI(stochrsi_config) => stochrsi_lookback(
+                stochrsi_config.time_period,
+                stochrsi_config.fast_k_period,
+                stochrsi_config.fast_d_period,
+                stochrsi_config.fast_d_ma_type.clone().into(),
+            ) as usize,
+            IndicatorConfig::TRIX(trix_config) => trix_lookback(trix_config.time_period) as usize,
+            IndicatorConfig::ULTOSC(ultosc_config) => {
+                ultosc_lookback(ultosc_config.time_period1, ultosc_config.time_period2, ultosc_config.time_period3) as usize
+            }
+            IndicatorConfig::WILLR(willr_config) => willr_lookback(willr_config.time_period) as usize,
+
+            // Volume
+            IndicatorConfig::AD(_) => ad_lookback() as usize,
+            IndicatorConfig::ADOSC(adosc_config) => adosc_lookback(adosc_config.fast_period, adosc_config.slow_period) as usize,
+            IndicatorConfig::OBV(_) => obv_lookback() as usize,
+            // Cycle
+            IndicatorConfig::HtDcperiod(_) => ht_dcperiod_lookback() as usize,
+            IndicatorConfig::HtDcphase(_) => ht_dcphase_lookback() as usize,
+            IndicatorConfig::HtPhasor(_) => ht_phasor_lookback() as usize,
+            IndicatorConfig::HtSine(_) => ht_sine_lookback() as usize,
+            IndicatorConfig::HtTrendmode(_) => ht_trendmode_lookback() as usize,
+
+            // Price Transform
+            IndicatorConfig::AVGPRICE(_) => avgprice_lookback() as usize,
+            IndicatorConfig::MEDPRICE(_) => medprice_lookback() as usize,
+            IndicatorConfig::TYPPRICE(_) => typprice_lookback() as usize,
+            IndicatorConfig::WCLPRICE(_) => wclprice_lookback() as usize,
+            // Volatility
+            IndicatorConfig::ATR(atr_config) => atr_lookback(atr_config.time_period) as usize,
+            IndicatorConfig::NATR(natr_config) => natr_lookback(natr_config.time_period) as usize,
+            IndicatorConfig::TRANGE(_) => trange_lookback() as usize,
+
+            // Pattern Recognition
+            IndicatorConfig::CDL2CROWS(_) => cdl2crows_lookback() as usize,
+            IndicatorConfig::CDL3BLACKCROWS(_) => cdl3blackcrows_lookback() as usize,
+            IndicatorConfig::CDL3INSIDE(_) => cdl3inside_lookback() as usize,
+            IndicatorConfig::CDL3OUTSIDE(_) => cdl3outside_lookback() as usize,
+            IndicatorConfig::CDLABANDONEDBABY(cdlabandonedbaby_config) => {
+                cdlabandonedbaby_lookback(cdlabandonedbaby_config.penetration.into()) as usize
+            }
+            IndicatorConfig::CDLADVANCEBLOCK(_) => cdladvanceblock_lookback() as usize,
+            IndicatorConfig::CDLBELTHOLD(_) => cdlbelthold_lookback() as usize,
+            IndicatorConfig::CDLBREAKAWAY(_) => cdlbreakaway_lookback() as usize,
+            IndicatorConfig::CDLCLOSINGMARUBOZU(_) => cdlclosingmarubozu_lookback() as usize,
+            IndicatorConfig::CDLCONCEALBABYSWALL(_) => cdlconcealbabyswall_lookback() as usize,
+            IndicatorConfig::CDLCOUNTERATTACK(_) => cdlcounterattack_lookback() as usize,
+            IndicatorConfig::CDLDARKCLOUDCOVER(cdldarkcloudcover_config) => {
+                cdldarkcloudcover_lookback(cdldarkcloudcover_config.penetration.into()) as usize
+            }
+            IndicatorConfig::CDLDOJI(_) => cdldoji_lookback() as usize,
+            IndicatorConfig::CDLDOJISTAR(_) => cdldojistar_lookback() as usize,
+            IndicatorConfig::CDLENGULFING(_) => cdlengulfing_lookback() as usize,
+            IndicatorConfig::CDLEVENINGDOJISTAR(cdleveningdojistar_config) => {
+                cdleveningdojistar_lookback(cdleveningdojistar_config.penetration.into()) as usize
+            }
+            IndicatorConfig::CDLEVENINGSTAR(cdleveningstar_config) => {
+                cdleveningstar_lookback(cdleveningstar_config.penetration.into()) as usize
+            }
+            IndicatorConfig::CDLGAPSIDESIDEWHITE(_) => cdlgapsidesidewhite_lookback() as usize,
+            IndicatorConfig::CDLGRAVESTONEDOJI(_) => cdlgravestonedoji_lookback() as usize,
+            IndicatorConfig::CDLHAMMER(_) => cdlhammer_lookback() as usize,
+            IndicatorConfig::CDLHANGINGMAN(_) => cdlhangingman_lookback() as usize,
+            IndicatorConfig::CDLHIKKAKE(_) => cdlhikkake_lookback() as usize,
+            IndicatorConfig::CDLHIKKAKEMOD(_) => cdlhikkakemod_lookback() as usize,
+            IndicatorConfig::CDLHOMINGPIGEON(_) => cdlhomingpigeon_lookback() as usize,
+            IndicatorConfig::CDLIDENTICAL3CROWS(_) => cdlidentical3crows_lookback() as usize,
+            IndicatorConfig::CDLINVERTEDHAMMER(_) => cdlinvertedhammer_lookback() as usize,
+            IndicatorConfig::CDLKICKING(_) => cdlkicking_lookback() as usize,
+
+            // Additional CDL indicators
+            IndicatorConfig::CDL3LINESTRIKE(_) => cdl3linestrike_lookback() as usize,
+            IndicatorConfig::CDL3STARSINSOUTH(_) => cdl3starsinsouth_lookback() as usize,
+            IndicatorConfig::CDL3WHITESOLDIERS(_) => cdl3whitesoldiers_lookback() as usize,
+            IndicatorConfig::CDLDRAGONFLYDOJI(_) => cdldragonflydoji_lookback() as usize,
+            IndicatorConfig::CDLHARAMI(_) => cdlharami_lookback() as usize,
+            IndicatorConfig::CDLHARAMICROSS(_) => cdlharamicross_lookback() as usize,
+            IndicatorConfig::CDLHIGHWAVE(_) => cdlhighwave_lookback() as usize,
+            IndicatorConfig::CDLINNECK(_) => cdlinneck_lookback() as usize,
+            IndicatorConfig::CDLKICKINGBYLENGTH(_) => cdlkickingbylength_lookback() as usize,
+            IndicatorConfig::CDLLADDERBOTTOM(_) => cdlladderbottom_lookback() as usize,
+            IndicatorConfig::CDLLONGLEGGEDDOJI(_) => cdllongleggeddoji_lookback() as usize,
+            IndicatorConfig::CDLLONGLINE(_) => cdllongline_lookback() as usize,
+            IndicatorConfig::CDLMARUBOZU(_) => cdlmarubozu_lookback() as usize,
+            IndicatorConfig::CDLMATCHINGLOW(_) => cdlmatchinglow_lookback() as usize,
+            IndicatorConfig::CDLMATHOLD(cdlmathold_config) => cdlmathold_lookback(cdlmathold_config.penetration.into()) as usize,
+            IndicatorConfig::CDLMORNINGDOJISTAR(cdlmorningdojistar_config) => {
+                cdlmorningdojistar_lookback(cdlmorningdojistar_config.penetration.into()) as usize
+            }
+            IndicatorConfig::CDLMORNINGSTAR(cdlmorningstar_config) => {
+                cdlmorningstar_lookback(cdlmorningstar_config.penetration.into()) as usize
+            }
+            IndicatorConfig::CDLONNECK(_cdlonneck_config) => cdlonneck_lookback() as usize,
+            IndicatorConfig::CDLPIERCING(_cdlpiercing_config) => cdlpiercing_lookback() as usize,
+            IndicatorConfig::CDLRICKSHAWMAN(_cdlrickshawman_config) => cdlrickshawman_lookback() as usize,
+            IndicatorConfig::CDLRISEFALL3METHODS(_) => cdlrisefall3methods_lookback() as usize,
+            IndicatorConfig::CDLSEPARATINGLINES(_) => cdlseparatinglines_lookback() as usize,
+            IndicatorConfig::CDLSHOOTINGSTAR(_) => cdlshootingstar_lookback() as usize,
+            IndicatorConfig::CDLSHORTLINE(_) => cdlshortline_lookback() as usize,
+            IndicatorConfig::CDLSPINNINGTOP(_) => cdlspinningtop_lookback() as usize,
+            IndicatorConfig::CDLSTALLEDPATTERN(_) => cdlstalledpattern_lookback() as usize,
+            IndicatorConfig::CDLSTICKSANDWICH(_) => cdlsticksandwich_lookback() as usize,
+            IndicatorConfig::CDLTAKURI(_) => cdltakuri_lookback() as usize,
+            IndicatorConfig::CDLTASUKIGAP(_) => cdltasukigap_lookback() as usize,
+            IndicatorConfig::CDLTHRUSTING(_) => cdlthrusting_lookback() as usize,
+            IndicatorConfig::CDLTRISTAR(_) => cdltristar_lookback() as usize,
+            IndicatorConfig::CDLUNIQUE3RIVER(_) => cdlunique3river_lookback() as usize,
+            IndicatorConfig::CDLUPSIDEGAP2CROWS(_) => cdlupsidegap2crows_lookback() as usize,
+            IndicatorConfig::CDLXSIDEGAP3METHODS(_) => cdlxsidegap3methods_lookback() as usize,
+        }
+    }
+}
@@ -0,0 +1,1292 @@
+use super::overlap::{int_ema, ma, ma_lookback, sma_lookback};
+use super::{check_ma_type, check_params, check_period, is_zero, is_zero_or_neg, per_to_k, true_range};
+use crate::error::TaLibError;
+use crate::indicator::MaTypeInt;
+
+type Output = Result<(usize, Vec<f64>), TaLibError>;
+type Output2 = Result<(usize, Vec<f64>, Vec<f64>), TaLibError>;
+type Output3 = Result<(usize, Vec<f64>, Vec<f64>, Vec<f64>), TaLibError>;
+
+/// -DM and +DM of bar `today`, at most one of them is not zero.
+fn directional_movement(high: &[f64], low: &[f64], today: usize) -> (f64, f64) {
+    let diff_p = high[today] - high[today - 1];
+    let diff_m = low[today - 1] - low[today];
+    let minus_dm = if diff_m > 0.0 && diff_p < diff_m { diff_m } else { 0.0 };
+    let plus_dm = if diff_p > 0.0 && diff_p > diff_m { diff_p } else { 0.0 };
+    (minus_dm, plus_dm)
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Minus,
+    Plus,
+}
+
+impl Direction {
+    fn pick(self, (minus, plus): (f64, f64)) -> f64 {
+        match self {
+            Direction::Minus => minus,
+            Direction::Plus => plus,
+        }
+    }
+}
+
+/// Wilder-smoothed -DM, +DM and true range shared by ADX, DX and the DI functions.
+struct DirectionalSums {
+    minus_dm: f64,
+    plus_dm: f64,
+    tr: f64,
+    period: f64,
+}
+
+impl DirectionalSums {
+    /// Sums the `period - 1` bars following `today` and returns the index of the last one.
+    fn seed(high: &[f64], low: &[f64], close: &[f64], today: usize, period: usize) -> (Self, usize) {
+        let mut sums = Self {
+            minus_dm: 0.0,
+            plus_dm: 0.0,
+            tr: 0.0,
+            period: period as f64,
+        };
+        let mut today = today;
+        for _ in 1..period {
+            today += 1;
+            let (minus_dm, plus_dm) = directional_movement(high, low, today);
+            sums.minus_dm += minus_dm;
+            sums.plus_dm += plus_dm;
+            sums.tr += true_range(high[today], low[today], close[today - 1]);
+        }
+        (sums, today)
+    }
+
+    fn smooth(&mut self, high: &[f64], low: &[f64], close: &[f64], today: usize) {
+        let (minus_dm, plus_dm) = directional_movement(high, low, today);
+        self.minus_dm = self.minus_dm - (self.minus_dm / self.period) + minus_dm;
+        self.plus_dm = self.plus_dm - (self.plus_dm / self.period) + plus_dm;
+        self.tr = self.tr - (self.tr / self.period) + true_range(high[today], low[today], close[today - 1]);
+    }
+
+    fn di(&self, direction: Direction) -> f64 {
+        if is_zero(self.tr) {
+            return 0.0;
+        }
+        100.0 * (direction.pick((self.minus_dm, self.plus_dm)) / self.tr)
+    }
+
+    /// `None` when there is no range or no directional movement to compare.
+    fn dx(&self) -> Option<f64> {
+        if is_zero(self.tr) {
+            return None;
+        }
+        let minus_di = 100.0 * (self.minus_dm / self.tr);
+        let plus_di = 100.0 * (self.plus_dm / self.tr);
+        let sum = minus_di + plus_di;
+        if is_zero(sum) {
+            return None;
+        }
+        Some(100.0 * ((minus_di - plus_di).abs() / sum))
+    }
+}
+
+pub(crate) fn adx_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    (2 * time_period) - 1
+}
+
+pub(crate) fn adx(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], close: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(adx_lookback(time_period), "ADX")?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let period = time_period as usize;
+    let (mut sums, mut today) = DirectionalSums::seed(high, low, close, start_idx - lookback, period);
+    let mut sum_dx = 0.0;
+    for _ in 0..period {
+        today += 1;
+        sums.smooth(high, low, close, today);
+        if let Some(dx) = sums.dx() {
+            sum_dx += dx;
+        }
+    }
+    let mut prev_adx = sum_dx / period as f64;
+
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    out.push(prev_adx);
+    while today < end_idx {
+        today += 1;
+        sums.smooth(high, low, close, today);
+        if let Some(dx) = sums.dx() {
+            prev_adx = ((prev_adx * (period - 1) as f64) + dx) / period as f64;
+        }
+        out.push(prev_adx);
+    }
+    Ok((start_idx, out))
+}
+
+pub(crate) fn adxr_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period + adx_lookback(time_period) - 1
+}
+
+pub(crate) fn adxr(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], close: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(adxr_lookback(time_period), "ADXR")?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let period = time_period as usize;
+    let (_, adx) = adx(start_idx - (period - 1), end_idx, high, low, close, time_period)?;
+    let out = adx[period - 1..]
+        .iter()
+        .zip(&adx)
+        .take(end_idx - start_idx + 1)
+        .map(|(today, before)| (today + before) / 2.0)
+        .collect();
+    Ok((start_idx, out))
+}
+
+pub(crate) fn apo_lookback(fast_period: i32, slow_period: i32, ma_type: MaTypeInt) -> i32 {
+    if !check_period(fast_period, 2) || !check_period(slow_period, 2) || !check_ma_type(ma_type) {
+        return -1;
+    }
+    ma_lookback(slow_period.max(fast_period), ma_type)
+}
+
+pub(crate) fn apo(start_idx: usize, end_idx: usize, data: &[f64], fast_period: i32, slow_period: i32, ma_type: MaTypeInt) -> Output {
+    check_params(apo_lookback(fast_period, slow_period, ma_type), "APO")?;
+    int_po(start_idx, end_idx, data, fast_period, slow_period, ma_type, false)
+}
+
+/// Price oscillator behind APO and PPO: the fast MA minus the slow one, optionally as a percentage.
+fn int_po(
+    start_idx: usize,
+    end_idx: usize,
+    data: &[f64],
+    fast_period: i32,
+    slow_period: i32,
+    ma_type: MaTypeInt,
+    percentage: bool,
+) -> Output {
+    let (fast_period, slow_period) = if slow_period < fast_period {
+        (slow_period, fast_period)
+    } else {
+        (fast_period, slow_period)
+    };
+    let (fast_begin, fast_ma) = ma(start_idx, end_idx, data, fast_period, ma_type)?;
+    let (slow_begin, slow_ma) = ma(start_idx, end_idx, data, slow_period, ma_type)?;
+
+    let fast_ma = &fast_ma[slow_begin - fast_begin..];
+    let out = slow_ma
+        .iter()
+        .zip(fast_ma)
+        .map(|(slow, fast)| {
+            if !percentage {
+                fast - slow
+            } else if !is_zero(*slow) {
+                ((fast - slow) / slow) * 100.0
+            } else {
+                0.0
+            }
+        })
+        .collect();
+    Ok((slow_begin, out))
+}
+
+pub(crate) fn aroon_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period
+}
+
+pub(crate) fn aroon(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], time_period: i32) -> Output2 {
+    check_params(aroon_lookback(time_period), "AROON")?;
+    let (begin, extremes) = aroon_extremes(start_idx, end_idx, high, low, time_period as usize);
+    let factor = 100.0 / time_period as f64;
+    let mut aroon_down = Vec::with_capacity(extremes.len());
+    let mut aroon_up = Vec::with_capacity(extremes.len());
+    for (today, lowest_idx, highest_idx) in extremes {
+        aroon_up.push(factor * (time_period as usize - (today - highest_idx)) as f64);
+        aroon_down.push(factor * (time_period as usize - (today - lowest_idx)) as f64);
+    }
+    Ok((begin, aroon_down, aroon_up))
+}
+
+pub(crate) fn aroonosc_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period
+}
+
+pub(crate) fn aroonosc(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], time_period: i32) -> Output {
+    check_params(aroonosc_lookback(time_period), "AROONOSC")?;
+    let (begin, extremes) = aroon_extremes(start_idx, end_idx, high, low, time_period as usize);
+    let factor = 100.0 / time_period as f64;
+    let out = extremes
+        .into_iter()
+        .map(|(_, lowest_idx, highest_idx)| factor * (highest_idx as i64 - lowest_idx as i64) as f64)
+        .collect();
+    Ok((begin, out))
+}
+
+/// Index of the most recent lowest low and highest high over the last `period + 1` bars, for every bar.
+fn aroon_extremes(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], period: usize) -> (usize, Vec<(usize, usize, usize)>) {
+    let start_idx = start_idx.max(period);
+    if start_idx > end_idx {
+        return (0, Vec::new());
+    }
+
+    let mut extremes = Vec::with_capacity(end_idx - start_idx + 1);
+    let (mut lowest_idx, mut highest_idx): (Option<usize>, Option<usize>) = (None, None);
+    let (mut lowest, mut highest) = (0.0, 0.0);
+    for (trailing_idx, today) in (start_idx - period..).zip(start_idx..=end_idx) {
+        match lowest_idx {
+            Some(idx) if idx >= trailing_idx => {
+                if low[today] <= lowest {
+                    lowest_idx = Some(today);
+                    lowest = low[today];
+                }
+            }
+            _ => {
+                let mut idx = trailing_idx;
+                lowest = low[idx];
+                for (i, value) in low.iter().enumerate().take(today + 1).skip(trailing_idx + 1) {
+                    if *value <= lowest {
+                        idx = i;
+                        lowest = *value;
+                    }
+                }
+                lowest_idx = Some(idx);
+            }
+        }
+        match highest_idx {
+            Some(idx) if idx >= trailing_idx => {
+                if high[today] >= highest {
+                    highest_idx = Some(today);
+                    highest = high[today];
+                }
+            }
+            _ => {
+                let mut idx = trailing_idx;
+                highest = high[idx];
+                for (i, value) in high.iter().enumerate().take(today + 1).skip(trailing_idx + 1) {
+                    if *value >= highest {
+                        idx = i;
+                        highest = *value;
+                    }
+                }
+                highest_idx = Some(idx);
+            }
+        }
+        extremes.push((today, lowest_idx.unwrap_or(today), highest_idx.unwrap_or(today)));
+    }
+    (start_idx, extremes)
+}
+
+pub(crate) fn bop_lookback() -> i32 {
+    0
+}
+
+pub(crate) fn bop(start_idx: usize, end_idx: usize, open: &[f64], high: &[f64], low: &[f64], close: &[f64]) -> Output {
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+    let out = (start_idx..=end_idx)
+        .map(|i| {
+            let range = high[i] - low[i];
+            if is_zero_or_neg(range) { 0.0 } else { (close[i] - open[i]) / range }
+        })
+        .collect();
+    Ok((start_idx, out))
+}
+
+pub(crate) fn cci_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period - 1
+}
+
+pub(crate) fn cci(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], close: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(cci_lookback(time_period), "CCI")?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let period = time_period as usize;
+    let typical_price = |i: usize| (high[i] + low[i] + close[i]) / 3.0;
+    let mut buffer = vec![0.0; period];
+    let mut buffer_idx = 0;
+    for i in start_idx - lookback..start_idx {
+        buffer[buffer_idx] = typical_price(i);
+        buffer_idx = (buffer_idx + 1) % period;
+    }
+
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    for i in start_idx..=end_idx {
+        let last_value = typical_price(i);
+        buffer[buffer_idx] = last_value;
+        let mut average = 0.0;
+        for value in &buffer {
+            average += value;
+        }
+        average /= period as f64;
+        let mut deviation = 0.0;
+        for value in &buffer {
+            deviation += (value - average).abs();
+        }
+        let distance = last_value - average;
+        if distance != 0.0 && deviation != 0.0 {
+            out.push(distance / (0.015 * (deviation / period as f64)));
+        } else {
+            out.push(0.0);
+        }
+        buffer_idx = (buffer_idx + 1) % period;
+    }
+    Ok((start_idx, out))
+}
+
+pub(crate) fn cmo_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period
+}
+
+pub(crate) fn cmo(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(cmo_lookback(time_period), "CMO")?;
+    Ok(wilder_gain_loss(start_idx, end_idx, data, lookback, |gain, loss| {
+        let total = gain + loss;
+        if !is_zero(total) { 100.0 * ((gain - loss) / total) } else { 0.0 }
+    }))
+}
+
+/// Shared body of CMO and RSI: Wilder-smoothed average gain and loss, combined by `value`.
+fn wilder_gain_loss(start_idx: usize, end_idx: usize, data: &[f64], period: usize, value: impl Fn(f64, f64) -> f64) -> (usize, Vec<f64>) {
+    let start_idx = start_idx.max(period);
+    if start_idx > end_idx {
+        return (0, Vec::new());
+    }
+
+    let mut today = start_idx - period;
+    let mut prev_value = data[today];
+    let (mut prev_gain, mut prev_loss) = (0.0, 0.0);
+    today += 1;
+    for _ in 0..period {
+        let current = data[today];
+        today += 1;
+        let diff = current - prev_value;
+        prev_value = current;
+        if diff < 0.0 {
+            prev_loss -= diff;
+        } else {
+            prev_gain += diff;
+        }
+    }
+    prev_loss /= period as f64;
+    prev_gain /= period as f64;
+
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    out.push(value(prev_gain, prev_loss));
+    while today <= end_idx {
+        let current = data[today];
+        today += 1;
+        let diff = current - prev_value;
+        prev_value = current;
+        prev_loss *= (period - 1) as f64;
+        prev_gain *= (period - 1) as f64;
+        if diff < 0.0 {
+            prev_loss -= diff;
+        } else {
+            prev_gain += diff;
+        }
+        prev_loss /= period as f64;
+        prev_gain /= period as f64;
+        out.push(value(prev_gain, prev_loss));
+    }
+    (start_idx, out)
+}
+
+pub(crate) fn dx_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period
+}
+
+pub(crate) fn dx(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], close: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(dx_lookback(time_period), "DX")?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let (mut sums, mut today) = DirectionalSums::seed(high, low, close, start_idx - lookback, time_period as usize);
+    today += 1;
+    sums.smooth(high, low, close, today);
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    out.push(sums.dx().unwrap_or(0.0));
+    while today < end_idx {
+        today += 1;
+        sums.smooth(high, low, close, today);
+        // Without range or movement the previous value is repeated.
+        let value = sums.dx().unwrap_or(out[out.len() - 1]);
+        out.push(value);
+    }
+    Ok((start_idx, out))
+}
+
+pub(crate) fn macd_lookback(fast_period: i32, slow_period: i32, signal_period: i32) -> i32 {
+    if !check_period(fast_period, 2) || !check_period(slow_period, 2) || !check_period(signal_period, 1) {
+        return -1;
+    }
+    // A signal period of 1 has no EMA lookback, `TA_EMA_Lookback` would reject it.
+    slow_period.max(fast_period) - 1 + signal_period - 1
+}
+
+pub(crate) fn macd(start_idx: usize, end_idx: usize, data: &[f64], fast_period: i32, slow_period: i32, signal_period: i32) -> Output3 {
+    check_params(macd_lookback(fast_period, slow_period, signal_period), "MACD")?;
+    let (fast_period, slow_period) = if slow_period < fast_period {
+        (slow_period as usize, fast_period as usize)
+    } else {
+        (fast_period as usize, slow_period as usize)
+    };
+    Ok(int_macd(
+        start_idx,
+        end_idx,
+        data,
+        (fast_period, per_to_k(fast_period)),
+        (slow_period, per_to_k(slow_period)),
+        signal_period as usize,
+    ))
+}
+
+pub(crate) fn macdfix_lookback(signal_period: i32) -> i32 {
+    if !check_period(signal_period, 1) {
+        return -1;
+    }
+    26 - 1 + signal_period - 1
+}
+
+pub(crate) fn macdfix(start_idx: usize, end_idx: usize, data: &[f64], signal_period: i32) -> Output3 {
+    check_params(macdfix_lookback(signal_period), "MACDFIX")?;
+    // The fixed 12/26 periods use the rounded k values of the original MACD definition.
+    Ok(int_macd(start_idx, end_idx, data, (12, 0.15), (26, 0.075), signal_period as usize))
+}
+
+fn int_macd(
+    start_idx: usize,
+    end_idx: usize,
+    data: &[f64],
+    (fast_period, fast_k): (usize, f64),
+    (slow_period, slow_k): (usize, f64),
+    signal_period: usize,
+) -> (usize, Vec<f64>, Vec<f64>, Vec<f64>) {
+    let lookback_signal = signal_period - 1;
+    let start_idx = start_idx.max(lookback_signal + slow_period - 1);
+    if start_idx > end_idx {
+        return (0, Vec::new(), Vec::new(), Vec::new());
+    }
+
+    let (_, slow_ema) = int_ema(start_idx - lookback_signal, end_idx, data, slow_period, slow_k);
+    let (_, fast_ema) = int_ema(start_idx - lookback_signal, end_idx, data, fast_period, fast_k);
+    let macd_full: Vec<f64> = fast_ema.iter().zip(&slow_ema).map(|(fast, slow)| fast - slow).collect();
+    let (_, signal) = int_ema(0, macd_full.len() - 1, &macd_full, signal_period, per_to_k(signal_period));
+    let macd = macd_full[lookback_signal..lookback_signal + signal.len()].to_vec();
+    let histogram = macd.iter().zip(&signal).map(|(macd, signal)| macd - signal).collect();
+    (start_idx, macd, signal, histogram)
+}
+
+pub(crate) fn macdext_lookback(
+    fast_period: i32,
+    fast_ma_type: MaTypeInt,
+    slow_period: i32,
+    slow_ma_type: MaTypeInt,
+    signal_period: i32,
+    signal_ma_type: MaTypeInt,
+) -> i32 {
+    if !check_period(fast_period, 2)
+        || !check_period(slow_period, 2)
+        || !check_period(signal_period, 1)
+        || !check_ma_type(fast_ma_type)
+        || !check_ma_type(slow_ma_type)
+        || !check_ma_type(signal_ma_type)
+    {
+        return -1;
+    }
+    let lookback_largest = ma_lookback(fast_period, fast_ma_type).max(ma_lookback(slow_period, slow_ma_type));
+    lookback_largest + ma_lookback(signal_period, signal_ma_type)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn macdext(
+    start_idx: usize,
+    end_idx: usize,
+    data: &[f64],
+    fast_period: i32,
+    fast_ma_type: MaTypeInt,
+    slow_period: i32,
+    slow_ma_type: MaTypeInt,
+    signal_period: i32,
+    signal_ma_type: MaTypeInt,
+) -> Output3 {
+    check_params(
+        macdext_lookback(fast_period, fast_ma_type, slow_period, slow_ma_type, signal_period, signal_ma_type),
+        "MACDEXT",
+    )?;
+    let ((fast_period, fast_ma_type), (slow_period, slow_ma_type)) = if slow_period < fast_period {
+        ((slow_period, slow_ma_type), (fast_period, fast_ma_type))
+    } else {
+        ((fast_period, fast_ma_type), (slow_period, slow_ma_type))
+    };
+
+    let lookback_largest = ma_lookback(fast_period, fast_ma_type).max(ma_lookback(slow_period, slow_ma_type)) as usize;
+    let lookback_signal = ma_lookback(signal_period, signal_ma_type) as usize;
+    let start_idx = start_idx.max(lookback_signal + lookback_largest);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new(), Vec::new(), Vec::new()));
+    }
+
+    let (_, slow_ma) = ma(start_idx - lookback_signal, end_idx, data, slow_period, slow_ma_type)?;
+    let (_, fast_ma) = ma(start_idx - lookback_signal, end_idx, data, fast_period, fast_ma_type)?;
+    let macd_full: Vec<f64> = fast_ma.iter().zip(&slow_ma).map(|(fast, slow)| fast - slow).collect();
+    let (_, signal) = ma(0, macd_full.len() - 1, &macd_full, signal_period, signal_ma_type)?;
+    let macd = macd_full[lookback_signal..lookback_signal + signal.len()].to_vec();
+    let histogram = macd.iter().zip(&signal).map(|(macd, signal)| macd - signal).collect();
+    Ok((start_idx, macd, signal, histogram))
+}
+
+pub(crate) fn mfi_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period
+}
+
+pub(crate) fn mfi(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], close: &[f64], volume: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(mfi_lookback(time_period), "MFI")?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let period = time_period as usize;
+    let typical_price = |i: usize| (high[i] + low[i] + close[i]) / 3.0;
+    // (positive, negative) money flow of the last `period` bars.
+    let mut flows = vec![(0.0, 0.0); period];
+    let mut flow_idx = 0;
+    let (mut pos_sum, mut neg_sum) = (0.0, 0.0);
+
+    let mut today = start_idx - lookback;
+    let mut prev_value = typical_price(today);
+    today += 1;
+    let next_flow = |today: usize, prev_value: &mut f64| {
+        let value = typical_price(today);
+        let diff = value - *prev_value;
+        *prev_value = value;
+        let flow = value * volume[today];
+        if diff < 0.0 {
+            (0.0, flow)
+        } else if diff > 0.0 {
+            (flow, 0.0)
+        } else {
+            (0.0, 0.0)
+        }
+    };
+    let ratio = |pos_sum: f64, neg_sum: f64| {
+        let total = pos_sum + neg_sum;
+        if total < 1.0 { 0.0 } else { 100.0 * (pos_sum / total) }
+    };
+
+    for _ in 0..period {
+        let flow = next_flow(today, &mut prev_value);
+        today += 1;
+        pos_sum += flow.0;
+        neg_sum += flow.1;
+        flows[flow_idx] = flow;
+        flow_idx = (flow_idx + 1) % period;
+    }
+
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    out.push(ratio(pos_sum, neg_sum));
+    while today <= end_idx {
+        pos_sum -= flows[flow_idx].0;
+        neg_sum -= flows[flow_idx].1;
+        let flow = next_flow(today, &mut prev_value);
+        today += 1;
+        pos_sum += flow.0;
+        neg_sum += flow.1;
+        flows[flow_idx] = flow;
+        out.push(ratio(pos_sum, neg_sum));
+        flow_idx = (flow_idx + 1) % period;
+    }
+    Ok((start_idx, out))
+}
+
+pub(crate) fn minus_di_lookback(time_period: i32) -> i32 {
+    di_lookback(time_period)
+}
+
+pub(crate) fn minus_di(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], close: &[f64], time_period: i32) -> Output {
+    check_params(minus_di_lookback(time_period), "MINUS_DI")?;
+    Ok(int_di(start_idx, end_idx, high, low, close, time_period as usize, Direction::Minus))
+}
+
+pub(crate) fn plus_di_lookback(time_period: i32) -> i32 {
+    di_lookback(time_period)
+}
+
+pub(crate) fn plus_di(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], close: &[f64], time_period: i32) -> Output {
+    check_params(plus_di_lookback(time_period), "PLUS_DI")?;
+    Ok(int_di(start_idx, end_idx, high, low, close, time_period as usize, Direction::Plus))
+}
+
+fn di_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 1) {
+        return -1;
+    }
+    if time_period > 1 { time_period } else { 1 }
+}
+
+fn int_di(
+    start_idx: usize,
+    end_idx: usize,
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    period: usize,
+    direction: Direction,
+) -> (usize, Vec<f64>) {
+    let lookback = period.max(1);
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return (0, Vec::new());
+    }
+
+    if period <= 1 {
+        // Raw ratio of the movement to the true range, not scaled to a percentage.
+        let out = (start_idx..=end_idx)
+            .map(|today| {
+                let movement = direction.pick(directional_movement(high, low, today));
+                if movement == 0.0 {
+                    return 0.0;
+                }
+                let range = true_range(high[today], low[today], close[today - 1]);
+                if is_zero(range) { 0.0 } else { movement / range }
+            })
+            .collect();
+        return (start_idx, out);
+    }
+
+    let (mut sums, mut today) = DirectionalSums::seed(high, low, close, start_idx - lookback, period);
+    today += 1;
+    sums.smooth(high, low, close, today);
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    out.push(sums.di(direction));
+    while today < end_idx {
+        today += 1;
+        sums.smooth(high, low, close, today);
+        out.push(sums.di(direction));
+    }
+    (start_idx, out)
+}
+
+pub(crate) fn minus_dm_lookback(time_period: i32) -> i32 {
+    dm_lookback(time_period)
+}
+
+pub(crate) fn minus_dm(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], time_period: i32) -> Output {
+    check_params(minus_dm_lookback(time_period), "MINUS_DM")?;
+    Ok(int_dm(start_idx, end_idx, high, low, time_period as usize, Direction::Minus))
+}
+
+pub(crate) fn plus_dm_lookback(time_period: i32) -> i32 {
+    dm_lookback(time_period)
+}
+
+pub(crate) fn plus_dm(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], time_period: i32) -> Output {
+    check_params(plus_dm_lookback(time_period), "PLUS_DM")?;
+    Ok(int_dm(start_idx, end_idx, high, low, time_period as usize, Direction::Plus))
+}
+
+fn dm_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 1) {
+        return -1;
+    }
+    if time_period > 1 { time_period - 1 } else { 1 }
+}
+
+fn int_dm(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], period: usize, direction: Direction) -> (usize, Vec<f64>) {
+    let lookback = if period > 1 { period - 1 } else { 1 };
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return (0, Vec::new());
+    }
+
+    if period <= 1 {
+        let out = (start_idx..=end_idx)
+            .map(|today| direction.pick(directional_movement(high, low, today)))
+            .collect();
+        return (start_idx, out);
+    }
+
+    let mut today = start_idx - lookback;
+    let mut prev_dm = 0.0;
+    for _ in 1..period {
+        today += 1;
+        prev_dm += direction.pick(directional_movement(high, low, today));
+    }
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    out.push(prev_dm);
+    while today < end_idx {
+        today += 1;
+        prev_dm = prev_dm - (prev_dm / period as f64) + direction.pick(directional_movement(high, low, today));
+        out.push(prev_dm);
+    }
+    (start_idx, out)
+}
+
+pub(crate) fn mom_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 1) {
+        return -1;
+    }
+    time_period
+}
+
+pub(crate) fn mom(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(mom_lookback(time_period), "MOM")?;
+    Ok(int_roc(start_idx, end_idx, data, lookback, |today, trailing| today - trailing))
+}
+
+pub(crate) fn ppo_lookback(fast_period: i32, slow_period: i32, ma_type: MaTypeInt) -> i32 {
+    apo_lookback(fast_period, slow_period, ma_type)
+}
+
+pub(crate) fn ppo(start_idx: usize, end_idx: usize, data: &[f64], fast_period: i32, slow_period: i32, ma_type: MaTypeInt) -> Output {
+    check_params(ppo_lookback(fast_period, slow_period, ma_type), "PPO")?;
+    int_po(start_idx, end_idx, data, fast_period, slow_period, ma_type, true)
+}
+
+/// Shared body of MOM and the ROC family, `value` receives the current and the `period` bars old value.
+fn int_roc(start_idx: usize, end_idx: usize, data: &[f64], period: usize, value: impl Fn(f64, f64) -> f64) -> (usize, Vec<f64>) {
+    let start_idx = start_idx.max(period);
+    if start_idx > end_idx {
+        return (0, Vec::new());
+    }
+    let out = (start_idx..=end_idx)
+        .map(|today| value(data[today], data[today - period]))
+        .collect();
+    (start_idx, out)
+}
+
+pub(crate) fn roc_lookback(time_period: i32) -> i32 {
+    mom_lookback(time_period)
+}
+
+pub(crate) fn roc(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(roc_lookback(time_period), "ROC")?;
+    Ok(int_roc(start_idx, end_idx, data, lookback, |today, trailing| {
+        if trailing != 0.0 { ((today / trailing) - 1.0) * 100.0 } else { 0.0 }
+    }))
+}
+
+pub(crate) fn rocp_lookback(time_period: i32) -> i32 {
+    mom_lookback(time_period)
+}
+
+pub(crate) fn rocp(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(rocp_lookback(time_period), "ROCP")?;
+    Ok(int_roc(start_idx, end_idx, data, lookback, |today, trailing| {
+        if trailing != 0.0 { (today - trailing) / trailing } else { 0.0 }
+    }))
+}
+
+pub(crate) fn rocr_lookback(time_period: i32) -> i32 {
+    mom_lookback(time_period)
+}
+
+pub(crate) fn rocr(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(rocr_lookback(time_period), "ROCR")?;
+    Ok(int_roc(start_idx, end_idx, data, lookback, |today, trailing| {
+        if trailing != 0.0 { today / trailing } else { 0.0 }
+    }))
+}
+
+pub(crate) fn rocr100_lookback(time_period: i32) -> i32 {
+    mom_lookback(time_period)
+}
+
+pub(crate) fn rocr100(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(rocr100_lookback(time_period), "ROCR100")?;
+    Ok(int_roc(start_idx, end_idx, data, lookback, |today, trailing| {
+        if trailing != 0.0 { (today / trailing) * 100.0 } else { 0.0 }
+    }))
+}
+
+pub(crate) fn rsi_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period
+}
+
+pub(crate) fn rsi(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(rsi_lookback(time_period), "RSI")?;
+    Ok(wilder_gain_loss(start_idx, end_idx, data, lookback, |gain, loss| {
+        let total = gain + loss;
+        if !is_zero(total) { 100.0 * (gain / total) } else { 0.0 }
+    }))
+}
+
+pub(crate) fn stoch_lookback(
+    fast_k_period: i32,
+    slow_k_period: i32,
+    slow_k_ma_type: MaTypeInt,
+    slow_d_period: i32,
+    slow_d_ma_type: MaTypeInt,
+) -> i32 {
+    if !check_period(fast_k_period, 1)
+        || !check_period(slow_k_period, 1)
+        || !check_period(slow_d_period, 1)
+        || !check_ma_type(slow_k_ma_type)
+        || !check_ma_type(slow_d_ma_type)
+    {
+        return -1;
+    }
+    (fast_k_period - 1) + ma_lookback(slow_k_period, slow_k_ma_type) + ma_lookback(slow_d_period, slow_d_ma_type)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn stoch(
+    start_idx: usize,
+    end_idx: usize,
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    fast_k_period: i32,
+    slow_k_period: i32,
+    slow_k_ma_type: MaTypeInt,
+    slow_d_period: i32,
+    slow_d_ma_type: MaTypeInt,
+) -> Output2 {
+    let lookback = check_params(
+        stoch_lookback(fast_k_period, slow_k_period, slow_k_ma_type, slow_d_period, slow_d_ma_type),
+        "STOCH",
+    )?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new(), Vec::new()));
+    }
+
+    let lookback_k = (fast_k_period - 1) as usize;
+    let lookback_d_slow = ma_lookback(slow_d_period, slow_d_ma_type) as usize;
+    let trailing_idx = start_idx - lookback;
+    let fast_k = raw_stochastic(high, low, close, trailing_idx, trailing_idx + lookback_k, end_idx);
+
+    let (_, slow_k) = ma(0, fast_k.len() - 1, &fast_k, slow_k_period, slow_k_ma_type)?;
+    if slow_k.is_empty() {
+        return Ok((0, Vec::new(), Vec::new()));
+    }
+    let (_, slow_d) = ma(0, slow_k.len() - 1, &slow_k, slow_d_period, slow_d_ma_type)?;
+    let slow_k = slow_k[lookback_d_slow..lookback_d_slow + slow_d.len()].to_vec();
+    Ok((start_idx, slow_k, slow_d))
+}
+
+pub(crate) fn stochf_lookback(fast_k_period: i32, fast_d_period: i32, fast_d_ma_type: MaTypeInt) -> i32 {
+    if !check_period(fast_k_period, 1) || !check_period(fast_d_period, 1) || !check_ma_type(fast_d_ma_type) {
+        return -1;
+    }
+    (fast_k_period - 1) + ma_lookback(fast_d_period, fast_d_ma_type)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn stochf(
+    start_idx: usize,
+    end_idx: usize,
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    fast_k_period: i32,
+    fast_d_period: i32,
+    fast_d_ma_type: MaTypeInt,
+) -> Output2 {
+    let lookback = check_params(stochf_lookback(fast_k_period, fast_d_period, fast_d_ma_type), "STOCHF")?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new(), Vec::new()));
+    }
+
+    let lookback_k = (fast_k_period - 1) as usize;
+    let lookback_fast_d = ma_lookback(fast_d_period, fast_d_ma_type) as usize;
+    let trailing_idx = start_idx - lookback;
+    let fast_k = raw_stochastic(high, low, close, trailing_idx, trailing_idx + lookback_k, end_idx);
+
+    let (_, fast_d) = ma(0, fast_k.len() - 1, &fast_k, fast_d_period, fast_d_ma_type)?;
+    if fast_d.is_empty() {
+        return Ok((0, Vec::new(), Vec::new()));
+    }
+    let fast_k = fast_k[lookback_fast_d..lookback_fast_d + fast_d.len()].to_vec();
+    Ok((start_idx, fast_k, fast_d))
+}
+
+/// Position of the close within the high/low range of the window, from `today` to `end_idx`.
+fn raw_stochastic(high: &[f64], low: &[f64], close: &[f64], trailing_idx: usize, today: usize, end_idx: usize) -> Vec<f64> {
+    let mut out = Vec::with_capacity(end_idx + 1 - today);
+    let (mut lowest_idx, mut highest_idx): (Option<usize>, Option<usize>) = (None, None);
+    let (mut lowest, mut highest, mut diff) = (0.0, 0.0, 0.0);
+    for (trailing_idx, today) in (trailing_idx..).zip(today..=end_idx) {
+        match lowest_idx {
+            Some(idx) if idx >= trailing_idx => {
+                if low[today] <= lowest {
+                    lowest_idx = Some(today);
+                    lowest = low[today];
+                    diff = (highest - lowest) / 100.0;
+                }
+            }
+            _ => {
+                let (idx, value) = scan_window(low, trailing_idx, today, |value, lowest| value < lowest);
+                lowest_idx = Some(idx);
+                lowest = value;
+                diff = (highest - lowest) / 100.0;
+            }
+        }
+        match highest_idx {
+            Some(idx) if idx >= trailing_idx => {
+                if high[today] >= highest {
+                    highest_idx = Some(today);
+                    highest = high[today];
+                    diff = (highest - lowest) / 100.0;
+                }
+            }
+            _ => {
+                let (idx, value) = scan_window(high, trailing_idx, today, |value, highest| value > highest);
+                highest_idx = Some(idx);
+                highest = value;
+                diff = (highest - lowest) / 100.0;
+            }
+        }
+        out.push(if diff != 0.0 { (close[today] - lowest) / diff } else { 0.0 });
+    }
+    out
+}
+
+/// Finds the extreme of `values[from..=to]`, `better` deciding when a later value replaces the current one.
+fn scan_window(values: &[f64], from: usize, to: usize, better: impl Fn(f64, f64) -> bool) -> (usize, f64) {
+    let mut extreme_idx = from;
+    let mut extreme = values[from];
+    for (i, value) in values.iter().enumerate().take(to + 1).skip(from + 1) {
+        if better(*value, extreme) {
+            extreme_idx = i;
+            extreme = *value;
+        }
+    }
+    (extreme_idx, extreme)
+}
+
+pub(crate) fn stochrsi_lookback(time_period: i32, fast_k_period: i32, fast_d_period: i32, fast_d_ma_type: MaTypeInt) -> i32 {
+    if !check_period(time_period, 2) || !check_period(fast_k_period, 1) || !check_period(fast_d_period, 1) || !check_ma_type(fast_d_ma_type)
+    {
+        return -1;
+    }
+    rsi_lookback(time_period) + stochf_lookback(fast_k_period, fast_d_period, fast_d_ma_type)
+}
+
+pub(crate) fn stochrsi(
+    start_idx: usize,
+    end_idx: usize,
+    data: &[f64],
+    time_period: i32,
+    fast_k_period: i32,
+    fast_d_period: i32,
+    fast_d_ma_type: MaTypeInt,
+) -> Output2 {
+    let lookback = check_params(
+        stochrsi_lookback(time_period, fast_k_period, fast_d_period, fast_d_ma_type),
+        "STOCHRSI",
+    )?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new(), Vec::new()));
+    }
+
+    let lookback_stochf = stochf_lookback(fast_k_period, fast_d_period, fast_d_ma_type) as usize;
+    let (_, rsi) = rsi(start_idx - lookback_stochf, end_idx, data, time_period)?;
+    if rsi.is_empty() {
+        return Ok((0, Vec::new(), Vec::new()));
+    }
+    let (_, fast_k, fast_d) = stochf(0, rsi.len() - 1, &rsi, &rsi, &rsi, fast_k_period, fast_d_period, fast_d_ma_type)?;
+    if fast_k.is_empty() {
+        return Ok((0, Vec::new(), Vec::new()));
+    }
+    Ok((start_idx, fast_k, fast_d))
+}
+
+pub(crate) fn trix_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 1) {
+        return -1;
+    }
+    // A period of 1 has no EMA lookback, `TA_EMA_Lookback` would reject it.
+    (time_period - 1) * 3 + roc_lookback(1)
+}
+
+pub(crate) fn trix(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    let total_lookback = check_params(trix_lookback(time_period), "TRIX")?;
+    let start_idx = start_idx.max(total_lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let period = time_period as usize;
+    let ema_lookback = period - 1;
+    let k = per_to_k(period);
+    let mut nb_element_to_output = (end_idx - start_idx) + 1 + total_lookback;
+    let (_, first_ema) = int_ema(start_idx - total_lookback, end_idx, data, period, k);
+    if first_ema.is_empty() {
+        return Ok((0, Vec::new()));
+    }
+    nb_element_to_output -= 1 + ema_lookback;
+    let (_, second_ema) = int_ema(0, nb_element_to_output, &first_ema, period, k);
+    if second_ema.is_empty() {
+        return Ok((0, Vec::new()));
+    }
+    nb_element_to_output -= ema_lookback;
+    let (_, third_ema) = int_ema(0, nb_element_to_output, &second_ema, period, k);
+    if third_ema.is_empty() {
+        return Ok((0, Vec::new()));
+    }
+    nb_element_to_output -= ema_lookback;
+    let (_, out) = roc(0, nb_element_to_output, &third_ema, 1)?;
+    if out.is_empty() {
+        return Ok((0, Vec::new()));
+    }
+    Ok((start_idx, out))
+}
+
+pub(crate) fn ultosc_lookback(time_period1: i32, time_period2: i32, time_period3: i32) -> i32 {
+    if !check_period(time_period1, 1) || !check_period(time_period2, 1) || !check_period(time_period3, 1) {
+        return -1;
+    }
+    // Every term needs the previous close, even when all the periods are 1.
+    sma_lookback(time_period1.max(time_period2).max(time_period3)).max(0) + 1
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn ultosc(
+    start_idx: usize,
+    end_idx: usize,
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    time_period1: i32,
+    time_period2: i32,
+    time_period3: i32,
+) -> Output {
+    let lookback = check_params(ultosc_lookback(time_period1, time_period2, time_period3), "ULTOSC")?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    // Shortest period weighted 4, middle 2, longest 1.
+    let mut periods = [time_period1 as usize, time_period2 as usize, time_period3 as usize];
+    periods.sort_unstable();
+
+    let terms = |day: usize| {
+        let prev_close = close[day - 1];
+        let true_low = low[day].min(prev_close);
+        let close_minus_true_low = close[day] - true_low;
+        let mut true_range = high[day] - low[day];
+        let value = (prev_close - high[day]).abs();
+        if value > true_range {
+            true_range = value;
+        }
+        let value = (prev_close - low[day]).abs();
+        if value > true_range {
+            true_range = value;
+        }
+        (close_minus_true_low, true_range)
+    };
+
+    let mut a_totals = [0.0; 3];
+    let mut b_totals = [0.0; 3];
+    for (period, (a_total, b_total)) in periods.iter().zip(a_totals.iter_mut().zip(b_totals.iter_mut())) {
+        for day in start_idx + 1 - period..start_idx {
+            let (a, b) = terms(day);
+            *a_total += a;
+            *b_total += b;
+        }
+    }
+
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    for today in start_idx..=end_idx {
+        let (a, b) = terms(today);
+        for (a_total, b_total) in a_totals.iter_mut().zip(b_totals.iter_mut()) {
+            *a_total += a;
+            *b_total += b;
+        }
+        let mut output = 0.0;
+        if !is_zero(b_totals[0]) {
+            output += 4.0 * (a_totals[0] / b_totals[0]);
+        }
+        if !is_zero(b_totals[1]) {
+            output += 2.0 * (a_totals[1] / b_totals[1]);
+        }
+        if !is_zero(b_totals[2]) {
+            output += a_totals[2] / b_totals[2];
+        }
+        for (i, period) in periods.iter().enumerate() {
+            let (a, b) = terms(today + 1 - period);
+            a_totals[i] -= a;
+            b_totals[i] -= b;
+        }
+        out.push(100.0 * (output / 7.0));
+    }
+    Ok((start_idx, out))
+}
+
+pub(crate) fn willr_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period - 1
+}
+
+pub(crate) fn willr(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], close: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(willr_lookback(time_period), "WILLR")?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    let (mut lowest_idx, mut highest_idx): (Option<usize>, Option<usize>) = (None, None);
+    let (mut lowest, mut highest, mut diff) = (0.0, 0.0, 0.0);
+    for (trailing_idx, today) in (start_idx - lookback..).zip(start_idx..=end_idx) {
+        match lowest_idx {
+            Some(idx) if idx >= trailing_idx => {
+                if low[today] <= lowest {
+                    lowest_idx = Some(today);
+                    lowest = low[today];
+                    diff = (highest - lowest) / (-100.0);
+                }
+            }
+            _ => {
+                let (idx, value) = scan_window(low, trailing_idx, today, |value, lowest| value < lowest);
+                lowest_idx = Some(idx);
+                lowest = value;
+                diff = (highest - lowest) / (-100.0);
+            }
+        }
+        match highest_idx {
+            Some(idx) if idx >= trailing_idx => {
+                if high[today] >= highest {
+                    highest_idx = Some(today);
+                    highest = high[today];
+                    diff = (highest - lowest) / (-100.0);
+                }
+            }
+            _ => {
+                let (idx, value) = scan_window(high, trailing_idx, today, |value, highest| value > highest);
+                highest_idx = Some(idx);
+                highest = value;
+                diff = (highest - lowest) / (-100.0);
+            }
+        }
+        out.push(if diff != 0.0 { (highest - close[today]) / diff } else { 0.0 });
+    }
+    Ok((start_idx, out))
+}
+
+#[cfg(all(test, not(feature = "pure-rust")))]
+mod tests {
+    use super::super::test_data::ohlcv;
+    use super::*;
+
+    #[test]
+    fn test_directional_movement_matches_talib() {
+        let data = ohlcv(400);
+        for period in [2, 5, 14, 30] {
+            assert_parity!(ADX => adx, [data.high, data.low, data.close], [period], [adx: f64]);
+            assert_parity!(ADXR => adxr, [data.high, data.low, data.close], [period], [adxr: f64]);
+            assert_parity!(DX => dx, [data.high, data.low, data.close], [period], [dx: f64]);
+            assert_parity!(AROON => aroon, [data.high, data.low], [period], [aroon_down: f64, aroon_up: f64]);
+            assert_parity!(AROONOSC => aroonosc, [data.high, data.low], [period], [aroonosc: f64]);
+        }
+        for period in [1, 2, 14] {
+            assert_parity!(MINUS_DI => minus_di, [data.high, data.low, data.close], [period], [minus_di: f64]);
+            assert_parity!(PLUS_DI => plus_di, [data.high, data.low, data.close], [period], [plus_di: f64]);
+            assert_parity!(MINUS_DM => minus_dm, [data.high, data.low], [period], [minus_dm: f64]);
+            assert_parity!(PLUS_DM => plus_dm, [data.high, data.low], [period], [plus_dm: f64]);
+        }
+    }
+
+    #[test]
+    fn test_oscillators_match_talib() {
+        let data = ohlcv(400);
+        assert_parity!(BOP => bop, [data.open, data.high, data.low, data.close], [], [bop: f64]);
+        for period in [2, 9, 14, 30] {
+            assert_parity!(CCI => cci, [data.high, data.low, data.close], [period], [cci: f64]);
+            assert_parity!(CMO => cmo, [data.close], [period], [cmo: f64]);
+            assert_parity!(RSI => rsi, [data.close], [period], [rsi: f64]);
+            assert_parity!(MFI => mfi, [data.high, data.low, data.close, data.volume], [period], [mfi: f64]);
+            assert_parity!(WILLR => willr, [data.high, data.low, data.close], [period], [willr: f64]);
+            assert_parity!(MOM => mom, [data.close], [period], [mom: f64]);
+            assert_parity!(ROC => roc, [data.close], [period], [roc: f64]);
+            assert_parity!(ROCP => rocp, [data.close], [period], [rocp: f64]);
+            assert_parity!(ROCR => rocr, [data.close], [period], [rocr: f64]);
+            assert_parity!(ROCR100 => rocr100, [data.close], [period], [rocr100: f64]);
+            assert_parity!(TRIX => trix, [data.close], [period], [trix: f64]);
+        }
+        assert_parity!(ULTOSC => ultosc, [data.high, data.low, data.close], [7, 14, 28], [ultosc: f64]);
+        assert_parity!(ULTOSC => ultosc, [data.high, data.low, data.close], [20, 3, 9], [ultosc: f64]);
+    }
+
+    #[test]
+    fn test_macd_and_price_oscillators_match_talib() {
+        let data = ohlcv(400);
+        assert_parity!(MACD => macd, [data.close], [12, 26, 9], [macd: f64, signal: f64, histogram: f64]);
+        assert_parity!(MACD => macd, [data.close], [26, 12, 9], [macd: f64, signal: f64, histogram: f64]);
+        assert_parity!(MACDFIX => macdfix, [data.close], [9], [macd: f64, signal: f64, histogram: f64]);
+        for ma_type in 0..=8 {
+            assert_parity!(APO => apo, [data.close], [12, 26, ma_type], [apo: f64]);
+            assert_parity!(PPO => ppo, [data.close], [12, 26, ma_type], [ppo: f64]);
+            assert_parity!(
+                MACDEXT => macdext,
+                [data.close],
+                [12, ma_type, 26, ma_type, 9, ma_type],
+                [macd: f64, signal: f64, histogram: f64]
+            );
+        }
+        assert_parity!(
+            MACDEXT => macdext,
+            [data.close],
+            [12, 1, 26, 0, 1, 2],
+            [macd: f64, signal: f64, histogram: f64]
+        );
+    }
+
+    #[test]
+    fn test_stochastics_match_talib() {
+        let data = ohlcv(400);
+        for ma_type in 0..=8 {
+            assert_parity!(
+                STOCH => stoch,
+                [data.high, data.low, data.close],
+                [5, 3, ma_type, 3, ma_type],
+                [slow_k: f64, slow_d: f64]
+            );
+            assert_parity!(STOCHF => stochf, [data.high, data.low, data.close], [5, 3, ma_type], [fast_k: f64, fast_d: f64]);
+            assert_parity!(STOCHRSI => stochrsi, [data.close], [14, 5, 3, ma_type], [fast_k: f64, fast_d: f64]);
+        }
+        assert_parity!(STOCHF => stochf, [data.high, data.low, data.close], [1, 1, 0], [fast_k: f64, fast_d: f64]);
+    }
+}
@@ -0,0 +1,987 @@
+use super::hilbert::{DominantCycle, PriceWma, SmoothPrice, Trendline, rad2deg_by_atan, rad2deg_by_pi};
+use super::momentum::minus_dm;
+use super::{MAX_REAL, check_ma_type, check_params, check_period, check_real, is_zero_or_neg, per_to_k};
+use crate::error::{BadParamSnafu, TaLibError};
+use crate::indicator::MaTypeInt;
+
+type Output = Result<(usize, Vec<f64>), TaLibError>;
+type Output3 = Result<(usize, Vec<f64>, Vec<f64>, Vec<f64>), TaLibError>;
+
+pub(crate) fn bbands_lookback(time_period: i32, dev_up: f64, dev_down: f64, ma_type: MaTypeInt) -> i32 {
+    if !check_period(time_period, 2) || !check_real(dev_up, -MAX_REAL, MAX_REAL) || !check_real(dev_down, -MAX_REAL, MAX_REAL) {
+        return -1;
+    }
+    ma_lookback(time_period, ma_type)
+}
+
+pub(crate) fn bbands(
+    start_idx: usize,
+    end_idx: usize,
+    data: &[f64],
+    time_period: i32,
+    dev_up: f64,
+    dev_down: f64,
+    ma_type: MaTypeInt,
+) -> Output3 {
+    check_params(bbands_lookback(time_period, dev_up, dev_down, ma_type), "BBANDS")?;
+    let period = time_period as usize;
+
+    let (begin, middle) = ma(start_idx, end_idx, data, time_period, ma_type)?;
+    if middle.is_empty() {
+        return Ok((begin, Vec::new(), Vec::new(), Vec::new()));
+    }
+    let (begin, std_dev) = if i64::from(ma_type) == 0 {
+        (begin, stddev_using_precalc_ma(data, &middle, begin, period))
+    } else {
+        int_stddev(begin, end_idx, data, period, 1.0)
+    };
+
+    let mut upper = Vec::with_capacity(std_dev.len());
+    let mut lower = Vec::with_capacity(std_dev.len());
+    for (std_dev, middle) in std_dev.iter().zip(&middle) {
+        upper.push(middle + std_dev * dev_up);
+        lower.push(middle - std_dev * dev_down);
+    }
+    let mut middle = middle;
+    middle.truncate(std_dev.len());
+    Ok((begin, upper, middle, lower))
+}
+
+pub(crate) fn dema_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    ema_lookback(time_period) * 2
+}
+
+pub(crate) fn dema(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    check_params(dema_lookback(time_period), "DEMA")?;
+    let period = time_period as usize;
+    let lookback_ema = period - 1;
+    let start_idx = start_idx.max(lookback_ema * 2);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let k = per_to_k(period);
+    let (first_begin, first_ema) = int_ema(start_idx - lookback_ema, end_idx, data, period, k);
+    if first_ema.is_empty() {
+        return Ok((0, Vec::new()));
+    }
+    let (second_begin, second_ema) = int_ema(0, first_ema.len() - 1, &first_ema, period, k);
+    if second_ema.is_empty() {
+        return Ok((0, Vec::new()));
+    }
+
+    let out = second_ema
+        .iter()
+        .zip(&first_ema[second_begin..])
+        .map(|(second, first)| (2.0 * first) - second)
+        .collect();
+    Ok((first_begin + second_begin, out))
+}
+
+pub(crate) fn ema_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period - 1
+}
+
+pub(crate) fn ema(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    check_params(ema_lookback(time_period), "EMA")?;
+    let period = time_period as usize;
+    Ok(int_ema(start_idx, end_idx, data, period, per_to_k(period)))
+}
+
+/// EMA seeded with the simple average of the first `period` values, as `TA_INT_EMA` does in the default
+/// compatibility mode.
+pub(super) fn int_ema(start_idx: usize, end_idx: usize, input: &[f64], period: usize, k: f64) -> (usize, Vec<f64>) {
+    let lookback = period - 1;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return (0, Vec::new());
+    }
+
+    let mut today = start_idx - lookback;
+    let mut total = 0.0;
+    for _ in 0..period {
+        total += input[today];
+        today += 1;
+    }
+    let mut prev_ma = total / period as f64;
+    while today <= start_idx {
+        prev_ma = ((input[today] - prev_ma) * k) + prev_ma;
+        today += 1;
+    }
+
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    out.push(prev_ma);
+    while today <= end_idx {
+        prev_ma = ((input[today] - prev_ma) * k) + prev_ma;
+        today += 1;
+        out.push(prev_ma);
+    }
+    (start_idx, out)
+}
+
+pub(crate) fn ht_trendline_lookback() -> i32 {
+    63
+}
+
+pub(crate) fn ht_trendline(start_idx: usize, end_idx: usize, data: &[f64]) -> Output {
+    let lookback = ht_trendline_lookback() as usize;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let (mut price_wma, mut today) = PriceWma::warm_up(data, start_idx - lookback, 34);
+    let mut cycle = DominantCycle::new(rad2deg_by_atan());
+    let mut smooth_price = SmoothPrice::new();
+    let mut trendline = Trendline::new();
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    while today <= end_idx {
+        let smoothed = price_wma.next(data, data[today]);
+        smooth_price.set(smoothed);
+        cycle.next(today, smoothed);
+        let value = trendline.next(data, today, cycle.smooth_period);
+        if today >= start_idx {
+            out.push(value);
+        }
+        smooth_price.advance();
+        today += 1;
+    }
+    Ok((start_idx, out))
+}
+
+pub(crate) fn kama_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period
+}
+
+pub(crate) fn kama(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    check_params(kama_lookback(time_period), "KAMA")?;
+    let const_max = 2.0 / (30.0 + 1.0);
+    let const_diff = 2.0 / (2.0 + 1.0) - const_max;
+    let smoothing = |sum_roc1: f64, period_roc: f64| {
+        let mut value = if sum_roc1 <= period_roc || super::is_zero(sum_roc1) {
+            1.0
+        } else {
+            (period_roc / sum_roc1).abs()
+        };
+        value = (value * const_diff) + const_max;
+        value * value
+    };
+
+    let lookback = time_period as usize;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let mut sum_roc1 = 0.0;
+    let mut today = start_idx - lookback;
+    let mut trailing_idx = today;
+    for _ in 0..lookback {
+        sum_roc1 += (data[today] - data[today + 1]).abs();
+        today += 1;
+    }
+
+    let mut prev_kama = data[today - 1];
+    let mut trailing_value = data[trailing_idx];
+    let period_roc = data[today] - trailing_value;
+    trailing_idx += 1;
+    prev_kama = ((data[today] - prev_kama) * smoothing(sum_roc1, period_roc)) + prev_kama;
+    today += 1;
+
+    let mut step = |today: usize, trailing_idx: &mut usize, prev_kama: f64| {
+        let value = data[today];
+        let trailing = data[*trailing_idx];
+        *trailing_idx += 1;
+        let period_roc = value - trailing;
+        sum_roc1 -= (trailing_value - trailing).abs();
+        sum_roc1 += (value - data[today - 1]).abs();
+        trailing_value = trailing;
+        ((value - prev_kama) * smoothing(sum_roc1, period_roc)) + prev_kama
+    };
+    while today <= start_idx {
+        prev_kama = step(today, &mut trailing_idx, prev_kama);
+        today += 1;
+    }
+
+    let begin = today - 1;
+    let mut out = Vec::with_capacity(end_idx - begin + 1);
+    out.push(prev_kama);
+    while today <= end_idx {
+        prev_kama = step(today, &mut trailing_idx, prev_kama);
+        today += 1;
+        out.push(prev_kama);
+    }
+    Ok((begin, out))
+}
+
+pub(crate) fn ma_lookback(time_period: i32, ma_type: MaTypeInt) -> i32 {
+    if !check_period(time_period, 1) || !check_ma_type(ma_type) {
+        return -1;
+    }
+    if time_period <= 1 {
+        return 0;
+    }
+    match i64::from(ma_type) {
+        0 => sma_lookback(time_period),
+        1 => ema_lookback(time_period),
+        2 => wma_lookback(time_period),
+        3 => dema_lookback(time_period),
+        4 => tema_lookback(time_period),
+        5 => trima_lookback(time_period),
+        6 => kama_lookback(time_period),
+        7 => mama_lookback(0.5, 0.05),
+        8 => t3_lookback(time_period, 0.7),
+        _ => 0,
+    }
+}
+
+pub(crate) fn ma(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32, ma_type: MaTypeInt) -> Output {
+    check_params(ma_lookback(time_period, ma_type), "MA")?;
+    if time_period == 1 {
+        return Ok((start_idx, data[start_idx..=end_idx].to_vec()));
+    }
+    match i64::from(ma_type) {
+        0 => sma(start_idx, end_idx, data, time_period),
+        1 => ema(start_idx, end_idx, data, time_period),
+        2 => wma(start_idx, end_idx, data, time_period),
+        3 => dema(start_idx, end_idx, data, time_period),
+        4 => tema(start_idx, end_idx, data, time_period),
+        5 => trima(start_idx, end_idx, data, time_period),
+        6 => kama(start_idx, end_idx, data, time_period),
+        7 => mama(start_idx, end_idx, data, 0.5, 0.05).map(|(begin, mama, _)| (begin, mama)),
+        8 => t3(start_idx, end_idx, data, time_period, 0.7),
+        _ => Err(BadParamSnafu { indicator_name: "MA" }.build()),
+    }
+}
+
+pub(crate) fn mama_lookback(fast_limit: f64, slow_limit: f64) -> i32 {
+    if !check_real(fast_limit, 0.01, 0.99) || !check_real(slow_limit, 0.01, 0.99) {
+        return -1;
+    }
+    32
+}
+
+pub(crate) fn mama(
+    start_idx: usize,
+    end_idx: usize,
+    data: &[f64],
+    fast_limit: f64,
+    slow_limit: f64,
+) -> Result<(usize, Vec<f64>, Vec<f64>), TaLibError> {
+    let lookback = check_params(mama_lookback(fast_limit, slow_limit), "MAMA")?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new(), Vec::new()));
+    }
+
+    let rad2deg = rad2deg_by_pi();
+    let (mut price_wma, mut today) = PriceWma::warm_up(data, start_idx - lookback, 9);
+    let mut cycle = DominantCycle::new(rad2deg);
+    let (mut mama, mut fama, mut prev_phase) = (0.0, 0.0, 0.0);
+    let mut out_mama = Vec::with_capacity(end_idx - start_idx + 1);
+    let mut out_fama = Vec::with_capacity(end_idx - start_idx + 1);
+    while today <= end_idx {
+        let today_value = data[today];
+        let smoothed = price_wma.next(data, today_value);
+        let (q1, i1) = cycle.next(today, smoothed);
+        let phase = if i1 != 0.0 { (q1 / i1).atan() * rad2deg } else { 0.0 };
+
+        let mut alpha = prev_phase - phase;
+        prev_phase = phase;
+        if alpha < 1.0 {
+            alpha = 1.0;
+        }
+        if alpha > 1.0 {
+            alpha = fast_limit / alpha;
+            if alpha < slow_limit {
+                alpha = slow_limit;
+            }
+        } else {
+            alpha = fast_limit;
+        }
+        mama = (alpha * today_value) + ((1.0 - alpha) * mama);
+        alpha *= 0.5;
+        fama = (alpha * mama) + ((1.0 - alpha) * fama);
+        if today >= start_idx {
+            out_mama.push(mama);
+            out_fama.push(fama);
+        }
+        today += 1;
+    }
+    Ok((start_idx, out_mama, out_fama))
+}
+
+pub(crate) fn midpoint_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period - 1
+}
+
+pub(crate) fn midpoint(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(midpoint_lookback(time_period), "MIDPOINT")?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    for today in start_idx..=end_idx {
+        let trailing_idx = today - lookback;
+        let mut lowest = data[trailing_idx];
+        let mut highest = lowest;
+        for &value in &data[trailing_idx + 1..=today] {
+            if value < lowest {
+                lowest = value;
+            } else if value > highest {
+                highest = value;
+            }
+        }
+        out.push((highest + lowest) / 2.0);
+    }
+    Ok((start_idx, out))
+}
+
+pub(crate) fn midprice_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period - 1
+}
+
+pub(crate) fn midprice(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(midprice_lookback(time_period), "MIDPRICE")?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    for today in start_idx..=end_idx {
+        let trailing_idx = today - lookback;
+        let mut lowest = low[trailing_idx];
+        let mut highest = high[trailing_idx];
+        for i in trailing_idx + 1..=today {
+            if low[i] < lowest {
+                lowest = low[i];
+            }
+            if high[i] > highest {
+                highest = high[i];
+            }
+        }
+        out.push((highest + lowest) / 2.0);
+    }
+    Ok((start_idx, out))
+}
+
+pub(crate) fn sar_lookback(acceleration: f64, maximum: f64) -> i32 {
+    if !check_real(acceleration, 0.0, MAX_REAL) || !check_real(maximum, 0.0, MAX_REAL) {
+        return -1;
+    }
+    1
+}
+
+pub(crate) fn sar(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], acceleration: f64, maximum: f64) -> Output {
+    check_params(sar_lookback(acceleration, maximum), "SAR")?;
+    let acceleration = acceleration.min(maximum);
+    Ok(parabolic_sar(
+        start_idx,
+        end_idx,
+        high,
+        low,
+        SarParams {
+            start_value: 0.0,
+            offset_on_reverse: 0.0,
+            init_long: acceleration,
+            long: acceleration,
+            max_long: maximum,
+            init_short: acceleration,
+            short: acceleration,
+            max_short: maximum,
+            signed_short: false,
+        },
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn sarext_lookback(
+    start_value: f64,
+    offset_on_reverse: f64,
+    acceleration_init_long: f64,
+    acceleration_long: f64,
+    acceleration_max_long: f64,
+    acceleration_init_short: f64,
+    acceleration_short: f64,
+    acceleration_max_short: f64,
+) -> i32 {
+    let positive = [
+        offset_on_reverse,
+        acceleration_init_long,
+        acceleration_long,
+        acceleration_max_long,
+        acceleration_init_short,
+        acceleration_short,
+        acceleration_max_short,
+    ];
+    if !check_real(start_value, -MAX_REAL, MAX_REAL) || positive.iter().any(|value| !check_real(*value, 0.0, MAX_REAL)) {
+        return -1;
+    }
+    1
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn sarext(
+    start_idx: usize,
+    end_idx: usize,
+    high: &[f64],
+    low: &[f64],
+    start_value: f64,
+    offset_on_reverse: f64,
+    acceleration_init_long: f64,
+    acceleration_long: f64,
+    acceleration_max_long: f64,
+    acceleration_init_short: f64,
+    acceleration_short: f64,
+    acceleration_max_short: f64,
+) -> Output {
+    check_params(
+        sarext_lookback(
+            start_value,
+            offset_on_reverse,
+            acceleration_init_long,
+            acceleration_long,
+            acceleration_max_long,
+            acceleration_init_short,
+            acceleration_short,
+            acceleration_max_short,
+        ),
+        "SAREXT",
+    )?;
+    Ok(parabolic_sar(
+        start_idx,
+        end_idx,
+        high,
+        low,
+        SarParams {
+            start_value,
+            offset_on_reverse,
+            init_long: acceleration_init_long.min(acceleration_max_long),
+            long: acceleration_long.min(acceleration_max_long),
+            max_long: acceleration_max_long,
+            init_short: acceleration_init_short.min(acceleration_max_short),
+            short: acceleration_short.min(acceleration_max_short),
+            max_short: acceleration_max_short,
+            signed_short: true,
+        },
+    ))
+}
+
+struct SarParams {
+    start_value: f64,
+    offset_on_reverse: f64,
+    init_long: f64,
+    long: f64,
+    max_long: f64,
+    init_short: f64,
+    short: f64,
+    max_short: f64,
+    /// SAREXT reports the short positions as negative values.
+    signed_short: bool,
+}
+
+/// Shared body of SAR and SAREXT, SAR being SAREXT with symmetric accelerations and no start value or offset.
+fn parabolic_sar(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], params: SarParams) -> (usize, Vec<f64>) {
+    let start_idx = start_idx.max(1);
+    if start_idx > end_idx {
+        return (0, Vec::new());
+    }
+    let short_sign = if params.signed_short { -1.0 } else { 1.0 };
+
+    let mut is_long = if params.start_value == 0.0 {
+        // The direction of the first bar decides the initial position.
+        minus_dm_1(start_idx, high, low) <= 0.0
+    } else {
+        params.start_value > 0.0
+    };
+
+    let mut today = start_idx;
+    let (mut ep, mut sar) = if params.start_value == 0.0 {
+        if is_long {
+            (high[today], low[today - 1])
+        } else {
+            (low[today], high[today - 1])
+        }
+    } else if params.start_value > 0.0 {
+        (high[today], params.start_value)
+    } else {
+        (low[today], params.start_value.abs())
+    };
+
+    let (mut af_long, mut af_short) = (params.init_long, params.init_short);
+    let mut new_low = low[today];
+    let mut new_high = high[today];
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    while today <= end_idx {
+        let prev_low = new_low;
+        let prev_high = new_high;
+        new_low = low[today];
+        new_high = high[today];
+        today += 1;
+
+        if is_long {
+            if new_low <= sar {
+                // Switch to short.
+                is_long = false;
+                sar = ep.max(prev_high).max(new_high);
+                if params.offset_on_reverse != 0.0 {
+                    sar += sar * params.offset_on_reverse;
+                }
+                out.push(sar * short_sign);
+                af_short = params.init_short;
+                ep = new_low;
+                sar = sar + af_short * (ep - sar);
+                sar = sar.max(prev_high).max(new_high);
+            } else {
+                out.push(sar);
+                if new_high > ep {
+                    ep = new_high;
+                    af_long += params.long;
+                    if af_long > params.max_long {
+                        af_long = params.max_long;
+                    }
+                }
+                sar = sar + af_long * (ep - sar);
+                sar = sar.min(prev_low).min(new_low);
+            }
+        } else if new_high >= sar {
+            // Switch to long.
+            is_long = true;
+            sar = ep.min(prev_low).min(new_low);
+            if params.offset_on_reverse != 0.0 {
+                sar -= sar * params.offset_on_reverse;
+            }
+            out.push(sar);
+            af_long = params.init_long;
+            ep = new_high;
+            sar = sar + af_long * (ep - sar);
+            sar = sar.min(prev_low).min(new_low);
+        } else {
+            out.push(sar * short_sign);
+            if new_low < ep {
+                ep = new_low;
+                af_short += params.short;
+                if af_short > params.max_short {
+                    af_short = params.max_short;
+                }
+            }
+            sar = sar + af_short * (ep - sar);
+            sar = sar.max(prev_high).max(new_high);
+        }
+    }
+    (start_idx, out)
+}
+
+/// `TA_MINUS_DM` with a period of 1 evaluated on a single bar.
+fn minus_dm_1(idx: usize, high: &[f64], low: &[f64]) -> f64 {
+    minus_dm(idx, idx, high, low, 1)
+        .ok()
+        .and_then(|(_, values)| values.first().copied())
+        .unwrap_or(0.0)
+}
+
+pub(crate) fn sma_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period - 1
+}
+
+pub(crate) fn sma(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    check_params(sma_lookback(time_period), "SMA")?;
+    Ok(int_sma(start_idx, end_idx, data, time_period as usize))
+}
+
+pub(super) fn int_sma(start_idx: usize, end_idx: usize, input: &[f64], period: usize) -> (usize, Vec<f64>) {
+    let lookback = period - 1;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return (0, Vec::new());
+    }
+
+    let mut total = 0.0;
+    let mut trailing_idx = start_idx - lookback;
+    let mut i = trailing_idx;
+    while i < start_idx {
+        total += input[i];
+        i += 1;
+    }
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    while i <= end_idx {
+        total += input[i];
+        i += 1;
+        let value = total;
+        total -= input[trailing_idx];
+        trailing_idx += 1;
+        out.push(value / period as f64);
+    }
+    (start_idx, out)
+}
+
+/// Standard deviation of the bars around a simple moving average that has already been calculated
+/// (`TA_INT_stddev_using_precalc_ma`).
+fn stddev_using_precalc_ma(input: &[f64], moving_average: &[f64], ma_begin: usize, period: usize) -> Vec<f64> {
+    let mut start_sum = 1 + ma_begin - period;
+    let mut end_sum = ma_begin;
+    let mut total2 = 0.0;
+    for value in &input[start_sum..end_sum] {
+        total2 += value * value;
+    }
+
+    let mut out = Vec::with_capacity(moving_average.len());
+    for average in moving_average {
+        let value = input[end_sum];
+        total2 += value * value;
+        let mut mean2 = total2 / period as f64;
+        let value = input[start_sum];
+        total2 -= value * value;
+        mean2 -= average * average;
+        out.push(if !is_zero_or_neg(mean2) { mean2.sqrt() } else { 0.0 });
+        start_sum += 1;
+        end_sum += 1;
+    }
+    out
+}
+
+pub(super) fn int_stddev(start_idx: usize, end_idx: usize, input: &[f64], period: usize, nb_dev: f64) -> (usize, Vec<f64>) {
+    let (begin, mut out) = int_var(start_idx, end_idx, input, period);
+    for value in out.iter_mut() {
+        *value = if !is_zero_or_neg(*value) { value.sqrt() * nb_dev } else { 0.0 };
+    }
+    (begin, out)
+}
+
+pub(super) fn int_var(start_idx: usize, end_idx: usize, input: &[f64], period: usize) -> (usize, Vec<f64>) {
+    let lookback = period - 1;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return (0, Vec::new());
+    }
+
+    let (mut total1, mut total2) = (0.0, 0.0);
+    let mut trailing_idx = start_idx - lookback;
+    let mut i = trailing_idx;
+    while i < start_idx {
+        let value = input[i];
+        total1 += value;
+        total2 += value * value;
+        i += 1;
+    }
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    while i <= end_idx {
+        let value = input[i];
+        i += 1;
+        total1 += value;
+        total2 += value * value;
+        let mean1 = total1 / period as f64;
+        let mean2 = total2 / period as f64;
+        let value = input[trailing_idx];
+        trailing_idx += 1;
+        total1 -= value;
+        total2 -= value * value;
+        out.push(mean2 - mean1 * mean1);
+    }
+    (start_idx, out)
+}
+
+pub(crate) fn t3_lookback(time_period: i32, v_factor: f64) -> i32 {
+    if !check_period(time_period, 2) || !check_real(v_factor, 0.0, 1.0) {
+        return -1;
+    }
+    6 * (time_period - 1)
+}
+
+pub(crate) fn t3(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32, v_factor: f64) -> Output {
+    let lookback = check_params(t3_lookback(time_period, v_factor), "T3")?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let period = time_period as usize;
+    let k = 2.0 / (time_period as f64 + 1.0);
+    let one_minus_k = 1.0 - k;
+    let mut today = start_idx - lookback;
+    let mut next = || {
+        let value = data[today];
+        today += 1;
+        value
+    };
+
+    // Each EMA of the cascade is seeded with the average of the first `period` values of the previous one.
+    let mut e = [0.0; 6];
+    let mut total = 0.0;
+    for _ in 0..period {
+        total += next();
+    }
+    e[0] = total / period as f64;
+    for level in 1..6 {
+        let mut total = e[level - 1];
+        for _ in 1..period {
+            e[0] = (k * next()) + (one_minus_k * e[0]);
+            for j in 1..level {
+                e[j] = (k * e[j - 1]) + (one_minus_k * e[j]);
+            }
+            total += e[level - 1];
+        }
+        e[level] = total / period as f64;
+    }
+
+    let step = |e: &mut [f64; 6], value: f64| {
+        e[0] = (k * value) + (one_minus_k * e[0]);
+        for j in 1..6 {
+            e[j] = (k * e[j - 1]) + (one_minus_k * e[j]);
+        }
+    };
+    while today <= start_idx {
+        step(&mut e, data[today]);
+        today += 1;
+    }
+
+    let temp = v_factor * v_factor;
+    let c1 = -(temp * v_factor);
+    let c2 = 3.0 * (temp - c1);
+    let c3 = -6.0 * temp - 3.0 * (v_factor - c1);
+    let c4 = 1.0 + 3.0 * v_factor - c1 + 3.0 * temp;
+    let t3 = |e: &[f64; 6]| c1 * e[5] + c2 * e[4] + c3 * e[3] + c4 * e[2];
+
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    out.push(t3(&e));
+    while today <= end_idx {
+        step(&mut e, data[today]);
+        today += 1;
+        out.push(t3(&e));
+    }
+    Ok((start_idx, out))
+}
+
+pub(crate) fn tema_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    ema_lookback(time_period) * 3
+}
+
+pub(crate) fn tema(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    check_params(tema_lookback(time_period), "TEMA")?;
+    let period = time_period as usize;
+    let lookback_ema = period - 1;
+    let start_idx = start_idx.max(lookback_ema * 3);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let k = per_to_k(period);
+    let (first_begin, first_ema) = int_ema(start_idx - lookback_ema * 2, end_idx, data, period, k);
+    if first_ema.is_empty() {
+        return Ok((0, Vec::new()));
+    }
+    let (second_begin, second_ema) = int_ema(0, first_ema.len() - 1, &first_ema, period, k);
+    if second_ema.is_empty() {
+        return Ok((0, Vec::new()));
+    }
+    let (third_begin, third_ema) = int_ema(0, second_ema.len() - 1, &second_ema, period, k);
+    if third_ema.is_empty() {
+        return Ok((0, Vec::new()));
+    }
+
+    let first_idx = third_begin + second_begin;
+    let out = third_ema
+        .iter()
+        .zip(&first_ema[first_idx..])
+        .zip(&second_ema[third_begin..])
+        .map(|((third, first), second)| third + ((3.0 * first) - (3.0 * second)))
+        .collect();
+    Ok((first_idx + first_begin, out))
+}
+
+pub(crate) fn trima_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period - 1
+}
+
+pub(crate) fn trima(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(trima_lookback(time_period), "TRIMA")?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let period = time_period as usize;
+    let half = period >> 1;
+    let odd = period % 2 == 1;
+    let factor = if odd {
+        1.0 / ((half + 1) * (half + 1)) as f64
+    } else {
+        1.0 / (half * (half + 1)) as f64
+    };
+
+    let mut trailing_idx = start_idx - lookback;
+    let mut middle_idx = if odd { trailing_idx + half } else { trailing_idx + half - 1 };
+    let mut today_idx = middle_idx + half;
+    let mut numerator = 0.0;
+    let mut numerator_sub = 0.0;
+    for i in (trailing_idx..=middle_idx).rev() {
+        numerator_sub += data[i];
+        numerator += numerator_sub;
+    }
+    let mut numerator_add = 0.0;
+    middle_idx += 1;
+    for value in &data[middle_idx..=today_idx] {
+        numerator_add += value;
+        numerator += numerator_add;
+    }
+
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    let mut value = data[trailing_idx];
+    trailing_idx += 1;
+    out.push(numerator * factor);
+    today_idx += 1;
+    while today_idx <= end_idx {
+        numerator -= numerator_sub;
+        numerator_sub -= value;
+        value = data[middle_idx];
+        middle_idx += 1;
+        numerator_sub += value;
+        // The odd and even versions differ in when the middle value leaves the adding half.
+        if odd {
+            numerator += numerator_add;
+            numerator_add -= value;
+        } else {
+            numerator_add -= value;
+            numerator += numerator_add;
+        }
+        value = data[today_idx];
+        today_idx += 1;
+        numerator_add += value;
+        numerator += value;
+        value = data[trailing_idx];
+        trailing_idx += 1;
+        out.push(numerator * factor);
+    }
+    Ok((start_idx, out))
+}
+
+pub(crate) fn wma_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 2) {
+        return -1;
+    }
+    time_period - 1
+}
+
+pub(crate) fn wma(start_idx: usize, end_idx: usize, data: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(wma_lookback(time_period), "WMA")?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let period = time_period as usize;
+    let divider = ((period * (period + 1)) >> 1) as f64;
+    let mut trailing_idx = start_idx - lookback;
+    let (mut period_sum, mut period_sub) = (0.0, 0.0);
+    for (weight, value) in data[trailing_idx..start_idx].iter().enumerate() {
+        period_sub += value;
+        period_sum += value * (weight + 1) as f64;
+    }
+
+    let mut trailing_value = 0.0;
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    for value in &data[start_idx..=end_idx] {
+        period_sub += value;
+        period_sub -= trailing_value;
+        period_sum += value * period as f64;
+        trailing_value = data[trailing_idx];
+        trailing_idx += 1;
+        out.push(period_sum / divider);
+        period_sum -= period_sub;
+    }
+    Ok((start_idx, out))
+}
+
+#[cfg(all(test, not(feature = "pure-rust")))]
+mod tests {
+    use super::super::test_data::ohlcv;
+    use super::*;
+
+    #[test]
+    fn test_moving_averages_match_talib() {
+        let data = ohlcv(400);
+        for period in [2, 3, 10, 30] {
+            assert_parity!(SMA => sma, [data.close], [period], [sma: f64]);
+            assert_parity!(EMA => ema, [data.close], [period], [ema: f64]);
+            assert_parity!(WMA => wma, [data.close], [period], [wma: f64]);
+            assert_parity!(DEMA => dema, [data.close], [period], [dema: f64]);
+            assert_parity!(TEMA => tema, [data.close], [period], [tema: f64]);
+            assert_parity!(TRIMA => trima, [data.close], [period], [trima: f64]);
+            assert_parity!(KAMA => kama, [data.close], [period], [kama: f64]);
+            assert_parity!(T3 => t3, [data.close], [period, 0.7], [t3: f64]);
+            assert_parity!(MIDPOINT => midpoint, [data.close], [period], [midpoint: f64]);
+            assert_parity!(MIDPRICE => midprice, [data.high, data.low], [period], [midprice: f64]);
+            for ma_type in 0..=8 {
+                assert_parity!(MA => ma, [data.close], [period, ma_type], [ma: f64]);
+                assert_parity!(
+                    BBANDS => bbands,
+                    [data.close],
+                    [period, 2.0, 1.5, ma_type],
+                    [upper: f64, middle: f64, lower: f64]
+                );
+            }
+        }
+        assert_parity!(MA => ma, [data.close], [1, 0], [ma: f64]);
+    }
+
+    #[test]
+    fn test_adaptive_averages_match_talib() {
+        let data = ohlcv(400);
+        assert_parity!(MAMA => mama, [data.close], [0.5, 0.05], [mama: f64, fama: f64]);
+        assert_parity!(HT_TRENDLINE => ht_trendline, [data.close], [], [trendline: f64]);
+        assert_parity!(SAR => sar, [data.high, data.low], [0.02, 0.2], [sar: f64]);
+        assert_parity!(
+            SAREXT => sarext,
+            [data.high, data.low],
+            [0.0, 0.01, 0.02, 0.02, 0.2, 0.03, 0.03, 0.25],
+            [sarext: f64]
+        );
+        assert_parity!(
+            SAREXT => sarext,
+            [data.high, data.low],
+            [-95.0, 0.0, 0.02, 0.02, 0.2, 0.02, 0.02, 0.2],
+            [sarext: f64]
+        );
+    }
+
+    #[test]
+    fn test_invalid_period_is_rejected() {
+        let data = ohlcv(50);
+        assert_eq!(sma_lookback(1), -1);
+        assert!(matches!(sma(0, 49, &data.close, 1), Err(TaLibError::BadParam { .. })));
+
+        // The indicator wrappers report the out of range period instead of a lookback mismatch
+        let datetime_list = vec![chrono::Utc::now(); data.close.len()];
+        assert!(matches!(
+            crate::talib::TALib::sma(&datetime_list, &data.close, 1),
+            Err(TaLibError::BadParam { .. })
+        ));
+    }
+}
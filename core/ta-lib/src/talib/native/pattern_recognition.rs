@@ -0,0 +1,99 @@
+//! Candlestick patterns are not ported yet, with the `pure-rust` feature they report the indicator as unsupported
+//! instead of silently returning no signal.
+
+use crate::error::{TaLibError, UnsupportTypeSnafu};
+
+type Output = Result<(usize, Vec<i32>), TaLibError>;
+
+macro_rules! unsupported_pattern {
+    ($($name:ident),+ $(,)?) => {
+        paste::paste! {$(
+            pub(crate) fn [<$name _lookback>]() -> i32 {
+                0
+            }
+
+            pub(crate) fn $name(_: usize, _: usize, _: &[f64], _: &[f64], _: &[f64], _: &[f64]) -> Output {
+                UnsupportTypeSnafu { indicator_type: stringify!([<$name:upper>]) }.fail()
+            }
+        )+}
+    };
+    (penetration: $($name:ident),+ $(,)?) => {
+        paste::paste! {$(
+            pub(crate) fn [<$name _lookback>](_: f64) -> i32 {
+                0
+            }
+
+            pub(crate) fn $name(_: usize, _: usize, _: &[f64], _: &[f64], _: &[f64], _: &[f64], _: f64) -> Output {
+                UnsupportTypeSnafu { indicator_type: stringify!([<$name:upper>]) }.fail()
+            }
+        )+}
+    };
+}
+
+unsupported_pattern!(
+    cdl2crows,
+    cdl3blackcrows,
+    cdl3inside,
+    cdl3linestrike,
+    cdl3outside,
+    cdl3starsinsouth,
+    cdl3whitesoldiers,
+    cdladvanceblock,
+    cdlbelthold,
+    cdlbreakaway,
+    cdlclosingmarubozu,
+    cdlconcealbabyswall,
+    cdlcounterattack,
+    cdldoji,
+    cdldojistar,
+    cdldragonflydoji,
+    cdlengulfing,
+    cdlgapsidesidewhite,
+    cdlgravestonedoji,
+    cdlhammer,
+    cdlhangingman,
+    cdlharami,
+    cdlharamicross,
+    cdlhighwave,
+    cdlhikkake,
+    cdlhikkakemod,
+    cdlhomingpigeon,
+    cdlidentical3crows,
+    cdlinneck,
+    cdlinvertedhammer,
+    cdlkicking,
+    cdlkickingbylength,
+    cdlladderbottom,
+    cdllongleggeddoji,
+    cdllongline,
+    cdlmarubozu,
+    cdlmatchinglow,
+    cdlonneck,
+    cdlpiercing,
+    cdlrickshawman,
+    cdlrisefall3methods,
+    cdlseparatinglines,
+    cdlshootingstar,
+    cdlshortline,
+    cdlspinningtop,
+    cdlstalledpattern,
+    cdlsticksandwich,
+    cdltakuri,
+    cdltasukigap,
+    cdlthrusting,
+    cdltristar,
+    cdlunique3river,
+    cdlupsidegap2crows,
+    cdlxsidegap3methods,
+);
+
+unsupported_pattern!(
+    penetration:
+    cdlabandonedbaby,
+    cdldarkcloudcover,
+    cdleveningdojistar,
+    cdleveningstar,
+    cdlmathold,
+    cdlmorningdojistar,
+    cdlmorningstar,
+);
@@ -0,0 +1,57 @@
+use crate::error::TaLibError;
+
+type Output = Result<(usize, Vec<f64>), TaLibError>;
+
+fn per_bar(start_idx: usize, end_idx: usize, value: impl Fn(usize) -> f64) -> Output {
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+    Ok((start_idx, (start_idx..=end_idx).map(value).collect()))
+}
+
+pub(crate) fn avgprice_lookback() -> i32 {
+    0
+}
+
+pub(crate) fn avgprice(start_idx: usize, end_idx: usize, open: &[f64], high: &[f64], low: &[f64], close: &[f64]) -> Output {
+    per_bar(start_idx, end_idx, |i| (high[i] + low[i] + close[i] + open[i]) / 4.0)
+}
+
+pub(crate) fn medprice_lookback() -> i32 {
+    0
+}
+
+pub(crate) fn medprice(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64]) -> Output {
+    per_bar(start_idx, end_idx, |i| (high[i] + low[i]) / 2.0)
+}
+
+pub(crate) fn typprice_lookback() -> i32 {
+    0
+}
+
+pub(crate) fn typprice(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], close: &[f64]) -> Output {
+    per_bar(start_idx, end_idx, |i| (high[i] + low[i] + close[i]) / 3.0)
+}
+
+pub(crate) fn wclprice_lookback() -> i32 {
+    0
+}
+
+pub(crate) fn wclprice(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], close: &[f64]) -> Output {
+    per_bar(start_idx, end_idx, |i| (high[i] + low[i] + (close[i] * 2.0)) / 4.0)
+}
+
+#[cfg(all(test, not(feature = "pure-rust")))]
+mod tests {
+    use super::super::test_data::ohlcv;
+    use super::*;
+
+    #[test]
+    fn test_price_transform_matches_talib() {
+        let data = ohlcv(100);
+        assert_parity!(AVGPRICE => avgprice, [data.open, data.high, data.low, data.close], [], [avgprice: f64]);
+        assert_parity!(MEDPRICE => medprice, [data.high, data.low], [], [medprice: f64]);
+        assert_parity!(TYPPRICE => typprice, [data.high, data.low, data.close], [], [typprice: f64]);
+        assert_parity!(WCLPRICE => wclprice, [data.high, data.low, data.close], [], [wclprice: f64]);
+    }
+}
@@ -0,0 +1,90 @@
+//! Shared fixtures for the parity tests between the native port and the C library.
+
+pub(super) struct Ohlcv {
+    pub open: Vec<f64>,
+    pub high: Vec<f64>,
+    pub low: Vec<f64>,
+    pub close: Vec<f64>,
+    pub volume: Vec<f64>,
+}
+
+/// Deterministic pseudo-random walk with a cyclic component, so that the Hilbert based indicators have
+/// something to lock onto.
+pub(super) fn ohlcv(len: usize) -> Ohlcv {
+    let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed % 10_000) as f64 / 10_000.0
+    };
+
+    let mut data = Ohlcv {
+        open: Vec::with_capacity(len),
+        high: Vec::with_capacity(len),
+        low: Vec::with_capacity(len),
+        close: Vec::with_capacity(len),
+        volume: Vec::with_capacity(len),
+    };
+    let mut price = 100.0;
+    for i in 0..len {
+        let open = price;
+        let close = open + (next() - 0.5) * 4.0 + (i as f64 / 12.0).sin() * 1.5;
+        let high = open.max(close) + next() * 2.0;
+        let low = open.min(close) - next() * 2.0;
+        data.open.push(open);
+        data.high.push(high);
+        data.low.push(low);
+        data.close.push(close);
+        data.volume.push(1_000.0 + next() * 5_000.0);
+        price = close;
+    }
+    data
+}
+
+/// Compares the full-length C output (values written from `lookback` onwards) with the compacted native output.
+pub(super) fn compare<T: Into<f64> + Copy>(name: &str, lookback: usize, expected: &[T], actual: &[T]) {
+    assert_eq!(expected.len() - lookback, actual.len(), "{name}: output length mismatch");
+    for (i, (expected, actual)) in expected[lookback..].iter().zip(actual).enumerate() {
+        let (expected, actual): (f64, f64) = ((*expected).into(), (*actual).into());
+        let tolerance = 1e-9 * expected.abs().max(1.0);
+        assert!(
+            (expected - actual).abs() <= tolerance,
+            "{name}: mismatch at {}: expected {expected}, got {actual}",
+            i + lookback
+        );
+    }
+}
+
+/// Runs `TA_<c_name>` and the native function over the same inputs, then checks the lookback, the begin
+/// index and every output value.
+macro_rules! assert_parity {
+    ($c_name:ident => $native:ident, [$($input:expr),+], [$($param:expr),*], [$($output:ident: $output_type:ty),+] $(,)?) => {
+        paste::paste! {{
+            let len = [$($input.len()),+][0];
+            let lookback = unsafe { crate::talib_bindings::[<TA_ $c_name _Lookback>]($($param),*) };
+            assert_eq!(lookback, [<$native _lookback>]($($param),*), "{}: lookback mismatch", stringify!($c_name));
+            let lookback = lookback as usize;
+
+            let mut out_begin = 0;
+            let mut out_size = 0;
+            $(let mut [<talib_ $output>]: Vec<$output_type> = vec![Default::default(); len];)+
+            let ret = unsafe {
+                crate::talib_bindings::[<TA_ $c_name>](
+                    0,
+                    (len - 1) as _,
+                    $($input.as_ptr(),)+
+                    $($param,)*
+                    &mut out_begin,
+                    &mut out_size,
+                    $([<talib_ $output>].as_mut_ptr().add(lookback),)+
+                )
+            };
+            assert_eq!(ret, crate::talib_bindings::TA_RetCode_TA_SUCCESS, "{}: C call failed", stringify!($c_name));
+
+            let (begin, $([<native_ $output>],)+) = $native(0, len - 1, $(&$input,)+ $($param,)*).unwrap();
+            assert_eq!(out_begin as usize, begin, "{}: begin index mismatch", stringify!($c_name));
+            $(crate::talib::native::test_data::compare(stringify!($c_name), lookback, &[<talib_ $output>], &[<native_ $output>]);)+
+        }}
+    };
+}
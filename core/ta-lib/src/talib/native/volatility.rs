@@ -0,0 +1,105 @@
+use super::{check_params, check_period, is_zero, true_range};
+use crate::error::TaLibError;
+
+type Output = Result<(usize, Vec<f64>), TaLibError>;
+
+pub(crate) fn atr_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 1) {
+        return -1;
+    }
+    time_period
+}
+
+pub(crate) fn atr(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], close: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(atr_lookback(time_period), "ATR")?;
+    if lookback <= 1 {
+        return trange(start_idx, end_idx, high, low, close);
+    }
+    Ok(int_atr(start_idx, end_idx, high, low, close, lookback, |_, atr| atr))
+}
+
+pub(crate) fn natr_lookback(time_period: i32) -> i32 {
+    if !check_period(time_period, 1) {
+        return -1;
+    }
+    time_period
+}
+
+pub(crate) fn natr(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], close: &[f64], time_period: i32) -> Output {
+    let lookback = check_params(natr_lookback(time_period), "NATR")?;
+    // Like TA-Lib, a period of 1 is the plain true range, not normalized by the close.
+    if lookback <= 1 {
+        return trange(start_idx, end_idx, high, low, close);
+    }
+    Ok(int_atr(start_idx, end_idx, high, low, close, lookback, |today, atr| {
+        if !is_zero(close[today]) {
+            (atr / close[today]) * 100.0
+        } else {
+            0.0
+        }
+    }))
+}
+
+/// Wilder-smoothed true range shared by ATR and NATR for periods above 1, `value` maps the average of bar `today` to the output.
+fn int_atr(
+    start_idx: usize,
+    end_idx: usize,
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    period: usize,
+    value: impl Fn(usize, f64) -> f64,
+) -> (usize, Vec<f64>) {
+    let start_idx = start_idx.max(period);
+    if start_idx > end_idx {
+        return (0, Vec::new());
+    }
+    // The first average is the plain mean of the `period` true ranges before `start_idx`.
+    let first = start_idx - period + 1;
+    let mut prev_atr = 0.0;
+    for today in first..first + period {
+        prev_atr += true_range(high[today], low[today], close[today - 1]);
+    }
+    prev_atr /= period as f64;
+
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    out.push(value(start_idx, prev_atr));
+    for today in start_idx + 1..=end_idx {
+        prev_atr *= (period - 1) as f64;
+        prev_atr += true_range(high[today], low[today], close[today - 1]);
+        prev_atr /= period as f64;
+        out.push(value(today, prev_atr));
+    }
+    (start_idx, out)
+}
+
+pub(crate) fn trange_lookback() -> i32 {
+    1
+}
+
+pub(crate) fn trange(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], close: &[f64]) -> Output {
+    let start_idx = start_idx.max(1);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+    let out = (start_idx..=end_idx)
+        .map(|today| true_range(high[today], low[today], close[today - 1]))
+        .collect();
+    Ok((start_idx, out))
+}
+
+#[cfg(all(test, not(feature = "pure-rust")))]
+mod tests {
+    use super::super::test_data::ohlcv;
+    use super::*;
+
+    #[test]
+    fn test_volatility_matches_talib() {
+        let data = ohlcv(300);
+        assert_parity!(TRANGE => trange, [data.high, data.low, data.close], [], [trange: f64]);
+        for period in [1, 2, 14, 30] {
+            assert_parity!(ATR => atr, [data.high, data.low, data.close], [period], [atr: f64]);
+            assert_parity!(NATR => natr, [data.high, data.low, data.close], [period], [natr: f64]);
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use super::overlap::ema_lookback;
+use super::{check_params, check_period, per_to_k};
+use crate::error::TaLibError;
+
+type Output = Result<(usize, Vec<f64>), TaLibError>;
+
+/// Accumulation/distribution of one bar, nothing is added when the bar has no range.
+fn money_flow_volume(high: f64, low: f64, close: f64, volume: f64) -> f64 {
+    let range = high - low;
+    if range > 0.0 {
+        (((close - low) - (high - close)) / range) * volume
+    } else {
+        0.0
+    }
+}
+
+pub(crate) fn ad_lookback() -> i32 {
+    0
+}
+
+pub(crate) fn ad(start_idx: usize, end_idx: usize, high: &[f64], low: &[f64], close: &[f64], volume: &[f64]) -> Output {
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+    let mut ad = 0.0;
+    let out = (start_idx..=end_idx)
+        .map(|today| {
+            ad += money_flow_volume(high[today], low[today], close[today], volume[today]);
+            ad
+        })
+        .collect();
+    Ok((start_idx, out))
+}
+
+pub(crate) fn adosc_lookback(fast_period: i32, slow_period: i32) -> i32 {
+    if !check_period(fast_period, 2) || !check_period(slow_period, 2) {
+        return -1;
+    }
+    ema_lookback(fast_period.max(slow_period))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn adosc(
+    start_idx: usize,
+    end_idx: usize,
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    volume: &[f64],
+    fast_period: i32,
+    slow_period: i32,
+) -> Output {
+    let lookback = check_params(adosc_lookback(fast_period, slow_period), "ADOSC")?;
+    let start_idx = start_idx.max(lookback);
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+
+    let fast_k = per_to_k(fast_period as usize);
+    let slow_k = per_to_k(slow_period as usize);
+    let mut today = start_idx - lookback;
+    let mut ad = money_flow_volume(high[today], low[today], close[today], volume[today]);
+    today += 1;
+    let (mut fast_ema, mut slow_ema) = (ad, ad);
+
+    let mut out = Vec::with_capacity(end_idx - start_idx + 1);
+    while today <= end_idx {
+        ad += money_flow_volume(high[today], low[today], close[today], volume[today]);
+        fast_ema = (fast_k * ad) + ((1.0 - fast_k) * fast_ema);
+        slow_ema = (slow_k * ad) + ((1.0 - slow_k) * slow_ema);
+        if today >= start_idx {
+            out.push(fast_ema - slow_ema);
+        }
+        today += 1;
+    }
+    Ok((start_idx, out))
+}
+
+pub(crate) fn obv_lookback() -> i32 {
+    0
+}
+
+pub(crate) fn obv(start_idx: usize, end_idx: usize, close: &[f64], volume: &[f64]) -> Output {
+    if start_idx > end_idx {
+        return Ok((0, Vec::new()));
+    }
+    let mut prev_obv = volume[start_idx];
+    let mut prev_close = close[start_idx];
+    let out = (start_idx..=end_idx)
+        .map(|today| {
+            if close[today] > prev_close {
+                prev_obv += volume[today];
+            } else if close[today] < prev_close {
+                prev_obv -= volume[today];
+            }
+            prev_close = close[today];
+            prev_obv
+        })
+        .collect();
+    Ok((start_idx, out))
+}
+
+#[cfg(all(test, not(feature = "pure-rust")))]
+mod tests {
+    use super::super::test_data::ohlcv;
+    use super::*;
+
+    #[test]
+    fn test_volume_matches_talib() {
+        let data = ohlcv(300);
+        assert_parity!(AD => ad, [data.high, data.low, data.close, data.volume], [], [ad: f64]);
+        assert_parity!(OBV => obv, [data.close, data.volume], [], [obv: f64]);
+        for (fast, slow) in [(3, 10), (10, 3), (2, 2)] {
+            assert_parity!(ADOSC => adosc, [data.high, data.low, data.close, data.volume], [fast, slow], [adosc: f64]);
+        }
+    }
+}
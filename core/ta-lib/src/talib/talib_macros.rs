@@ -10,12 +10,22 @@ macro_rules! talib_fn {
         paste::paste! {
             pub fn [<$indicator_name:lower>]($datetime_field: $datetime_type, $input_field: $input_type, $($param_field: $param_type),*) -> Result<Vec<Indicator>, crate::error::TaLibError> {
                 let input_size = $input_field.len();
+                #[cfg(not(feature = "pure-rust"))]
                 let lookback = unsafe {
                     [<TA_ $indicator_name:upper _Lookback>]
                     (
                         $($param_field),*
-                    ) as usize
+                    )
                 };
+                #[cfg(feature = "pure-rust")]
+                let lookback = crate::talib::native::[<$indicator_name:lower _lookback>]($($param_field),*);
+                // A negative lookback means a parameter is out of range
+                if lookback < 0 {
+                    return Err(crate::error::BadParamSnafu {
+                        indicator_name: stringify!($indicator_name).to_string(),
+                    }.build());
+                }
+                let lookback = lookback as usize;
                 if input_size <= lookback {
                     return Err(crate::error::DataLessThenLookbackSnafu {
                         indicator_name: stringify!($indicator_name).to_string(),
@@ -24,6 +34,7 @@ macro_rules! talib_fn {
                     }.build());
                 }
 
+                #[cfg(not(feature = "pure-rust"))]
                 let ($([<out_ $output_name>],)*) = crate::execute_talib_function!(
                     $indicator_name,
                     input_size,
@@ -32,6 +43,15 @@ macro_rules! talib_fn {
                     [$($param_field),*],
                     [$($output_name: $output_type),*]
                 );
+                #[cfg(feature = "pure-rust")]
+                let ($([<out_ $output_name>],)*) = crate::execute_native_function!(
+                    [<$indicator_name:lower>],
+                    input_size,
+                    lookback,
+                    [$input_field],
+                    [$($param_field),*],
+                    [$($output_name: $output_type),*]
+                );
 
                 let result: Vec<Indicator> = (0..input_size)
                     .map(|i| $indicator_name {
@@ -80,12 +100,22 @@ macro_rules! talib_fn {
                 // If all equal, take the first value to compare with lookback
                 let input_size = input_size[0];
 
+                #[cfg(not(feature = "pure-rust"))]
                 let lookback = unsafe {
                     [<TA_ $indicator_name:upper _Lookback>]
                     (
                         $($param_field),*
-                    ) as usize
+                    )
                 };
+                #[cfg(feature = "pure-rust")]
+                let lookback = crate::talib::native::[<$indicator_name:lower _lookback>]($($param_field),*);
+                // A negative lookback means a parameter is out of range
+                if lookback < 0 {
+                    return Err(crate::error::BadParamSnafu {
+                        indicator_name: stringify!($indicator_name).to_string(),
+                    }.build());
+                }
+                let lookback = lookback as usize;
 
                 if input_size <= lookback {
                     return Err(crate::error::DataLessThenLookbackSnafu {
@@ -95,6 +125,7 @@ macro_rules! talib_fn {
                     }.build());
                 }
 
+                #[cfg(not(feature = "pure-rust"))]
                 let ($([<out_ $output_name>],)*) = crate::execute_talib_function!(
                     $indicator_name,
                     input_size,
@@ -103,6 +134,15 @@ macro_rules! talib_fn {
                     [$($param_field),*],
                     [$($output_name: $output_type),*]
                 );
+                #[cfg(feature = "pure-rust")]
+                let ($([<out_ $output_name>],)*) = crate::execute_native_function!(
+                    [<$indicator_name:lower>],
+                    input_size,
+                    lookback,
+                    [$($input_field),*],
+                    [$($param_field),*],
+                    [$($output_name: $output_type),*]
+                );
 
                 let result: Vec<Indicator> = (0..input_size)
                     .map(|i| $indicator_name {
@@ -131,12 +171,22 @@ macro_rules! talib_snake_fn {
         paste::paste! {
             pub fn [<$indicator_name:snake:lower>]($datetime_field: $datetime_type, $input_field: $input_type, $($param_field: $param_type),*) -> Result<Vec<Indicator>, crate::error::TaLibError> {
                 let input_size = $input_field.len();
+                #[cfg(not(feature = "pure-rust"))]
                 let lookback = unsafe {
                     [<TA_ $indicator_name:snake:upper _Lookback>]
                     (
                         $($param_field),*
-                    ) as usize
+                    )
                 };
+                #[cfg(feature = "pure-rust")]
+                let lookback = crate::talib::native::[<$indicator_name:snake:lower _lookback>]($($param_field),*);
+                // A negative lookback means a parameter is out of range
+                if lookback < 0 {
+                    return Err(crate::error::BadParamSnafu {
+                        indicator_name: stringify!($indicator_name).to_string(),
+                    }.build());
+                }
+                let lookback = lookback as usize;
                 if input_size <= lookback {
                     return Err(crate::error::DataLessThenLookbackSnafu {
                         indicator_name: stringify!($indicator_name).to_string(),
@@ -145,6 +195,7 @@ macro_rules! talib_snake_fn {
                     }.build());
                 }
 
+                #[cfg(not(feature = "pure-rust"))]
                 let ($([<out_ $output_name>],)*) = crate::execute_talib_function_snake!(
                     $indicator_name,
                     input_size,
@@ -153,6 +204,15 @@ macro_rules! talib_snake_fn {
                     [$($param_field),*],
                     [$($output_name: $output_type),*]
                 );
+                #[cfg(feature = "pure-rust")]
+                let ($([<out_ $output_name>],)*) = crate::execute_native_function!(
+                    [<$indicator_name:snake:lower>],
+                    input_size,
+                    lookback,
+                    [$input_field],
+                    [$($param_field),*],
+                    [$($output_name: $output_type),*]
+                );
 
                 let result: Vec<Indicator> = (0..input_size)
                     .map(|i| $indicator_name {
@@ -201,12 +261,22 @@ macro_rules! talib_snake_fn {
                 // If all equal, take the first value to compare with lookback
                 let input_size = input_size[0];
 
+                #[cfg(not(feature = "pure-rust"))]
                 let lookback = unsafe {
                     [<TA_ $indicator_name:snake:upper _Lookback>]
                     (
                         $($param_field),*
-                    ) as usize
+                    )
                 };
+                #[cfg(feature = "pure-rust")]
+                let lookback = crate::talib::native::[<$indicator_name:snake:lower _lookback>]($($param_field),*);
+                // A negative lookback means a parameter is out of range
+                if lookback < 0 {
+                    return Err(crate::error::BadParamSnafu {
+                        indicator_name: stringify!($indicator_name).to_string(),
+                    }.build());
+                }
+                let lookback = lookback as usize;
 
                 if input_size <= lookback {
                     return Err(crate::error::DataLessThenLookbackSnafu {
@@ -216,6 +286,7 @@ macro_rules! talib_snake_fn {
                     }.build());
                 }
 
+                #[cfg(not(feature = "pure-rust"))]
                 let ($([<out_ $output_name>],)*) = crate::execute_talib_function_snake!(
                     $indicator_name,
                     input_size,
@@ -224,6 +295,15 @@ macro_rules! talib_snake_fn {
                     [$($param_field),*],
                     [$($output_name: $output_type),*]
                 );
+                #[cfg(feature = "pure-rust")]
+                let ($([<out_ $output_name>],)*) = crate::execute_native_function!(
+                    [<$indicator_name:snake:lower>],
+                    input_size,
+                    lookback,
+                    [$($input_field),*],
+                    [$($param_field),*],
+                    [$($output_name: $output_type),*]
+                );
 
                 let result: Vec<Indicator> = (0..input_size)
                     .map(|i| $indicator_name {
@@ -330,3 +410,36 @@ macro_rules! execute_talib_function_snake {
         }
     }};
 }
+
+// Internal macro: calls the pure-Rust port and lays its outputs out like the TA-Lib ones, starting at `lookback`
+#[macro_export]
+macro_rules! execute_native_function {
+    (
+        $function_name:ident,
+        $input_size:expr,
+        $lookback:expr,
+        [$($input_field:ident),*],
+        [$($param_field:ident),*],
+        [$($output_name:ident: $output_type:ty),*]
+    ) => {{
+        paste::paste! {
+            let (_, $([<native_ $output_name>],)*) = crate::talib::native::$function_name(
+                0,
+                $input_size - 1,
+                $(
+                    $input_field,
+                )*
+                $($param_field,)*
+            )?;
+
+            $(
+                let mut [<out_ $output_name>]: Vec<$output_type> = vec![$output_type::default(); $input_size];
+                for (out, value) in [<out_ $output_name>][$lookback..].iter_mut().zip([<native_ $output_name>]) {
+                    *out = value;
+                }
+            )*
+
+            ($(([<out_ $output_name>]),)*)
+        }
+    }};
+}
@@ -10,3 +10,6 @@ include!("bindings_windows.rs");
 
 #[cfg(target_os = "macos")]
 include!("bindings_macos.rs");
+
+#[cfg(target_os = "linux")]
+include!("bindings_linux.rs");
@@ -9,7 +9,10 @@ use star_river_core::{
 };
 use tokio::sync::oneshot;
 
-use crate::error::VtsError;
+use crate::{
+    error::VtsError,
+    types::{OrderGroupId, OrderGroupType, PartialCloseSize, TrailingStop},
+};
 
 // ================================ VTS Command Base ================================
 
@@ -162,8 +165,13 @@ where
 #[derive(Debug, From)]
 pub enum VtsCommand {
     CreateOrder(CreateOrderCommand),
+    CreateOrderGroup(CreateOrderGroupCommand),
     ClosePosition(ClosePositionCommand),
+    PartiallyClosePosition(PartiallyClosePositionCommand),
     CloseAllPositions(CloseAllPositionsCommand),
+    CancelOrder(CancelOrderCommand),
+    CancelAllOrders(CancelAllOrdersCommand),
+    ModifyOrder(ModifyOrderCommand),
 }
 
 /// CreateOrder
@@ -171,18 +179,43 @@ pub type CreateOrderCommand = GenericVtsCommand<CreateOrderCmdPayload, CreateOrd
 
 pub type CreateOrderResponse = VtsResponse<CreateOrderRespPayload>;
 
+/// CreateOrderGroup of oco, oto or bracket orders
+pub type CreateOrderGroupCommand = GenericVtsCommand<CreateOrderGroupCmdPayload, CreateOrderGroupRespPayload>;
+
+pub type CreateOrderGroupResponse = VtsResponse<CreateOrderGroupRespPayload>;
+
 /// ClosePosition for one symbol
 pub type ClosePositionCommand = GenericVtsCommand<ClosePositionCmdPayload, ClosePositionRespPayload>;
 
 pub type ClosePositionResponse = VtsResponse<ClosePositionRespPayload>;
 
+/// PartiallyClosePosition for one symbol
+pub type PartiallyClosePositionCommand = GenericVtsCommand<PartiallyClosePositionCmdPayload, PartiallyClosePositionRespPayload>;
+
+pub type PartiallyClosePositionResponse = VtsResponse<PartiallyClosePositionRespPayload>;
+
 /// Close All Positions Command Payload
 pub type CloseAllPositionsCommand = GenericVtsCommand<CloseAllPositionsCmdPayload, CloseAllPositionsRespPayload>;
 
 pub type CloseAllPositionsResponse = VtsResponse<CloseAllPositionsRespPayload>;
 
+/// CancelOrder
+pub type CancelOrderCommand = GenericVtsCommand<CancelOrderCmdPayload, CancelOrderRespPayload>;
+
+pub type CancelOrderResponse = VtsResponse<CancelOrderRespPayload>;
+
+/// CancelAllOrders of a symbol or a node
+pub type CancelAllOrdersCommand = GenericVtsCommand<CancelAllOrdersCmdPayload, CancelAllOrdersRespPayload>;
+
+pub type CancelAllOrdersResponse = VtsResponse<CancelAllOrdersRespPayload>;
+
+/// ModifyOrder
+pub type ModifyOrderCommand = GenericVtsCommand<ModifyOrderCmdPayload, ModifyOrderRespPayload>;
+
+pub type ModifyOrderResponse = VtsResponse<ModifyOrderRespPayload>;
+
 /// Create Order Command Payload
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CreateOrderCmdPayload {
     pub strategy_id: StrategyId,
     pub node_id: NodeId,
@@ -199,6 +232,9 @@ pub struct CreateOrderCmdPayload {
     pub tp_type: Option<TpslType>,
     pub sl_type: Option<TpslType>,
     pub point: Option<f64>,
+    pub reduce_only: bool,
+    pub stop_price: Option<f64>, // Stop price of a stop limit order, the price of a stop market order is its stop price
+    pub trailing_stop: Option<TrailingStop>, // Trailing and break-even adjustment of the stop loss order
 }
 
 impl CreateOrderCmdPayload {
@@ -219,6 +255,9 @@ impl CreateOrderCmdPayload {
         tp_type: Option<TpslType>,
         sl_type: Option<TpslType>,
         point: Option<f64>,
+        reduce_only: bool,
+        stop_price: Option<f64>,
+        trailing_stop: Option<TrailingStop>,
     ) -> Self {
         Self {
             strategy_id,
@@ -236,6 +275,9 @@ impl CreateOrderCmdPayload {
             tp_type,
             sl_type,
             point,
+            reduce_only,
+            stop_price,
+            trailing_stop,
         }
     }
 }
@@ -252,6 +294,32 @@ impl CreateOrderRespPayload {
     }
 }
 
+/// Create Order Group Command Payload
+#[derive(Debug)]
+pub struct CreateOrderGroupCmdPayload {
+    pub group_type: OrderGroupType,
+    pub orders: Vec<CreateOrderCmdPayload>, // The first order is the oto parent or the bracket entry
+}
+
+impl CreateOrderGroupCmdPayload {
+    pub fn new(group_type: OrderGroupType, orders: Vec<CreateOrderCmdPayload>) -> Self {
+        Self { group_type, orders }
+    }
+}
+
+/// Create Order Group Response Payload
+#[derive(Debug)]
+pub struct CreateOrderGroupRespPayload {
+    pub group_id: OrderGroupId,
+    pub order_ids: Vec<OrderId>,
+}
+
+impl CreateOrderGroupRespPayload {
+    pub fn new(group_id: OrderGroupId, order_ids: Vec<OrderId>) -> Self {
+        Self { group_id, order_ids }
+    }
+}
+
 /// Close Position Command Payload
 #[derive(Debug)]
 pub struct ClosePositionCmdPayload {
@@ -288,6 +356,46 @@ impl ClosePositionRespPayload {
     }
 }
 
+/// Partially Close Position Command Payload
+#[derive(Debug)]
+pub struct PartiallyClosePositionCmdPayload {
+    pub node_id: NodeId,
+    pub node_name: NodeName,
+    pub symbol: String,
+    pub exchange: Exchange,
+    pub config_id: i32,
+    pub size: PartialCloseSize,
+}
+
+impl PartiallyClosePositionCmdPayload {
+    pub fn new(node_id: NodeId, node_name: NodeName, symbol: String, exchange: Exchange, config_id: i32, size: PartialCloseSize) -> Self {
+        Self {
+            node_id,
+            node_name,
+            symbol,
+            exchange,
+            config_id,
+            size,
+        }
+    }
+}
+
+/// Partially Close Position Response Payload
+#[derive(Debug)]
+pub struct PartiallyClosePositionRespPayload {
+    pub position_id: PositionId,
+    pub close_quantity: f64,
+}
+
+impl PartiallyClosePositionRespPayload {
+    pub fn new(position_id: PositionId, close_quantity: f64) -> Self {
+        Self {
+            position_id,
+            close_quantity,
+        }
+    }
+}
+
 /// Close All Positions Command Payload
 #[derive(Debug)]
 pub struct CloseAllPositionsCmdPayload {
@@ -317,3 +425,105 @@ impl CloseAllPositionsRespPayload {
         Self { position_ids }
     }
 }
+
+/// Cancel Order Command Payload
+#[derive(Debug)]
+pub struct CancelOrderCmdPayload {
+    pub order_id: OrderId,
+}
+
+impl CancelOrderCmdPayload {
+    pub fn new(order_id: OrderId) -> Self {
+        Self { order_id }
+    }
+}
+
+/// Cancel Order Response Payload
+#[derive(Debug)]
+pub struct CancelOrderRespPayload {
+    pub order_id: OrderId,
+}
+
+impl CancelOrderRespPayload {
+    pub fn new(order_id: OrderId) -> Self {
+        Self { order_id }
+    }
+}
+
+/// Cancel All Orders Command Payload
+/// Only pending entry orders are canceled, tp/sl orders of open positions are kept
+#[derive(Debug)]
+pub struct CancelAllOrdersCmdPayload {
+    pub exchange: Exchange,
+    pub symbol: Option<String>,  // Cancel the orders of this symbol
+    pub node_id: Option<NodeId>, // Cancel the orders created by this node
+}
+
+impl CancelAllOrdersCmdPayload {
+    pub fn new(exchange: Exchange, symbol: Option<String>, node_id: Option<NodeId>) -> Self {
+        Self { exchange, symbol, node_id }
+    }
+}
+
+/// Cancel All Orders Response Payload
+#[derive(Debug)]
+pub struct CancelAllOrdersRespPayload {
+    pub order_ids: Vec<OrderId>,
+}
+
+impl CancelAllOrdersRespPayload {
+    pub fn new(order_ids: Vec<OrderId>) -> Self {
+        Self { order_ids }
+    }
+}
+
+/// Modify Order Command Payload
+/// None keeps the current value, tp/sl are calculated from the (new) order price like in CreateOrder
+#[derive(Debug, Clone)]
+pub struct ModifyOrderCmdPayload {
+    pub order_id: OrderId,
+    pub price: Option<f64>,
+    pub quantity: Option<f64>,
+    pub tp: Option<f64>,
+    pub sl: Option<f64>,
+    pub tp_type: Option<TpslType>,
+    pub sl_type: Option<TpslType>,
+    pub point: Option<f64>,
+}
+
+impl ModifyOrderCmdPayload {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        order_id: OrderId,
+        price: Option<f64>,
+        quantity: Option<f64>,
+        tp: Option<f64>,
+        sl: Option<f64>,
+        tp_type: Option<TpslType>,
+        sl_type: Option<TpslType>,
+        point: Option<f64>,
+    ) -> Self {
+        Self {
+            order_id,
+            price,
+            quantity,
+            tp,
+            sl,
+            tp_type,
+            sl_type,
+            point,
+        }
+    }
+}
+
+/// Modify Order Response Payload
+#[derive(Debug)]
+pub struct ModifyOrderRespPayload {
+    pub order_id: OrderId,
+}
+
+impl ModifyOrderRespPayload {
+    pub fn new(order_id: OrderId) -> Self {
+        Self { order_id }
+    }
+}
@@ -1,14 +1,14 @@
 pub mod command_handler;
+pub mod funding_handler;
+pub mod liquidation_handler;
+pub mod order_group_handler;
 pub mod order_handler;
 pub mod position_handler;
+pub mod snapshot_handler;
 pub mod statistics_handler;
 pub mod transaction_handler;
 
-use std::{
-    collections::HashMap,
-    fmt::Debug,
-    sync::{Arc, atomic::Ordering},
-};
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
 
 use chrono::{DateTime, Utc};
 use snafu::{OptionExt, ResultExt};
@@ -20,13 +20,15 @@ use tokio_util::sync::CancellationToken;
 use crate::{
     command::VtsCommand,
     event::{VtsEvent, VtsEventReceiver, VtsEventSender},
+    fee_schedule::FeeSchedule,
+    fill_model::FillModel,
+    funding::FundingConfig,
+    margin::MarginConfig,
+    market_session::TradingCalendar,
 };
 use crate::{
     error::{EventSendFailedSnafu, KlineKeyNotFoundSnafu, VtsError},
-    types::{
-        VirtualOrder, VirtualPosition, VirtualTransaction,
-        id_generator::{ORDER_ID_COUNTER, POSITION_ID_COUNTER, TRANSACTION_ID_COUNTER},
-    },
+    types::{FundingPayment, ScaleInLimit, VirtualOrder, VirtualPosition, VirtualTransaction, id_generator::IdGenerator},
 };
 
 /// Virtual Trading System
@@ -44,6 +46,10 @@ where
     kline_node_event_receiver: Vec<broadcast::Receiver<E>>,
     pub leverage: Leverage, // Leverage
 
+    bar_count: HashMap<(Exchange, String), u64>,   // Bars played of each symbol
+    pub trading_calendar: Option<TradingCalendar>, // Exchange sessions, the market never closes without one
+    atr_values: HashMap<(NodeId, i32), f64>,       // Latest ATR of each (indicator node, indicator config), used by ATR trailing stops
+
     pub kline_price: HashMap<(Exchange, String), Kline>, // Kline cache key for getting all kline cache data, cache key -> (latest close price, latest timestamp), only get kline data from min_interval_symbols
 
     // Fund related
@@ -51,25 +57,41 @@ where
     pub balance: Balance,           // Account balance (account balance = initial balance + realized pnl)
     pub available_balance: Balance, // Available balance (available balance = equity - used margin - frozen margin)
     pub equity: Equity,             // Equity (equity = account balance + unrealized pnl)
+    pub peak_equity: Equity,        // Highest equity so far, the base of the current drawdown
 
     // PnL related
     pub realized_pnl: Pnl,   // Realized profit/loss
     pub unrealized_pnl: Pnl, // Unrealized profit/loss
 
     // Margin related
-    pub used_margin: Margin,       // Used margin
-    pub frozen_margin: Margin,     // Frozen margin (margin occupied by pending orders)
-    pub margin_ratio: MarginRatio, // Margin ratio
+    pub used_margin: Margin,         // Used margin
+    pub frozen_margin: Margin,       // Frozen margin (margin occupied by pending orders)
+    pub margin_ratio: MarginRatio,   // Margin ratio
+    pub margin_config: MarginConfig, // Margin mode, maintenance margin tiers and liquidation fee
 
     // Fee related
-    pub fee_rate: FeeRate, // Fee rate
+    pub fee_schedule: FeeSchedule, // Maker/taker fee rates
+    pub total_fee: f64,            // Trading fees charged
+
+    // Funding related
+    pub funding_config: FundingConfig,                             // Funding rate and interval
+    pub funding_payments: Vec<FundingPayment>,                     // Funding payment history
+    pub total_funding: f64,                                        // Funding received (positive) or paid (negative)
+    last_funding_time: HashMap<(Exchange, String), DateTime<Utc>>, // Latest settled funding time of each symbol
+
+    // Fill related
+    pub fill_model: FillModel, // Slippage and execution timing of orders
 
     // Position related
-    pub current_positions: Vec<VirtualPosition>, // Current positions
-    pub history_positions: Vec<VirtualPosition>, // History positions
-    pub unfilled_orders: Vec<VirtualOrder>,      // All orders (unfilled orders)
-    pub history_orders: Vec<VirtualOrder>,       // History orders (filled orders)
-    pub transactions: Vec<VirtualTransaction>,   // Transaction history
+    pub scale_in_limit: ScaleInLimit,                        // Max quantity and adds of a position
+    pub current_positions: Vec<VirtualPosition>,             // Current positions
+    pub history_positions: Vec<VirtualPosition>,             // History positions
+    pub unfilled_orders: Vec<VirtualOrder>,                  // All orders (unfilled orders)
+    pending_trailing_stops: Vec<(PositionId, VirtualOrder)>, // Filled entry orders whose ATR trailing stop loss waits for the ATR value
+    pub history_orders: Vec<VirtualOrder>,                   // History orders (filled orders)
+    pub transactions: Vec<VirtualTransaction>,               // Transaction history
+    last_trade_bar: HashMap<(Exchange, String), u64>,        // Bar count of each symbol at its latest transaction
+    id_generator: IdGenerator,                               // Order, position, transaction and order group ids of this vts
 }
 
 // Virtual Trading System getter methods
@@ -83,23 +105,38 @@ where
         Self {
             strategy_time_watch_rx,
             kline_price: HashMap::new(),
+            bar_count: HashMap::new(),
+            trading_calendar: None,
+            atr_values: HashMap::new(),
             kline_node_event_receiver: vec![],
             initial_balance: 0.0,
             balance: 0.0,
             available_balance: 0.0,
             equity: 0.0,
+            peak_equity: 0.0,
             leverage: 0,
             realized_pnl: 0.0,
             unrealized_pnl: 0.0,
             used_margin: 0.0,
             frozen_margin: 0.0,
             margin_ratio: 0.0,
-            fee_rate: 0.0,
+            margin_config: MarginConfig::default(),
+            fee_schedule: FeeSchedule::default(),
+            total_fee: 0.0,
+            funding_config: FundingConfig::default(),
+            funding_payments: vec![],
+            total_funding: 0.0,
+            last_funding_time: HashMap::new(),
+            fill_model: FillModel::default(),
+            scale_in_limit: ScaleInLimit::default(),
             current_positions: vec![],
             history_positions: vec![],
             unfilled_orders: vec![],
+            pending_trailing_stops: vec![],
             history_orders: vec![],
             transactions: vec![],
+            last_trade_bar: HashMap::new(),
+            id_generator: IdGenerator::default(),
             event_sender: tx,
             command_transceiver: (command_tx, Arc::new(Mutex::new(command_rx))),
             cancel_token: CancellationToken::new(),
@@ -139,15 +176,44 @@ where
         self.kline_price = kline_price;
     }
 
-    pub fn handle_kline_update(&mut self, exchange: Exchange, symbol: String, kline: Kline) {
+    pub fn handle_kline_update(&mut self, exchange: Exchange, symbol: String, kline: Kline) -> Result<(), VtsError> {
         // if kline_key not in hashmap key, skip
-        if !self.kline_price.contains_key(&(exchange.clone(), symbol.clone())) {
-            return;
+        let Some(last_kline) = self.kline_price.get_mut(&(exchange.clone(), symbol.clone())) else {
+            return Ok(());
+        };
+        if last_kline.datetime != kline.datetime {
+            *self.bar_count.entry((exchange.clone(), symbol.clone())).or_default() += 1;
         }
-        self.kline_price
-            .entry((exchange.clone(), symbol.clone()))
-            .and_modify(|e| *e = kline.clone());
-        self.update_system(&exchange, &symbol, &kline).unwrap();
+        *last_kline = kline.clone();
+        self.update_system(&exchange, &symbol, &kline)
+    }
+
+    pub fn bar_count(&self, exchange: &Exchange, symbol: &String) -> u64 {
+        self.bar_count.get(&(exchange.clone(), symbol.clone())).copied().unwrap_or_default()
+    }
+
+    pub fn is_market_open(&self) -> bool {
+        self.trading_calendar
+            .as_ref()
+            .is_none_or(|calendar| calendar.is_open(self.current_datetime()))
+    }
+
+    /// Whether the market is open and the symbol has a traded (not forward filled) bar at the current time
+    pub fn is_tradable(&self, exchange: &Exchange, symbol: &String) -> bool {
+        self.is_market_open()
+            && self
+                .kline_price
+                .get(&(exchange.clone(), symbol.clone()))
+                .is_some_and(|kline| kline.datetime == self.current_datetime() && kline.volume > 0.0)
+    }
+
+    pub fn atr_values(&self) -> &HashMap<(NodeId, i32), f64> {
+        &self.atr_values
+    }
+
+    /// Cache the latest ATR value of an indicator for ATR based trailing stops
+    pub fn update_atr_value(&mut self, node_id: NodeId, config_id: i32, atr: f64) {
+        self.atr_values.insert((node_id, config_id), atr);
     }
 
     pub fn add_kline_node_event_receiver(&mut self, kline_node_event_receiver: broadcast::Receiver<E>) {
@@ -159,10 +225,14 @@ where
         self.check_unfilled_orders(exchange, symbol, kline)?;
         // After price update, update positions
         self.update_current_positions(exchange, symbol, kline)?;
+        // Settle funding of open positions at the funding time
+        self.settle_funding(exchange, symbol, kline)?;
 
         // Update balance related data in correct order
-        // 1. Update realized pnl
+        // 1. Update realized pnl, fees and funding
         self.update_realized_pnl();
+        self.update_total_fee();
+        self.update_total_funding();
         // 2. Update unrealized pnl
         self.update_unrealized_pnl();
         // 3. Update used margin
@@ -191,28 +261,61 @@ where
     pub fn set_initial_balance(&mut self, initial_balance: Balance) {
         self.initial_balance = initial_balance;
         self.available_balance = initial_balance;
+        self.peak_equity = initial_balance;
     }
 
     pub fn set_leverage(&mut self, leverage: Leverage) {
         self.leverage = leverage;
     }
 
+    // Same rate for maker and taker
     pub fn set_fee_rate(&mut self, fee_rate: FeeRate) {
-        self.fee_rate = fee_rate;
+        self.fee_schedule = FeeSchedule::uniform(fee_rate);
+    }
+
+    pub fn set_fee_schedule(&mut self, fee_schedule: FeeSchedule) {
+        self.fee_schedule = fee_schedule;
+    }
+
+    pub fn set_funding_config(&mut self, funding_config: FundingConfig) {
+        self.funding_config = funding_config;
+    }
+
+    pub fn set_margin_config(&mut self, margin_config: MarginConfig) {
+        self.margin_config = margin_config;
+    }
+
+    pub fn set_scale_in_limit(&mut self, scale_in_limit: ScaleInLimit) {
+        self.scale_in_limit = scale_in_limit;
+    }
+
+    pub fn set_fill_model(&mut self, fill_model: FillModel) {
+        self.fill_model = fill_model;
+    }
+
+    pub fn set_trading_calendar(&mut self, trading_calendar: Option<TradingCalendar>) {
+        self.trading_calendar = trading_calendar;
     }
 
     // Reset system
-    // Clear all positions and orders
+    // Clear all positions and orders, ids restart from 0 for this context only
     pub fn reset(&mut self) {
         self.current_positions.clear();
         self.history_positions.clear();
         self.unfilled_orders.clear();
+        self.pending_trailing_stops.clear();
         self.history_orders.clear();
         self.transactions.clear();
+        self.funding_payments.clear();
+        self.last_funding_time.clear();
+        self.bar_count.clear();
+        self.atr_values.clear();
+        self.last_trade_bar.clear();
+        self.peak_equity = self.initial_balance;
+        self.total_fee = 0.0;
+        self.total_funding = 0.0;
         self.available_balance = self.initial_balance;
         self.used_margin = 0.0;
-        ORDER_ID_COUNTER.store(0, Ordering::SeqCst);
-        POSITION_ID_COUNTER.store(0, Ordering::SeqCst);
-        TRANSACTION_ID_COUNTER.store(0, Ordering::SeqCst);
+        self.id_generator = IdGenerator::default();
     }
 }
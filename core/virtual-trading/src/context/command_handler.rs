@@ -4,8 +4,10 @@ use tokio::sync::{Mutex, mpsc};
 
 use super::VtsContext;
 use crate::command::{
-    CloseAllPositionsRespPayload, CloseAllPositionsResponse, ClosePositionRespPayload, ClosePositionResponse, CreateOrderRespPayload,
-    CreateOrderResponse, VtsCommand,
+    CancelAllOrdersRespPayload, CancelAllOrdersResponse, CancelOrderRespPayload, CancelOrderResponse, CloseAllPositionsRespPayload,
+    CloseAllPositionsResponse, ClosePositionRespPayload, ClosePositionResponse, CreateOrderGroupRespPayload, CreateOrderGroupResponse,
+    CreateOrderRespPayload, CreateOrderResponse, ModifyOrderRespPayload, ModifyOrderResponse, PartiallyClosePositionRespPayload,
+    PartiallyClosePositionResponse, VtsCommand,
 };
 
 impl<E> VtsContext<E>
@@ -23,26 +25,10 @@ where
     pub async fn handle_command(&mut self, command: VtsCommand) {
         match command {
             VtsCommand::CreateOrder(cmd) => {
-                let result = self.create_order(
-                    cmd.strategy_id,
-                    cmd.node_id.clone(),
-                    cmd.node_name.clone(),
-                    cmd.order_config_id,
-                    cmd.symbol.clone(),
-                    cmd.exchange.clone(),
-                    cmd.price,
-                    cmd.order_side.clone(),
-                    cmd.order_type.clone(),
-                    cmd.quantity,
-                    cmd.tp,
-                    cmd.sl,
-                    cmd.tp_type.clone(),
-                    cmd.sl_type.clone(),
-                    cmd.point,
-                );
+                let result = self.create_order(cmd.command_payload.clone(), None);
                 match result {
-                    Ok(()) => {
-                        let payload = CreateOrderRespPayload::new(1);
+                    Ok(order_id) => {
+                        let payload = CreateOrderRespPayload::new(order_id);
                         let response = CreateOrderResponse::success(payload);
                         cmd.respond(response);
                     }
@@ -52,6 +38,20 @@ where
                     }
                 }
             }
+            VtsCommand::CreateOrderGroup(cmd) => {
+                let result = self.create_order_group(cmd.group_type, cmd.orders.clone());
+                match result {
+                    Ok((group_id, order_ids)) => {
+                        let payload = CreateOrderGroupRespPayload::new(group_id, order_ids);
+                        let response = CreateOrderGroupResponse::success(payload);
+                        cmd.respond(response);
+                    }
+                    Err(e) => {
+                        let response = CreateOrderGroupResponse::fail(e);
+                        cmd.respond(response);
+                    }
+                }
+            }
             VtsCommand::ClosePosition(cmd) => {
                 let result = self.close_position(&cmd.node_id, &cmd.node_name, cmd.config_id, &cmd.symbol, &cmd.exchange);
                 match result {
@@ -66,6 +66,21 @@ where
                     }
                 }
             }
+            VtsCommand::PartiallyClosePosition(cmd) => {
+                let result =
+                    self.partially_close_position(&cmd.node_id, &cmd.node_name, cmd.config_id, &cmd.symbol, &cmd.exchange, &cmd.size);
+                match result {
+                    Ok((position_id, close_quantity)) => {
+                        let payload = PartiallyClosePositionRespPayload::new(position_id, close_quantity);
+                        let response = PartiallyClosePositionResponse::success(payload);
+                        cmd.respond(response);
+                    }
+                    Err(e) => {
+                        let response = PartiallyClosePositionResponse::fail(e);
+                        cmd.respond(response);
+                    }
+                }
+            }
             VtsCommand::CloseAllPositions(cmd) => {
                 let result = self.close_all_positions(&cmd.node_id, &cmd.node_name, cmd.config_id);
                 match result {
@@ -80,6 +95,48 @@ where
                     }
                 }
             }
+            VtsCommand::CancelOrder(cmd) => {
+                let result = self.cancel_order(cmd.order_id);
+                match result {
+                    Ok(order) => {
+                        let payload = CancelOrderRespPayload::new(order.order_id);
+                        let response = CancelOrderResponse::success(payload);
+                        cmd.respond(response);
+                    }
+                    Err(e) => {
+                        let response = CancelOrderResponse::fail(e);
+                        cmd.respond(response);
+                    }
+                }
+            }
+            VtsCommand::CancelAllOrders(cmd) => {
+                let result = self.cancel_all_orders(&cmd.exchange, cmd.symbol.as_ref(), cmd.node_id.as_ref());
+                match result {
+                    Ok(order_ids) => {
+                        let payload = CancelAllOrdersRespPayload::new(order_ids);
+                        let response = CancelAllOrdersResponse::success(payload);
+                        cmd.respond(response);
+                    }
+                    Err(e) => {
+                        let response = CancelAllOrdersResponse::fail(e);
+                        cmd.respond(response);
+                    }
+                }
+            }
+            VtsCommand::ModifyOrder(cmd) => {
+                let result = self.modify_order(cmd.command_payload.clone());
+                match result {
+                    Ok(order) => {
+                        let payload = ModifyOrderRespPayload::new(order.order_id);
+                        let response = ModifyOrderResponse::success(payload);
+                        cmd.respond(response);
+                    }
+                    Err(e) => {
+                        let response = ModifyOrderResponse::fail(e);
+                        cmd.respond(response);
+                    }
+                }
+            }
         }
     }
 }
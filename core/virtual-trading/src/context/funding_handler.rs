@@ -0,0 +1,51 @@
+use star_river_core::{custom_type::PositionId, exchange::Exchange, kline::Kline};
+
+use super::VtsContext;
+use crate::{error::VtsError, event::VtsEvent, types::FundingPayment};
+
+impl<E> VtsContext<E>
+where
+    E: Clone + Send + Sync + 'static,
+{
+    // Settle funding once per funding time for the positions opened before it
+    pub fn settle_funding(&mut self, exchange: &Exchange, symbol: &String, kline: &Kline) -> Result<(), VtsError> {
+        let Some(funding_time) = self.funding_config.funding_time(kline.datetime) else {
+            return Ok(());
+        };
+
+        let key = (exchange.clone(), symbol.clone());
+        if self
+            .last_funding_time
+            .get(&key)
+            .is_some_and(|last_funding_time| *last_funding_time >= funding_time)
+        {
+            return Ok(());
+        }
+        self.last_funding_time.insert(key, funding_time);
+
+        let Some(funding_rate) = self.funding_config.rate_at(symbol, funding_time) else {
+            return Ok(());
+        };
+
+        let position_ids: Vec<PositionId> = self
+            .current_positions
+            .iter()
+            .filter(|p| &p.exchange == exchange && &p.symbol == symbol && p.create_time < funding_time)
+            .map(|p| p.position_id)
+            .collect();
+
+        let current_datetime = self.current_datetime();
+        for position_id in position_ids {
+            let (position, funding_payment) = {
+                let position = self.find_position_mut(position_id)?;
+                let funding_payment = FundingPayment::new(position, funding_rate, funding_time, current_datetime);
+                position.funding += funding_payment.amount;
+                (position.clone(), funding_payment)
+            };
+            self.funding_payments.push(funding_payment.clone());
+            self.send_event(VtsEvent::FundingSettled(funding_payment))?;
+            self.send_event(VtsEvent::PositionUpdated(position))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+use star_river_core::{
+    custom_type::{PositionId, Price},
+    exchange::Exchange,
+    kline::Kline,
+    order::{FuturesOrderSide, OrderStatus, OrderType},
+    position::PositionSide,
+};
+
+use super::VtsContext;
+use crate::{
+    error::VtsError,
+    event::VtsEvent,
+    fee_schedule::Liquidity,
+    fill_model::OrderFill,
+    margin::MarginMode,
+    types::{VirtualOrder, VirtualPosition},
+};
+
+impl<E> VtsContext<E>
+where
+    E: Clone + Send + Sync + 'static,
+{
+    // Isolated: the margin balance of the position, its initial margin plus the funding received minus the fees paid
+    // Cross: the account balance minus the maintenance margin of the other positions, plus their unrealized pnl
+    fn liquidation_collateral(&self, position: &VirtualPosition) -> f64 {
+        match self.margin_config.margin_mode {
+            MarginMode::Isolated => {
+                let initial_margin = position.open_price * position.quantity / position.leverage.max(1) as f64;
+                let paid_fee = self
+                    .transactions
+                    .iter()
+                    .filter(|transaction| transaction.position_id == position.position_id)
+                    .map(|transaction| transaction.fee)
+                    .sum::<f64>();
+                initial_margin + position.funding - paid_fee
+            }
+            MarginMode::Cross => {
+                let (other_maintenance_margin, other_unrealized_pnl) = self
+                    .current_positions
+                    .iter()
+                    .filter(|p| p.position_id != position.position_id)
+                    .fold((0.0, 0.0), |(maintenance_margin, unrealized_pnl), p| {
+                        (
+                            maintenance_margin + self.margin_config.maintenance_margin(p.current_price * p.quantity),
+                            unrealized_pnl + p.unrealized_profit,
+                        )
+                    });
+                self.balance - other_maintenance_margin + other_unrealized_pnl
+            }
+        }
+    }
+
+    pub fn liquidation_price(&self, position: &VirtualPosition) -> Price {
+        let collateral = self.liquidation_collateral(position);
+        self.margin_config
+            .liquidation_price(&position.position_side, position.open_price, position.quantity, collateral)
+    }
+
+    // Force close the positions whose liquidation price is touched by the bar
+    pub fn check_liquidation(&mut self, exchange: &Exchange, symbol: &String, kline: &Kline) -> Result<(), VtsError> {
+        let liquidations: Vec<(PositionId, Price)> = self
+            .current_positions
+            .iter()
+            .filter(|p| &p.exchange == exchange && &p.symbol == symbol)
+            .filter_map(|p| {
+                let liquidation_price = self.liquidation_price(p);
+                let breached = match p.position_side {
+                    PositionSide::Long => liquidation_price > 0.0 && kline.low <= liquidation_price,
+                    PositionSide::Short => liquidation_price > 0.0 && kline.high >= liquidation_price,
+                };
+                breached.then_some((p.position_id, liquidation_price))
+            })
+            .collect();
+
+        for (position_id, liquidation_price) in liquidations {
+            self.liquidate_position(position_id, liquidation_price, kline)?;
+        }
+        Ok(())
+    }
+
+    fn liquidate_position(&mut self, position_id: PositionId, liquidation_price: Price, kline: &Kline) -> Result<(), VtsError> {
+        let execute_datetime = self.current_datetime();
+        let (strategy_id, exchange, symbol, order_side, quantity, fill_price) = {
+            let position = self.find_position(position_id)?;
+            // a bar that opens beyond the liquidation price is filled at the open
+            let (order_side, fill_price) = match position.position_side {
+                PositionSide::Long => (FuturesOrderSide::Short, liquidation_price.min(kline.open)),
+                PositionSide::Short => (FuturesOrderSide::Long, liquidation_price.max(kline.open)),
+            };
+            (
+                position.strategy_id,
+                position.exchange.clone(),
+                position.symbol.clone(),
+                order_side,
+                position.quantity,
+                fill_price,
+            )
+        };
+
+        // the liquidation is attributed to the node that opened the position
+        let (node_id, node_name, order_config_id) = self
+            .transactions
+            .iter()
+            .find(|transaction| transaction.position_id == position_id)
+            .map(|transaction| {
+                (
+                    transaction.node_id.clone(),
+                    transaction.node_name.clone(),
+                    transaction.order_config_id,
+                )
+            })
+            .unwrap_or_default();
+
+        let mut liquidation_order = VirtualOrder::new(
+            self.id_generator.next_order_id(),
+            Some(position_id),
+            strategy_id,
+            node_id,
+            node_name,
+            order_config_id,
+            exchange.clone(),
+            symbol.clone(),
+            order_side,
+            OrderType::Market,
+            quantity,
+            fill_price,
+            None,
+            None,
+            None,
+            None,
+            None,
+            execute_datetime,
+        );
+        liquidation_order.order_status = OrderStatus::Filled;
+        self.history_orders.push(liquidation_order.clone());
+
+        let transaction_id = self.id_generator.next_transaction_id();
+        let (position, mut transaction) = {
+            let position = self.find_position_mut(position_id)?;
+            position.liquidate(transaction_id, &liquidation_order, fill_price, execute_datetime)
+        };
+        let fill = OrderFill {
+            price: fill_price,
+            slippage: (fill_price - liquidation_price).abs(),
+            liquidity: Liquidity::Taker,
+        };
+        self.apply_fill(&mut transaction, &fill);
+        let liquidation_fee = self.margin_config.liquidation_fee(fill_price, quantity);
+        transaction.fee += liquidation_fee;
+
+        tracing::warn!(
+            "position liquidated: ID: {:?}, side: {:?}, liquidation price: {:?}, fill price: {:?}, quantity: {:?}",
+            position_id,
+            position.position_side,
+            liquidation_price,
+            fill_price,
+            quantity
+        );
+
+        self.send_event(VtsEvent::PositionLiquidated {
+            liquidation_price,
+            liquidation_fee,
+            position: position.clone(),
+        })?;
+        self.send_event(VtsEvent::PositionClosed(position.clone()))?;
+        self.record_transaction(transaction.clone());
+        self.send_event(VtsEvent::TransactionCreated(transaction))?;
+
+        // cancel the tp/sl orders of the liquidated position and move it to history
+        for id in self.find_position_tp_order_ids(position_id) {
+            let canceled_tp_order = self.update_order_status(id, OrderStatus::Canceled)?;
+            self.send_event(VtsEvent::TakeProfitOrderCanceled(canceled_tp_order))?;
+        }
+        for id in self.find_position_sl_order_ids(position_id) {
+            let canceled_sl_order = self.update_order_status(id, OrderStatus::Canceled)?;
+            self.send_event(VtsEvent::StopLossOrderCanceled(canceled_sl_order))?;
+        }
+        self.remove_open_position(position_id);
+        self.history_positions.push(position);
+        Ok(())
+    }
+}
@@ -0,0 +1,237 @@
+use snafu::OptionExt;
+use star_river_core::{
+    custom_type::OrderId,
+    order::{FuturesOrderSide, OrderStatus},
+    position::PositionSide,
+};
+
+use super::VtsContext;
+use crate::{
+    command::CreateOrderCmdPayload,
+    error::{InvalidOrderGroupSnafu, OrderNotFoundSnafu, VtsError},
+    event::VtsEvent,
+    types::{OrderGroup, OrderGroupId, OrderGroupType, VirtualOrder},
+};
+
+impl<E> VtsContext<E>
+where
+    E: Clone + Send + Sync + 'static,
+{
+    /// A group leg waits while its parent order is unfilled
+    pub fn is_waiting_for_parent(&self, order: &VirtualOrder) -> bool {
+        order
+            .parent_order_id()
+            .is_some_and(|parent_order_id| self.unfilled_orders.iter().any(|o| o.order_id == parent_order_id))
+    }
+
+    /// Create the orders of a group, the first order is the parent of the oto children or the bracket entry
+    /// If an order fails, the orders already created are canceled
+    /// The returned ids only cover the placed orders, an oco group stops at the first leg finished on creation
+    pub fn create_order_group(
+        &mut self,
+        group_type: OrderGroupType,
+        orders: Vec<CreateOrderCmdPayload>,
+    ) -> Result<(OrderGroupId, Vec<OrderId>), VtsError> {
+        let reason = match group_type {
+            OrderGroupType::Oco | OrderGroupType::Oto if orders.len() < 2 => Some("at least 2 orders are required"),
+            OrderGroupType::Bracket if orders.len() != 1 => Some("exactly 1 entry order is required"),
+            OrderGroupType::Bracket if orders[0].tp.is_none() && orders[0].sl.is_none() && orders[0].trailing_stop.is_none() => {
+                Some("the entry order has neither tp nor sl")
+            }
+            // The stop loss leg is placed with the entry order, it cannot wait for the ATR value
+            OrderGroupType::Bracket
+                if orders[0].sl.is_none()
+                    && orders[0].trailing_stop.as_ref().is_some_and(|trailing_stop| {
+                        trailing_stop.trailing.is_some() && !trailing_stop.has_initial_stop(self.atr_values())
+                    }) =>
+            {
+                Some("the ATR of the trailing stop has no value yet")
+            }
+            _ => None,
+        };
+        if let Some(reason) = reason {
+            return Err(InvalidOrderGroupSnafu {
+                group_type: group_type.to_string(),
+                reason: reason.to_string(),
+            }
+            .build());
+        }
+
+        let group = OrderGroup::new(self.id_generator.next_order_group_id(), group_type);
+        let mut order_ids: Vec<OrderId> = Vec::new();
+        for payload in orders {
+            let order_group = match order_ids.first() {
+                Some(parent_order_id) if group_type != OrderGroupType::Oco => group.child_of(*parent_order_id),
+                _ => group.clone(),
+            };
+            let result = self.create_order(payload, Some(order_group));
+            match result {
+                Ok(order_id) => {
+                    order_ids.push(order_id);
+                    // An oco leg filled or canceled on creation ends the group, the later legs are never placed
+                    if group_type == OrderGroupType::Oco && self.history_orders.iter().any(|o| o.order_id == order_id) {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    for order_id in order_ids {
+                        if self.find_unfilled_order(&order_id).is_ok() {
+                            self.cancel_order(order_id)?;
+                        }
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        if group_type == OrderGroupType::Bracket {
+            let leg_ids = self.create_bracket_legs(order_ids[0], &group)?;
+            order_ids.extend(leg_ids);
+        }
+
+        // Orders filled or canceled on creation were resolved before the later legs existed
+        for order_id in order_ids.iter() {
+            let finished_order = self.history_orders.iter().find(|o| o.order_id == *order_id).cloned();
+            if let Some(finished_order) = finished_order {
+                self.resolve_order_group(&finished_order)?;
+            }
+        }
+        Ok((group.group_id, order_ids))
+    }
+
+    // Take profit and stop loss legs of a bracket, placed with the tp/sl of the entry order
+    fn create_bracket_legs(&mut self, entry_order_id: OrderId, group: &OrderGroup) -> Result<Vec<OrderId>, VtsError> {
+        let entry_order = self
+            .unfilled_orders
+            .iter()
+            .chain(self.history_orders.iter())
+            .find(|o| o.order_id == entry_order_id)
+            .cloned()
+            .context(OrderNotFoundSnafu { order_id: entry_order_id })?;
+        let (position_side, leg_side) = match entry_order.order_side {
+            FuturesOrderSide::Long => (PositionSide::Long, FuturesOrderSide::Short),
+            FuturesOrderSide::Short => (PositionSide::Short, FuturesOrderSide::Long),
+        };
+        let leg_group = Some(group.child_of(entry_order_id));
+        let current_datetime = self.current_datetime();
+        let mut leg_ids = Vec::new();
+
+        if let Some(tp) = entry_order.tp {
+            let tp_order = VirtualOrder::create_take_profit_order(
+                self.id_generator.next_order_id(),
+                None,
+                entry_order.strategy_id,
+                entry_order.node_id.clone(),
+                entry_order.node_name.clone(),
+                entry_order.order_config_id,
+                entry_order.exchange.clone(),
+                entry_order.symbol.clone(),
+                leg_side.clone(),
+                entry_order.quantity,
+                tp,
+                current_datetime,
+            )
+            .with_order_group(leg_group.clone());
+            leg_ids.push(tp_order.order_id);
+            self.unfilled_orders.push(tp_order.clone());
+            self.send_event(VtsEvent::TakeProfitOrderCreated(tp_order))?;
+        }
+
+        // A trailing stop without a stop loss price trails from the entry price
+        let sl = entry_order.sl.or_else(|| {
+            entry_order
+                .trailing_stop
+                .as_ref()
+                .and_then(|trailing_stop| trailing_stop.initial_stop(&position_side, entry_order.open_price, self.atr_values()))
+        });
+        if let Some(sl) = sl {
+            let sl_order = VirtualOrder::create_stop_loss_order(
+                self.id_generator.next_order_id(),
+                None,
+                entry_order.strategy_id,
+                entry_order.node_id.clone(),
+                entry_order.node_name.clone(),
+                entry_order.order_config_id,
+                entry_order.exchange.clone(),
+                entry_order.symbol.clone(),
+                leg_side,
+                entry_order.quantity,
+                sl,
+                current_datetime,
+            )
+            .with_trailing_stop(entry_order.trailing_stop.clone())
+            .with_order_group(leg_group);
+            leg_ids.push(sl_order.order_id);
+            self.unfilled_orders.push(sl_order.clone());
+            self.send_event(VtsEvent::StopLossOrderCreated(sl_order))?;
+        }
+        Ok(leg_ids)
+    }
+
+    /// Activate or cancel the other legs of the group of a filled or canceled order
+    pub(crate) fn resolve_order_group(&mut self, order: &VirtualOrder) -> Result<(), VtsError> {
+        let Some(group) = &order.order_group else {
+            return Ok(());
+        };
+        let filled = order.order_status == OrderStatus::Filled;
+        let mut activate_ids = Vec::new();
+        let mut cancel_ids = Vec::new();
+        for leg in self
+            .unfilled_orders
+            .iter()
+            .filter(|o| o.order_group.as_ref().is_some_and(|g| g.group_id == group.group_id))
+        {
+            if leg.parent_order_id() == Some(order.order_id) {
+                // Children start with the fill of their parent and are dropped with it otherwise
+                if filled {
+                    activate_ids.push(leg.order_id);
+                } else {
+                    cancel_ids.push(leg.order_id);
+                }
+            } else if group.group_type == OrderGroupType::Oco
+                || (group.group_type == OrderGroupType::Bracket
+                    && group.parent_order_id.is_some()
+                    && leg.parent_order_id() == group.parent_order_id)
+            {
+                // Other legs of an oco group, or the other half of a bracket's tp/sl pair
+                cancel_ids.push(leg.order_id);
+            }
+        }
+
+        for order_id in activate_ids {
+            self.activate_group_leg(order_id)?;
+        }
+        for order_id in cancel_ids {
+            // May already be canceled through another leg
+            if self.find_unfilled_order(&order_id).is_ok() {
+                self.cancel_order(order_id)?;
+            }
+        }
+        Ok(())
+    }
+
+    // A tp/sl leg is bound to the position its parent opened, and canceled if the parent left no position
+    fn activate_group_leg(&mut self, order_id: OrderId) -> Result<(), VtsError> {
+        let current_datetime = self.current_datetime();
+        let leg = self.find_unfilled_order(&order_id)?;
+        if leg.is_tpsl_order() {
+            let position_id = self
+                .current_positions
+                .iter()
+                .find(|p| p.exchange == leg.exchange && p.symbol == leg.symbol)
+                .map(|p| p.position_id);
+            match position_id {
+                Some(position_id) => self.update_order_position_id(order_id, position_id)?,
+                None => {
+                    self.cancel_order(order_id)?;
+                    return Ok(());
+                }
+            }
+        }
+
+        let leg = self.find_unfilled_order_mut(&order_id)?;
+        leg.update_time = current_datetime;
+        let activated_leg = leg.clone();
+        self.send_event(VtsEvent::OrderGroupLegActivated(activated_leg))?;
+        Ok(())
+    }
+}
@@ -4,16 +4,21 @@ use star_river_core::kline::Kline;
 use star_river_core::{
     custom_type::*,
     exchange::Exchange,
-    order::{FuturesOrderSide, OrderStatus, OrderType, TpslType},
+    order::{FuturesOrderSide, OrderStatus, OrderType},
     position::PositionSide,
 };
 
 // Local module imports
 use super::VtsContext;
 use crate::{
-    error::{OrderNotFoundSnafu, UnsupportedOrderTypeSnafu, VtsError},
+    command::{CreateOrderCmdPayload, ModifyOrderCmdPayload},
+    error::{
+        InvalidOrderModificationSnafu, InvalidTrailingStopSnafu, OrderNotFoundSnafu, ReduceOnlyOrderRejectedSnafu,
+        StopPriceNotConfiguredSnafu, UnsupportedOrderTypeSnafu, VtsError,
+    },
     event::VtsEvent,
-    types::{VirtualOrder, VirtualPosition},
+    fill_model::OrderFill,
+    types::{OrderGroup, VirtualOrder, VirtualPosition},
 };
 
 impl<E> VtsContext<E>
@@ -37,7 +42,12 @@ where
     pub fn find_tp_order_ids(&self, symbol: &String, exchange: &Exchange) -> Vec<OrderId> {
         self.unfilled_orders
             .iter()
-            .filter(|order| order.exchange == *exchange && order.symbol == *symbol && order.order_type == OrderType::TakeProfitMarket)
+            .filter(|order| {
+                order.exchange == *exchange
+                    && order.symbol == *symbol
+                    && order.order_type == OrderType::TakeProfitMarket
+                    && !self.is_waiting_for_parent(order)
+            })
             .map(|order| order.order_id)
             .collect()
     }
@@ -45,7 +55,25 @@ where
     pub fn find_sl_order_ids(&self, symbol: &String, exchange: &Exchange) -> Vec<OrderId> {
         self.unfilled_orders
             .iter()
-            .filter(|order| order.exchange == *exchange && order.symbol == *symbol && order.order_type == OrderType::StopMarket)
+            .filter(|order| {
+                order.exchange == *exchange && order.symbol == *symbol && order.is_stop_loss_order() && !self.is_waiting_for_parent(order)
+            })
+            .map(|order| order.order_id)
+            .collect()
+    }
+
+    pub fn find_position_tp_order_ids(&self, position_id: PositionId) -> Vec<OrderId> {
+        self.unfilled_orders
+            .iter()
+            .filter(|order| order.position_id == Some(position_id) && order.order_type == OrderType::TakeProfitMarket)
+            .map(|order| order.order_id)
+            .collect()
+    }
+
+    pub fn find_position_sl_order_ids(&self, position_id: PositionId) -> Vec<OrderId> {
+        self.unfilled_orders
+            .iter()
+            .filter(|order| order.position_id == Some(position_id) && order.is_stop_loss_order())
             .map(|order| order.order_id)
             .collect()
     }
@@ -57,6 +85,7 @@ where
                 order.exchange == *exchange
                     && order.symbol == *symbol
                     && (order.order_status == OrderStatus::Created || order.order_status == OrderStatus::Placed)
+                    && !self.is_waiting_for_parent(order)
             })
             .map(|order| order.order_id)
             .collect()
@@ -107,6 +136,8 @@ where
         {
             let order = self.unfilled_orders.remove(order_idx);
             self.history_orders.push(order.clone());
+            // Cancel or activate the other legs of its group
+            self.resolve_order_group(&order)?;
             Ok(order)
         } else {
             Ok(order.clone())
@@ -131,31 +162,88 @@ impl<E> VtsContext<E>
 where
     E: Clone + Send + Sync + 'static,
 {
-    // Create order
-    pub fn create_order(
-        &mut self,
-        strategy_id: StrategyId,
-        node_id: NodeId,
-        node_name: NodeName,
-        order_config_id: i32,
-        symbol: String,
-        exchange: Exchange,
-        price: f64,
-        order_side: FuturesOrderSide,
-        order_type: OrderType,
-        quantity: f64,
-        tp: Option<f64>,
-        sl: Option<f64>,
-        tp_type: Option<TpslType>,
-        sl_type: Option<TpslType>,
-        point: Option<f64>,
-    ) -> Result<(), VtsError> {
+    // Create order, a group leg carries its order group
+    pub fn create_order(&mut self, payload: CreateOrderCmdPayload, order_group: Option<OrderGroup>) -> Result<OrderId, VtsError> {
+        let CreateOrderCmdPayload {
+            strategy_id,
+            node_id,
+            node_name,
+            order_config_id,
+            symbol,
+            exchange,
+            price,
+            order_side,
+            order_type,
+            quantity,
+            tp,
+            sl,
+            tp_type,
+            sl_type,
+            point,
+            reduce_only,
+            stop_price,
+            trailing_stop,
+        } = payload;
+        // A reduce only order needs an opposite position and closes at most its quantity
+        let quantity = if reduce_only {
+            let reducible_quantity = self
+                .reducible_quantity(&symbol, &exchange, &order_side)
+                .context(ReduceOnlyOrderRejectedSnafu {
+                    symbol: symbol.clone(),
+                    exchange: exchange.to_string(),
+                    reason: format!("no position opposite to the {order_side} order"),
+                })?;
+            quantity.min(reducible_quantity)
+        } else {
+            self.check_scale_in(&symbol, &exchange, &order_side, quantity)?;
+            quantity
+        };
+        // Break-even only moves an existing stop loss order
+        if sl.is_none() && trailing_stop.as_ref().is_some_and(|trailing_stop| trailing_stop.trailing.is_none()) {
+            return Err(InvalidTrailingStopSnafu {
+                symbol: symbol.clone(),
+                exchange: exchange.to_string(),
+                reason: "break-even needs a stop loss price or a trailing distance".to_string(),
+            }
+            .build());
+        }
         let current_datetime = self.current_datetime();
-        let kline = self.find_kline_price(&exchange, &symbol)?;
+        let kline = self.find_kline_price(&exchange, &symbol)?.clone();
         let current_price = kline.close;
+        // A group leg waiting for its parent is only placed, it is never filled on creation
+        let waiting = order_group
+            .as_ref()
+            .and_then(|group| group.parent_order_id)
+            .is_some_and(|parent_order_id| self.find_unfilled_order(&parent_order_id).is_ok());
+        // A stop order whose stop price is already reached is placed as the order it triggers
+        let (order_type, trigger_price) = match order_type {
+            OrderType::StopMarket | OrderType::StopLimit => {
+                let trigger_price = if order_type == OrderType::StopMarket {
+                    price
+                } else {
+                    stop_price.context(StopPriceNotConfiguredSnafu {
+                        order_type: order_type.to_string(),
+                    })?
+                };
+                let triggered = !waiting
+                    && match order_side {
+                        FuturesOrderSide::Long => current_price >= trigger_price,
+                        FuturesOrderSide::Short => current_price <= trigger_price,
+                    };
+                let order_type = match order_type {
+                    OrderType::StopMarket if triggered => OrderType::Market,
+                    OrderType::StopLimit if triggered => OrderType::Limit,
+                    order_type => order_type,
+                };
+                (order_type, Some(trigger_price))
+            }
+            order_type => (order_type, None),
+        };
+        let next_bar_open = self.fill_model.is_next_bar_open();
         // order create closure
         let create_order = |price| -> Result<VirtualOrder, VtsError> {
             let order = VirtualOrder::create_order(
+                self.id_generator.next_order_id(),
                 strategy_id,
                 node_id,
                 node_name,
@@ -172,7 +260,11 @@ where
                 sl_type,
                 point,
                 current_datetime,
-            );
+            )
+            .with_reduce_only(reduce_only)
+            .with_trigger_price(trigger_price)
+            .with_trailing_stop(trailing_stop)
+            .with_order_group(order_group);
             let order_create_event = VtsEvent::FuturesOrderCreated(order.clone());
             self.send_event(order_create_event)?;
             // Insert order
@@ -180,54 +272,60 @@ where
             Ok(order)
         };
         // Determine if the order should be executed immediately based on order type
-        match &order_type {
+        let order_id = match &order_type {
+            // Waiting leg, a market leg fills at the open of the bar after its parent fills
+            OrderType::Market if waiting => create_order(current_price)?.order_id,
+            OrderType::Limit | OrderType::StopMarket | OrderType::StopLimit if waiting => create_order(price)?.order_id,
             // Market order
             OrderType::Market => {
                 let market_order = create_order(current_price)?;
 
-                // After creation, execute order immediately
-                self.execute_order(&market_order, current_price)?;
+                // After creation, execute order immediately or wait for the next bar
+                self.fill_market_order(&market_order, &kline)?;
+                market_order.order_id
             }
             // Limit order
             OrderType::Limit => {
-                // if limit price >= close price, execute order immidetely
-                match order_side {
-                    FuturesOrderSide::Long => {
-                        // if configered price is higher than current price, execute order immidetely
-                        if price >= current_price {
-                            let limit_order = create_order(current_price)?;
-                            self.execute_order(&limit_order, current_price)?;
-                            let directly_execute_event = VtsEvent::LimitOrderExecutedDirectly {
-                                limit_price: price,
-                                order: limit_order,
-                            };
-                            self.send_event(directly_execute_event)?;
-                        } else {
-                            create_order(price)?;
-                        }
-                    }
-                    FuturesOrderSide::Short => {
-                        if price <= current_price {
-                            let limit_order = create_order(current_price)?;
-                            self.execute_order(&limit_order, current_price)?;
-                            let directly_execute_event = VtsEvent::LimitOrderExecutedDirectly {
-                                limit_price: price,
-                                order: limit_order,
-                            };
-                            self.send_event(directly_execute_event)?;
-                        } else {
-                            create_order(price)?;
-                        }
-                    }
+                // a limit price at or through the close is marketable
+                let marketable = match order_side {
+                    FuturesOrderSide::Long => price >= current_price,
+                    FuturesOrderSide::Short => price <= current_price,
+                };
+                // In next bar open mode a marketable limit order rests until the next bar, like a market order
+                if marketable && !next_bar_open {
+                    let limit_order = create_order(current_price)?;
+                    let fill = self.fill_model.limit_fill_price(&order_side, price, current_price, &kline);
+                    self.execute_order(&limit_order, fill)?;
+                    let order_id = limit_order.order_id;
+                    let directly_execute_event = VtsEvent::LimitOrderExecutedDirectly {
+                        limit_price: price,
+                        order: limit_order,
+                    };
+                    self.send_event(directly_execute_event)?;
+                    order_id
+                } else {
+                    create_order(price)?.order_id
                 }
             }
+            // Untriggered stop order, waits for the bar high/low to reach the stop price
+            OrderType::StopMarket | OrderType::StopLimit => create_order(price)?.order_id,
             _ => {
                 return Err(UnsupportedOrderTypeSnafu {
                     order_type: order_type.to_string(),
                 }
                 .build());
             }
+        };
+        Ok(order_id)
+    }
+
+    // Execute market order at the current close, in next bar open mode it stays unfilled until check_unfilled_orders
+    pub fn fill_market_order(&mut self, order: &VirtualOrder, kline: &Kline) -> Result<(), VtsError> {
+        if self.fill_model.is_next_bar_open() {
+            return Ok(());
         }
+        let fill = self.fill_model.fill_price(&order.order_side, kline.close, kline);
+        self.execute_order(order, fill)?;
         Ok(())
     }
 
@@ -243,25 +341,43 @@ where
         let high_price = kline.high;
         let low_price = kline.low;
         for order_id in unfilled_order_ids {
-            let order = self.find_unfilled_order(&order_id).map(|order| order.clone());
+            let order = self.find_unfilled_order(&order_id).cloned();
             if let Ok(order) = order {
                 match order.order_type {
-                    OrderType::Limit => {
-                        match order.order_side {
-                            FuturesOrderSide::Long => {
-                                // Limit long: execute when low price <= order price
-                                if low_price <= order.open_price {
-                                    // The execution price for limit orders should be the limit price
-                                    self.execute_order(&order, order.open_price)?;
-                                }
-                            }
-                            FuturesOrderSide::Short => {
-                                // Limit short: execute when high price >= order price
-                                if high_price >= order.open_price {
-                                    // The execution price for limit orders should be the limit price
-                                    self.execute_order(&order, order.open_price)?;
-                                }
-                            }
+                    // Pending market order of next bar open mode, never filled on the bar it was created on
+                    OrderType::Market if kline.datetime > order.create_time => {
+                        let fill = self.fill_model.fill_price(&order.order_side, kline.open, kline);
+                        self.execute_order(&order, fill)?;
+                    }
+                    // Never filled on the bar it was created on. A bar opening through the limit price fills
+                    // at the open as taker, otherwise the limit price is touched inside the bar and fills as maker
+                    OrderType::Limit if kline.datetime > order.create_time => {
+                        let opened_through = match order.order_side {
+                            FuturesOrderSide::Long => kline.open <= order.open_price,
+                            FuturesOrderSide::Short => kline.open >= order.open_price,
+                        };
+                        let touched = match order.order_side {
+                            FuturesOrderSide::Long => low_price <= order.open_price,
+                            FuturesOrderSide::Short => high_price >= order.open_price,
+                        };
+                        if opened_through {
+                            let fill = self
+                                .fill_model
+                                .limit_fill_price(&order.order_side, order.open_price, kline.open, kline);
+                            self.execute_order(&order, fill)?;
+                        } else if touched {
+                            self.execute_order(&order, OrderFill::maker(order.open_price))?;
+                        }
+                    }
+                    // Stop entry: long triggers when high price >= stop price, short when low price <= stop price.
+                    // Never triggered on the bar it was created on, checked here so it does not fall through to the stop loss arm
+                    OrderType::StopMarket | OrderType::StopLimit if let Some(trigger_price) = order.trigger_price => {
+                        let triggered = match order.order_side {
+                            FuturesOrderSide::Long => high_price >= trigger_price,
+                            FuturesOrderSide::Short => low_price <= trigger_price,
+                        };
+                        if triggered && kline.datetime > order.create_time {
+                            self.trigger_stop_order(&order, trigger_price, kline)?;
                         }
                     }
                     OrderType::StopMarket => {
@@ -269,29 +385,32 @@ where
                             FuturesOrderSide::Long => {
                                 // Close long stop loss: execute when high price >= stop loss price
                                 if high_price >= order.open_price {
-                                    self.execute_sl_order(&order)?;
+                                    let fill = self.fill_model.stop_fill_price(&order.order_side, order.open_price, kline);
+                                    self.execute_sl_order(&order, fill)?;
                                 }
                             }
                             FuturesOrderSide::Short => {
                                 // Close short stop loss: execute when low price <= stop loss price
                                 if low_price <= order.open_price {
-                                    self.execute_sl_order(&order)?;
+                                    let fill = self.fill_model.stop_fill_price(&order.order_side, order.open_price, kline);
+                                    self.execute_sl_order(&order, fill)?;
                                 }
                             }
                         }
                     }
+                    // Take profit rests at its price, filled as maker at that price
                     OrderType::TakeProfitMarket => {
                         match order.order_side {
                             FuturesOrderSide::Long => {
                                 // Close long take profit: execute when low price <= take profit price
                                 if low_price <= order.open_price {
-                                    self.execute_tp_order(&order)?;
+                                    self.execute_tp_order(&order, OrderFill::maker(order.open_price))?;
                                 }
                             }
                             FuturesOrderSide::Short => {
                                 // Close short take profit: execute when high price >= take profit price
                                 if high_price >= order.open_price {
-                                    self.execute_tp_order(&order)?;
+                                    self.execute_tp_order(&order, OrderFill::maker(order.open_price))?;
                                 }
                             }
                         }
@@ -305,6 +424,43 @@ where
         Ok(())
     }
 
+    // A stop market order fills at the stop price, or at the open when the bar gaps through it
+    // A stop limit order is placed as a limit order, filled right away when its limit is reachable from the trigger price
+    fn trigger_stop_order(&mut self, order: &VirtualOrder, trigger_price: f64, kline: &Kline) -> Result<(), VtsError> {
+        if order.order_type == OrderType::StopMarket {
+            let fill = self.fill_model.stop_fill_price(&order.order_side, trigger_price, kline);
+            self.execute_order(order, fill)?;
+            return Ok(());
+        }
+
+        let current_datetime = self.current_datetime();
+        let limit_order = {
+            let limit_order = self.find_unfilled_order_mut(&order.order_id)?;
+            limit_order.order_type = OrderType::Limit;
+            limit_order.update_time = current_datetime;
+            limit_order.clone()
+        };
+        self.send_event(VtsEvent::FuturesOrderModified(limit_order.clone()))?;
+
+        let (reference_price, marketable) = match order.order_side {
+            FuturesOrderSide::Long => {
+                let reference_price = trigger_price.max(kline.open);
+                (reference_price, limit_order.open_price >= reference_price)
+            }
+            FuturesOrderSide::Short => {
+                let reference_price = trigger_price.min(kline.open);
+                (reference_price, limit_order.open_price <= reference_price)
+            }
+        };
+        if marketable {
+            let fill = self
+                .fill_model
+                .limit_fill_price(&order.order_side, limit_order.open_price, reference_price, kline);
+            self.execute_order(&limit_order, fill)?;
+        }
+        Ok(())
+    }
+
     pub fn create_tp_order(&mut self, order: &VirtualOrder, position: &VirtualPosition) -> Option<VirtualOrder> {
         // if order has tp, create take profit order
         if let Some(tp) = order.tp {
@@ -314,6 +470,7 @@ where
             };
 
             let tp_order = VirtualOrder::create_take_profit_order(
+                self.id_generator.next_order_id(),
                 Some(position.position_id),
                 position.strategy_id,
                 order.node_id.clone(),
@@ -332,14 +489,21 @@ where
     }
 
     pub fn create_sl_order(&mut self, order: &VirtualOrder, position: &VirtualPosition) -> Option<VirtualOrder> {
-        // Generate stop loss order
-        if let Some(sl) = order.sl {
+        // Generate stop loss order, a trailing stop without a stop loss price starts trailing from the entry price
+        let sl = order.sl.or_else(|| {
+            order
+                .trailing_stop
+                .as_ref()
+                .and_then(|trailing_stop| trailing_stop.initial_stop(&position.position_side, position.open_price, self.atr_values()))
+        });
+        if let Some(sl) = sl {
             let sl_order_side = match position.position_side {
                 PositionSide::Long => FuturesOrderSide::Short,
                 PositionSide::Short => FuturesOrderSide::Long,
             };
 
             let sl_order = VirtualOrder::create_stop_loss_order(
+                self.id_generator.next_order_id(),
                 Some(position.position_id),
                 order.strategy_id,
                 order.node_id.clone(),
@@ -351,9 +515,75 @@ where
                 order.quantity, // Full position
                 sl,             // The open price of stop loss order is the stop loss price in the main order
                 self.current_datetime(),
-            );
+            )
+            .with_trailing_stop(order.trailing_stop.clone());
             return Some(sl_order);
         }
         None
     }
+
+    // Cancel an unfilled order, the canceled event matches the order type
+    pub fn cancel_order(&mut self, order_id: OrderId) -> Result<VirtualOrder, VtsError> {
+        let canceled_order = self.update_order_status(order_id, OrderStatus::Canceled)?;
+        let canceled_event = match canceled_order.order_type {
+            OrderType::TakeProfitMarket => VtsEvent::TakeProfitOrderCanceled(canceled_order.clone()),
+            OrderType::StopMarket if canceled_order.is_stop_loss_order() => VtsEvent::StopLossOrderCanceled(canceled_order.clone()),
+            _ => VtsEvent::FuturesOrderCanceled(canceled_order.clone()),
+        };
+        self.send_event(canceled_event)?;
+        Ok(canceled_order)
+    }
+
+    // Cancel the pending entry orders of a symbol and/or a node, tp/sl orders of open positions are kept
+    pub fn cancel_all_orders(
+        &mut self,
+        exchange: &Exchange,
+        symbol: Option<&String>,
+        node_id: Option<&NodeId>,
+    ) -> Result<Vec<OrderId>, VtsError> {
+        let order_ids: Vec<OrderId> = self
+            .unfilled_orders
+            .iter()
+            .filter(|order| {
+                &order.exchange == exchange
+                    && !order.is_tpsl_order()
+                    && symbol.is_none_or(|symbol| &order.symbol == symbol)
+                    && node_id.is_none_or(|node_id| &order.node_id == node_id)
+            })
+            .map(|order| order.order_id)
+            .collect();
+
+        for order_id in order_ids.iter() {
+            self.cancel_order(*order_id)?;
+        }
+        Ok(order_ids)
+    }
+
+    // Modify the price, quantity or tp/sl of an unfilled order
+    pub fn modify_order(&mut self, payload: ModifyOrderCmdPayload) -> Result<VirtualOrder, VtsError> {
+        let current_datetime = self.current_datetime();
+        let order = self.find_unfilled_order_mut(&payload.order_id)?;
+
+        let reason = match order.order_type {
+            OrderType::Market if payload.price.is_some() => Some("the price of a market order cannot be modified"),
+            _ if order.is_tpsl_order() && (payload.tp.is_some() || payload.sl.is_some()) => {
+                Some("tp/sl can only be modified on entry orders")
+            }
+            _ if payload.price.is_some_and(|price| price <= 0.0) => Some("price must be greater than 0"),
+            _ if payload.quantity.is_some_and(|quantity| quantity <= 0.0) => Some("quantity must be greater than 0"),
+            _ => None,
+        };
+        if let Some(reason) = reason {
+            return Err(InvalidOrderModificationSnafu {
+                order_id: payload.order_id,
+                reason: reason.to_string(),
+            }
+            .build());
+        }
+
+        order.modify(&payload, current_datetime);
+        let modified_order = order.clone();
+        self.send_event(VtsEvent::FuturesOrderModified(modified_order.clone()))?;
+        Ok(modified_order)
+    }
 }
@@ -13,9 +13,13 @@ use star_river_core::{order::OrderType, position::PositionState};
 // Local module imports
 use super::VtsContext;
 use crate::{
-    error::{MarginNotEnoughSnafu, PositionNotFoundForSymbolSnafu, PositionNotFoundSnafu, VtsError},
+    error::{
+        InvalidPartialCloseSnafu, MarginNotEnoughSnafu, PositionNotFoundForSymbolSnafu, PositionNotFoundSnafu, ScaleInLimitExceededSnafu,
+        VtsError,
+    },
     event::VtsEvent,
-    types::{VirtualOrder, VirtualPosition, VirtualTransaction},
+    fill_model::OrderFill,
+    types::{PartialCloseSize, VirtualOrder, VirtualPosition, VirtualTransaction},
     utils::Formula,
 };
 
@@ -47,6 +51,43 @@ where
             .context(PositionNotFoundSnafu { position_id: position_id })
     }
 
+    /// Quantity an order of `order_side` can reduce, None when there is no opposite position
+    pub fn reducible_quantity(&self, symbol: &String, exchange: &Exchange, order_side: &FuturesOrderSide) -> Option<f64> {
+        let position = self.find_position_for(symbol, exchange).ok()?;
+        match (order_side, &position.position_side) {
+            (FuturesOrderSide::Long, PositionSide::Short) | (FuturesOrderSide::Short, PositionSide::Long) => Some(position.quantity),
+            _ => None,
+        }
+    }
+
+    /// Check the scale-in limit for an order opening a position or adding to the same side one
+    pub fn check_scale_in(
+        &self,
+        symbol: &String,
+        exchange: &Exchange,
+        order_side: &FuturesOrderSide,
+        quantity: f64,
+    ) -> Result<(), VtsError> {
+        // An opposite order only reduces the position
+        if self.reducible_quantity(symbol, exchange, order_side).is_some() {
+            return Ok(());
+        }
+        let position = self.find_position_for(symbol, exchange).ok();
+        self.scale_in_limit.check(position, quantity).map_err(|reason| {
+            ScaleInLimitExceededSnafu {
+                symbol: symbol.clone(),
+                exchange: exchange.to_string(),
+                reason,
+            }
+            .build()
+        })
+    }
+
+    /// Bars played since the position was opened
+    pub fn holding_bars(&self, position: &VirtualPosition) -> u64 {
+        self.bar_count(&position.exchange, &position.symbol) - position.open_bar
+    }
+
     // Remove position from current positions list
     pub fn remove_open_position(&mut self, position_id: PositionId) {
         self.current_positions.retain(|p| p.position_id != position_id);
@@ -79,12 +120,14 @@ impl<E> VtsContext<E>
 where
     E: Clone + Send + Sync + 'static,
 {
-    pub fn create_position(&mut self, order: &VirtualOrder, current_price: f64) -> Result<(VirtualPosition, VirtualTransaction), VtsError> {
-        // Check if margin is sufficient
+    pub fn create_position(&mut self, order: &VirtualOrder, fill: OrderFill) -> Result<(VirtualPosition, VirtualTransaction), VtsError> {
+        let current_price = fill.price;
+        // Check if margin and the opening fee are covered
         let margin = Formula::calculate_margin(self.leverage, current_price, order.quantity);
-        if margin > self.available_balance {
+        let opening_fee = self.fee_schedule.fee(&order.symbol, fill.liquidity, current_price, order.quantity);
+        if margin + opening_fee > self.available_balance {
             return Err(MarginNotEnoughSnafu {
-                need_margin: margin,
+                need_margin: margin + opening_fee,
                 available_balance: self.available_balance,
             }
             .build());
@@ -96,7 +139,8 @@ where
         };
         let force_price = Formula::calculate_force_price(&position_side, self.leverage, current_price, order.quantity);
         let margin_ratio = Formula::calculate_margin_ratio(self.available_balance, self.leverage, current_price, order.quantity);
-        let virtual_position = VirtualPosition::new(
+        let mut virtual_position = VirtualPosition::new(
+            self.id_generator.next_position_id(),
             position_side,
             order.strategy_id,
             order.exchange.clone(),
@@ -109,9 +153,11 @@ where
             self.leverage,
             self.current_datetime(),
         );
+        virtual_position.open_bar = self.bar_count(&order.exchange, &order.symbol);
         // tracing::debug!("create position successfully: {:#?}", virtual_position);
         self.current_positions.push(virtual_position.clone());
-        let transaction = VirtualTransaction::new(
+        let mut transaction = VirtualTransaction::new(
+            self.id_generator.next_transaction_id(),
             order.order_id,
             virtual_position.position_id,
             order.strategy_id,
@@ -126,13 +172,56 @@ where
             None,
             self.current_datetime(),
         );
-        self.transactions.push(transaction.clone());
+        self.apply_fill(&mut transaction, &fill);
+        self.record_transaction(transaction.clone());
         Ok((virtual_position, transaction))
     }
 
+    // Record slippage, liquidity and the fee of the fill on the transaction
+    pub(crate) fn apply_fill(&self, transaction: &mut VirtualTransaction, fill: &OrderFill) {
+        transaction.slippage = fill.slippage;
+        transaction.liquidity = fill.liquidity;
+        transaction.fee = self
+            .fee_schedule
+            .fee(&transaction.symbol, fill.liquidity, transaction.price, transaction.quantity);
+    }
+
     /// Execute an open order, return position id
     /// Generate position and transaction details
-    pub fn execute_order(&mut self, order: &VirtualOrder, current_price: f64) -> Result<PositionId, VtsError> {
+    /// A reduce only order without an opposite position left, or an order exceeding the scale-in limit, is canceled instead and None is returned
+    pub fn execute_order(&mut self, order: &VirtualOrder, fill: OrderFill) -> Result<Option<PositionId>, VtsError> {
+        let capped_order;
+        let order = if order.reduce_only {
+            match self.reducible_quantity(&order.symbol, &order.exchange, &order.order_side) {
+                None => {
+                    let canceled_order = self.update_order_status(order.order_id, OrderStatus::Canceled)?;
+                    self.send_event(VtsEvent::FuturesOrderCanceled(canceled_order))?;
+                    return Ok(None);
+                }
+                // The position may have shrunk since the order was created
+                Some(reducible_quantity) if order.quantity > reducible_quantity => {
+                    self.find_unfilled_order_mut(&order.order_id)?.quantity = reducible_quantity;
+                    capped_order = VirtualOrder {
+                        quantity: reducible_quantity,
+                        ..order.clone()
+                    };
+                    &capped_order
+                }
+                Some(_) => order,
+            }
+        } else {
+            // Pending orders are checked again, other orders may have grown the position since they were created
+            if self
+                .check_scale_in(&order.symbol, &order.exchange, &order.order_side, order.quantity)
+                .is_err()
+            {
+                let canceled_order = self.update_order_status(order.order_id, OrderStatus::Canceled)?;
+                self.send_event(VtsEvent::FuturesOrderCanceled(canceled_order))?;
+                return Ok(None);
+            }
+            order
+        };
+        let current_price = fill.price;
         // tracing::info!("execute open order: {:#?}, execute price: {:?}", order, current_price);
 
         let execute_datetime = self.current_datetime();
@@ -148,12 +237,13 @@ where
         if let Some(position_id) = existing_position_id {
             tracing::debug!("existing position: {:#?}", position_id);
             let available_balance = self.available_balance;
-            let (position, transaction) = {
+            let transaction_id = self.id_generator.next_transaction_id();
+            let (position, mut transaction) = {
                 let position = self.find_position_mut(position_id)?;
-                let (position, transaction) = position.update_with_new_order(order, current_price, available_balance, execute_datetime)?;
-                (position, transaction)
+                position.update_with_new_order(transaction_id, order, current_price, available_balance, execute_datetime)?
             };
-            self.transactions.push(transaction.clone());
+            self.apply_fill(&mut transaction, &fill);
+            self.record_transaction(transaction.clone());
             self.send_event(VtsEvent::TransactionCreated(transaction))?;
             self.send_event(VtsEvent::PositionUpdated(position.clone()))?;
 
@@ -176,33 +266,41 @@ where
                 self.history_positions.push(position);
             }
 
-            return Ok(position_id);
+            return Ok(Some(position_id));
         } else {
             tracing::debug!("no existing position, create new position for order: {:#?}", order.order_id);
-            let (position, transaction) = self.create_position(order, current_price)?;
+            let (position, transaction) = self.create_position(order, fill)?;
             self.send_event(VtsEvent::TransactionCreated(transaction))?;
 
             self.update_order_position_id(order.order_id, position.position_id)?;
             self.send_event(VtsEvent::PositionCreated(position.clone()))?;
-            if let Some(tp_order) = self.create_tp_order(order, &position) {
-                self.unfilled_orders.push(tp_order.clone());
-                self.send_event(VtsEvent::TakeProfitOrderCreated(tp_order))?;
-            }
+            // The tp/sl legs of a bracket entry already wait in its group
+            if !order.is_bracket_entry() {
+                if let Some(tp_order) = self.create_tp_order(order, &position) {
+                    self.unfilled_orders.push(tp_order.clone());
+                    self.send_event(VtsEvent::TakeProfitOrderCreated(tp_order))?;
+                }
 
-            // Create stop loss order
-            if let Some(sl_order) = self.create_sl_order(order, &position) {
-                self.unfilled_orders.push(sl_order.clone());
-                self.send_event(VtsEvent::StopLossOrderCreated(sl_order))?;
+                // Create stop loss order, an ATR trailing stop without a stop loss price waits for the ATR value
+                if let Some(sl_order) = self.create_sl_order(order, &position) {
+                    self.unfilled_orders.push(sl_order.clone());
+                    self.send_event(VtsEvent::StopLossOrderCreated(sl_order))?;
+                } else if order.sl.is_none() && order.trailing_stop.is_some() {
+                    self.pending_trailing_stops.push((position.position_id, order.clone()));
+                }
             }
 
             let filled_order = self.update_order_status(order.order_id, OrderStatus::Filled)?;
             self.send_event(VtsEvent::FuturesOrderFilled(filled_order))?;
-            return Ok(position.position_id);
+            return Ok(Some(position.position_id));
         };
     }
 
     // Update positions
     pub fn update_current_positions(&mut self, exchange: &Exchange, symbol: &String, kline: &Kline) -> Result<(), VtsError> {
+        // Liquidate first, the liquidation price of the previous bar is checked against this bar's high/low
+        self.check_liquidation(exchange, symbol, kline)?;
+
         let position_ids: Vec<PositionId> = self
             .current_positions
             .iter()
@@ -217,6 +315,7 @@ where
         for position_id in position_ids {
             let leverage = self.leverage;
             let available_balance = self.available_balance;
+            let force_price = self.liquidation_price(self.find_position(position_id)?);
 
             let position = self.find_position_mut(position_id)?;
             let current_price = kline.close;
@@ -226,23 +325,94 @@ where
             // Calculate new margin information
             let margin = Formula::calculate_margin(leverage, current_price, quantity);
             let margin_ratio = Formula::calculate_margin_ratio(available_balance, leverage, current_price, quantity);
-            let force_price = Formula::calculate_force_price(&position.position_side, leverage, current_price, quantity);
 
             // Update position
             position.update(current_price, current_datetime, margin, margin_ratio, force_price);
             let position_updated_event = VtsEvent::PositionUpdated(position.clone());
             self.send_event(position_updated_event)?;
         }
+        // The moved stops take effect from the next bar
+        self.adjust_stop_loss_orders(exchange, symbol, kline)?;
+        Ok(())
+    }
+
+    /// Create the stop loss orders of the pending trailing stops of the symbol once their ATR has a value
+    fn create_pending_trailing_stops(&mut self, exchange: &Exchange, symbol: &String) -> Result<(), VtsError> {
+        // Positions closed before the ATR had a value need no stop loss
+        let current_positions = &self.current_positions;
+        self.pending_trailing_stops
+            .retain(|(position_id, _)| current_positions.iter().any(|p| p.position_id == *position_id));
+
+        let mut index = 0;
+        while index < self.pending_trailing_stops.len() {
+            let (position_id, entry_order) = &self.pending_trailing_stops[index];
+            if &entry_order.exchange != exchange || &entry_order.symbol != symbol {
+                index += 1;
+                continue;
+            }
+            let position = self.find_position(*position_id)?.clone();
+            let entry_order = entry_order.clone();
+            match self.create_sl_order(&entry_order, &position) {
+                Some(sl_order) => {
+                    self.pending_trailing_stops.remove(index);
+                    self.unfilled_orders.push(sl_order.clone());
+                    self.send_event(VtsEvent::StopLossOrderCreated(sl_order))?;
+                }
+                None => index += 1,
+            }
+        }
+        Ok(())
+    }
+
+    /// Move trailing and break-even stop loss orders of the symbol with the bar's high/low
+    fn adjust_stop_loss_orders(&mut self, exchange: &Exchange, symbol: &String, kline: &Kline) -> Result<(), VtsError> {
+        self.create_pending_trailing_stops(exchange, symbol)?;
+
+        let current_datetime = self.current_datetime();
+        let mut updated_orders = Vec::new();
+        for order in self
+            .unfilled_orders
+            .iter_mut()
+            .filter(|o| &o.exchange == exchange && &o.symbol == symbol && o.is_stop_loss_order())
+        {
+            let Some(trailing_stop) = order.trailing_stop.as_ref() else {
+                continue;
+            };
+            let Some(position) = self.current_positions.iter().find(|p| Some(p.position_id) == order.position_id) else {
+                continue;
+            };
+            let previous_best_price = order.trailing_best_price.unwrap_or(position.open_price);
+            let best_price = match position.position_side {
+                PositionSide::Long => previous_best_price.max(kline.high),
+                PositionSide::Short => previous_best_price.min(kline.low),
+            };
+            order.trailing_best_price = Some(best_price);
+            if let Some(stop) = trailing_stop.adjust(
+                &position.position_side,
+                position.open_price,
+                order.open_price,
+                best_price,
+                &self.atr_values,
+            ) {
+                order.open_price = stop;
+                order.update_time = current_datetime;
+                updated_orders.push(order.clone());
+            }
+        }
+        for order in updated_orders {
+            self.send_event(VtsEvent::StopLossOrderUpdated(order))?;
+        }
         Ok(())
     }
 
     /// Execute take profit order
-    pub fn execute_tp_order(&mut self, tp_order: &VirtualOrder) -> Result<(), VtsError> {
+    pub fn execute_tp_order(&mut self, tp_order: &VirtualOrder, fill: OrderFill) -> Result<(), VtsError> {
         tracing::info!(
-            "execute tp order: ID: {:?}, side: {:?}, price: {:?}",
+            "execute tp order: ID: {:?}, side: {:?}, price: {:?}, fill price: {:?}",
             tp_order.order_id,
             tp_order.order_side,
-            tp_order.open_price
+            tp_order.open_price,
+            fill.price
         );
 
         let position_id = match tp_order.position_id {
@@ -253,11 +423,13 @@ where
         let execute_datetime = self.current_datetime();
 
         // Update position and determine if fully closed
-        let (position, virtual_transaction) = {
+        let (position, mut virtual_transaction) = {
             let available_balance = self.available_balance;
+            let transaction_id = self.id_generator.next_transaction_id();
             let position = self.find_position_mut(position_id)?;
-            position.update_with_tp_order(tp_order, available_balance, execute_datetime)
+            position.update_with_tp_order(transaction_id, tp_order, fill.price, available_balance, execute_datetime)
         };
+        self.apply_fill(&mut virtual_transaction, &fill);
         tracing::debug!("update position with tp order: {:#?}", position);
 
         // Send position updated event
@@ -267,7 +439,7 @@ where
         if position.position_state == PositionState::Closed {
             self.send_event(VtsEvent::PositionClosed(position.clone()))?;
         }
-        self.transactions.push(virtual_transaction.clone());
+        self.record_transaction(virtual_transaction.clone());
         self.send_event(VtsEvent::TransactionCreated(virtual_transaction))?;
 
         // Update tp order status to filled
@@ -294,12 +466,13 @@ where
     }
 
     /// Execute stop loss order
-    pub fn execute_sl_order(&mut self, sl_order: &VirtualOrder) -> Result<(), VtsError> {
+    pub fn execute_sl_order(&mut self, sl_order: &VirtualOrder, fill: OrderFill) -> Result<(), VtsError> {
         tracing::info!(
-            "execute sl order: ID: {:?}, side: {:?}, price: {:?}, quantity: {:?}",
+            "execute sl order: ID: {:?}, side: {:?}, price: {:?}, fill price: {:?}, quantity: {:?}",
             sl_order.order_id,
             sl_order.order_side,
             sl_order.open_price,
+            fill.price,
             sl_order.quantity
         );
 
@@ -311,11 +484,13 @@ where
         let execute_datetime = self.current_datetime();
 
         // Update position and determine if fully closed
-        let (position, virtual_transaction) = {
+        let (position, mut virtual_transaction) = {
             let available_balance = self.available_balance;
+            let transaction_id = self.id_generator.next_transaction_id();
             let position = self.find_position_mut(position_id)?;
-            position.update_with_sl_order(sl_order, available_balance, execute_datetime)
+            position.update_with_sl_order(transaction_id, sl_order, fill.price, available_balance, execute_datetime)
         };
+        self.apply_fill(&mut virtual_transaction, &fill);
 
         // Send position updated event
         self.send_event(VtsEvent::PositionUpdated(position.clone()))?;
@@ -326,7 +501,7 @@ where
         }
 
         // send transaction
-        self.transactions.push(virtual_transaction.clone());
+        self.record_transaction(virtual_transaction.clone());
         self.send_event(VtsEvent::TransactionCreated(virtual_transaction))?;
 
         // Update sl order status to filled
@@ -366,9 +541,11 @@ where
             (position.position_id, strategy_id, order_side, quantity)
         };
 
-        let current_price = self.find_kline_price(&exchange, &symbol)?.close;
+        let kline = self.find_kline_price(&exchange, &symbol)?.clone();
+        let current_price = kline.close;
         // Close position by creating a market order
         let market_order = VirtualOrder::create_order(
+            self.id_generator.next_order_id(),
             strategy_id,
             node_id.clone(),
             node_name.clone(),
@@ -388,11 +565,65 @@ where
         );
         self.unfilled_orders.push(market_order.clone());
         self.send_event(VtsEvent::FuturesOrderCreated(market_order.clone()))?;
-        self.execute_order(&market_order, current_price)?;
+        self.fill_market_order(&market_order, &kline)?;
         // position
         Ok(position_id)
     }
 
+    /// Close part of the position of a symbol with a reduce only market order, return position id and close quantity
+    pub fn partially_close_position(
+        &mut self,
+        node_id: &NodeId,
+        node_name: &NodeName,
+        config_id: i32,
+        symbol: &String,
+        exchange: &Exchange,
+        size: &PartialCloseSize,
+    ) -> Result<(PositionId, f64), VtsError> {
+        let (position_id, strategy_id, order_side, close_quantity) = {
+            let position = self.find_position_for(symbol, exchange)?;
+            let order_side = match position.position_side {
+                PositionSide::Long => FuturesOrderSide::Short,
+                PositionSide::Short => FuturesOrderSide::Long,
+            };
+            let close_quantity = size.close_quantity(position.quantity).map_err(|reason| {
+                InvalidPartialCloseSnafu {
+                    position_id: position.position_id,
+                    reason,
+                }
+                .build()
+            })?;
+            (position.position_id, position.strategy_id, order_side, close_quantity)
+        };
+
+        let kline = self.find_kline_price(exchange, symbol)?.clone();
+        let market_order = VirtualOrder::create_order(
+            self.id_generator.next_order_id(),
+            strategy_id,
+            node_id.clone(),
+            node_name.clone(),
+            config_id,
+            exchange.clone(),
+            symbol.clone(),
+            order_side,
+            OrderType::Market,
+            close_quantity,
+            kline.close,
+            None,
+            None,
+            None,
+            None,
+            None,
+            self.current_datetime(),
+        )
+        .with_reduce_only(true);
+        self.unfilled_orders.push(market_order.clone());
+        self.send_event(VtsEvent::FuturesOrderCreated(market_order.clone()))?;
+        // The opposite fill goes through VirtualPosition::close_partially, fees and slippage apply as for any order
+        self.fill_market_order(&market_order, &kline)?;
+        Ok((position_id, close_quantity))
+    }
+
     pub fn close_all_positions(&mut self, node_id: &NodeId, node_name: &NodeName, config_id: i32) -> Result<Vec<PositionId>, VtsError> {
         let all_position_ids = self.current_positions.iter().map(|p| p.position_id).collect::<Vec<PositionId>>();
 
@@ -410,10 +641,12 @@ where
                 (strategy_id, order_side, quantity, exchange, symbol)
             };
 
-            let current_price = self.find_kline_price(&exchange, &symbol)?.close;
+            let kline = self.find_kline_price(&exchange, &symbol)?.clone();
+            let current_price = kline.close;
 
             // Close position by creating a market order
             let market_order = VirtualOrder::create_order(
+                self.id_generator.next_order_id(),
                 strategy_id,
                 node_id.clone(),
                 node_name.clone(),
@@ -434,7 +667,7 @@ where
             tracing::debug!("close position, market order created: {:#?}", market_order);
             self.unfilled_orders.push(market_order.clone());
             self.send_event(VtsEvent::FuturesOrderCreated(market_order.clone()))?;
-            self.execute_order(&market_order, current_price)?;
+            self.fill_market_order(&market_order, &kline)?;
         }
 
         Ok(all_position_ids)
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use star_river_core::{custom_type::*, exchange::Exchange, kline::Kline, system::map_entries};
+
+use super::VtsContext;
+use crate::types::{FundingPayment, VirtualOrder, VirtualPosition, VirtualTransaction, id_generator::IdGenerator};
+
+/// Trading state of the virtual trading system at one point of a backtest
+///
+/// Only the state that changes while playing is kept, configuration (balance, leverage, fees, margin, funding, fill model)
+/// stays the one set on initialization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VtsSnapshot {
+    #[serde(with = "map_entries")]
+    pub kline_price: HashMap<(Exchange, String), Kline>,
+    pub balance: Balance,
+    pub available_balance: Balance,
+    pub equity: Equity,
+    #[serde(default)]
+    pub peak_equity: Equity,
+    pub realized_pnl: Pnl,
+    pub unrealized_pnl: Pnl,
+    pub used_margin: Margin,
+    pub frozen_margin: Margin,
+    pub margin_ratio: MarginRatio,
+    pub total_fee: f64,
+    pub funding_payments: Vec<FundingPayment>,
+    pub total_funding: f64,
+    #[serde(with = "map_entries")]
+    pub last_funding_time: HashMap<(Exchange, String), DateTime<Utc>>,
+    pub current_positions: Vec<VirtualPosition>,
+    pub history_positions: Vec<VirtualPosition>,
+    pub unfilled_orders: Vec<VirtualOrder>,
+    #[serde(default)]
+    pub pending_trailing_stops: Vec<(PositionId, VirtualOrder)>,
+    pub history_orders: Vec<VirtualOrder>,
+    pub transactions: Vec<VirtualTransaction>,
+    #[serde(default, with = "map_entries")]
+    pub bar_count: HashMap<(Exchange, String), u64>,
+    #[serde(default, with = "map_entries")]
+    pub last_trade_bar: HashMap<(Exchange, String), u64>,
+    #[serde(default, with = "map_entries")]
+    pub atr_values: HashMap<(NodeId, i32), f64>,
+    // Id counters of this context, restoring them keeps the ids of replayed orders identical to the first play
+    pub order_id_counter: i32,
+    pub position_id_counter: i32,
+    pub transaction_id_counter: i32,
+    #[serde(default)]
+    pub order_group_id_counter: i32,
+}
+
+impl<E> VtsContext<E>
+where
+    E: Clone + Send + Sync + 'static,
+{
+    pub fn snapshot(&self) -> VtsSnapshot {
+        VtsSnapshot {
+            kline_price: self.kline_price.clone(),
+            balance: self.balance,
+            available_balance: self.available_balance,
+            equity: self.equity,
+            peak_equity: self.peak_equity,
+            realized_pnl: self.realized_pnl,
+            unrealized_pnl: self.unrealized_pnl,
+            used_margin: self.used_margin,
+            frozen_margin: self.frozen_margin,
+            margin_ratio: self.margin_ratio,
+            total_fee: self.total_fee,
+            funding_payments: self.funding_payments.clone(),
+            total_funding: self.total_funding,
+            last_funding_time: self.last_funding_time.clone(),
+            current_positions: self.current_positions.clone(),
+            history_positions: self.history_positions.clone(),
+            unfilled_orders: self.unfilled_orders.clone(),
+            pending_trailing_stops: self.pending_trailing_stops.clone(),
+            history_orders: self.history_orders.clone(),
+            transactions: self.transactions.clone(),
+            bar_count: self.bar_count.clone(),
+            last_trade_bar: self.last_trade_bar.clone(),
+            atr_values: self.atr_values.clone(),
+            order_id_counter: self.id_generator.order_id,
+            position_id_counter: self.id_generator.position_id,
+            transaction_id_counter: self.id_generator.transaction_id,
+            order_group_id_counter: self.id_generator.order_group_id,
+        }
+    }
+
+    pub fn restore(&mut self, snapshot: VtsSnapshot) {
+        self.kline_price = snapshot.kline_price;
+        self.balance = snapshot.balance;
+        self.available_balance = snapshot.available_balance;
+        self.equity = snapshot.equity;
+        self.peak_equity = snapshot.peak_equity;
+        self.realized_pnl = snapshot.realized_pnl;
+        self.unrealized_pnl = snapshot.unrealized_pnl;
+        self.used_margin = snapshot.used_margin;
+        self.frozen_margin = snapshot.frozen_margin;
+        self.margin_ratio = snapshot.margin_ratio;
+        self.total_fee = snapshot.total_fee;
+        self.funding_payments = snapshot.funding_payments;
+        self.total_funding = snapshot.total_funding;
+        self.last_funding_time = snapshot.last_funding_time;
+        self.current_positions = snapshot.current_positions;
+        self.history_positions = snapshot.history_positions;
+        self.unfilled_orders = snapshot.unfilled_orders;
+        self.pending_trailing_stops = snapshot.pending_trailing_stops;
+        self.history_orders = snapshot.history_orders;
+        self.transactions = snapshot.transactions;
+        self.bar_count = snapshot.bar_count;
+        self.last_trade_bar = snapshot.last_trade_bar;
+        self.atr_values = snapshot.atr_values;
+        self.id_generator = IdGenerator {
+            order_id: snapshot.order_id_counter,
+            position_id: snapshot.position_id_counter,
+            transaction_id: snapshot.transaction_id_counter,
+            order_group_id: snapshot.order_group_id_counter,
+        };
+    }
+}
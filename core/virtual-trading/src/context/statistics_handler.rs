@@ -16,6 +16,16 @@ where
         self.realized_pnl = self.transactions.iter().map(|transaction| transaction.profit.unwrap_or(0.0)).sum();
     }
 
+    // Update total trading fee
+    pub fn update_total_fee(&mut self) {
+        self.total_fee = self.transactions.iter().map(|transaction| transaction.fee).sum();
+    }
+
+    // Update total funding
+    pub fn update_total_funding(&mut self) {
+        self.total_funding = self.funding_payments.iter().map(|payment| payment.amount).sum();
+    }
+
     // Update used margin
     pub fn update_used_margin(&mut self) {
         self.used_margin = self.current_positions.iter().map(|position| position.margin).sum();
@@ -41,12 +51,22 @@ where
 
     // Update account balance
     pub fn update_balance(&mut self) {
-        self.balance = self.initial_balance + self.realized_pnl;
+        self.balance = self.initial_balance + self.realized_pnl - self.total_fee + self.total_funding;
     }
 
     // Update equity
     pub fn update_equity(&mut self) {
         self.equity = self.balance + self.unrealized_pnl;
+        self.peak_equity = self.peak_equity.max(self.equity);
+    }
+
+    // Current drawdown from the peak equity, as a fraction
+    pub fn current_drawdown(&self) -> f64 {
+        if self.peak_equity <= 0.0 {
+            0.0
+        } else {
+            (self.peak_equity - self.equity) / self.peak_equity
+        }
     }
 
     // Update available balance
@@ -1,3 +1,22 @@
+use star_river_core::exchange::Exchange;
+
 use super::VtsContext;
+use crate::types::VirtualTransaction;
+
+impl<E> VtsContext<E>
+where
+    E: Clone + Send + Sync + 'static,
+{
+    pub fn record_transaction(&mut self, transaction: VirtualTransaction) {
+        let bar_count = self.bar_count(&transaction.exchange, &transaction.symbol);
+        self.last_trade_bar
+            .insert((transaction.exchange.clone(), transaction.symbol.clone()), bar_count);
+        self.transactions.push(transaction);
+    }
 
-impl<E> VtsContext<E> where E: Clone + Send + Sync + 'static {}
+    /// Bars played since the latest transaction of the symbol, None before its first transaction
+    pub fn bars_since_last_trade(&self, exchange: &Exchange, symbol: &String) -> Option<u64> {
+        let last_trade_bar = self.last_trade_bar.get(&(exchange.clone(), symbol.clone()))?;
+        Some(self.bar_count(exchange, symbol) - last_trade_bar)
+    }
+}
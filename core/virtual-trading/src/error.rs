@@ -90,6 +90,64 @@ pub enum VtsError {
         source: serde_json::Error,
         backtrace: Backtrace,
     },
+
+    #[snafu(display("read funding rate file [{path}] failed: {source}"))]
+    FundingRateFileReadFailed {
+        path: String,
+        source: csv::Error,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("invalid funding rate record in file [{path}] at line {line}, expected symbol,datetime,rate"))]
+    InvalidFundingRateRecord { path: String, line: usize, backtrace: Backtrace },
+
+    #[snafu(display("order [{order_id}] cannot be modified: {reason}"))]
+    InvalidOrderModification {
+        order_id: i32,
+        reason: String,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("reduce only order of [{symbol}] on [{exchange}] is rejected: {reason}"))]
+    ReduceOnlyOrderRejected {
+        symbol: String,
+        exchange: String,
+        reason: String,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("{order_type} order requires a stop price"))]
+    StopPriceNotConfigured { order_type: String, backtrace: Backtrace },
+
+    #[snafu(display("Position #{position_id} cannot be partially closed: {reason}"))]
+    InvalidPartialClose {
+        position_id: PositionId,
+        reason: String,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("Invalid {group_type} order group: {reason}"))]
+    InvalidOrderGroup {
+        group_type: String,
+        reason: String,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("order of [{symbol}] on [{exchange}] exceeds the scale-in limit: {reason}"))]
+    ScaleInLimitExceeded {
+        symbol: String,
+        exchange: String,
+        reason: String,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("invalid trailing stop of [{symbol}] on [{exchange}]: {reason}"))]
+    InvalidTrailingStop {
+        symbol: String,
+        exchange: String,
+        reason: String,
+        backtrace: Backtrace,
+    },
 }
 
 // Implement the StarRiverErrorTrait for IndicatorError
@@ -114,6 +172,15 @@ impl StarRiverErrorTrait for VtsError {
             VtsError::SlOrderQuantityMoreThanPosQuantity { .. } => 1011, // sl order quantity more than pos quantity
             VtsError::PositionNotFoundForSymbol { .. } => 1012,          // position not found for symbol and exchange
             VtsError::VirtualOrderSerializeFailed { .. } => 1013,        // virtual order serialize failed
+            VtsError::FundingRateFileReadFailed { .. } => 1014,          // funding rate file read failed
+            VtsError::InvalidFundingRateRecord { .. } => 1015,           // invalid funding rate record
+            VtsError::InvalidOrderModification { .. } => 1016,           // invalid order modification
+            VtsError::ReduceOnlyOrderRejected { .. } => 1017,            // reduce only order rejected
+            VtsError::InvalidPartialClose { .. } => 1018,                // invalid partial close
+            VtsError::StopPriceNotConfigured { .. } => 1019,             // stop price not configured
+            VtsError::InvalidOrderGroup { .. } => 1020,                  // invalid order group
+            VtsError::ScaleInLimitExceeded { .. } => 1021,               // scale-in limit exceeded
+            VtsError::InvalidTrailingStop { .. } => 1022,                // invalid trailing stop
         };
         format!("{}_{:04}", prefix, code)
     }
@@ -183,6 +250,39 @@ impl StarRiverErrorTrait for VtsError {
                 VtsError::VirtualOrderSerializeFailed { source, virtual_order, .. } => {
                     format!("订单序列化失败: {source}, 订单: {virtual_order:?}")
                 }
+                VtsError::FundingRateFileReadFailed { path, source, .. } => {
+                    format!("读取资金费率文件 [{path}] 失败: {source}")
+                }
+                VtsError::InvalidFundingRateRecord { path, line, .. } => {
+                    format!("资金费率文件 [{path}] 第 {line} 行格式错误, 应为 symbol,datetime,rate")
+                }
+                VtsError::InvalidOrderModification { order_id, reason, .. } => {
+                    format!("订单 [{order_id}] 无法修改: {reason}")
+                }
+                VtsError::ReduceOnlyOrderRejected {
+                    symbol, exchange, reason, ..
+                } => {
+                    format!("[{exchange}] [{symbol}] 只减仓订单被拒绝: {reason}")
+                }
+                VtsError::InvalidPartialClose { position_id, reason, .. } => {
+                    format!("仓位 #{position_id} 无法部分平仓: {reason}")
+                }
+                VtsError::StopPriceNotConfigured { order_type, .. } => {
+                    format!("{order_type} 订单未配置触发价格")
+                }
+                VtsError::InvalidOrderGroup { group_type, reason, .. } => {
+                    format!("无效的 {group_type} 订单组: {reason}")
+                }
+                VtsError::ScaleInLimitExceeded {
+                    symbol, exchange, reason, ..
+                } => {
+                    format!("[{exchange}] [{symbol}] 订单超出加仓限制: {reason}")
+                }
+                VtsError::InvalidTrailingStop {
+                    symbol, exchange, reason, ..
+                } => {
+                    format!("[{exchange}] [{symbol}] 无效的移动止损: {reason}")
+                }
             },
         }
     }
@@ -201,6 +301,11 @@ impl StarRiverErrorTrait for VtsError {
             VtsError::SlOrderQuantityMoreThanPosQuantity { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             VtsError::PositionNotFoundForSymbol { .. } => StatusCode::NOT_FOUND,
             VtsError::VirtualOrderSerializeFailed { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            VtsError::FundingRateFileReadFailed { .. } | VtsError::InvalidFundingRateRecord { .. } => StatusCode::BAD_REQUEST,
+            VtsError::InvalidOrderModification { .. } => StatusCode::BAD_REQUEST,
+            VtsError::ReduceOnlyOrderRejected { .. } | VtsError::InvalidPartialClose { .. } => StatusCode::BAD_REQUEST,
+            VtsError::StopPriceNotConfigured { .. } | VtsError::InvalidOrderGroup { .. } => StatusCode::BAD_REQUEST,
+            VtsError::ScaleInLimitExceeded { .. } | VtsError::InvalidTrailingStop { .. } => StatusCode::BAD_REQUEST,
         }
     }
 
@@ -218,7 +323,16 @@ impl StarRiverErrorTrait for VtsError {
             | VtsError::TpOrderQuantityMoreThanPosQuantity { .. }
             | VtsError::SlOrderQuantityMoreThanPosQuantity { .. }
             | VtsError::PositionNotFoundForSymbol { .. }
-            | VtsError::VirtualOrderSerializeFailed { .. } => vec![self.error_code()],
+            | VtsError::VirtualOrderSerializeFailed { .. }
+            | VtsError::FundingRateFileReadFailed { .. }
+            | VtsError::InvalidFundingRateRecord { .. }
+            | VtsError::InvalidOrderModification { .. }
+            | VtsError::ReduceOnlyOrderRejected { .. }
+            | VtsError::InvalidPartialClose { .. }
+            | VtsError::StopPriceNotConfigured { .. }
+            | VtsError::InvalidOrderGroup { .. }
+            | VtsError::ScaleInLimitExceeded { .. }
+            | VtsError::InvalidTrailingStop { .. } => vec![self.error_code()],
         }
     }
 }
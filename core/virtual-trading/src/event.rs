@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use strum::Display;
 use tokio::sync::broadcast;
 
-use crate::types::{order::VirtualOrder, position::VirtualPosition, transaction::VirtualTransaction};
+use crate::types::{funding_payment::FundingPayment, order::VirtualOrder, position::VirtualPosition, transaction::VirtualTransaction};
 
 // Virtual trading system event sender
 pub type VtsEventSender = broadcast::Sender<VtsEvent>;
@@ -15,12 +15,17 @@ pub enum VtsEvent {
     // All data updated
     UpdateFinished,
 
-    LimitOrderExecutedDirectly { limit_price: f64, order: VirtualOrder }, // Limit order executed directly (limit price, order)
+    LimitOrderExecutedDirectly {
+        limit_price: f64,
+        order: VirtualOrder,
+    }, // Limit order executed directly (limit price, order)
 
     // Order events
-    FuturesOrderCreated(VirtualOrder),  // Order created
-    FuturesOrderFilled(VirtualOrder),   // Order filled
-    FuturesOrderCanceled(VirtualOrder), // Order canceled
+    FuturesOrderCreated(VirtualOrder),    // Order created
+    FuturesOrderFilled(VirtualOrder),     // Order filled
+    FuturesOrderCanceled(VirtualOrder),   // Order canceled
+    FuturesOrderModified(VirtualOrder),   // Order price, quantity or tp/sl modified, or a stop limit order placed as limit order
+    OrderGroupLegActivated(VirtualOrder), // Waiting leg of an oto or bracket group activated by the fill of its parent order
 
     // Take profit order events
     TakeProfitOrderCreated(VirtualOrder),  // Take profit order created
@@ -31,12 +36,21 @@ pub enum VtsEvent {
     StopLossOrderCreated(VirtualOrder),  // Stop loss order created
     StopLossOrderFilled(VirtualOrder),   // Stop loss order filled
     StopLossOrderCanceled(VirtualOrder), // Stop loss order canceled
+    StopLossOrderUpdated(VirtualOrder),  // Stop loss price moved by a trailing stop or break-even
 
     // Position events
     PositionCreated(VirtualPosition), // Position created
     PositionUpdated(VirtualPosition), // Position updated (price change, tp/sl change, unrealized pnl change)
     PositionClosed(VirtualPosition),  // Position closed
+    PositionLiquidated {
+        liquidation_price: f64,
+        liquidation_fee: f64,
+        position: VirtualPosition,
+    }, // Position force closed at the liquidation price (liquidation price, liquidation fee, position)
 
     // Transaction events
     TransactionCreated(VirtualTransaction), // Transaction created
+
+    // Funding events
+    FundingSettled(FundingPayment), // Funding paid or received by an open position
 }
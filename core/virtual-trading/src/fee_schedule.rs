@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use star_river_core::custom_type::FeeRate;
+use strum::Display;
+use utoipa::ToSchema;
+
+/// Liquidity side of a fill: resting limit orders are maker, everything that takes the book is taker
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Display, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum Liquidity {
+    Maker,
+    #[default]
+    Taker,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeRates {
+    pub maker_rate: FeeRate,
+    pub taker_rate: FeeRate,
+}
+
+impl FeeRates {
+    pub fn rate(&self, liquidity: Liquidity) -> FeeRate {
+        match liquidity {
+            Liquidity::Maker => self.maker_rate,
+            Liquidity::Taker => self.taker_rate,
+        }
+    }
+}
+
+/// Fee schedule of the virtual trading system, symbol overrides take precedence over the default rates
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeSchedule {
+    #[serde(flatten)]
+    pub default_rates: FeeRates,
+
+    #[serde(default)]
+    pub symbol_overrides: HashMap<String, FeeRates>,
+}
+
+impl FeeSchedule {
+    /// Same rate for maker and taker, used when only the strategy fee rate is configured
+    pub fn uniform(fee_rate: FeeRate) -> Self {
+        Self {
+            default_rates: FeeRates {
+                maker_rate: fee_rate,
+                taker_rate: fee_rate,
+            },
+            symbol_overrides: HashMap::new(),
+        }
+    }
+
+    pub fn rate(&self, symbol: &str, liquidity: Liquidity) -> FeeRate {
+        self.symbol_overrides.get(symbol).unwrap_or(&self.default_rates).rate(liquidity)
+    }
+
+    /// Fee charged on the notional value of a fill
+    pub fn fee(&self, symbol: &str, liquidity: Liquidity, price: f64, quantity: f64) -> f64 {
+        (price * quantity).abs() * self.rate(symbol, liquidity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_schedule() {
+        let fee_schedule: FeeSchedule = serde_json::from_str(
+            r#"{"makerRate": 0.0002, "takerRate": 0.0005, "symbolOverrides": {"ETHUSDT": {"makerRate": 0.0, "takerRate": 0.0004}}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(fee_schedule.rate("BTCUSDT", Liquidity::Maker), 0.0002);
+        assert_eq!(fee_schedule.rate("ETHUSDT", Liquidity::Maker), 0.0);
+        assert!((fee_schedule.fee("BTCUSDT", Liquidity::Taker, 100.0, 2.0) - 0.1).abs() < 1e-12);
+        assert!((fee_schedule.fee("ETHUSDT", Liquidity::Taker, 100.0, 2.0) - 0.08).abs() < 1e-12);
+    }
+}
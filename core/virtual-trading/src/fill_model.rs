@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+use star_river_core::{kline::Kline, order::FuturesOrderSide};
+use strum::Display;
+use utoipa::ToSchema;
+
+use crate::fee_schedule::Liquidity;
+
+/// Slippage model - how far the fill price moves against the order from the reference price
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum SlippageModel {
+    /// Fill exactly at the reference price
+    #[default]
+    None,
+
+    /// Fixed number of ticks
+    FixedTicks { tick_size: f64, ticks: u32 },
+
+    /// Percentage of the reference price (0.001 = 0.1%)
+    Percentage { rate: f64 },
+
+    /// Fraction of the bar range (high - low)
+    Volatility { range_ratio: f64 },
+}
+
+impl SlippageModel {
+    /// Slippage amount in price units, always >= 0
+    pub fn slippage(&self, reference_price: f64, kline: &Kline) -> f64 {
+        let slippage = match self {
+            SlippageModel::None => 0.0,
+            SlippageModel::FixedTicks { tick_size, ticks } => tick_size * *ticks as f64,
+            SlippageModel::Percentage { rate } => reference_price * rate,
+            SlippageModel::Volatility { range_ratio } => (kline.high - kline.low) * range_ratio,
+        };
+        slippage.max(0.0)
+    }
+}
+
+/// When market orders are executed
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Display, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[strum(serialize_all = "camelCase")]
+pub enum ExecutionMode {
+    /// Execute at the close of the bar the order is created on
+    #[default]
+    CurrentBarClose,
+
+    /// Keep the order pending and execute at the open of the next bar
+    NextBarOpen,
+}
+
+/// Result of the fill model: the execution price, the slippage from the reference price and the liquidity side
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrderFill {
+    pub price: f64,
+    pub slippage: f64,
+    pub liquidity: Liquidity,
+}
+
+impl OrderFill {
+    /// Resting limit order, filled at its own price as maker
+    pub fn maker(price: f64) -> Self {
+        Self {
+            price,
+            slippage: 0.0,
+            liquidity: Liquidity::Maker,
+        }
+    }
+}
+
+/// Fill model of the virtual trading system
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FillModel {
+    #[serde(default)]
+    pub slippage_model: SlippageModel,
+
+    #[serde(default)]
+    pub execution_mode: ExecutionMode,
+}
+
+impl FillModel {
+    pub fn is_next_bar_open(&self) -> bool {
+        self.execution_mode == ExecutionMode::NextBarOpen
+    }
+
+    /// Apply slippage against the order side, filled as taker
+    pub fn fill_price(&self, order_side: &FuturesOrderSide, reference_price: f64, kline: &Kline) -> OrderFill {
+        let slippage = self.slippage_model.slippage(reference_price, kline);
+        let price = match order_side {
+            FuturesOrderSide::Long => reference_price + slippage,
+            FuturesOrderSide::Short => (reference_price - slippage).max(0.0),
+        };
+        OrderFill {
+            price,
+            slippage: (price - reference_price).abs(),
+            liquidity: Liquidity::Taker,
+        }
+    }
+
+    /// Marketable limit order: slippage is applied but never beyond the limit price
+    pub fn limit_fill_price(&self, order_side: &FuturesOrderSide, limit_price: f64, reference_price: f64, kline: &Kline) -> OrderFill {
+        let fill = self.fill_price(order_side, reference_price, kline);
+        let price = match order_side {
+            FuturesOrderSide::Long => fill.price.min(limit_price),
+            FuturesOrderSide::Short => fill.price.max(limit_price),
+        };
+        OrderFill {
+            price,
+            slippage: (price - reference_price).abs(),
+            ..fill
+        }
+    }
+
+    /// Stop market order: if the bar gaps through the stop price, the reference price is the bar open
+    pub fn stop_fill_price(&self, order_side: &FuturesOrderSide, stop_price: f64, kline: &Kline) -> OrderFill {
+        let reference_price = match order_side {
+            FuturesOrderSide::Long => stop_price.max(kline.open),
+            FuturesOrderSide::Short => stop_price.min(kline.open),
+        };
+        let fill = self.fill_price(order_side, reference_price, kline);
+        OrderFill {
+            slippage: (fill.price - stop_price).abs(),
+            ..fill
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn kline() -> Kline {
+        Kline {
+            datetime: Utc::now(),
+            open: 100.0,
+            high: 110.0,
+            low: 90.0,
+            close: 105.0,
+            volume: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_fill_price() {
+        let kline = kline();
+        let fill_model = FillModel {
+            slippage_model: SlippageModel::FixedTicks { tick_size: 0.5, ticks: 2 },
+            execution_mode: ExecutionMode::CurrentBarClose,
+        };
+        let fill = fill_model.fill_price(&FuturesOrderSide::Long, 105.0, &kline);
+        assert_eq!((fill.price, fill.slippage, fill.liquidity), (106.0, 1.0, Liquidity::Taker));
+        let fill = fill_model.fill_price(&FuturesOrderSide::Short, 105.0, &kline);
+        assert_eq!((fill.price, fill.slippage), (104.0, 1.0));
+        // capped by the limit price
+        let fill = fill_model.limit_fill_price(&FuturesOrderSide::Long, 105.5, 105.0, &kline);
+        assert_eq!((fill.price, fill.slippage), (105.5, 0.5));
+
+        let fill_model = FillModel {
+            slippage_model: SlippageModel::Volatility { range_ratio: 0.1 },
+            execution_mode: ExecutionMode::NextBarOpen,
+        };
+        // sell stop at 102 gapped by the open at 100, then 10% of the 20 range
+        let fill = fill_model.stop_fill_price(&FuturesOrderSide::Short, 102.0, &kline);
+        assert_eq!((fill.price, fill.slippage), (98.0, 4.0));
+    }
+
+    #[test]
+    fn test_deserialize_fill_model() {
+        let fill_model: FillModel =
+            serde_json::from_str(r#"{"slippageModel": {"type": "percentage", "rate": 0.001}, "executionMode": "nextBarOpen"}"#).unwrap();
+        assert_eq!(fill_model.slippage_model, SlippageModel::Percentage { rate: 0.001 });
+        assert!(fill_model.is_next_bar_open());
+        assert_eq!(serde_json::from_str::<FillModel>("{}").unwrap(), FillModel::default());
+    }
+}
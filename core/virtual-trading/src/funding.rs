@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use snafu::{OptionExt, ResultExt};
+use utoipa::ToSchema;
+
+use crate::error::{FundingRateFileReadFailedSnafu, InvalidFundingRateRecordSnafu, VtsError};
+
+fn default_interval_hours() -> u32 {
+    8
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRatePoint {
+    pub datetime: DateTime<Utc>,
+    pub rate: f64,
+}
+
+/// Where the funding rate comes from
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum FundingRateSource {
+    /// No funding
+    #[default]
+    None,
+
+    /// Same rate at every funding time
+    Constant { rate: f64 },
+
+    /// Historical funding rates, symbol -> rates sorted by datetime
+    Series { rates: HashMap<String, Vec<FundingRatePoint>> },
+
+    /// Csv file with `symbol,datetime,rate` columns, loaded into a series when the strategy initializes
+    File { path: String },
+}
+
+/// Funding of perpetual futures: at every funding time open positions pay or receive notional * rate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingConfig {
+    /// Hours between two funding times, aligned to 00:00 UTC
+    #[serde(default = "default_interval_hours")]
+    pub interval_hours: u32,
+
+    #[serde(default)]
+    pub source: FundingRateSource,
+}
+
+impl Default for FundingConfig {
+    fn default() -> Self {
+        Self {
+            interval_hours: default_interval_hours(),
+            source: FundingRateSource::None,
+        }
+    }
+}
+
+impl FundingConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.source != FundingRateSource::None && self.interval_hours > 0
+    }
+
+    /// The latest funding time at or before the datetime, None if funding is disabled
+    pub fn funding_time(&self, datetime: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if !self.is_enabled() {
+            return None;
+        }
+        let interval_millis = self.interval_hours as i64 * 60 * 60 * 1000;
+        let timestamp = datetime.timestamp_millis();
+        Utc.timestamp_millis_opt(timestamp - timestamp.rem_euclid(interval_millis)).single()
+    }
+
+    /// Funding rate of the symbol at the funding time, a series uses the latest rate published before it
+    pub fn rate_at(&self, symbol: &str, funding_time: DateTime<Utc>) -> Option<f64> {
+        match &self.source {
+            FundingRateSource::Constant { rate } => Some(*rate),
+            FundingRateSource::Series { rates } => {
+                let rates = rates.get(symbol)?;
+                let index = rates.partition_point(|point| point.datetime <= funding_time);
+                index.checked_sub(1).map(|index| rates[index].rate)
+            }
+            FundingRateSource::None | FundingRateSource::File { .. } => None,
+        }
+    }
+
+    /// Replace a file source with the series read from the file
+    pub async fn load_series_file(&mut self) -> Result<(), VtsError> {
+        let FundingRateSource::File { path } = &self.source else {
+            return Ok(());
+        };
+
+        let content = tokio::fs::read(path)
+            .await
+            .map_err(csv::Error::from)
+            .context(FundingRateFileReadFailedSnafu { path: path.clone() })?;
+        let mut reader = csv::Reader::from_reader(content.as_slice());
+        let mut rates: HashMap<String, Vec<FundingRatePoint>> = HashMap::new();
+        for (index, record) in reader.records().enumerate() {
+            let record = record.context(FundingRateFileReadFailedSnafu { path: path.clone() })?;
+            // header is line 1
+            let line = index + 2;
+            let (symbol, point) = parse_funding_rate_record(&record).context(InvalidFundingRateRecordSnafu { path: path.clone(), line })?;
+            rates.entry(symbol).or_default().push(point);
+        }
+        rates.values_mut().for_each(|points| points.sort_by_key(|point| point.datetime));
+
+        self.source = FundingRateSource::Series { rates };
+        Ok(())
+    }
+}
+
+// datetime is rfc3339 or a millisecond timestamp
+fn parse_funding_rate_record(record: &csv::StringRecord) -> Option<(String, FundingRatePoint)> {
+    let symbol = record.get(0)?.trim().to_string();
+    let datetime = record.get(1)?.trim();
+    let datetime = match datetime.parse::<i64>() {
+        Ok(timestamp) => Utc.timestamp_millis_opt(timestamp).single()?,
+        Err(_) => DateTime::parse_from_rfc3339(datetime).ok()?.to_utc(),
+    };
+    let rate = record.get(2)?.trim().parse::<f64>().ok()?;
+    Some((symbol, FundingRatePoint { datetime, rate }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_funding_rate_series() {
+        let datetime = |hour| Utc.with_ymd_and_hms(2024, 1, 1, hour, 0, 0).unwrap();
+        let config = FundingConfig {
+            interval_hours: 8,
+            source: FundingRateSource::Series {
+                rates: HashMap::from([(
+                    "BTCUSDT".to_string(),
+                    vec![
+                        FundingRatePoint {
+                            datetime: datetime(0),
+                            rate: 0.0001,
+                        },
+                        FundingRatePoint {
+                            datetime: datetime(8),
+                            rate: -0.0002,
+                        },
+                    ],
+                )]),
+            },
+        };
+
+        assert_eq!(config.funding_time(datetime(11)), Some(datetime(8)));
+        assert_eq!(config.rate_at("BTCUSDT", datetime(0)), Some(0.0001));
+        assert_eq!(config.rate_at("BTCUSDT", datetime(16)), Some(-0.0002));
+        assert_eq!(config.rate_at("ETHUSDT", datetime(16)), None);
+        assert_eq!(FundingConfig::default().funding_time(datetime(8)), None);
+    }
+}
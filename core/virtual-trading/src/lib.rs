@@ -2,6 +2,11 @@ pub mod command;
 pub mod context;
 pub mod error;
 pub mod event;
+pub mod fee_schedule;
+pub mod fill_model;
+pub mod funding;
+pub mod margin;
+pub mod market_session;
 
 pub mod types;
 pub(crate) mod utils;
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+use star_river_core::{
+    custom_type::{Margin, Price},
+    position::PositionSide,
+};
+use strum::Display;
+use utoipa::ToSchema;
+
+/// Margin mode of the virtual trading system
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Display, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[strum(serialize_all = "camelCase")]
+pub enum MarginMode {
+    /// Each position can only lose its own initial margin
+    #[default]
+    Isolated,
+
+    /// All positions share the account balance
+    Cross,
+}
+
+/// Maintenance margin tier, applies to positions whose notional value is at least `notional_floor`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintenanceMarginTier {
+    pub notional_floor: f64,
+
+    pub maintenance_margin_rate: f64,
+
+    /// Deducted from notional * rate so the maintenance margin is continuous between tiers
+    #[serde(default)]
+    pub maintenance_amount: f64,
+}
+
+/// Margin and liquidation settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginConfig {
+    #[serde(default)]
+    pub margin_mode: MarginMode,
+
+    /// Sorted by notional floor, no tier means a maintenance margin of 0
+    #[serde(default)]
+    pub maintenance_margin_tiers: Vec<MaintenanceMarginTier>,
+
+    /// Charged on the notional value of a liquidated position, on top of the taker fee
+    #[serde(default)]
+    pub liquidation_fee_rate: f64,
+}
+
+impl MarginConfig {
+    pub fn tier(&self, notional: f64) -> MaintenanceMarginTier {
+        self.maintenance_margin_tiers
+            .iter()
+            .rev()
+            .find(|tier| notional >= tier.notional_floor)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn maintenance_margin(&self, notional: f64) -> Margin {
+        let tier = self.tier(notional);
+        (notional * tier.maintenance_margin_rate - tier.maintenance_amount).max(0.0)
+    }
+
+    pub fn liquidation_fee(&self, price: Price, quantity: f64) -> f64 {
+        (price * quantity).abs() * self.liquidation_fee_rate
+    }
+
+    /// Price at which collateral + position pnl drops to the maintenance margin, 0 if it can never be reached
+    ///
+    /// Long:  collateral + q * (P - open) = q * P * mmr - amount
+    /// Short: collateral + q * (open - P) = q * P * mmr - amount
+    pub fn liquidation_price(&self, position_side: &PositionSide, open_price: Price, quantity: f64, collateral: Margin) -> Price {
+        if quantity <= 0.0 {
+            return 0.0;
+        }
+        let tier = self.tier(open_price * quantity);
+        let rate = tier.maintenance_margin_rate;
+        let price = match position_side {
+            PositionSide::Long if rate < 1.0 => (quantity * open_price - collateral - tier.maintenance_amount) / (quantity * (1.0 - rate)),
+            PositionSide::Long => 0.0,
+            PositionSide::Short => (quantity * open_price + collateral + tier.maintenance_amount) / (quantity * (1.0 + rate)),
+        };
+        price.max(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_liquidation_price() {
+        let margin_config = MarginConfig {
+            margin_mode: MarginMode::Isolated,
+            maintenance_margin_tiers: vec![
+                MaintenanceMarginTier {
+                    notional_floor: 0.0,
+                    maintenance_margin_rate: 0.0,
+                    maintenance_amount: 0.0,
+                },
+                MaintenanceMarginTier {
+                    notional_floor: 1000.0,
+                    maintenance_margin_rate: 0.01,
+                    maintenance_amount: 10.0,
+                },
+            ],
+            liquidation_fee_rate: 0.005,
+        };
+
+        // 10x long of 1 @ 100, no maintenance margin below 1000 notional: the whole margin is lost at 90
+        assert!((margin_config.liquidation_price(&PositionSide::Long, 100.0, 1.0, 10.0) - 90.0).abs() < 1e-9);
+        assert!((margin_config.liquidation_price(&PositionSide::Short, 100.0, 1.0, 10.0) - 110.0).abs() < 1e-9);
+        // 10x long of 20 @ 100: 200 + 20 * (P - 100) = 0.2 * P - 10 => P = 1790 / 19.8
+        assert!((margin_config.liquidation_price(&PositionSide::Long, 100.0, 20.0, 200.0) - 1790.0 / 19.8).abs() < 1e-9);
+        assert_eq!(margin_config.maintenance_margin(2000.0), 10.0);
+        // collateral larger than the notional: never liquidated
+        assert_eq!(margin_config.liquidation_price(&PositionSide::Long, 100.0, 1.0, 200.0), 0.0);
+        assert!((margin_config.liquidation_fee(95.0, 2.0) - 0.95).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+/// Trading sessions and holidays of an exchange, in exchange local time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradingCalendar {
+    pub timezone: Tz,
+    pub sessions: Vec<TradingSession>,
+    #[serde(default)]
+    pub holidays: Vec<NaiveDate>,
+}
+
+/// Trading session opening on each of `weekdays` at `open` (exchange local time)
+///
+/// A `close` not after `open` closes on the next day, e.g. forex 17:00 -> 17:00
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradingSession {
+    pub weekdays: Vec<Weekday>,
+    pub open: NaiveTime,
+    pub close: NaiveTime,
+}
+
+impl TradingCalendar {
+    /// Whether `datetime` falls inside a session that did not open on a holiday
+    pub fn is_open(&self, datetime: DateTime<Utc>) -> bool {
+        let local_datetime = datetime.with_timezone(&self.timezone).naive_local();
+        let local_date = local_datetime.date();
+        // An overnight session may have opened on the previous day
+        [local_date, local_date - Duration::days(1)].iter().any(|open_date| {
+            if self.holidays.contains(open_date) {
+                return false;
+            }
+            self.sessions.iter().any(|session| {
+                if !session.weekdays.contains(&open_date.weekday()) {
+                    return false;
+                }
+                let open = open_date.and_time(session.open);
+                let close = if session.close > session.open {
+                    open_date.and_time(session.close)
+                } else {
+                    (*open_date + Duration::days(1)).and_time(session.close)
+                };
+                local_datetime >= open && local_datetime < close
+            })
+        })
+    }
+}
@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use star_river_core::{
+        exchange::Exchange,
+        kline::Kline,
+        order::{FuturesOrderSide, OrderType},
+        position::PositionState,
+    };
+    use tokio::sync::watch;
+
+    use crate::{
+        VtsContext,
+        fill_model::OrderFill,
+        margin::{MarginConfig, MarginMode},
+        types::VirtualOrder,
+    };
+
+    #[test]
+    fn test_isolated_position_liquidated() {
+        let datetime = Utc::now();
+        let (_time_tx, time_rx) = watch::channel(datetime);
+        let mut ctx = VtsContext::<()>::new(time_rx);
+        let _event_rx = ctx.vts_event_receiver();
+        ctx.set_initial_balance(1000.0);
+        ctx.set_leverage(10);
+        ctx.set_margin_config(MarginConfig {
+            margin_mode: MarginMode::Isolated,
+            maintenance_margin_tiers: vec![],
+            liquidation_fee_rate: 0.01,
+        });
+
+        let order = VirtualOrder::create_order(
+            100,
+            1,
+            "test_node".to_string(),
+            "Test Node".to_string(),
+            1,
+            Exchange::Binance,
+            "btcusdt".to_string(),
+            FuturesOrderSide::Long,
+            OrderType::Market,
+            1.0,
+            100.0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            datetime,
+        );
+        ctx.create_position(&order, OrderFill::maker(100.0)).unwrap();
+
+        let kline = |open: f64, low: f64| Kline {
+            datetime,
+            open,
+            high: open,
+            low,
+            close: open,
+            volume: 1.0,
+        };
+        // 10x long @ 100 is liquidated at 90
+        ctx.update_current_positions(&Exchange::Binance, &"btcusdt".to_string(), &kline(95.0, 91.0))
+            .unwrap();
+        assert_eq!(ctx.current_positions[0].force_price, 90.0);
+
+        ctx.update_current_positions(&Exchange::Binance, &"btcusdt".to_string(), &kline(95.0, 89.0))
+            .unwrap();
+        assert!(ctx.current_positions.is_empty());
+        assert_eq!(ctx.history_positions[0].position_state, PositionState::ForcedClosed);
+        let transaction = ctx.transactions.last().unwrap();
+        assert_eq!((transaction.price, transaction.profit), (90.0, Some(-10.0)));
+        assert!((transaction.fee - 0.9).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_liquidation_uses_margin_balance() {
+        let datetime = Utc::now();
+        let (_time_tx, time_rx) = watch::channel(datetime);
+        let mut ctx = VtsContext::<()>::new(time_rx);
+        let _event_rx = ctx.vts_event_receiver();
+        ctx.set_initial_balance(1000.0);
+        ctx.set_leverage(10);
+        ctx.set_margin_config(MarginConfig {
+            margin_mode: MarginMode::Isolated,
+            maintenance_margin_tiers: vec![],
+            liquidation_fee_rate: 0.0,
+        });
+
+        // Ids out of the range the vts hands out to its own orders
+        let order = |order_id: i32, position_id: Option<i32>, order_side: FuturesOrderSide, order_type: OrderType, price: f64| {
+            VirtualOrder::new(
+                order_id,
+                position_id,
+                1,
+                "test_node".to_string(),
+                "Test Node".to_string(),
+                1,
+                Exchange::Binance,
+                "btcusdt".to_string(),
+                order_side,
+                order_type,
+                1.0,
+                price,
+                None,
+                None,
+                None,
+                None,
+                None,
+                datetime,
+            )
+        };
+        let (position, _) = ctx
+            .create_position(
+                &order(100, None, FuturesOrderSide::Long, OrderType::Market, 100.0),
+                OrderFill::maker(100.0),
+            )
+            .unwrap();
+        // funding paid while the position is open is taken from its margin
+        ctx.find_position_mut(position.position_id).unwrap().funding = -1.0;
+        // a take profit of another position on the same symbol
+        let other_tp_order = order(
+            101,
+            Some(position.position_id + 1),
+            FuturesOrderSide::Long,
+            OrderType::TakeProfitMarket,
+            120.0,
+        );
+        ctx.unfilled_orders.push(other_tp_order.clone());
+
+        let kline = |low: f64| Kline {
+            datetime,
+            open: 95.0,
+            high: 95.0,
+            low,
+            close: 95.0,
+            volume: 1.0,
+        };
+        // the collateral is 10 - 1, liquidated at 91 instead of 90
+        ctx.update_current_positions(&Exchange::Binance, &"btcusdt".to_string(), &kline(90.5))
+            .unwrap();
+        assert!(ctx.current_positions.is_empty());
+        assert_eq!(ctx.transactions.last().unwrap().price, 91.0);
+        assert_eq!(ctx.unfilled_orders.len(), 1);
+        assert_eq!(ctx.unfilled_orders[0].order_id, other_tp_order.order_id);
+    }
+}
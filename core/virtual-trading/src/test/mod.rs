@@ -1 +1,5 @@
+mod liquidation_test;
+mod order_test;
 mod position_test;
+mod snapshot_test;
+mod test_util;
@@ -0,0 +1,278 @@
+#[cfg(test)]
+mod tests {
+    use star_river_core::{
+        custom_type::OrderId,
+        exchange::Exchange,
+        order::{FuturesOrderSide, OrderStatus, OrderType, TpslType},
+    };
+
+    use crate::{
+        VtsContext,
+        command::{CreateOrderCmdPayload, ModifyOrderCmdPayload},
+        fee_schedule::Liquidity,
+        fill_model::{ExecutionMode, FillModel, SlippageModel},
+        test::test_util::{TestVts, order},
+        types::{OrderGroupType, VirtualOrder},
+    };
+
+    fn status_of(ctx: &VtsContext<()>, order_id: OrderId) -> OrderStatus {
+        ctx.unfilled_orders
+            .iter()
+            .chain(ctx.history_orders.iter())
+            .find(|o: &&VirtualOrder| o.order_id == order_id)
+            .map(|o| o.order_status.clone())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_modify_and_cancel_limit_order() {
+        let mut vts = TestVts::new(&["btcusdt"]);
+
+        let limit_order = |node_id: &str, price: f64| CreateOrderCmdPayload {
+            node_id: node_id.to_string(),
+            node_name: node_id.to_string(),
+            ..order("btcusdt", FuturesOrderSide::Long, OrderType::Limit, price, 1.0)
+        };
+        vts.create_order(limit_order("node_a", 90.0), None).unwrap();
+        vts.create_order(limit_order("node_b", 95.0), None).unwrap();
+        let order_id = vts.unfilled_orders[0].order_id;
+
+        // tp is recalculated from the new price
+        let modified_order = vts
+            .modify_order(ModifyOrderCmdPayload::new(
+                order_id,
+                Some(80.0),
+                Some(2.0),
+                Some(10.0),
+                None,
+                Some(TpslType::Percentage),
+                None,
+                None,
+            ))
+            .unwrap();
+        assert_eq!(
+            (modified_order.open_price, modified_order.quantity, modified_order.tp),
+            (80.0, 2.0, Some(88.0))
+        );
+        let zero_quantity = ModifyOrderCmdPayload::new(order_id, None, Some(0.0), None, None, None, None, None);
+        assert!(vts.modify_order(zero_quantity).is_err());
+
+        let canceled_order_ids = vts
+            .cancel_all_orders(&Exchange::Binance, None, Some(&"node_b".to_string()))
+            .unwrap();
+        assert_eq!(canceled_order_ids.len(), 1);
+        vts.cancel_order(order_id).unwrap();
+        assert!(vts.unfilled_orders.is_empty());
+        assert!(vts.history_orders.iter().all(|order| order.order_status == OrderStatus::Canceled));
+    }
+
+    #[test]
+    fn test_stop_entry_orders() {
+        let mut vts = TestVts::new(&["btcusdt", "ethusdt"]);
+        vts.set_initial_balance(10000.0);
+
+        let stop_order = |symbol: &str, order_type: OrderType, price: f64, stop_price: Option<f64>| CreateOrderCmdPayload {
+            stop_price,
+            ..order(symbol, FuturesOrderSide::Long, order_type, price, 1.0)
+        };
+        assert!(
+            vts.create_order(stop_order("btcusdt", OrderType::StopLimit, 106.0, None), None)
+                .is_err()
+        );
+        vts.create_order(stop_order("btcusdt", OrderType::StopMarket, 105.0, None), None)
+            .unwrap();
+        vts.create_order(stop_order("ethusdt", OrderType::StopLimit, 106.0, Some(105.0)), None)
+            .unwrap();
+        assert_eq!(vts.unfilled_order_count(), 2);
+
+        // An intrabar update of the creation bar through the stop price does not trigger it
+        vts.play("btcusdt", 0, 100.0, 107.0, 99.0, 106.0);
+        assert_eq!(vts.unfilled_order_count(), 2);
+        assert!(vts.current_positions.is_empty());
+
+        // Triggered inside the bar, filled at the stop price
+        vts.play("btcusdt", 1, 102.0, 107.0, 101.0, 106.0);
+        // Gapped above the limit, the order rests as a limit order
+        vts.play("ethusdt", 1, 110.0, 112.0, 108.0, 111.0);
+        assert_eq!(
+            vts.find_position_for(&"btcusdt".to_string(), &Exchange::Binance)
+                .unwrap()
+                .open_price,
+            105.0
+        );
+        assert_eq!(vts.unfilled_orders[0].order_type, OrderType::Limit);
+
+        vts.play("ethusdt", 2, 108.0, 109.0, 104.0, 105.0);
+        assert_eq!(
+            vts.find_position_for(&"ethusdt".to_string(), &Exchange::Binance)
+                .unwrap()
+                .open_price,
+            106.0
+        );
+        assert_eq!(vts.unfilled_order_count(), 0);
+    }
+
+    #[test]
+    fn test_next_bar_open_limit_order() {
+        let mut vts = TestVts::new(&["btcusdt"]);
+        vts.set_initial_balance(10000.0);
+        vts.set_fill_model(FillModel {
+            slippage_model: SlippageModel::FixedTicks { tick_size: 0.5, ticks: 2 },
+            execution_mode: ExecutionMode::NextBarOpen,
+        });
+
+        // Marketable at the close, but deferred to the next bar
+        let limit_order = CreateOrderCmdPayload {
+            tp: Some(110.0),
+            tp_type: Some(TpslType::Price),
+            ..order("btcusdt", FuturesOrderSide::Long, OrderType::Limit, 102.0, 1.0)
+        };
+        vts.create_order(limit_order, None).unwrap();
+        assert!(vts.current_positions.is_empty());
+
+        // Filled at the next open plus slippage, capped by the limit price
+        vts.play("btcusdt", 1, 101.5, 103.0, 101.0, 102.0);
+        let position = vts.find_position_for(&"btcusdt".to_string(), &Exchange::Binance).unwrap();
+        assert_eq!(position.open_price, 102.0);
+        assert_eq!(vts.transactions[0].liquidity, Liquidity::Taker);
+
+        // Take profit fills at its price as maker, without slippage
+        vts.play("btcusdt", 2, 104.0, 112.0, 103.0, 111.0);
+        assert!(vts.current_positions.is_empty());
+        let close_transaction = vts.transactions.last().unwrap();
+        assert_eq!(
+            (close_transaction.price, close_transaction.slippage, close_transaction.liquidity),
+            (110.0, 0.0, Liquidity::Maker)
+        );
+    }
+
+    #[test]
+    fn test_order_groups() {
+        let mut vts = TestVts::new(&["btcusdt", "ethusdt", "solusdt"]);
+        vts.set_initial_balance(10000.0);
+        let payload = |symbol: &str, order_type: OrderType, order_side: FuturesOrderSide, price: f64, tp: Option<f64>, sl: Option<f64>| {
+            CreateOrderCmdPayload {
+                tp,
+                sl,
+                tp_type: Some(TpslType::Price),
+                sl_type: Some(TpslType::Price),
+                ..order(symbol, order_side, order_type, price, 1.0)
+            }
+        };
+
+        let no_tpsl_entry = vec![payload("ethusdt", OrderType::Limit, FuturesOrderSide::Long, 95.0, None, None)];
+        assert!(vts.create_order_group(OrderGroupType::Bracket, no_tpsl_entry).is_err());
+
+        // Buy the dip or the breakout, whichever comes first
+        let (_, oco_ids) = vts
+            .create_order_group(
+                OrderGroupType::Oco,
+                vec![
+                    payload("btcusdt", OrderType::Limit, FuturesOrderSide::Long, 95.0, None, None),
+                    payload("btcusdt", OrderType::StopMarket, FuturesOrderSide::Long, 105.0, None, None),
+                ],
+            )
+            .unwrap();
+        let (_, bracket_ids) = vts
+            .create_order_group(
+                OrderGroupType::Bracket,
+                vec![payload(
+                    "ethusdt",
+                    OrderType::Limit,
+                    FuturesOrderSide::Long,
+                    95.0,
+                    Some(110.0),
+                    Some(90.0),
+                )],
+            )
+            .unwrap();
+        assert_eq!(bracket_ids.len(), 3);
+        let (_, oto_ids) = vts
+            .create_order_group(
+                OrderGroupType::Oto,
+                vec![
+                    payload("solusdt", OrderType::Limit, FuturesOrderSide::Long, 90.0, None, None),
+                    payload("solusdt", OrderType::Limit, FuturesOrderSide::Short, 120.0, None, None),
+                ],
+            )
+            .unwrap();
+
+        vts.play("btcusdt", 1, 100.0, 101.0, 94.0, 96.0);
+        vts.play("ethusdt", 1, 100.0, 101.0, 94.0, 96.0);
+        vts.play("solusdt", 1, 100.0, 125.0, 95.0, 100.0);
+        assert_eq!(status_of(&vts, oco_ids[0]), OrderStatus::Filled);
+        assert_eq!(status_of(&vts, oco_ids[1]), OrderStatus::Canceled);
+        // The entry filled, its tp/sl legs are bound to the new position
+        assert_eq!(status_of(&vts, bracket_ids[0]), OrderStatus::Filled);
+        let position_id = vts
+            .find_position_for(&"ethusdt".to_string(), &Exchange::Binance)
+            .unwrap()
+            .position_id;
+        assert!(
+            bracket_ids[1..]
+                .iter()
+                .all(|id| vts.find_unfilled_order(id).unwrap().position_id == Some(position_id))
+        );
+        // The child never fills while its parent is unfilled
+        assert_eq!(status_of(&vts, oto_ids[1]), OrderStatus::Created);
+
+        vts.play("ethusdt", 2, 100.0, 111.0, 99.0, 108.0);
+        assert_eq!(status_of(&vts, bracket_ids[1]), OrderStatus::Filled);
+        assert_eq!(status_of(&vts, bracket_ids[2]), OrderStatus::Canceled);
+        assert!(vts.find_position_for(&"ethusdt".to_string(), &Exchange::Binance).is_err());
+
+        vts.cancel_order(oto_ids[0]).unwrap();
+        assert_eq!(status_of(&vts, oto_ids[1]), OrderStatus::Canceled);
+        assert_eq!(vts.unfilled_order_count(), 0);
+    }
+
+    #[test]
+    fn test_oco_group_with_market_first_leg() {
+        let mut vts = TestVts::new(&["btcusdt", "ethusdt"]);
+
+        // The market leg fills on creation, the marketable limit leg is never placed
+        let (_, order_ids) = vts
+            .create_order_group(
+                OrderGroupType::Oco,
+                vec![
+                    order("btcusdt", FuturesOrderSide::Long, OrderType::Market, 100.0, 1.0),
+                    order("btcusdt", FuturesOrderSide::Long, OrderType::Limit, 101.0, 1.0),
+                ],
+            )
+            .unwrap();
+        assert_eq!(order_ids.len(), 1);
+        assert_eq!(status_of(&vts, order_ids[0]), OrderStatus::Filled);
+        let position = vts.find_position_for(&"btcusdt".to_string(), &Exchange::Binance).unwrap();
+        assert_eq!(position.quantity, 1.0);
+
+        // A market leg after a resting leg cancels it
+        let (_, order_ids) = vts
+            .create_order_group(
+                OrderGroupType::Oco,
+                vec![
+                    order("ethusdt", FuturesOrderSide::Long, OrderType::Limit, 95.0, 1.0),
+                    order("ethusdt", FuturesOrderSide::Long, OrderType::Market, 100.0, 1.0),
+                ],
+            )
+            .unwrap();
+        assert_eq!(status_of(&vts, order_ids[0]), OrderStatus::Canceled);
+        assert_eq!(status_of(&vts, order_ids[1]), OrderStatus::Filled);
+        assert_eq!(vts.unfilled_order_count(), 0);
+    }
+
+    #[test]
+    fn test_order_ids_are_per_context() {
+        let mut vts_a = TestVts::new(&["btcusdt"]);
+        let mut vts_b = TestVts::new(&["btcusdt"]);
+        let limit_order = || order("btcusdt", FuturesOrderSide::Long, OrderType::Limit, 90.0, 1.0);
+
+        // Concurrent backtests number their orders independently
+        assert_eq!(vts_a.create_order(limit_order(), None).unwrap(), 0);
+        assert_eq!(vts_a.create_order(limit_order(), None).unwrap(), 1);
+        assert_eq!(vts_b.create_order(limit_order(), None).unwrap(), 0);
+
+        vts_a.reset();
+        assert_eq!(vts_a.create_order(limit_order(), None).unwrap(), 0);
+        assert_eq!(vts_b.create_order(limit_order(), None).unwrap(), 1);
+    }
+}
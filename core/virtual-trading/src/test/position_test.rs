@@ -1,13 +1,23 @@
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use chrono::Utc;
     use star_river_core::{
         exchange::Exchange,
-        order::{FuturesOrderSide, OrderType},
+        order::{FuturesOrderSide, OrderStatus, OrderType},
         position::PositionSide,
     };
 
-    use crate::types::{VirtualOrder, VirtualPosition};
+    use crate::{
+        VtsContext,
+        command::CreateOrderCmdPayload,
+        error::VtsError,
+        event::VtsEvent,
+        fill_model::OrderFill,
+        test::test_util::{TestVts, order},
+        types::{BreakEven, PartialCloseSize, ScaleInLimit, StopDistance, TrailingStop, VirtualOrder, VirtualPosition},
+    };
 
     #[test]
     fn test_update_with_new_order_close_all() {
@@ -15,6 +25,7 @@ mod tests {
         // symbol=btcusdt, exchange=binance, open_price=100000, quantity=0.5
         let datetime = Utc::now();
         let mut position = VirtualPosition::new(
+            1, // position_id
             PositionSide::Long,
             1, // strategy_id
             Exchange::Binance,
@@ -31,6 +42,7 @@ mod tests {
         // Create a short order to fully close the long position
         // Short order with same quantity (0.5) should fully close the position
         let close_order = VirtualOrder::create_order(
+            1,                       // order_id
             1,                       // strategy_id
             "test_node".to_string(), // node_id
             "Test Node".to_string(), // node_name
@@ -51,6 +63,7 @@ mod tests {
 
         // Execute close order
         let result = position.update_with_new_order(
+            1, // transaction_id
             &close_order,
             110000.0, // current_price (actual close price)
             10000.0,  // available_balance
@@ -80,4 +93,225 @@ mod tests {
         assert_eq!(transaction.price, 110000.0);
         assert_eq!(transaction.symbol, "btcusdt");
     }
+
+    #[test]
+    fn test_margin_check_includes_opening_fee() {
+        let mut vts = TestVts::new(&[]);
+        vts.set_fee_rate(0.001);
+        let datetime = vts.start;
+
+        let order = |quantity: f64| {
+            VirtualOrder::create_order(
+                100,
+                1,
+                "test_node".to_string(),
+                "Test Node".to_string(),
+                1,
+                Exchange::Binance,
+                "btcusdt".to_string(),
+                FuturesOrderSide::Long,
+                OrderType::Market,
+                quantity,
+                100.0,
+                None,
+                None,
+                None,
+                None,
+                None,
+                datetime,
+            )
+        };
+        // margin 1000 uses the whole balance, the 10 opening fee is not covered
+        assert!(vts.create_position(&order(100.0), OrderFill::maker(100.0)).is_err());
+        // margin 990 plus the 9.9 opening fee
+        vts.create_position(&order(99.0), OrderFill::maker(100.0)).unwrap();
+    }
+
+    #[test]
+    fn test_partial_close_and_reduce_only_orders() {
+        let mut vts = TestVts::new(&["btcusdt"]);
+        let symbol = "btcusdt".to_string();
+        let node_id = "node".to_string();
+
+        let market_order = |vts: &mut TestVts, order_side: FuturesOrderSide, quantity: f64, reduce_only: bool| {
+            let payload = CreateOrderCmdPayload {
+                reduce_only,
+                ..order("btcusdt", order_side, OrderType::Market, 100.0, quantity)
+            };
+            vts.create_order(payload, None)
+        };
+        // Nothing to reduce yet
+        assert!(market_order(&mut vts, FuturesOrderSide::Short, 1.0, true).is_err());
+        market_order(&mut vts, FuturesOrderSide::Long, 2.0, false).unwrap();
+        // A reduce only order never adds to the position
+        assert!(market_order(&mut vts, FuturesOrderSide::Long, 1.0, true).is_err());
+
+        let partially_close = |vts: &mut TestVts, size: PartialCloseSize| {
+            vts.partially_close_position(&node_id, &node_id, 1, &symbol, &Exchange::Binance, &size)
+        };
+        let kline = vts.kline(0, 110.0, 110.0, 110.0, 110.0);
+        vts.set_kline_price(HashMap::from([((Exchange::Binance, "btcusdt".to_string()), kline)]));
+        assert_eq!(partially_close(&mut vts, PartialCloseSize::Percentage(50.0)).unwrap().1, 1.0);
+        // Only the closed half realizes its profit
+        assert_eq!(vts.transactions.last().unwrap().profit, Some(10.0));
+        assert_eq!(vts.current_positions[0].unrealized_profit, 10.0);
+        assert_eq!(partially_close(&mut vts, PartialCloseSize::TargetQuantity(0.25)).unwrap().1, 0.75);
+        assert_eq!(vts.current_positions[0].quantity, 0.25);
+        assert!(partially_close(&mut vts, PartialCloseSize::TargetQuantity(0.25)).is_err());
+        assert!(partially_close(&mut vts, PartialCloseSize::Percentage(150.0)).is_err());
+
+        // Capped at the position quantity, the position is closed instead of flipped
+        market_order(&mut vts, FuturesOrderSide::Short, 5.0, true).unwrap();
+        assert!(vts.current_positions.is_empty());
+        assert_eq!(vts.transactions.last().unwrap().quantity, 0.25);
+    }
+
+    #[test]
+    fn test_scale_in_limit() {
+        let mut vts = TestVts::new(&["btcusdt"]);
+        vts.set_scale_in_limit(ScaleInLimit {
+            max_quantity: Some(3.0),
+            max_adds: Some(1),
+        });
+
+        let create = |vts: &mut TestVts, order_side: FuturesOrderSide, order_type: OrderType, price: f64, quantity: f64| {
+            vts.create_order(order("btcusdt", order_side, order_type, price, quantity), None)
+        };
+        // The opening order counts towards the max quantity
+        assert!(create(&mut vts, FuturesOrderSide::Long, OrderType::Market, 100.0, 4.0).is_err());
+        create(&mut vts, FuturesOrderSide::Long, OrderType::Market, 100.0, 1.5).unwrap();
+        // Placed while the position still has room
+        let limit_order_id = create(&mut vts, FuturesOrderSide::Long, OrderType::Limit, 90.0, 1.0).unwrap();
+        create(&mut vts, FuturesOrderSide::Long, OrderType::Market, 100.0, 1.0).unwrap();
+        assert_eq!(vts.current_positions[0].add_count, 1);
+        assert!(create(&mut vts, FuturesOrderSide::Long, OrderType::Market, 100.0, 0.1).is_err());
+
+        // No add left when the limit order fills, it is canceled
+        let limit_order = vts.find_unfilled_order(&limit_order_id).unwrap().clone();
+        assert_eq!(vts.execute_order(&limit_order, OrderFill::maker(90.0)).unwrap(), None);
+        assert_eq!(vts.history_orders.last().unwrap().order_status, OrderStatus::Canceled);
+        assert_eq!(vts.current_positions[0].quantity, 2.5);
+
+        // Reducing the position is never limited
+        create(&mut vts, FuturesOrderSide::Short, OrderType::Market, 100.0, 1.0).unwrap();
+        assert_eq!(vts.current_positions[0].quantity, 1.5);
+    }
+
+    #[test]
+    fn test_bar_counts_and_drawdown() {
+        let mut vts = TestVts::new(&["btcusdt"]);
+        let symbol = "btcusdt".to_string();
+        assert!(vts.is_tradable(&Exchange::Binance, &symbol));
+        assert_eq!(vts.bars_since_last_trade(&Exchange::Binance, &symbol), None);
+
+        vts.create_order(order("btcusdt", FuturesOrderSide::Long, OrderType::Market, 100.0, 1.0), None)
+            .unwrap();
+        for bar in 1..=3 {
+            let close = 100.0 - bar as f64;
+            vts.play("btcusdt", bar, close, close, close, close);
+        }
+
+        assert_eq!(vts.holding_bars(&vts.current_positions[0]), 3);
+        assert_eq!(vts.bars_since_last_trade(&Exchange::Binance, &symbol), Some(3));
+        // Equity fell from 1000 to 997
+        assert!((vts.current_drawdown() - 0.003).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_trailing_stop_and_break_even() {
+        let mut vts = TestVts::new(&["btcusdt"]);
+        // Trail 2 ATR behind the high, break even with 0.5 locked in after a 2% move
+        vts.update_atr_value("atr_node".to_string(), 1, 2.5);
+        let trailing_stop = TrailingStop {
+            trailing: Some(StopDistance::Atr {
+                node_id: "atr_node".to_string(),
+                config_id: 1,
+                multiplier: 2.0,
+            }),
+            break_even: Some(BreakEven {
+                trigger: StopDistance::Percentage { value: 2.0 },
+                offset: 0.5,
+            }),
+        };
+
+        let payload = CreateOrderCmdPayload {
+            trailing_stop: Some(trailing_stop),
+            ..order("btcusdt", FuturesOrderSide::Long, OrderType::Market, 100.0, 1.0)
+        };
+        vts.create_order(payload, None).unwrap();
+        let stop_price = |ctx: &VtsContext<()>| ctx.unfilled_orders.iter().find(|o| o.is_stop_loss_order()).map(|o| o.open_price);
+        assert_eq!(stop_price(&vts), Some(95.0));
+
+        // (high, low, expected stop after the bar)
+        let bars = [
+            (101.0, 99.0, 96.0),
+            (103.0, 101.0, 100.5),
+            (110.0, 104.0, 105.0),
+            (108.0, 106.0, 105.0),
+        ];
+        for (bar, (high, low, expected_stop)) in bars.into_iter().enumerate() {
+            let mid = (high + low) / 2.0;
+            vts.play("btcusdt", bar as i64 + 1, mid, high, low, mid);
+            assert_eq!(stop_price(&vts), Some(expected_stop));
+        }
+
+        vts.play("btcusdt", 5, 105.0, 106.0, 104.0, 105.0);
+        assert!(vts.current_positions.is_empty());
+        assert_eq!(vts.transactions.last().unwrap().price, 105.0);
+
+        let mut updated_count = 0;
+        while let Ok(event) = vts.event_rx.try_recv() {
+            if matches!(event, VtsEvent::StopLossOrderUpdated(_)) {
+                updated_count += 1;
+            }
+        }
+        assert_eq!(updated_count, 3);
+    }
+
+    #[test]
+    fn test_atr_trailing_stop_waits_for_atr_value() {
+        let mut vts = TestVts::new(&["btcusdt"]);
+        let trailing_stop = TrailingStop {
+            trailing: Some(StopDistance::Atr {
+                node_id: "atr_node".to_string(),
+                config_id: 1,
+                multiplier: 2.0,
+            }),
+            break_even: None,
+        };
+
+        // Filled before the ATR has a value, the stop loss is not placed yet
+        let payload = CreateOrderCmdPayload {
+            trailing_stop: Some(trailing_stop),
+            ..order("btcusdt", FuturesOrderSide::Long, OrderType::Market, 100.0, 1.0)
+        };
+        vts.create_order(payload, None).unwrap();
+        assert_eq!(vts.current_positions.len(), 1);
+        assert!(!vts.unfilled_orders.iter().any(|o| o.is_stop_loss_order()));
+
+        vts.play("btcusdt", 1, 100.0, 101.0, 99.0, 100.0);
+        assert!(!vts.unfilled_orders.iter().any(|o| o.is_stop_loss_order()));
+
+        // The stop loss trails from the entry price on the first bar with an ATR value, then follows the high
+        vts.update_atr_value("atr_node".to_string(), 1, 2.5);
+        vts.play("btcusdt", 2, 101.0, 102.0, 100.0, 101.0);
+        let stop_loss_order = vts.unfilled_orders.iter().find(|o| o.is_stop_loss_order()).unwrap();
+        assert_eq!(stop_loss_order.open_price, 97.0);
+        assert_eq!(stop_loss_order.trailing_best_price, Some(102.0));
+
+        // A break-even without a stop loss price or trailing distance has no stop loss to move
+        let break_even_only = TrailingStop {
+            trailing: None,
+            break_even: Some(BreakEven {
+                trigger: StopDistance::Percentage { value: 2.0 },
+                offset: 0.0,
+            }),
+        };
+        let payload = CreateOrderCmdPayload {
+            trailing_stop: Some(break_even_only),
+            ..order("btcusdt", FuturesOrderSide::Long, OrderType::Market, 100.0, 1.0)
+        };
+        let result = vts.create_order(payload, None);
+        assert!(matches!(result, Err(VtsError::InvalidTrailingStop { .. })));
+    }
 }
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::Utc;
+    use star_river_core::{
+        exchange::Exchange,
+        kline::Kline,
+        order::{FuturesOrderSide, OrderType},
+    };
+    use tokio::sync::watch;
+
+    use crate::{
+        VtsContext,
+        command::CreateOrderCmdPayload,
+        context::snapshot_handler::VtsSnapshot,
+        fill_model::OrderFill,
+        test::test_util::{TestVts, order},
+        types::{StopDistance, TrailingStop, VirtualOrder},
+    };
+
+    #[test]
+    fn test_snapshot_json_roundtrip() {
+        let datetime = Utc::now();
+        let (_time_tx, time_rx) = watch::channel(datetime);
+        let mut ctx = VtsContext::<()>::new(time_rx);
+        let _event_rx = ctx.vts_event_receiver();
+        ctx.set_initial_balance(1000.0);
+        ctx.set_leverage(10);
+
+        let kline = Kline::new(datetime, 100.0, 100.0, 100.0, 100.0, 1.0);
+        ctx.set_kline_price(HashMap::from([((Exchange::Binance, "btcusdt".to_string()), kline)]));
+        let order = VirtualOrder::create_order(
+            100,
+            1,
+            "test_node".to_string(),
+            "Test Node".to_string(),
+            1,
+            Exchange::Binance,
+            "btcusdt".to_string(),
+            FuturesOrderSide::Long,
+            OrderType::Market,
+            1.0,
+            100.0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            datetime,
+        );
+        ctx.create_position(&order, OrderFill::maker(100.0)).unwrap();
+
+        let json = serde_json::to_string(&ctx.snapshot()).unwrap();
+        let (_time_tx, time_rx) = watch::channel(datetime);
+        let mut restored = VtsContext::<()>::new(time_rx);
+        restored.restore(serde_json::from_str::<VtsSnapshot>(&json).unwrap());
+
+        assert_eq!(
+            restored.find_kline_price(&Exchange::Binance, &"btcusdt".to_string()).unwrap().close,
+            100.0
+        );
+        assert_eq!(restored.current_positions.len(), 1);
+        assert_eq!(restored.current_positions[0].position_id, ctx.current_positions[0].position_id);
+        assert_eq!(restored.transactions.len(), ctx.transactions.len());
+        assert_eq!((restored.balance, restored.used_margin), (ctx.balance, ctx.used_margin));
+    }
+
+    #[test]
+    fn test_restore_id_counters() {
+        let mut vts = TestVts::new(&["btcusdt"]);
+        let mut other_vts = TestVts::new(&["btcusdt"]);
+        let limit_order = || order("btcusdt", FuturesOrderSide::Long, OrderType::Limit, 90.0, 1.0);
+
+        vts.create_order(limit_order(), None).unwrap();
+        let snapshot = vts.snapshot();
+        assert_eq!(snapshot.order_id_counter, 1);
+        vts.create_order(limit_order(), None).unwrap();
+
+        // The replayed order gets the id it got on the first play, other contexts keep their own ids
+        other_vts.create_order(limit_order(), None).unwrap();
+        vts.restore(snapshot);
+        assert_eq!(vts.create_order(limit_order(), None).unwrap(), 1);
+        assert_eq!(other_vts.create_order(limit_order(), None).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_snapshot_with_atr_trailing_stop() {
+        let mut vts = TestVts::new(&["btcusdt"]);
+        vts.update_atr_value("atr_node".to_string(), 1, 2.5);
+        let payload = CreateOrderCmdPayload {
+            trailing_stop: Some(TrailingStop {
+                trailing: Some(StopDistance::Atr {
+                    node_id: "atr_node".to_string(),
+                    config_id: 1,
+                    multiplier: 2.0,
+                }),
+                break_even: None,
+            }),
+            ..order("btcusdt", FuturesOrderSide::Long, OrderType::Market, 100.0, 1.0)
+        };
+        vts.create_order(payload, None).unwrap();
+        let json = serde_json::to_string(&vts.snapshot()).unwrap();
+
+        // Restored into a context that never saw the ATR, the stop loss keeps trailing 2 ATR behind the high
+        let mut restored = TestVts::new(&["btcusdt"]);
+        restored.restore(serde_json::from_str::<VtsSnapshot>(&json).unwrap());
+        assert_eq!(restored.atr_values(), vts.atr_values());
+        restored.play("btcusdt", 1, 102.0, 104.0, 101.0, 103.0);
+        let stop_loss_order = restored.unfilled_orders.iter().find(|o| o.is_stop_loss_order()).unwrap();
+        assert_eq!(stop_loss_order.open_price, 99.0);
+    }
+}
@@ -0,0 +1,99 @@
+use std::ops::{Deref, DerefMut};
+
+use chrono::{DateTime, Utc};
+use star_river_core::{
+    exchange::Exchange,
+    kline::Kline,
+    order::{FuturesOrderSide, OrderType},
+};
+use tokio::sync::watch;
+
+use crate::{VtsContext, command::CreateOrderCmdPayload, event::VtsEventReceiver};
+
+/// Vts with a 1000 balance and 10x leverage, the symbols trade on binance at 100 on bar 0
+pub struct TestVts {
+    pub ctx: VtsContext<()>,
+    pub event_rx: VtsEventReceiver, // Keeps the event channel open, sending fails without a receiver
+    pub time_tx: watch::Sender<DateTime<Utc>>,
+    pub start: DateTime<Utc>,
+}
+
+impl TestVts {
+    pub fn new(symbols: &[&str]) -> Self {
+        let start = Utc::now();
+        let (time_tx, time_rx) = watch::channel(start);
+        let mut ctx = VtsContext::<()>::new(time_rx);
+        let event_rx = ctx.vts_event_receiver();
+        ctx.set_initial_balance(1000.0);
+        ctx.set_leverage(10);
+        let mut vts = Self {
+            ctx,
+            event_rx,
+            time_tx,
+            start,
+        };
+        let kline_price = symbols
+            .iter()
+            .map(|symbol| ((Exchange::Binance, symbol.to_string()), vts.kline(0, 100.0, 100.0, 100.0, 100.0)))
+            .collect();
+        vts.set_kline_price(kline_price);
+        vts
+    }
+
+    /// Kline of a one minute bar counted from the start
+    pub fn kline(&self, bar: i64, open: f64, high: f64, low: f64, close: f64) -> Kline {
+        Kline {
+            datetime: self.start + chrono::Duration::minutes(bar),
+            open,
+            high,
+            low,
+            close,
+            volume: 1.0,
+        }
+    }
+
+    /// Move the strategy time to the bar and play its kline
+    pub fn play(&mut self, symbol: &str, bar: i64, open: f64, high: f64, low: f64, close: f64) {
+        let kline = self.kline(bar, open, high, low, close);
+        self.time_tx.send(kline.datetime).unwrap();
+        self.ctx.handle_kline_update(Exchange::Binance, symbol.to_string(), kline).unwrap();
+    }
+}
+
+impl Deref for TestVts {
+    type Target = VtsContext<()>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.ctx
+    }
+}
+
+impl DerefMut for TestVts {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.ctx
+    }
+}
+
+/// Order of config 1 of node "node" without tp/sl, other fields are set with struct update syntax
+pub fn order(symbol: &str, order_side: FuturesOrderSide, order_type: OrderType, price: f64, quantity: f64) -> CreateOrderCmdPayload {
+    CreateOrderCmdPayload {
+        strategy_id: 1,
+        node_id: "node".to_string(),
+        node_name: "node".to_string(),
+        order_config_id: 1,
+        symbol: symbol.to_string(),
+        exchange: Exchange::Binance,
+        price,
+        order_side,
+        order_type,
+        quantity,
+        tp: None,
+        sl: None,
+        tp_type: None,
+        sl_type: None,
+        point: None,
+        reduce_only: false,
+        stop_price: None,
+        trailing_stop: None,
+    }
+}
@@ -1,8 +1,14 @@
+pub mod funding_payment;
 pub mod id_generator;
 pub mod order;
+pub mod order_group;
 pub mod position;
+pub mod trailing_stop;
 pub mod transaction;
 
+pub use funding_payment::FundingPayment;
 pub use order::VirtualOrder;
-pub use position::VirtualPosition;
+pub use order_group::{OrderGroup, OrderGroupId, OrderGroupType};
+pub use position::{PartialCloseSize, ScaleInLimit, VirtualPosition};
+pub use trailing_stop::{BreakEven, StopDistance, TrailingStop};
 pub use transaction::VirtualTransaction;
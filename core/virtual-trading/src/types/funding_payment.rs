@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use star_river_core::{custom_type::*, exchange::Exchange, position::PositionSide};
+use utoipa::ToSchema;
+
+use super::VirtualPosition;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingPayment {
+    pub position_id: PositionId, // Position ID
+
+    pub strategy_id: StrategyId, // Strategy ID
+
+    pub exchange: Exchange, // Exchange
+
+    pub symbol: String, // Trading symbol
+
+    pub position_side: PositionSide, // Position side
+
+    pub quantity: f64, // Position quantity at funding time
+
+    pub mark_price: Price, // Price used for the position notional
+
+    pub funding_rate: f64, // Funding rate
+
+    pub amount: f64, // Received (positive) or paid (negative)
+
+    pub funding_time: DateTime<Utc>, // Funding time
+
+    pub create_time: DateTime<Utc>, // Create time
+}
+
+impl FundingPayment {
+    // Longs pay shorts when the rate is positive
+    pub fn new(position: &VirtualPosition, funding_rate: f64, funding_time: DateTime<Utc>, datetime: DateTime<Utc>) -> Self {
+        let notional = position.quantity * position.current_price;
+        let amount = match position.position_side {
+            PositionSide::Long => -notional * funding_rate,
+            PositionSide::Short => notional * funding_rate,
+        };
+
+        Self {
+            position_id: position.position_id,
+            strategy_id: position.strategy_id,
+            exchange: position.exchange.clone(),
+            symbol: position.symbol.clone(),
+            position_side: position.position_side.clone(),
+            quantity: position.quantity,
+            mark_price: position.current_price,
+            funding_rate,
+            amount,
+            funding_time,
+            create_time: datetime,
+        }
+    }
+}
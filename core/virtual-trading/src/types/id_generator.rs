@@ -1,5 +1,36 @@
-use std::sync::atomic::AtomicI32;
+use star_river_core::custom_type::{OrderId, PositionId, TransactionId};
 
-pub static TRANSACTION_ID_COUNTER: AtomicI32 = AtomicI32::new(0);
-pub static ORDER_ID_COUNTER: AtomicI32 = AtomicI32::new(0);
-pub static POSITION_ID_COUNTER: AtomicI32 = AtomicI32::new(0);
+use super::OrderGroupId;
+
+/// Id counters of one vts instance, concurrent backtests never share ids
+#[derive(Debug, Clone, Default)]
+pub struct IdGenerator {
+    pub order_id: OrderId,
+    pub position_id: PositionId,
+    pub transaction_id: TransactionId,
+    pub order_group_id: OrderGroupId,
+}
+
+impl IdGenerator {
+    pub fn next_order_id(&mut self) -> OrderId {
+        Self::next(&mut self.order_id)
+    }
+
+    pub fn next_position_id(&mut self) -> PositionId {
+        Self::next(&mut self.position_id)
+    }
+
+    pub fn next_transaction_id(&mut self) -> TransactionId {
+        Self::next(&mut self.transaction_id)
+    }
+
+    pub fn next_order_group_id(&mut self) -> OrderGroupId {
+        Self::next(&mut self.order_group_id)
+    }
+
+    fn next(counter: &mut i32) -> i32 {
+        let id = *counter;
+        *counter += 1;
+        id
+    }
+}
@@ -1,5 +1,3 @@
-use std::sync::atomic::Ordering;
-
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use snafu::ResultExt;
@@ -10,8 +8,14 @@ use star_river_core::{
 };
 use utoipa::ToSchema;
 
-use super::id_generator::ORDER_ID_COUNTER;
-use crate::error::{VirtualOrderSerializeFailedSnafu, VtsError};
+use super::{
+    order_group::{OrderGroup, OrderGroupType},
+    trailing_stop::TrailingStop,
+};
+use crate::{
+    command::ModifyOrderCmdPayload,
+    error::{VirtualOrderSerializeFailedSnafu, VtsError},
+};
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct VirtualOrder {
@@ -30,6 +34,16 @@ pub struct VirtualOrder {
     pub open_price: f64,                 // Open price
     pub tp: Option<f64>,                 // Take profit
     pub sl: Option<f64>,                 // Stop loss
+    #[serde(default)]
+    pub reduce_only: bool, // Only reduces an opposite position, never opens or adds to one
+    #[serde(default)]
+    pub trigger_price: Option<f64>, // Stop price of a stop entry order, None for stop loss orders
+    #[serde(default)]
+    pub trailing_stop: Option<TrailingStop>, // Per bar stop loss adjustment, carried from the entry order to its stop loss order
+    #[serde(default)]
+    pub trailing_best_price: Option<f64>, // Highest high (long) or lowest low (short) since entry, tracked for the trailing stop
+    #[serde(default)]
+    pub order_group: Option<OrderGroup>, // Oco, oto or bracket group the order belongs to
     pub create_time: DateTime<Utc>,      // Create time
     pub update_time: DateTime<Utc>,      // Update time
 }
@@ -42,6 +56,7 @@ impl VirtualOrder {
     }
 
     pub fn new(
+        order_id: OrderId,
         position_id: Option<PositionId>,
         strategy_id: StrategyId,
         node_id: NodeId,
@@ -60,7 +75,6 @@ impl VirtualOrder {
         point: Option<f64>,
         datetime: DateTime<Utc>,
     ) -> Self {
-        let order_id = ORDER_ID_COUNTER.fetch_add(1, Ordering::SeqCst);
         Self {
             order_id,
             position_id,
@@ -77,12 +91,63 @@ impl VirtualOrder {
             tp: Self::calculate_tp(open_price, tp, &tp_type, &order_side, point),
             sl: Self::calculate_sl(open_price, sl, &sl_type, &order_side, point),
             order_status: OrderStatus::Created,
+            reduce_only: false,
+            trigger_price: None,
+            trailing_stop: None,
+            trailing_best_price: None,
+            order_group: None,
             create_time: datetime,
             update_time: datetime,
         }
     }
 
+    pub fn with_reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = reduce_only;
+        self
+    }
+
+    pub fn with_trigger_price(mut self, trigger_price: Option<f64>) -> Self {
+        self.trigger_price = trigger_price;
+        self
+    }
+
+    pub fn with_trailing_stop(mut self, trailing_stop: Option<TrailingStop>) -> Self {
+        self.trailing_stop = trailing_stop;
+        self
+    }
+
+    pub fn with_order_group(mut self, order_group: Option<OrderGroup>) -> Self {
+        self.order_group = order_group;
+        self
+    }
+
+    /// Order this leg waits for before it can fill
+    pub fn parent_order_id(&self) -> Option<OrderId> {
+        self.order_group.as_ref().and_then(|group| group.parent_order_id)
+    }
+
+    /// Entry order of a bracket, its tp/sl legs are created with the group instead of on fill
+    pub fn is_bracket_entry(&self) -> bool {
+        self.order_group
+            .as_ref()
+            .is_some_and(|group| group.group_type == OrderGroupType::Bracket && group.parent_order_id.is_none())
+    }
+
+    /// Take profit or stop loss order of a position, stop entry orders are not
+    pub fn is_tpsl_order(&self) -> bool {
+        match self.order_type {
+            OrderType::TakeProfitMarket => true,
+            OrderType::StopMarket => self.trigger_price.is_none(),
+            _ => false,
+        }
+    }
+
+    pub fn is_stop_loss_order(&self) -> bool {
+        self.order_type == OrderType::StopMarket && self.trigger_price.is_none()
+    }
+
     pub fn create_order(
+        order_id: OrderId,
         strategy_id: StrategyId,
         node_id: NodeId,
         node_name: NodeName,
@@ -101,6 +166,7 @@ impl VirtualOrder {
         datetime: DateTime<Utc>,
     ) -> Self {
         Self::new(
+            order_id,
             None,
             strategy_id,
             node_id,
@@ -122,6 +188,7 @@ impl VirtualOrder {
     }
 
     pub fn create_take_profit_order(
+        order_id: OrderId,
         position_id: Option<PositionId>,
         strategy_id: StrategyId,
         node_id: NodeId,
@@ -135,6 +202,7 @@ impl VirtualOrder {
         datetime: DateTime<Utc>,
     ) -> Self {
         Self::new(
+            order_id,
             position_id,
             strategy_id,
             node_id,
@@ -156,6 +224,7 @@ impl VirtualOrder {
     }
 
     pub fn create_stop_loss_order(
+        order_id: OrderId,
         position_id: Option<PositionId>,
         strategy_id: StrategyId,
         node_id: NodeId,
@@ -169,6 +238,7 @@ impl VirtualOrder {
         datetime: DateTime<Utc>,
     ) -> Self {
         Self::new(
+            order_id,
             position_id,
             strategy_id,
             node_id,
@@ -189,6 +259,39 @@ impl VirtualOrder {
         )
     }
 
+    /// Amend a resting order, None keeps the current value
+    pub fn modify(&mut self, modification: &ModifyOrderCmdPayload, datetime: DateTime<Utc>) {
+        if let Some(price) = modification.price {
+            self.open_price = price;
+            // The price of a stop market entry is its stop price
+            if self.order_type == OrderType::StopMarket && self.trigger_price.is_some() {
+                self.trigger_price = Some(price);
+            }
+        }
+        if let Some(quantity) = modification.quantity {
+            self.quantity = quantity;
+        }
+        if modification.tp.is_some() {
+            self.tp = Self::calculate_tp(
+                self.open_price,
+                modification.tp,
+                &modification.tp_type,
+                &self.order_side,
+                modification.point,
+            );
+        }
+        if modification.sl.is_some() {
+            self.sl = Self::calculate_sl(
+                self.open_price,
+                modification.sl,
+                &modification.sl_type,
+                &self.order_side,
+                modification.point,
+            );
+        }
+        self.update_time = datetime;
+    }
+
     fn calculate_tp(
         open_price: f64,
         tp: Option<f64>,
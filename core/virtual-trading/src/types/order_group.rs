@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use star_river_core::custom_type::OrderId;
+use strum::Display;
+use utoipa::ToSchema;
+
+pub type OrderGroupId = i32;
+
+/// Linkage between the orders of a group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, Display)]
+#[serde(rename_all = "camelCase")]
+pub enum OrderGroupType {
+    /// One-cancels-other, a leg filled or canceled cancels the other legs
+    #[strum(serialize = "oco")]
+    Oco,
+    /// One-triggers-other, the child legs wait for the parent to fill and are canceled with it
+    #[strum(serialize = "oto")]
+    Oto,
+    /// Entry with take profit and stop loss legs, the legs wait for the entry and cancel each other
+    #[strum(serialize = "bracket")]
+    Bracket,
+}
+
+/// Group membership of an order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderGroup {
+    pub group_id: OrderGroupId,
+    pub group_type: OrderGroupType,
+    #[serde(default)]
+    pub parent_order_id: Option<OrderId>, // Order this leg waits for, None for the parent and oco legs
+}
+
+impl OrderGroup {
+    pub fn new(group_id: OrderGroupId, group_type: OrderGroupType) -> Self {
+        Self {
+            group_id,
+            group_type,
+            parent_order_id: None,
+        }
+    }
+
+    /// Membership of a leg that waits for `parent_order_id`
+    pub fn child_of(&self, parent_order_id: OrderId) -> Self {
+        Self {
+            parent_order_id: Some(parent_order_id),
+            ..self.clone()
+        }
+    }
+}
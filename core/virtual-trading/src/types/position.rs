@@ -1,5 +1,3 @@
-use std::sync::atomic::Ordering;
-
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use star_river_core::{
@@ -10,7 +8,6 @@ use star_river_core::{
 };
 use utoipa::ToSchema;
 
-use super::id_generator::POSITION_ID_COUNTER;
 use crate::{
     error::VtsError,
     types::{VirtualOrder, VirtualTransaction},
@@ -35,12 +32,19 @@ pub struct VirtualPosition {
     pub margin: Margin,            // Margin used by the position
     pub margin_ratio: MarginRatio, // Margin ratio
     pub roi: f64,                  // Return on investment
+    #[serde(default)]
+    pub funding: Pnl, // Funding received (positive) or paid (negative) while the position is open
+    #[serde(default)]
+    pub open_bar: u64, // Bar count of the symbol when the position was opened
+    #[serde(default)]
+    pub add_count: u32, // Orders that added to the position after it was opened
     pub create_time: DateTime<Utc>,
     pub update_time: DateTime<Utc>,
 }
 
 impl VirtualPosition {
     pub fn new(
+        position_id: PositionId,
         position_side: PositionSide,
         strategy_id: StrategyId,
         exchange: Exchange,
@@ -54,7 +58,7 @@ impl VirtualPosition {
         datetime: DateTime<Utc>,
     ) -> Self {
         Self {
-            position_id: POSITION_ID_COUNTER.fetch_add(1, Ordering::SeqCst),
+            position_id,
             strategy_id,
             exchange,
             symbol,
@@ -69,6 +73,9 @@ impl VirtualPosition {
             margin_ratio,
             leverage,
             roi: 0.0,
+            funding: 0.0,
+            open_bar: 0,
+            add_count: 0,
             create_time: datetime,
             update_time: datetime,
         }
@@ -89,6 +96,7 @@ impl VirtualPosition {
 
     pub fn update_with_new_order(
         &mut self,
+        transaction_id: TransactionId,
         order: &VirtualOrder,
         current_price: Price,
         available_balance: Balance,
@@ -97,7 +105,7 @@ impl VirtualPosition {
         match (&order.order_side, &self.position_side) {
             // same direction, add position quantity
             (FuturesOrderSide::Long, PositionSide::Long) | (FuturesOrderSide::Short, PositionSide::Short) => {
-                let transaction = self.add_position(order, current_price, available_balance, datetime);
+                let transaction = self.add_position(transaction_id, order, current_price, available_balance, datetime);
                 return Ok((self.clone(), transaction));
             }
             // opposite direction, subtract position quantity
@@ -107,7 +115,7 @@ impl VirtualPosition {
                     order
                 );
                 // Use close_partially which handles both partial and full close
-                let transaction = self.close_partially(order, current_price, order.quantity, available_balance, datetime);
+                let transaction = self.close_partially(transaction_id, order, current_price, order.quantity, available_balance, datetime);
                 return Ok((self.clone(), transaction));
             }
         }
@@ -116,22 +124,24 @@ impl VirtualPosition {
     // return true if all closed, false if partial closed
     pub fn update_with_tp_order(
         &mut self,
+        transaction_id: TransactionId,
         tp_order: &VirtualOrder,
+        close_price: Price,
         balance: Balance,
         datetime: DateTime<Utc>,
     ) -> (VirtualPosition, VirtualTransaction) {
         // Check quantity and route to appropriate close method
         if (tp_order.quantity - self.quantity).abs() < f64::EPSILON {
             // Full close
-            let transaction = self.close_all(tp_order, tp_order.open_price, datetime);
+            let transaction = self.close_all(transaction_id, tp_order, close_price, datetime);
             return (self.clone(), transaction);
         } else if tp_order.quantity < self.quantity {
             // Partial close
-            let transaction = self.close_partially(tp_order, tp_order.open_price, tp_order.quantity, balance, datetime);
+            let transaction = self.close_partially(transaction_id, tp_order, close_price, tp_order.quantity, balance, datetime);
             return (self.clone(), transaction);
         } else {
             // Over quantity close - close all
-            let transaction = self.close_all(tp_order, tp_order.open_price, datetime);
+            let transaction = self.close_all(transaction_id, tp_order, close_price, datetime);
             return (self.clone(), transaction);
         }
     }
@@ -139,29 +149,50 @@ impl VirtualPosition {
     /// Update position with stop loss order execution
     pub fn update_with_sl_order(
         &mut self,
+        transaction_id: TransactionId,
         sl_order: &VirtualOrder,
+        close_price: Price,
         balance: Balance,
         datetime: DateTime<Utc>,
     ) -> (VirtualPosition, VirtualTransaction) {
         // Check quantity and route to appropriate close method
         if (sl_order.quantity - self.quantity).abs() < f64::EPSILON {
             // Full close
-            let transaction = self.close_all(sl_order, sl_order.open_price, datetime);
+            let transaction = self.close_all(transaction_id, sl_order, close_price, datetime);
             return (self.clone(), transaction);
         } else if sl_order.quantity < self.quantity {
             // Partial close
-            let transaction = self.close_partially(sl_order, sl_order.open_price, sl_order.quantity, balance, datetime);
+            let transaction = self.close_partially(transaction_id, sl_order, close_price, sl_order.quantity, balance, datetime);
             return (self.clone(), transaction);
         } else {
             // Over quantity close - close all
-            let transaction = self.close_all(sl_order, sl_order.open_price, datetime);
+            let transaction = self.close_all(transaction_id, sl_order, close_price, datetime);
             return (self.clone(), transaction);
         }
     }
 
+    /// Force close the whole position at the liquidation fill price
+    pub fn liquidate(
+        &mut self,
+        transaction_id: TransactionId,
+        liquidation_order: &VirtualOrder,
+        close_price: Price,
+        datetime: DateTime<Utc>,
+    ) -> (VirtualPosition, VirtualTransaction) {
+        let transaction = self.close_all(transaction_id, liquidation_order, close_price, datetime);
+        self.position_state = PositionState::ForcedClosed;
+        (self.clone(), transaction)
+    }
+
     /// Close all position with the given order
     /// Returns the realized profit and transaction record
-    fn close_all(&mut self, order: &VirtualOrder, close_price: Price, datetime: DateTime<Utc>) -> VirtualTransaction {
+    fn close_all(
+        &mut self,
+        transaction_id: TransactionId,
+        order: &VirtualOrder,
+        close_price: Price,
+        datetime: DateTime<Utc>,
+    ) -> VirtualTransaction {
         self.current_price = close_price;
         self.update_time = datetime;
         self.position_state = PositionState::Closed;
@@ -183,6 +214,7 @@ impl VirtualPosition {
         self.quantity = 0.0;
 
         let transaction = VirtualTransaction::new(
+            transaction_id,
             order.order_id,
             self.position_id,
             order.strategy_id,
@@ -205,6 +237,7 @@ impl VirtualPosition {
     /// Returns the transaction record
     fn add_position(
         &mut self,
+        transaction_id: TransactionId,
         order: &VirtualOrder,
         add_price: Price,
         available_balance: Balance,
@@ -215,6 +248,7 @@ impl VirtualPosition {
 
         // Update position quantity and open price
         self.quantity = new_total_quantity;
+        self.add_count += 1;
         self.open_price = new_open_price;
         self.current_price = add_price;
         self.update_time = datetime;
@@ -232,6 +266,7 @@ impl VirtualPosition {
         self.force_price = Formula::calculate_force_price(&self.position_side, self.leverage, add_price, self.quantity);
 
         let transaction = VirtualTransaction::new(
+            transaction_id,
             order.order_id,
             self.position_id,
             order.strategy_id,
@@ -255,6 +290,7 @@ impl VirtualPosition {
     /// If close_quantity >= position quantity, will close all position
     fn close_partially(
         &mut self,
+        transaction_id: TransactionId,
         order: &VirtualOrder,
         close_price: Price,
         close_quantity: f64,
@@ -268,7 +304,7 @@ impl VirtualPosition {
                 order.quantity,
                 self.quantity
             );
-            return self.close_all(order, close_price, datetime);
+            return self.close_all(transaction_id, order, close_price, datetime);
         }
 
         self.current_price = close_price;
@@ -296,6 +332,7 @@ impl VirtualPosition {
         self.force_price = Formula::calculate_force_price(&self.position_side, self.leverage, close_price, self.quantity);
 
         let transaction = VirtualTransaction::new(
+            transaction_id,
             order.order_id,
             self.position_id,
             order.strategy_id,
@@ -314,3 +351,80 @@ impl VirtualPosition {
         transaction
     }
 }
+
+/// Size of a partial position close
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum PartialCloseSize {
+    /// Close this quantity
+    Quantity(f64),
+    /// Close this percentage (0, 100] of the position quantity
+    Percentage(f64),
+    /// Close until this quantity is left
+    TargetQuantity(f64),
+}
+
+impl PartialCloseSize {
+    /// Quantity to close from a position of `position_quantity`, at most the whole position
+    pub fn close_quantity(&self, position_quantity: f64) -> Result<f64, String> {
+        let close_quantity = match *self {
+            PartialCloseSize::Quantity(quantity) => {
+                if quantity <= 0.0 {
+                    return Err(format!("close quantity {quantity} must be positive"));
+                }
+                quantity
+            }
+            PartialCloseSize::Percentage(percentage) => {
+                if percentage <= 0.0 || percentage > 100.0 {
+                    return Err(format!("close percentage {percentage} must be in (0, 100]"));
+                }
+                position_quantity * percentage / 100.0
+            }
+            PartialCloseSize::TargetQuantity(target_quantity) => {
+                if target_quantity < 0.0 || target_quantity >= position_quantity {
+                    return Err(format!(
+                        "target quantity {target_quantity} must be in [0, position quantity {position_quantity})"
+                    ));
+                }
+                position_quantity - target_quantity
+            }
+        };
+        Ok(close_quantity.min(position_quantity))
+    }
+}
+
+/// Limits on growing a position, nothing is limited by default
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScaleInLimit {
+    /// Largest quantity a position may reach, its opening order included
+    #[serde(default)]
+    pub max_quantity: Option<f64>,
+    /// Most orders that may add to a position after it was opened
+    #[serde(default)]
+    pub max_adds: Option<u32>,
+}
+
+impl ScaleInLimit {
+    /// Check an order of `quantity` opening a position, or adding to the same side `position`
+    pub fn check(&self, position: Option<&VirtualPosition>, quantity: f64) -> Result<(), String> {
+        if let Some(max_adds) = self.max_adds
+            && let Some(position) = position
+            && position.add_count >= max_adds
+        {
+            return Err(format!(
+                "position was already added to {} times, at most {max_adds} allowed",
+                position.add_count
+            ));
+        }
+        let new_quantity = position.map_or(0.0, |position| position.quantity) + quantity;
+        if let Some(max_quantity) = self.max_quantity
+            && new_quantity > max_quantity + f64::EPSILON
+        {
+            return Err(format!(
+                "position quantity would reach {new_quantity}, at most {max_quantity} allowed"
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use star_river_core::{custom_type::NodeId, position::PositionSide};
+use utoipa::ToSchema;
+
+/// Price distance of a stop adjustment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum StopDistance {
+    /// Fixed price distance
+    Price { value: f64 },
+    /// Percentage of the reference price
+    Percentage { value: f64 },
+    /// Multiple of the latest value of an ATR indicator, referenced by its indicator node and indicator config
+    Atr { node_id: NodeId, config_id: i32, multiplier: f64 },
+}
+
+impl StopDistance {
+    /// Distance from `reference_price`, None until the ATR indicator has a value
+    pub fn distance(&self, reference_price: f64, atr_values: &HashMap<(NodeId, i32), f64>) -> Option<f64> {
+        match self {
+            StopDistance::Price { value } => Some(*value),
+            StopDistance::Percentage { value } => Some(reference_price * value / 100.0),
+            StopDistance::Atr {
+                node_id,
+                config_id,
+                multiplier,
+            } => atr_values.get(&(node_id.clone(), *config_id)).map(|atr| atr * multiplier),
+        }
+    }
+}
+
+/// Move the stop loss to the entry price once the position is in profit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakEven {
+    pub trigger: StopDistance, // Favorable move from the entry price that arms the break-even stop
+    #[serde(default)]
+    pub offset: f64, // Price distance beyond the entry price to lock in, e.g. to cover fees
+}
+
+/// Stop loss adjustments re-evaluated every bar, the stop only moves in the position's favor
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrailingStop {
+    #[serde(default)]
+    pub trailing: Option<StopDistance>, // Distance the stop follows behind the best price since entry
+    #[serde(default)]
+    pub break_even: Option<BreakEven>,
+}
+
+impl TrailingStop {
+    /// Initial stop of a position without a configured stop loss, trailing from the entry price
+    /// (indicator node id, indicator config id) of the ATR indicators the distances refer to
+    pub fn atr_indicators(&self) -> Vec<(&NodeId, i32)> {
+        self.trailing
+            .iter()
+            .chain(self.break_even.iter().map(|break_even| &break_even.trigger))
+            .filter_map(|distance| match distance {
+                StopDistance::Atr { node_id, config_id, .. } => Some((node_id, *config_id)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Whether the initial stop can be placed, an ATR trailing distance waits for the ATR value
+    pub fn has_initial_stop(&self, atr_values: &HashMap<(NodeId, i32), f64>) -> bool {
+        match &self.trailing {
+            Some(StopDistance::Atr { node_id, config_id, .. }) => atr_values.contains_key(&(node_id.clone(), *config_id)),
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    pub fn initial_stop(&self, position_side: &PositionSide, open_price: f64, atr_values: &HashMap<(NodeId, i32), f64>) -> Option<f64> {
+        let distance = self.trailing.as_ref()?.distance(open_price, atr_values)?;
+        match position_side {
+            PositionSide::Long => Some(open_price - distance),
+            PositionSide::Short => Some(open_price + distance),
+        }
+    }
+
+    /// New stop if it improves on `current_stop`, `best_price` is the highest high (long) or lowest low (short) since entry
+    pub fn adjust(
+        &self,
+        position_side: &PositionSide,
+        open_price: f64,
+        current_stop: f64,
+        best_price: f64,
+        atr_values: &HashMap<(NodeId, i32), f64>,
+    ) -> Option<f64> {
+        // Direction of a favorable move, stops only move this way
+        let direction = match position_side {
+            PositionSide::Long => 1.0,
+            PositionSide::Short => -1.0,
+        };
+
+        let mut candidates = Vec::new();
+        if let Some(trailing) = &self.trailing
+            && let Some(distance) = trailing.distance(best_price, atr_values)
+        {
+            candidates.push(best_price - direction * distance);
+        }
+        if let Some(break_even) = &self.break_even
+            && let Some(trigger) = break_even.trigger.distance(open_price, atr_values)
+            && (best_price - open_price) * direction >= trigger
+        {
+            candidates.push(open_price + direction * break_even.offset);
+        }
+
+        candidates
+            .into_iter()
+            .filter(|stop| (stop - current_stop) * direction > 0.0)
+            .max_by(|a, b| (a * direction).total_cmp(&(b * direction)))
+    }
+}
@@ -1,11 +1,9 @@
-use std::sync::atomic::Ordering;
-
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use star_river_core::{custom_type::*, exchange::Exchange, transaction::FuturesTransSide};
 use utoipa::{IntoParams, ToSchema};
 
-use super::id_generator::TRANSACTION_ID_COUNTER;
+use crate::fee_schedule::Liquidity;
 #[derive(Debug, Clone, Serialize, Deserialize, IntoParams, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct VirtualTransaction {
@@ -35,11 +33,21 @@ pub struct VirtualTransaction {
 
     pub profit: Option<f64>, // Profit
 
+    #[serde(default)]
+    pub slippage: f64, // Slippage applied to the fill price, in price units
+
+    #[serde(default)]
+    pub liquidity: Liquidity, // Maker or taker
+
+    #[serde(default)]
+    pub fee: f64, // Trading fee charged on this transaction
+
     pub create_time: DateTime<Utc>, // Create time
 }
 
 impl VirtualTransaction {
     pub fn new(
+        transaction_id: TransactionId,
         order_id: OrderId,
         position_id: PositionId,
         strategy_id: StrategyId,
@@ -54,8 +62,6 @@ impl VirtualTransaction {
         profit: Option<f64>,
         datetime: DateTime<Utc>,
     ) -> Self {
-        let transaction_id = TRANSACTION_ID_COUNTER.fetch_add(1, Ordering::SeqCst);
-
         Self {
             transaction_id,
             order_id,
@@ -70,6 +76,9 @@ impl VirtualTransaction {
             quantity,
             price,
             profit,
+            slippage: 0.0,
+            liquidity: Liquidity::Taker,
+            fee: 0.0,
             create_time: datetime,
         }
     }
@@ -95,6 +95,11 @@ impl DatabaseManager {
             {
                 Self::get_windows_database_path()
             }
+
+            #[cfg(target_os = "linux")]
+            {
+                Self::get_linux_database_path()
+            }
         }
     }
 
@@ -144,6 +149,31 @@ impl DatabaseManager {
         Ok(app_data_path)
     }
 
+    /// Get database path on Linux system
+    /// Database is stored in $XDG_DATA_HOME/star-river/app_data (defaults to ~/.local/share)
+    #[allow(unused)]
+    #[cfg(target_os = "linux")]
+    fn get_linux_database_path() -> Result<PathBuf, DatabaseError> {
+        let data_home = match env::var("XDG_DATA_HOME") {
+            Ok(data_home) if !data_home.is_empty() => PathBuf::from(data_home),
+            _ => PathBuf::from(env::var("HOME").context(HomeDirNotFoundSnafu {})?)
+                .join(".local")
+                .join("share"),
+        };
+
+        let app_data_path = data_home.join("star-river").join("app_data");
+
+        // Create directory if it doesn't exist
+        if !app_data_path.exists() {
+            std::fs::create_dir_all(&app_data_path).context(DirCreateFailedSnafu {
+                dir: app_data_path.display().to_string(),
+            })?;
+        }
+
+        tracing::info!("Linux database path: {}", app_data_path.display());
+        Ok(app_data_path)
+    }
+
     // find workspace root directory
     fn find_workspace_root() -> Result<PathBuf, DatabaseError> {
         // First try to find from CARGO_MANIFEST_DIR upwards (at compile time)
@@ -0,0 +1,92 @@
+use ::entity::{backtest_checkpoint, backtest_checkpoint::Entity as BacktestCheckpointEntity};
+use chrono::{DateTime, Utc};
+use sea_orm::*;
+use star_river_core::{
+    backtest_checkpoint::BacktestCheckpointInfo,
+    custom_type::{StrategyId, StrategyName},
+};
+
+use crate::{error::DatabaseError, query::backtest_checkpoint_query::BacktestCheckpointQuery};
+
+#[derive(Debug, Clone)]
+pub struct NewBacktestCheckpoint {
+    pub strategy_id: StrategyId,
+    pub strategy_name: StrategyName,
+    pub play_index: i64,
+    pub strategy_datetime: DateTime<Utc>,
+    pub checkpoint: JsonValue,
+}
+
+pub struct BacktestCheckpointMutation;
+
+impl BacktestCheckpointMutation {
+    pub async fn create_backtest_checkpoint(
+        db: &DbConn,
+        new_checkpoint: NewBacktestCheckpoint,
+    ) -> Result<BacktestCheckpointInfo, DatabaseError> {
+        let checkpoint_model = backtest_checkpoint::ActiveModel {
+            id: NotSet,
+            strategy_id: Set(new_checkpoint.strategy_id),
+            strategy_name: Set(new_checkpoint.strategy_name),
+            play_index: Set(new_checkpoint.play_index),
+            strategy_datetime: Set(new_checkpoint.strategy_datetime),
+            checkpoint: Set(new_checkpoint.checkpoint),
+            created_time: Set(Utc::now()),
+        }
+        .insert(db)
+        .await?;
+        Ok(BacktestCheckpointQuery::model_to_info(checkpoint_model))
+    }
+
+    pub async fn delete_backtest_checkpoint(db: &DbConn, checkpoint_id: i32) -> Result<(), DatabaseError> {
+        let result = BacktestCheckpointEntity::delete_by_id(checkpoint_id).exec(db).await?;
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotFound("Cannot find backtest checkpoint.".to_owned()).into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::DatabaseManager;
+
+    #[tokio::test]
+    async fn test_backtest_checkpoint_is_saved_and_loaded() {
+        let database = DatabaseManager::new_in_memory().await.unwrap();
+        let db = database.get_conn();
+
+        let new_checkpoint = NewBacktestCheckpoint {
+            strategy_id: 1,
+            strategy_name: "test strategy".to_string(),
+            play_index: 499,
+            strategy_datetime: Utc::now(),
+            checkpoint: json!({"playIndex": 499}),
+        };
+        let checkpoint = BacktestCheckpointMutation::create_backtest_checkpoint(&db, new_checkpoint)
+            .await
+            .unwrap();
+
+        let page = BacktestCheckpointQuery::get_backtest_checkpoint_list_in_page(&db, 1, 1, 10)
+            .await
+            .unwrap();
+        assert_eq!(page.total_items, 1);
+        let (info, data) = BacktestCheckpointQuery::get_backtest_checkpoint_by_id(&db, checkpoint.id)
+            .await
+            .unwrap();
+        assert_eq!(info.play_index, 499);
+        assert_eq!(data, json!({"playIndex": 499}));
+
+        BacktestCheckpointMutation::delete_backtest_checkpoint(&db, checkpoint.id)
+            .await
+            .unwrap();
+        assert!(
+            BacktestCheckpointMutation::delete_backtest_checkpoint(&db, checkpoint.id)
+                .await
+                .is_err()
+        );
+    }
+}
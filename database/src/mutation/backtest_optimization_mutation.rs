@@ -0,0 +1,169 @@
+use ::entity::{backtest_optimization, backtest_optimization::Entity as BacktestOptimizationEntity, backtest_optimization_trial};
+use chrono::Utc;
+use sea_orm::*;
+use star_river_core::{
+    backtest_optimization::{BacktestOptimizationInfo, BacktestOptimizationStatus},
+    custom_type::{StrategyId, StrategyName},
+};
+
+use crate::{error::DatabaseError, query::backtest_optimization_query::BacktestOptimizationQuery};
+
+#[derive(Debug, Clone)]
+pub struct NewBacktestOptimization {
+    pub strategy_id: StrategyId,
+    pub strategy_name: StrategyName,
+    pub optimization_config: JsonValue,
+    pub metric: String,
+    pub total_trials: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewBacktestOptimizationTrial {
+    pub parameters: JsonValue,
+    pub score: Option<f64>,
+    pub performance: Option<JsonValue>,
+    pub error: Option<String>,
+}
+
+pub struct BacktestOptimizationMutation;
+
+impl BacktestOptimizationMutation {
+    // Created before the first trial is played, the optimization stays running until it is finished
+    pub async fn create_backtest_optimization(
+        db: &DbConn,
+        new_optimization: NewBacktestOptimization,
+    ) -> Result<BacktestOptimizationInfo, DatabaseError> {
+        let optimization_model = backtest_optimization::ActiveModel {
+            id: NotSet,
+            strategy_id: Set(new_optimization.strategy_id),
+            strategy_name: Set(new_optimization.strategy_name),
+            optimization_config: Set(new_optimization.optimization_config),
+            metric: Set(new_optimization.metric),
+            status: Set(BacktestOptimizationStatus::Running.to_string()),
+            total_trials: Set(new_optimization.total_trials),
+            created_time: Set(Utc::now()),
+            finished_time: Set(None),
+        }
+        .insert(db)
+        .await?;
+        Ok(BacktestOptimizationQuery::model_to_info(optimization_model))
+    }
+
+    pub async fn create_backtest_optimization_trial(
+        db: &DbConn,
+        optimization_id: i32,
+        new_trial: NewBacktestOptimizationTrial,
+    ) -> Result<(), DatabaseError> {
+        backtest_optimization_trial::ActiveModel {
+            id: NotSet,
+            optimization_id: Set(optimization_id),
+            parameters: Set(new_trial.parameters),
+            score: Set(new_trial.score),
+            performance: Set(new_trial.performance),
+            error: Set(new_trial.error),
+            created_time: Set(Utc::now()),
+        }
+        .insert(db)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn finish_backtest_optimization(
+        db: &DbConn,
+        optimization_id: i32,
+        status: BacktestOptimizationStatus,
+    ) -> Result<BacktestOptimizationInfo, DatabaseError> {
+        let optimization_model: backtest_optimization::ActiveModel = BacktestOptimizationEntity::find_by_id(optimization_id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Cannot find backtest optimization.".to_owned()))
+            .map(Into::into)?;
+
+        let optimization_model = backtest_optimization::ActiveModel {
+            id: optimization_model.id,
+            status: Set(status.to_string()),
+            finished_time: Set(Some(Utc::now())),
+            ..Default::default()
+        }
+        .update(db)
+        .await?;
+        Ok(BacktestOptimizationQuery::model_to_info(optimization_model))
+    }
+
+    pub async fn delete_backtest_optimization(db: &DbConn, optimization_id: i32) -> Result<(), DatabaseError> {
+        let txn = db.begin().await?;
+        BacktestOptimizationEntity::find_by_id(optimization_id)
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Cannot find backtest optimization.".to_owned()))?;
+
+        // Delete trials explicitly, sqlite only cascades when foreign keys are enabled
+        backtest_optimization_trial::Entity::delete_many()
+            .filter(backtest_optimization_trial::Column::OptimizationId.eq(optimization_id))
+            .exec(&txn)
+            .await?;
+        BacktestOptimizationEntity::delete_by_id(optimization_id).exec(&txn).await?;
+
+        txn.commit().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::DatabaseManager;
+
+    #[tokio::test]
+    async fn test_backtest_optimization_trials_are_ranked() {
+        let database = DatabaseManager::new_in_memory().await.unwrap();
+        let db = database.get_conn();
+
+        let new_optimization = NewBacktestOptimization {
+            strategy_id: 1,
+            strategy_name: "test strategy".to_string(),
+            optimization_config: json!({"parameters": []}),
+            metric: "sharpeRatio".to_string(),
+            total_trials: 3,
+        };
+        let optimization = BacktestOptimizationMutation::create_backtest_optimization(&db, new_optimization)
+            .await
+            .unwrap();
+        assert_eq!(optimization.status, "running");
+
+        for (period, score) in [(10, Some(0.5)), (20, None), (30, Some(1.5))] {
+            let new_trial = NewBacktestOptimizationTrial {
+                parameters: json!([{"nodeId": "indicator_node", "path": "/period", "value": period}]),
+                score,
+                performance: score.map(|score| json!({"sharpeRatio": score})),
+                error: score.is_none().then(|| "trial failed".to_string()),
+            };
+            BacktestOptimizationMutation::create_backtest_optimization_trial(&db, optimization.id, new_trial)
+                .await
+                .unwrap();
+        }
+        BacktestOptimizationMutation::finish_backtest_optimization(&db, optimization.id, BacktestOptimizationStatus::Completed)
+            .await
+            .unwrap();
+
+        let detail = BacktestOptimizationQuery::get_backtest_optimization_by_id(&db, optimization.id)
+            .await
+            .unwrap();
+        assert_eq!(detail.info.status, "completed");
+        assert!(detail.info.finished_time.is_some());
+        let ranks = detail.trials.iter().map(|trial| (trial.rank, trial.score)).collect::<Vec<_>>();
+        assert_eq!(ranks, vec![(Some(1), Some(1.5)), (Some(2), Some(0.5)), (None, None)]);
+
+        BacktestOptimizationMutation::delete_backtest_optimization(&db, optimization.id)
+            .await
+            .unwrap();
+        assert!(
+            BacktestOptimizationQuery::get_backtest_optimization_by_id(&db, optimization.id)
+                .await
+                .is_err()
+        );
+        assert_eq!(backtest_optimization_trial::Entity::find().count(&db).await.unwrap(), 0);
+    }
+}
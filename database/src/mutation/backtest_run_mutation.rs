@@ -0,0 +1,363 @@
+use ::entity::{backtest_run, backtest_run::Entity as BacktestRunEntity, order, position, strategy_statistics, transaction};
+use chrono::{DateTime, Utc};
+use sea_orm::*;
+use star_river_core::{
+    backtest_run::{BacktestRunEquity, BacktestRunInfo, BacktestRunStatus},
+    custom_type::{Balance, FeeRate, StrategyId, StrategyName},
+};
+
+use crate::{error::DatabaseError, query::backtest_run_query::BacktestRunQuery};
+
+// Order of a backtest run, the full serialized order is kept in `extra_info`
+#[derive(Debug, Clone)]
+pub struct BacktestRunOrder {
+    pub order_id: i64,
+    pub node_id: String,
+    pub exchange: String,
+    pub symbol: String,
+    pub order_side: String,
+    pub order_status: String,
+    pub order_type: String,
+    pub quantity: f64,
+    pub price: f64,
+    pub sl: Option<f64>,
+    pub tp: Option<f64>,
+    pub extra_info: JsonValue,
+    pub created_time: DateTime<Utc>,
+    pub updated_time: DateTime<Utc>,
+}
+
+// Position of a backtest run, the full serialized position is kept in `extra_info`
+#[derive(Debug, Clone)]
+pub struct BacktestRunPosition {
+    pub position_id: i64,
+    pub node_id: String,
+    pub exchange: String,
+    pub symbol: String,
+    pub position_side: String,
+    pub position_state: String,
+    pub quantity: f64,
+    pub open_price: f64,
+    pub unrealized_profit: f64,
+    pub extra_info: JsonValue,
+    pub created_time: DateTime<Utc>,
+    pub updated_time: DateTime<Utc>,
+}
+
+// Transaction of a backtest run, the full serialized transaction is kept in `extra_info`
+#[derive(Debug, Clone)]
+pub struct BacktestRunTransaction {
+    pub transaction_id: i64,
+    pub order_id: i64,
+    pub position_id: i64,
+    pub node_id: String,
+    pub exchange: String,
+    pub symbol: String,
+    pub transaction_type: String,
+    pub transaction_side: String,
+    pub quantity: f64,
+    pub price: f64,
+    pub extra_info: JsonValue,
+    pub created_time: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewBacktestRun {
+    pub strategy_id: StrategyId,
+    pub strategy_name: StrategyName,
+    pub config_snapshot: Option<JsonValue>,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    pub initial_balance: Balance,
+    pub fee_rate: FeeRate,
+    pub leverage: i32,
+    pub status: BacktestRunStatus,
+    pub orders: Vec<BacktestRunOrder>,
+    pub positions: Vec<BacktestRunPosition>,
+    pub transactions: Vec<BacktestRunTransaction>,
+    pub equity_curve: Vec<BacktestRunEquity>,
+}
+
+// SQLite limits the number of bind variables per statement, so records are inserted in chunks
+const RECORDS_PER_INSERT: usize = 500;
+
+// Backtest accounts are virtual, the account_id column is not nullable
+const BACKTEST_ACCOUNT_ID: i32 = 0;
+
+pub struct BacktestRunMutation;
+
+impl BacktestRunMutation {
+    // Save a finished backtest run in one transaction, its results go to the order/position/transaction/strategy_statistics tables
+    pub async fn create_backtest_run(db: &DbConn, new_backtest_run: NewBacktestRun) -> Result<BacktestRunInfo, DatabaseError> {
+        let txn = db.begin().await?;
+
+        let backtest_run_model = backtest_run::ActiveModel {
+            id: NotSet,
+            strategy_id: Set(new_backtest_run.strategy_id),
+            strategy_name: Set(new_backtest_run.strategy_name),
+            config_snapshot: Set(new_backtest_run.config_snapshot),
+            start_time: Set(new_backtest_run.start_time),
+            end_time: Set(new_backtest_run.end_time),
+            initial_balance: Set(new_backtest_run.initial_balance),
+            fee_rate: Set(new_backtest_run.fee_rate),
+            leverage: Set(new_backtest_run.leverage),
+            status: Set(new_backtest_run.status.to_string()),
+            created_time: Set(Utc::now()),
+        }
+        .insert(&txn)
+        .await?;
+        let backtest_run_id = backtest_run_model.id;
+        let strategy_id = new_backtest_run.strategy_id as i64;
+
+        let orders = new_backtest_run
+            .orders
+            .into_iter()
+            .map(|o| order::ActiveModel {
+                id: NotSet,
+                strategy_id: Set(strategy_id),
+                node_id: Set(o.node_id),
+                exchange_order_id: Set(o.order_id),
+                account_id: Set(BACKTEST_ACCOUNT_ID),
+                exchange: Set(o.exchange),
+                symbol: Set(o.symbol),
+                order_side: Set(o.order_side),
+                order_status: Set(o.order_status),
+                order_type: Set(o.order_type),
+                quantity: Set(o.quantity),
+                price: Set(o.price),
+                sl: Set(o.sl),
+                tp: Set(o.tp),
+                extra_info: Set(Some(o.extra_info)),
+                created_time: Set(o.created_time),
+                updated_time: Set(o.updated_time),
+                backtest_run_id: Set(Some(backtest_run_id)),
+            })
+            .collect();
+        Self::insert_records(&txn, orders).await?;
+
+        let positions = new_backtest_run
+            .positions
+            .into_iter()
+            .map(|p| position::ActiveModel {
+                id: NotSet,
+                strategy_id: Set(strategy_id),
+                node_id: Set(p.node_id),
+                exchange_position_id: Set(p.position_id),
+                account_id: Set(BACKTEST_ACCOUNT_ID),
+                exchange: Set(p.exchange),
+                symbol: Set(p.symbol),
+                position_side: Set(p.position_side),
+                position_state: Set(p.position_state),
+                quantity: Set(p.quantity),
+                open_price: Set(p.open_price),
+                unrealized_profit: Set(Some(p.unrealized_profit)),
+                sl: Set(None),
+                tp: Set(None),
+                extra_info: Set(Some(p.extra_info)),
+                created_time: Set(p.created_time),
+                updated_time: Set(p.updated_time),
+                backtest_run_id: Set(Some(backtest_run_id)),
+            })
+            .collect();
+        Self::insert_records(&txn, positions).await?;
+
+        let transactions = new_backtest_run
+            .transactions
+            .into_iter()
+            .map(|t| transaction::ActiveModel {
+                id: NotSet,
+                strategy_id: Set(strategy_id),
+                node_id: Set(t.node_id),
+                exchange: Set(t.exchange),
+                symbol: Set(t.symbol),
+                exchange_position_id: Set(t.position_id),
+                exchange_transaction_id: Set(t.transaction_id),
+                exchange_order_id: Set(t.order_id),
+                transaction_type: Set(t.transaction_type),
+                transaction_side: Set(t.transaction_side),
+                quantity: Set(t.quantity),
+                price: Set(t.price),
+                created_time: Set(t.created_time),
+                extra_info: Set(Some(t.extra_info)),
+                backtest_run_id: Set(Some(backtest_run_id)),
+            })
+            .collect();
+        Self::insert_records(&txn, transactions).await?;
+
+        let equity_curve = new_backtest_run
+            .equity_curve
+            .into_iter()
+            .map(|e| strategy_statistics::ActiveModel {
+                id: NotSet,
+                strategy_id: Set(new_backtest_run.strategy_id),
+                backtest_run_id: Set(backtest_run_id),
+                datetime: Set(e.datetime),
+                balance: Set(e.balance),
+                available_balance: Set(e.available_balance),
+                unrealized_pnl: Set(e.unrealized_pnl),
+                equity: Set(e.equity),
+                cumulative_return: Set(e.cumulative_return),
+                realized_pnl: Set(e.realized_pnl),
+                total_fee: Set(e.total_fee),
+                total_funding: Set(e.total_funding),
+            })
+            .collect();
+        Self::insert_records(&txn, equity_curve).await?;
+
+        txn.commit().await?;
+        Ok(BacktestRunQuery::model_to_info(backtest_run_model))
+    }
+
+    pub async fn delete_backtest_run(db: &DbConn, backtest_run_id: i32) -> Result<(), DatabaseError> {
+        let txn = db.begin().await?;
+        BacktestRunEntity::find_by_id(backtest_run_id)
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Cannot find backtest run.".to_owned()))?;
+
+        // Delete result records explicitly, sqlite only cascades when foreign keys are enabled
+        order::Entity::delete_many()
+            .filter(order::Column::BacktestRunId.eq(backtest_run_id))
+            .exec(&txn)
+            .await?;
+        position::Entity::delete_many()
+            .filter(position::Column::BacktestRunId.eq(backtest_run_id))
+            .exec(&txn)
+            .await?;
+        transaction::Entity::delete_many()
+            .filter(transaction::Column::BacktestRunId.eq(backtest_run_id))
+            .exec(&txn)
+            .await?;
+        strategy_statistics::Entity::delete_many()
+            .filter(strategy_statistics::Column::BacktestRunId.eq(backtest_run_id))
+            .exec(&txn)
+            .await?;
+        BacktestRunEntity::delete_by_id(backtest_run_id).exec(&txn).await?;
+
+        txn.commit().await?;
+        Ok(())
+    }
+
+    async fn insert_records<A, C>(db: &C, records: Vec<A>) -> Result<(), DbErr>
+    where
+        A: ActiveModelTrait + Clone + Send,
+        C: ConnectionTrait,
+    {
+        for chunk in records.chunks(RECORDS_PER_INSERT) {
+            A::Entity::insert_many(chunk.to_vec()).exec(db).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::DatabaseManager;
+
+    #[tokio::test]
+    async fn test_create_get_and_delete_backtest_run() {
+        let database = DatabaseManager::new_in_memory().await.unwrap();
+        let db = database.get_conn();
+
+        let now = Utc::now();
+        let order = BacktestRunOrder {
+            order_id: 1,
+            node_id: "futures_order_node".to_string(),
+            exchange: "binance".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            order_side: "long".to_string(),
+            order_status: "filled".to_string(),
+            order_type: "market".to_string(),
+            quantity: 1.0,
+            price: 100.0,
+            sl: None,
+            tp: Some(110.0),
+            extra_info: json!({"orderId": 1}),
+            created_time: now,
+            updated_time: now,
+        };
+        let position = BacktestRunPosition {
+            position_id: 1,
+            node_id: "futures_order_node".to_string(),
+            exchange: "binance".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            position_side: "long".to_string(),
+            position_state: "open".to_string(),
+            quantity: 1.0,
+            open_price: 100.0,
+            unrealized_profit: 5.0,
+            extra_info: json!({"positionId": 1}),
+            created_time: now,
+            updated_time: now,
+        };
+        let transactions = (1..=2)
+            .map(|transaction_id| BacktestRunTransaction {
+                transaction_id,
+                order_id: 1,
+                position_id: 1,
+                node_id: "futures_order_node".to_string(),
+                exchange: "binance".to_string(),
+                symbol: "BTCUSDT".to_string(),
+                transaction_type: "open".to_string(),
+                transaction_side: "open_long".to_string(),
+                quantity: 1.0,
+                price: 100.0,
+                extra_info: json!({"transactionId": transaction_id}),
+                created_time: now,
+            })
+            .collect();
+        let equity_curve = (0..2500)
+            .map(|i| BacktestRunEquity {
+                datetime: now + chrono::Duration::minutes(i),
+                balance: 10000.0,
+                available_balance: 10000.0,
+                unrealized_pnl: 0.0,
+                equity: 10000.0 + i as f64,
+                cumulative_return: 0.0,
+                realized_pnl: 0.0,
+                total_fee: 0.0,
+                total_funding: 0.0,
+            })
+            .collect();
+        let new_backtest_run = NewBacktestRun {
+            strategy_id: 1,
+            strategy_name: "test strategy".to_string(),
+            config_snapshot: Some(json!({"nodes": [], "edges": []})),
+            start_time: now,
+            end_time: now,
+            initial_balance: 10000.0,
+            fee_rate: 0.001,
+            leverage: 10,
+            status: BacktestRunStatus::Completed,
+            orders: vec![order],
+            positions: vec![position],
+            transactions,
+            equity_curve,
+        };
+        let backtest_run = BacktestRunMutation::create_backtest_run(&db, new_backtest_run).await.unwrap();
+
+        let page = BacktestRunQuery::get_backtest_run_list_in_page(&db, 1, 1, 10).await.unwrap();
+        assert_eq!(page.total_items, 1);
+        assert_eq!(page.data[0].status, "completed");
+
+        // Results are stored in the existing tables
+        let order_model = order::Entity::find().one(&db).await.unwrap().unwrap();
+        assert_eq!(order_model.backtest_run_id, Some(backtest_run.id));
+        assert_eq!(order_model.tp, Some(110.0));
+
+        let detail = BacktestRunQuery::get_backtest_run_by_id(&db, backtest_run.id).await.unwrap();
+        assert_eq!(detail.orders, vec![json!({"orderId": 1})]);
+        assert_eq!(detail.positions.len(), 1);
+        assert_eq!(detail.transactions[1], json!({"transactionId": 2}));
+        assert_eq!(detail.equity_curve.len(), 2500);
+        assert_eq!(detail.equity_curve[2499].equity, 12499.0);
+
+        BacktestRunMutation::delete_backtest_run(&db, backtest_run.id).await.unwrap();
+        assert!(BacktestRunQuery::get_backtest_run_by_id(&db, backtest_run.id).await.is_err());
+        assert_eq!(strategy_statistics::Entity::find().count(&db).await.unwrap(), 0);
+        assert_eq!(transaction::Entity::find().count(&db).await.unwrap(), 0);
+    }
+}
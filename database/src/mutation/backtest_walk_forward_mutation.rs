@@ -0,0 +1,220 @@
+use ::entity::{backtest_walk_forward, backtest_walk_forward::Entity as BacktestWalkForwardEntity, backtest_walk_forward_window};
+use chrono::Utc;
+use sea_orm::*;
+use star_river_core::{
+    backtest_walk_forward::{BacktestWalkForwardInfo, BacktestWalkForwardStatus},
+    custom_type::{StrategyId, StrategyName},
+    system::DateTimeUtc,
+};
+
+use crate::{error::DatabaseError, query::backtest_walk_forward_query::BacktestWalkForwardQuery};
+
+#[derive(Debug, Clone)]
+pub struct NewBacktestWalkForward {
+    pub strategy_id: StrategyId,
+    pub strategy_name: StrategyName,
+    pub walk_forward_config: JsonValue,
+    pub metric: String,
+    pub window_mode: String,
+    pub total_windows: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewBacktestWalkForwardWindow {
+    pub window_index: i32,
+    pub in_sample_start: DateTimeUtc,
+    pub in_sample_end: DateTimeUtc,
+    pub out_of_sample_start: DateTimeUtc,
+    pub out_of_sample_end: DateTimeUtc,
+    pub best_parameters: Option<JsonValue>,
+    pub in_sample_score: Option<f64>,
+    pub out_of_sample_score: Option<f64>,
+    pub out_of_sample_performance: Option<JsonValue>,
+    pub failed_trial_count: i32,
+    pub error: Option<String>,
+}
+
+pub struct BacktestWalkForwardMutation;
+
+impl BacktestWalkForwardMutation {
+    // Created before the first window is played, the analysis stays running until it is finished
+    pub async fn create_backtest_walk_forward(
+        db: &DbConn,
+        new_walk_forward: NewBacktestWalkForward,
+    ) -> Result<BacktestWalkForwardInfo, DatabaseError> {
+        let walk_forward_model = backtest_walk_forward::ActiveModel {
+            id: NotSet,
+            strategy_id: Set(new_walk_forward.strategy_id),
+            strategy_name: Set(new_walk_forward.strategy_name),
+            walk_forward_config: Set(new_walk_forward.walk_forward_config),
+            metric: Set(new_walk_forward.metric),
+            window_mode: Set(new_walk_forward.window_mode),
+            status: Set(BacktestWalkForwardStatus::Running.to_string()),
+            total_windows: Set(new_walk_forward.total_windows),
+            equity_curve: Set(None),
+            performance: Set(None),
+            created_time: Set(Utc::now()),
+            finished_time: Set(None),
+        }
+        .insert(db)
+        .await?;
+        Ok(BacktestWalkForwardQuery::model_to_info(walk_forward_model))
+    }
+
+    pub async fn create_backtest_walk_forward_window(
+        db: &DbConn,
+        walk_forward_id: i32,
+        new_window: NewBacktestWalkForwardWindow,
+    ) -> Result<(), DatabaseError> {
+        backtest_walk_forward_window::ActiveModel {
+            id: NotSet,
+            walk_forward_id: Set(walk_forward_id),
+            window_index: Set(new_window.window_index),
+            in_sample_start: Set(new_window.in_sample_start),
+            in_sample_end: Set(new_window.in_sample_end),
+            out_of_sample_start: Set(new_window.out_of_sample_start),
+            out_of_sample_end: Set(new_window.out_of_sample_end),
+            best_parameters: Set(new_window.best_parameters),
+            in_sample_score: Set(new_window.in_sample_score),
+            out_of_sample_score: Set(new_window.out_of_sample_score),
+            out_of_sample_performance: Set(new_window.out_of_sample_performance),
+            failed_trial_count: Set(new_window.failed_trial_count),
+            error: Set(new_window.error),
+            created_time: Set(Utc::now()),
+        }
+        .insert(db)
+        .await?;
+        Ok(())
+    }
+
+    // The stitched out-of-sample equity curve and its performance are only stored by a completed analysis
+    pub async fn finish_backtest_walk_forward(
+        db: &DbConn,
+        walk_forward_id: i32,
+        status: BacktestWalkForwardStatus,
+        equity_curve: Option<JsonValue>,
+        performance: Option<JsonValue>,
+    ) -> Result<BacktestWalkForwardInfo, DatabaseError> {
+        let walk_forward_model: backtest_walk_forward::ActiveModel = BacktestWalkForwardEntity::find_by_id(walk_forward_id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Cannot find backtest walk-forward.".to_owned()))
+            .map(Into::into)?;
+
+        let walk_forward_model = backtest_walk_forward::ActiveModel {
+            id: walk_forward_model.id,
+            status: Set(status.to_string()),
+            equity_curve: Set(equity_curve),
+            performance: Set(performance),
+            finished_time: Set(Some(Utc::now())),
+            ..Default::default()
+        }
+        .update(db)
+        .await?;
+        Ok(BacktestWalkForwardQuery::model_to_info(walk_forward_model))
+    }
+
+    pub async fn delete_backtest_walk_forward(db: &DbConn, walk_forward_id: i32) -> Result<(), DatabaseError> {
+        let txn = db.begin().await?;
+        BacktestWalkForwardEntity::find_by_id(walk_forward_id)
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Cannot find backtest walk-forward.".to_owned()))?;
+
+        // Delete windows explicitly, sqlite only cascades when foreign keys are enabled
+        backtest_walk_forward_window::Entity::delete_many()
+            .filter(backtest_walk_forward_window::Column::WalkForwardId.eq(walk_forward_id))
+            .exec(&txn)
+            .await?;
+        BacktestWalkForwardEntity::delete_by_id(walk_forward_id).exec(&txn).await?;
+
+        txn.commit().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+    use serde_json::json;
+
+    use super::*;
+    use crate::DatabaseManager;
+
+    #[tokio::test]
+    async fn test_backtest_walk_forward_windows_are_persisted() {
+        let database = DatabaseManager::new_in_memory().await.unwrap();
+        let db = database.get_conn();
+
+        let new_walk_forward = NewBacktestWalkForward {
+            strategy_id: 1,
+            strategy_name: "test strategy".to_string(),
+            walk_forward_config: json!({"parameters": [], "inSampleDays": 7, "outOfSampleDays": 5}),
+            metric: "sharpeRatio".to_string(),
+            window_mode: "rolling".to_string(),
+            total_windows: 2,
+        };
+        let walk_forward = BacktestWalkForwardMutation::create_backtest_walk_forward(&db, new_walk_forward)
+            .await
+            .unwrap();
+        assert_eq!(walk_forward.status, "running");
+
+        // Windows are stored in the order they finish, the second one first here
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        for window_index in [1, 0] {
+            let out_of_sample_start = start + Duration::days(7 + 5 * window_index as i64);
+            let new_window = NewBacktestWalkForwardWindow {
+                window_index,
+                in_sample_start: out_of_sample_start - Duration::days(7),
+                in_sample_end: out_of_sample_start,
+                out_of_sample_start,
+                out_of_sample_end: out_of_sample_start + Duration::days(5),
+                best_parameters: (window_index == 0).then(|| json!([{"nodeId": "indicator_node", "path": "/period", "value": 10}])),
+                in_sample_score: (window_index == 0).then_some(1.5),
+                out_of_sample_score: (window_index == 0).then_some(0.5),
+                out_of_sample_performance: (window_index == 0).then(|| json!({"sharpeRatio": 0.5})),
+                failed_trial_count: window_index,
+                error: (window_index == 1).then(|| "no in-sample trial".to_string()),
+            };
+            BacktestWalkForwardMutation::create_backtest_walk_forward_window(&db, walk_forward.id, new_window)
+                .await
+                .unwrap();
+        }
+
+        // Played windows are visible while the analysis is running
+        let detail = BacktestWalkForwardQuery::get_backtest_walk_forward_by_id(&db, walk_forward.id)
+            .await
+            .unwrap();
+        assert_eq!(
+            detail.windows.iter().map(|window| window.window_index).collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        assert!(detail.performance.is_none());
+
+        BacktestWalkForwardMutation::finish_backtest_walk_forward(
+            &db,
+            walk_forward.id,
+            BacktestWalkForwardStatus::Completed,
+            Some(json!({"snapshots": []})),
+            Some(json!({"sharpeRatio": 0.5})),
+        )
+        .await
+        .unwrap();
+        let detail = BacktestWalkForwardQuery::get_backtest_walk_forward_by_id(&db, walk_forward.id)
+            .await
+            .unwrap();
+        assert_eq!(detail.info.status, "completed");
+        assert!(detail.info.finished_time.is_some());
+        assert_eq!(detail.performance, Some(json!({"sharpeRatio": 0.5})));
+
+        BacktestWalkForwardMutation::delete_backtest_walk_forward(&db, walk_forward.id)
+            .await
+            .unwrap();
+        assert!(
+            BacktestWalkForwardQuery::get_backtest_walk_forward_by_id(&db, walk_forward.id)
+                .await
+                .is_err()
+        );
+        assert_eq!(backtest_walk_forward_window::Entity::find().count(&db).await.unwrap(), 0);
+    }
+}
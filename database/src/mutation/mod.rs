@@ -1,5 +1,9 @@
 pub mod account_config_mutation;
 pub mod account_info_mutation;
+pub mod backtest_checkpoint_mutation;
+pub mod backtest_optimization_mutation;
+pub mod backtest_run_mutation;
+pub mod backtest_walk_forward_mutation;
 pub mod order_mutation;
 pub mod position_mutation;
 pub mod strategy_config_mutation;
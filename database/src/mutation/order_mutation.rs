@@ -35,6 +35,7 @@ impl OrderMutation {
                     extra_info: Set(original_order.get_extra_info()),
                     created_time: Set(original_order.get_created_time().to_utc()),
                     updated_time: Set(original_order.get_updated_time().to_utc()),
+                    backtest_run_id: NotSet,
                 }
                 .insert(db)
                 .await
@@ -36,6 +36,7 @@ impl PositionMutation {
                     extra_info: Set(extra_info),
                     created_time: Set(exchange_position.get_create_time().to_utc()),
                     updated_time: Set(exchange_position.get_update_time().to_utc()),
+                    backtest_run_id: NotSet,
                 }
                 .insert(db)
                 .await?;
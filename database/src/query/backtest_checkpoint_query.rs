@@ -0,0 +1,87 @@
+use ::entity::{backtest_checkpoint, backtest_checkpoint::Entity as BacktestCheckpointEntity};
+use sea_orm::*;
+use star_river_core::{backtest_checkpoint::BacktestCheckpointInfo, system::DateTimeUtc};
+
+use crate::{error::DatabaseError, page::PageResult};
+
+pub struct BacktestCheckpointQuery;
+
+impl BacktestCheckpointQuery {
+    // Get paginated checkpoint list of a strategy, newest first
+    pub async fn get_backtest_checkpoint_list_in_page(
+        db: &DbConn,
+        strategy_id: i32,
+        page: u64,
+        items_per_page: u64,
+    ) -> Result<PageResult<BacktestCheckpointInfo>, DatabaseError> {
+        // The state column can be large, only the summary columns are read
+        let paginator = BacktestCheckpointEntity::find()
+            .select_only()
+            .columns([
+                backtest_checkpoint::Column::Id,
+                backtest_checkpoint::Column::StrategyId,
+                backtest_checkpoint::Column::StrategyName,
+                backtest_checkpoint::Column::PlayIndex,
+                backtest_checkpoint::Column::StrategyDatetime,
+                backtest_checkpoint::Column::CreatedTime,
+            ])
+            .filter(backtest_checkpoint::Column::StrategyId.eq(strategy_id))
+            .order_by_desc(backtest_checkpoint::Column::CreatedTime)
+            .order_by_desc(backtest_checkpoint::Column::Id)
+            .into_model::<BacktestCheckpointSummary>()
+            .paginate(db, items_per_page);
+
+        let total_items = paginator.num_items().await?;
+        let total_pages = total_items.div_ceil(items_per_page);
+
+        let summaries = paginator.fetch_page(page - 1).await?;
+        let checkpoints = summaries
+            .into_iter()
+            .map(|summary| BacktestCheckpointInfo {
+                id: summary.id,
+                strategy_id: summary.strategy_id,
+                strategy_name: summary.strategy_name,
+                play_index: summary.play_index,
+                strategy_datetime: summary.strategy_datetime,
+                created_time: summary.created_time,
+            })
+            .collect::<Vec<BacktestCheckpointInfo>>();
+
+        Ok(PageResult::new(checkpoints, total_items, page, items_per_page, total_pages))
+    }
+
+    // The checkpoint with its saved strategy state
+    pub async fn get_backtest_checkpoint_by_id(
+        db: &DbConn,
+        checkpoint_id: i32,
+    ) -> Result<(BacktestCheckpointInfo, JsonValue), DatabaseError> {
+        let mut checkpoint_model = BacktestCheckpointEntity::find_by_id(checkpoint_id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Cannot find backtest checkpoint.".to_owned()))?;
+
+        let checkpoint = std::mem::take(&mut checkpoint_model.checkpoint);
+        Ok((Self::model_to_info(checkpoint_model), checkpoint))
+    }
+
+    pub(crate) fn model_to_info(model: backtest_checkpoint::Model) -> BacktestCheckpointInfo {
+        BacktestCheckpointInfo {
+            id: model.id,
+            strategy_id: model.strategy_id,
+            strategy_name: model.strategy_name,
+            play_index: model.play_index,
+            strategy_datetime: model.strategy_datetime,
+            created_time: model.created_time,
+        }
+    }
+}
+
+#[derive(Debug, FromQueryResult)]
+struct BacktestCheckpointSummary {
+    id: i32,
+    strategy_id: i32,
+    strategy_name: String,
+    play_index: i64,
+    strategy_datetime: DateTimeUtc,
+    created_time: DateTimeUtc,
+}
@@ -0,0 +1,87 @@
+use ::entity::{backtest_optimization, backtest_optimization::Entity as BacktestOptimizationEntity, backtest_optimization_trial};
+use sea_orm::*;
+use star_river_core::backtest_optimization::{BacktestOptimizationDetail, BacktestOptimizationInfo, BacktestOptimizationTrialInfo};
+
+use crate::{error::DatabaseError, page::PageResult};
+
+pub struct BacktestOptimizationQuery;
+
+impl BacktestOptimizationQuery {
+    // Get paginated optimization list of a strategy, newest first
+    pub async fn get_backtest_optimization_list_in_page(
+        db: &DbConn,
+        strategy_id: i32,
+        page: u64,
+        items_per_page: u64,
+    ) -> Result<PageResult<BacktestOptimizationInfo>, DatabaseError> {
+        let paginator = BacktestOptimizationEntity::find()
+            .filter(backtest_optimization::Column::StrategyId.eq(strategy_id))
+            .order_by_desc(backtest_optimization::Column::CreatedTime)
+            .order_by_desc(backtest_optimization::Column::Id)
+            .paginate(db, items_per_page);
+
+        let total_items = paginator.num_items().await?;
+        let total_pages = total_items.div_ceil(items_per_page);
+
+        let models = paginator.fetch_page(page - 1).await?;
+        let optimizations = models
+            .into_iter()
+            .map(Self::model_to_info)
+            .collect::<Vec<BacktestOptimizationInfo>>();
+
+        Ok(PageResult::new(optimizations, total_items, page, items_per_page, total_pages))
+    }
+
+    // Trials are ranked by score, failed trials (no score) come last
+    pub async fn get_backtest_optimization_by_id(db: &DbConn, optimization_id: i32) -> Result<BacktestOptimizationDetail, DatabaseError> {
+        let optimization_model = BacktestOptimizationEntity::find_by_id(optimization_id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Cannot find backtest optimization.".to_owned()))?;
+
+        let trial_models = optimization_model
+            .find_related(backtest_optimization_trial::Entity)
+            .order_by_desc(backtest_optimization_trial::Column::Score)
+            .order_by_asc(backtest_optimization_trial::Column::Id)
+            .all(db)
+            .await?;
+
+        let mut trials = trial_models
+            .into_iter()
+            .map(|model| BacktestOptimizationTrialInfo {
+                id: model.id,
+                rank: None,
+                parameters: model.parameters,
+                score: model.score,
+                performance: model.performance,
+                error: model.error,
+                created_time: model.created_time,
+            })
+            .collect::<Vec<BacktestOptimizationTrialInfo>>();
+        // Do not rely on how the database orders NULL
+        trials.sort_by_key(|trial| trial.score.is_none());
+        for (index, trial) in trials.iter_mut().filter(|trial| trial.score.is_some()).enumerate() {
+            trial.rank = Some(index as u32 + 1);
+        }
+
+        let optimization_config = optimization_model.optimization_config.clone();
+        Ok(BacktestOptimizationDetail {
+            info: Self::model_to_info(optimization_model),
+            optimization_config,
+            trials,
+        })
+    }
+
+    pub(crate) fn model_to_info(model: backtest_optimization::Model) -> BacktestOptimizationInfo {
+        BacktestOptimizationInfo {
+            id: model.id,
+            strategy_id: model.strategy_id,
+            strategy_name: model.strategy_name,
+            metric: model.metric,
+            status: model.status,
+            total_trials: model.total_trials,
+            created_time: model.created_time,
+            finished_time: model.finished_time,
+        }
+    }
+}
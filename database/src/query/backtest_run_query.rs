@@ -0,0 +1,107 @@
+use ::entity::{backtest_run, backtest_run::Entity as BacktestRunEntity, order, position, strategy_statistics, transaction};
+use sea_orm::*;
+use star_river_core::backtest_run::{BacktestRunDetail, BacktestRunEquity, BacktestRunInfo};
+
+use crate::{error::DatabaseError, page::PageResult};
+
+pub struct BacktestRunQuery;
+
+impl BacktestRunQuery {
+    // Get paginated backtest run list of a strategy, newest first
+    pub async fn get_backtest_run_list_in_page(
+        db: &DbConn,
+        strategy_id: i32,
+        page: u64,
+        items_per_page: u64,
+    ) -> Result<PageResult<BacktestRunInfo>, DatabaseError> {
+        let paginator = BacktestRunEntity::find()
+            .filter(backtest_run::Column::StrategyId.eq(strategy_id))
+            .order_by_desc(backtest_run::Column::CreatedTime)
+            .order_by_desc(backtest_run::Column::Id)
+            .paginate(db, items_per_page);
+
+        let total_items = paginator.num_items().await?;
+        let total_pages = total_items.div_ceil(items_per_page);
+
+        let models = paginator.fetch_page(page - 1).await?;
+        let backtest_runs = models.into_iter().map(Self::model_to_info).collect::<Vec<BacktestRunInfo>>();
+
+        Ok(PageResult::new(backtest_runs, total_items, page, items_per_page, total_pages))
+    }
+
+    pub async fn get_backtest_run_by_id(db: &DbConn, backtest_run_id: i32) -> Result<BacktestRunDetail, DatabaseError> {
+        let backtest_run_model = BacktestRunEntity::find_by_id(backtest_run_id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Cannot find backtest run.".to_owned()))?;
+
+        // The full serialized records are kept in extra_info
+        let orders = backtest_run_model
+            .find_related(order::Entity)
+            .order_by_asc(order::Column::Id)
+            .all(db)
+            .await?
+            .into_iter()
+            .filter_map(|m| m.extra_info)
+            .collect();
+        let positions = backtest_run_model
+            .find_related(position::Entity)
+            .order_by_asc(position::Column::Id)
+            .all(db)
+            .await?
+            .into_iter()
+            .filter_map(|m| m.extra_info)
+            .collect();
+        let transactions = backtest_run_model
+            .find_related(transaction::Entity)
+            .order_by_asc(transaction::Column::Id)
+            .all(db)
+            .await?
+            .into_iter()
+            .filter_map(|m| m.extra_info)
+            .collect();
+        let equity_curve = backtest_run_model
+            .find_related(strategy_statistics::Entity)
+            .order_by_asc(strategy_statistics::Column::Datetime)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|m| BacktestRunEquity {
+                datetime: m.datetime,
+                balance: m.balance,
+                available_balance: m.available_balance,
+                unrealized_pnl: m.unrealized_pnl,
+                equity: m.equity,
+                cumulative_return: m.cumulative_return,
+                realized_pnl: m.realized_pnl,
+                total_fee: m.total_fee,
+                total_funding: m.total_funding,
+            })
+            .collect();
+
+        let config_snapshot = backtest_run_model.config_snapshot.clone();
+        Ok(BacktestRunDetail {
+            info: Self::model_to_info(backtest_run_model),
+            config_snapshot,
+            orders,
+            positions,
+            transactions,
+            equity_curve,
+        })
+    }
+
+    pub(crate) fn model_to_info(model: backtest_run::Model) -> BacktestRunInfo {
+        BacktestRunInfo {
+            id: model.id,
+            strategy_id: model.strategy_id,
+            strategy_name: model.strategy_name,
+            start_time: model.start_time,
+            end_time: model.end_time,
+            initial_balance: model.initial_balance,
+            fee_rate: model.fee_rate,
+            leverage: model.leverage,
+            status: model.status,
+            created_time: model.created_time,
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use ::entity::{backtest_walk_forward, backtest_walk_forward::Entity as BacktestWalkForwardEntity, backtest_walk_forward_window};
+use sea_orm::*;
+use star_river_core::backtest_walk_forward::{BacktestWalkForwardDetail, BacktestWalkForwardInfo, BacktestWalkForwardWindowInfo};
+
+use crate::{error::DatabaseError, page::PageResult};
+
+pub struct BacktestWalkForwardQuery;
+
+impl BacktestWalkForwardQuery {
+    // Get paginated walk-forward list of a strategy, newest first
+    pub async fn get_backtest_walk_forward_list_in_page(
+        db: &DbConn,
+        strategy_id: i32,
+        page: u64,
+        items_per_page: u64,
+    ) -> Result<PageResult<BacktestWalkForwardInfo>, DatabaseError> {
+        let paginator = BacktestWalkForwardEntity::find()
+            .filter(backtest_walk_forward::Column::StrategyId.eq(strategy_id))
+            .order_by_desc(backtest_walk_forward::Column::CreatedTime)
+            .order_by_desc(backtest_walk_forward::Column::Id)
+            .paginate(db, items_per_page);
+
+        let total_items = paginator.num_items().await?;
+        let total_pages = total_items.div_ceil(items_per_page);
+
+        let models = paginator.fetch_page(page - 1).await?;
+        let walk_forwards = models
+            .into_iter()
+            .map(Self::model_to_info)
+            .collect::<Vec<BacktestWalkForwardInfo>>();
+
+        Ok(PageResult::new(walk_forwards, total_items, page, items_per_page, total_pages))
+    }
+
+    // Windows are returned in time order, a running analysis only has the windows played so far
+    pub async fn get_backtest_walk_forward_by_id(db: &DbConn, walk_forward_id: i32) -> Result<BacktestWalkForwardDetail, DatabaseError> {
+        let walk_forward_model = BacktestWalkForwardEntity::find_by_id(walk_forward_id)
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Cannot find backtest walk-forward.".to_owned()))?;
+
+        let windows = walk_forward_model
+            .find_related(backtest_walk_forward_window::Entity)
+            .order_by_asc(backtest_walk_forward_window::Column::WindowIndex)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|model| BacktestWalkForwardWindowInfo {
+                id: model.id,
+                window_index: model.window_index,
+                in_sample_start: model.in_sample_start,
+                in_sample_end: model.in_sample_end,
+                out_of_sample_start: model.out_of_sample_start,
+                out_of_sample_end: model.out_of_sample_end,
+                best_parameters: model.best_parameters,
+                in_sample_score: model.in_sample_score,
+                out_of_sample_score: model.out_of_sample_score,
+                out_of_sample_performance: model.out_of_sample_performance,
+                failed_trial_count: model.failed_trial_count,
+                error: model.error,
+                created_time: model.created_time,
+            })
+            .collect::<Vec<BacktestWalkForwardWindowInfo>>();
+
+        let walk_forward_config = walk_forward_model.walk_forward_config.clone();
+        let equity_curve = walk_forward_model.equity_curve.clone();
+        let performance = walk_forward_model.performance.clone();
+        Ok(BacktestWalkForwardDetail {
+            info: Self::model_to_info(walk_forward_model),
+            walk_forward_config,
+            windows,
+            equity_curve,
+            performance,
+        })
+    }
+
+    pub(crate) fn model_to_info(model: backtest_walk_forward::Model) -> BacktestWalkForwardInfo {
+        BacktestWalkForwardInfo {
+            id: model.id,
+            strategy_id: model.strategy_id,
+            strategy_name: model.strategy_name,
+            metric: model.metric,
+            window_mode: model.window_mode,
+            status: model.status,
+            total_windows: model.total_windows,
+            created_time: model.created_time,
+            finished_time: model.finished_time,
+        }
+    }
+}
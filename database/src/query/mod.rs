@@ -1,4 +1,8 @@
 pub mod account_config_query;
+pub mod backtest_checkpoint_query;
+pub mod backtest_optimization_query;
+pub mod backtest_run_query;
+pub mod backtest_walk_forward_query;
 pub mod position_query;
 pub mod strategy_config_query;
 // pub mod strategy_sys_variable_query;
@@ -1,8 +1,14 @@
+pub mod backtest_checkpoint;
+pub mod backtest_optimization;
+pub mod backtest_run;
 pub mod chart_config;
 pub mod data_query;
 pub mod playback_control;
 
 // Re-export all public functions
+pub use backtest_checkpoint::*;
+pub use backtest_optimization::*;
+pub use backtest_run::*;
 pub use chart_config::*;
 pub use data_query::*;
 pub use playback_control::*;
@@ -0,0 +1,213 @@
+use axum::{
+    extract::{Json, Path, Query, State},
+    http::StatusCode,
+};
+use backtest_engine::engine_error::BacktestEngineError;
+use database::{
+    mutation::backtest_checkpoint_mutation::BacktestCheckpointMutation, page::PageResult,
+    query::backtest_checkpoint_query::BacktestCheckpointQuery,
+};
+use engine_core::EngineContextAccessor;
+use serde::{Deserialize, Serialize};
+use snafu::Report;
+use star_river_core::{backtest_checkpoint::BacktestCheckpointInfo, error::StarRiverErrorTrait};
+use tracing::instrument;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    api::response::ApiResponseEnum,
+    error::{PageMustGreaterThanOneSnafu, TooManyItemsPerPageSnafu},
+    star_river::StarRiver,
+};
+
+#[derive(Debug, Serialize, Deserialize, IntoParams, ToSchema)]
+#[schema(
+    title = "Get backtest checkpoint list query",
+    description = "Get backtest checkpoint list query",
+    example = json!({
+        "page": 1,
+        "items_per_page": 10
+    })
+)]
+pub struct GetBacktestCheckpointListQuery {
+    /// Page number (starts from 1)
+    #[schema(example = 1, minimum = 1, default = 1)]
+    pub page: u64,
+    /// Number of checkpoints per page
+    #[schema(example = 10, minimum = 1, maximum = 100, default = 10)]
+    pub items_per_page: u64,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategy/backtest/{strategy_id}/checkpoints",
+    tag = "Backtest Strategy",
+    summary = "Save the paused playback state of a strategy",
+    params(
+        ("strategy_id" = i32, Path, description = "The ID of the strategy")
+    ),
+    responses(
+        (status = 200, description = "Checkpoint saved", body = ApiResponseEnum<BacktestCheckpointInfo>),
+        (status = 409, description = "Strategy is playing or nothing has been played", body = ApiResponseEnum<BacktestCheckpointInfo>)
+    )
+)]
+#[instrument(skip(star_river))]
+pub async fn save_backtest_checkpoint(
+    State(star_river): State<StarRiver>,
+    Path(strategy_id): Path<i32>,
+) -> (StatusCode, Json<ApiResponseEnum<BacktestCheckpointInfo>>) {
+    let engine_manager = star_river.engine_manager.lock().await;
+    let engine = engine_manager.backtest_engine().await;
+    let engine_guard = engine.lock().await;
+
+    let result: Result<BacktestCheckpointInfo, BacktestEngineError> = engine_guard
+        .with_ctx_read_async(|ctx| {
+            Box::pin(async move {
+                let checkpoint = ctx
+                    .with_strategy_ctx_read_async(strategy_id, move |ctx| Box::pin(async move { ctx.save_checkpoint().await }))
+                    .await?
+                    .map_err(BacktestEngineError::from)?;
+                Ok(checkpoint)
+            })
+        })
+        .await;
+
+    match result {
+        Ok(checkpoint) => {
+            tracing::info!("save checkpoint {} of strategy {} successfully", checkpoint.id, strategy_id);
+            (StatusCode::OK, Json(ApiResponseEnum::success(checkpoint)))
+        }
+        Err(e) => {
+            let report = Report::from_error(&e);
+            tracing::error!("save checkpoint of strategy {} failed: {}", strategy_id, report);
+            (e.http_status_code(), Json(ApiResponseEnum::error(e)))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategy/backtest/{strategy_id}/checkpoints/{checkpoint_id}/load",
+    tag = "Backtest Strategy",
+    summary = "Restore a strategy to a saved checkpoint, the playback stays paused on it",
+    params(
+        ("strategy_id" = i32, Path, description = "The ID of the strategy"),
+        ("checkpoint_id" = i32, Path, description = "The ID of the checkpoint to load")
+    ),
+    responses(
+        (status = 200, description = "Checkpoint loaded", body = ApiResponseEnum<BacktestCheckpointInfo>),
+        (status = 409, description = "Strategy is playing or the checkpoint does not match the strategy", body = ApiResponseEnum<BacktestCheckpointInfo>)
+    )
+)]
+#[instrument(skip(star_river))]
+pub async fn load_backtest_checkpoint(
+    State(star_river): State<StarRiver>,
+    Path((strategy_id, checkpoint_id)): Path<(i32, i32)>,
+) -> (StatusCode, Json<ApiResponseEnum<BacktestCheckpointInfo>>) {
+    let engine_manager = star_river.engine_manager.lock().await;
+    let engine = engine_manager.backtest_engine().await;
+    let engine_guard = engine.lock().await;
+
+    let result: Result<BacktestCheckpointInfo, BacktestEngineError> = engine_guard
+        .with_ctx_write_async(|ctx| {
+            Box::pin(async move {
+                let checkpoint = ctx
+                    .with_strategy_ctx_write_async(strategy_id, move |ctx| {
+                        Box::pin(async move { ctx.load_checkpoint(checkpoint_id).await })
+                    })
+                    .await?
+                    .map_err(BacktestEngineError::from)?;
+                Ok(checkpoint)
+            })
+        })
+        .await;
+
+    match result {
+        Ok(checkpoint) => {
+            tracing::info!("load checkpoint {} into strategy {} successfully", checkpoint_id, strategy_id);
+            (StatusCode::OK, Json(ApiResponseEnum::success(checkpoint)))
+        }
+        Err(e) => {
+            let report = Report::from_error(&e);
+            tracing::error!("load checkpoint {} into strategy {} failed: {}", checkpoint_id, strategy_id, report);
+            (e.http_status_code(), Json(ApiResponseEnum::error(e)))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategy/backtest/{strategy_id}/checkpoints",
+    tag = "Backtest Strategy",
+    summary = "Get saved checkpoints of a strategy",
+    params(
+        ("strategy_id" = i32, Path, description = "The ID of the strategy"),
+        GetBacktestCheckpointListQuery
+    ),
+    responses(
+        (status = 200, description = "Success", body = ApiResponseEnum<PageResult<BacktestCheckpointInfo>>),
+        (status = 400, description = "Bad Request", body = ApiResponseEnum<PageResult<BacktestCheckpointInfo>>)
+    )
+)]
+#[instrument(skip(star_river))]
+pub async fn get_backtest_checkpoint_list(
+    State(star_river): State<StarRiver>,
+    Path(strategy_id): Path<i32>,
+    Query(params): Query<GetBacktestCheckpointListQuery>,
+) -> (StatusCode, Json<ApiResponseEnum<PageResult<BacktestCheckpointInfo>>>) {
+    if params.page == 0 {
+        let error = PageMustGreaterThanOneSnafu { page: params.page }.build();
+        return (error.http_status_code(), Json(ApiResponseEnum::error(error)));
+    }
+    if params.items_per_page == 0 || params.items_per_page > 100 {
+        let error = TooManyItemsPerPageSnafu {
+            items_per_page: params.items_per_page,
+        }
+        .build();
+        return (error.http_status_code(), Json(ApiResponseEnum::error(error)));
+    }
+
+    let db = &star_river.database.lock().await.conn;
+    match BacktestCheckpointQuery::get_backtest_checkpoint_list_in_page(db, strategy_id, params.page, params.items_per_page).await {
+        Ok(page_result) => {
+            tracing::debug!("get checkpoint list of strategy {} successfully", strategy_id);
+            (StatusCode::OK, Json(ApiResponseEnum::success(page_result)))
+        }
+        Err(e) => {
+            let report = Report::from_error(&e);
+            tracing::error!("get checkpoint list of strategy {} failed: {}", strategy_id, report);
+            (e.http_status_code(), Json(ApiResponseEnum::error(e)))
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/strategy/backtest/checkpoints/{checkpoint_id}",
+    tag = "Backtest Strategy",
+    summary = "Delete backtest checkpoint",
+    params(
+        ("checkpoint_id" = i32, Path, description = "The ID of the checkpoint to delete")
+    ),
+    responses(
+        (status = 200, description = "Checkpoint deleted successfully", content_type = "application/json"),
+        (status = 400, description = "Checkpoint deletion failed", content_type = "application/json")
+    )
+)]
+pub async fn delete_backtest_checkpoint(
+    State(star_river): State<StarRiver>,
+    Path(checkpoint_id): Path<i32>,
+) -> (StatusCode, Json<ApiResponseEnum<()>>) {
+    let db = &star_river.database.lock().await.conn;
+    match BacktestCheckpointMutation::delete_backtest_checkpoint(db, checkpoint_id).await {
+        Ok(_) => {
+            tracing::info!("Delete backtest checkpoint {checkpoint_id} successfully");
+            (StatusCode::OK, Json(ApiResponseEnum::success(())))
+        }
+        Err(e) => {
+            let report = Report::from_error(&e);
+            tracing::error!("{report}");
+            (e.http_status_code(), Json(ApiResponseEnum::error(e)))
+        }
+    }
+}
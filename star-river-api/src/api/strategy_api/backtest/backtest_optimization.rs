@@ -0,0 +1,344 @@
+use axum::{
+    extract::{Json, Path, Query, State},
+    http::StatusCode,
+};
+use backtest_engine::optimizer::{OptimizationConfig, WalkForwardConfig};
+use database::{
+    mutation::{backtest_optimization_mutation::BacktestOptimizationMutation, backtest_walk_forward_mutation::BacktestWalkForwardMutation},
+    page::PageResult,
+    query::{backtest_optimization_query::BacktestOptimizationQuery, backtest_walk_forward_query::BacktestWalkForwardQuery},
+};
+use engine_core::EngineContextAccessor;
+use serde::{Deserialize, Serialize};
+use snafu::Report;
+use star_river_core::{
+    backtest_optimization::{BacktestOptimizationDetail, BacktestOptimizationInfo},
+    backtest_walk_forward::{BacktestWalkForwardDetail, BacktestWalkForwardInfo},
+    error::StarRiverErrorTrait,
+};
+use tracing::instrument;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    api::response::ApiResponseEnum,
+    error::{PageMustGreaterThanOneSnafu, TooManyItemsPerPageSnafu},
+    star_river::StarRiver,
+};
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategy/backtest/{strategy_id}/optimizations",
+    tag = "Backtest Strategy",
+    summary = "Start a parameter optimization of a strategy",
+    params(
+        ("strategy_id" = i32, Path, description = "The ID of the strategy to optimize")
+    ),
+    request_body = OptimizationConfig,
+    responses(
+        (status = 200, description = "Optimization started", body = ApiResponseEnum<BacktestOptimizationInfo>),
+        (status = 400, description = "Invalid parameter ranges", body = ApiResponseEnum<BacktestOptimizationInfo>)
+    )
+)]
+#[instrument(skip(star_river))]
+pub async fn start_backtest_optimization(
+    State(star_river): State<StarRiver>,
+    Path(strategy_id): Path<i32>,
+    Json(optimization_config): Json<OptimizationConfig>,
+) -> (StatusCode, Json<ApiResponseEnum<BacktestOptimizationInfo>>) {
+    // Trials run on their own strategy instances, the engine is only needed to start them
+    let engine_context = {
+        let engine_manager = star_river.engine_manager.lock().await;
+        let engine = engine_manager.backtest_engine().await;
+        let engine_guard = engine.lock().await;
+        engine_guard.with_ctx_read(|ctx| ctx.clone()).await
+    };
+
+    match engine_context.start_optimization(strategy_id, optimization_config).await {
+        Ok(optimization) => {
+            tracing::info!("start optimization {} of strategy {} successfully", optimization.id, strategy_id);
+            (StatusCode::OK, Json(ApiResponseEnum::success(optimization)))
+        }
+        Err(e) => {
+            let report = Report::from_error(&e);
+            tracing::error!("start optimization of strategy {} failed: {}", strategy_id, report);
+            (e.http_status_code(), Json(ApiResponseEnum::error(e)))
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategy/backtest/{strategy_id}/walk-forwards",
+    tag = "Backtest Strategy",
+    summary = "Start walk-forward analysis of a strategy",
+    params(
+        ("strategy_id" = i32, Path, description = "The ID of the strategy to analyse")
+    ),
+    request_body = WalkForwardConfig,
+    responses(
+        (status = 200, description = "Walk-forward analysis started", body = ApiResponseEnum<BacktestWalkForwardInfo>),
+        (status = 400, description = "Invalid parameter ranges or windows", body = ApiResponseEnum<BacktestWalkForwardInfo>)
+    )
+)]
+#[instrument(skip(star_river))]
+pub async fn start_walk_forward(
+    State(star_river): State<StarRiver>,
+    Path(strategy_id): Path<i32>,
+    Json(walk_forward_config): Json<WalkForwardConfig>,
+) -> (StatusCode, Json<ApiResponseEnum<BacktestWalkForwardInfo>>) {
+    // Windows run on their own strategy instances, the engine is only needed to start them
+    let engine_context = {
+        let engine_manager = star_river.engine_manager.lock().await;
+        let engine = engine_manager.backtest_engine().await;
+        let engine_guard = engine.lock().await;
+        engine_guard.with_ctx_read(|ctx| ctx.clone()).await
+    };
+
+    match engine_context.start_walk_forward(strategy_id, walk_forward_config).await {
+        Ok(walk_forward) => {
+            tracing::info!("start walk-forward {} of strategy {} successfully", walk_forward.id, strategy_id);
+            (StatusCode::OK, Json(ApiResponseEnum::success(walk_forward)))
+        }
+        Err(e) => {
+            let report = Report::from_error(&e);
+            tracing::error!("start walk-forward of strategy {} failed: {}", strategy_id, report);
+            (e.http_status_code(), Json(ApiResponseEnum::error(e)))
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, IntoParams, ToSchema)]
+#[schema(
+    title = "Get backtest optimization list query",
+    description = "Get backtest optimization list query",
+    example = json!({
+        "page": 1,
+        "items_per_page": 10
+    })
+)]
+pub struct GetBacktestOptimizationListQuery {
+    /// Page number (starts from 1)
+    #[schema(example = 1, minimum = 1, default = 1)]
+    pub page: u64,
+    /// Number of optimizations per page
+    #[schema(example = 10, minimum = 1, maximum = 100, default = 10)]
+    pub items_per_page: u64,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategy/backtest/{strategy_id}/optimizations",
+    tag = "Backtest Strategy",
+    summary = "Get parameter optimization history of a strategy",
+    params(
+        ("strategy_id" = i32, Path, description = "The ID of the strategy"),
+        GetBacktestOptimizationListQuery
+    ),
+    responses(
+        (status = 200, description = "Success", body = ApiResponseEnum<PageResult<BacktestOptimizationInfo>>),
+        (status = 400, description = "Bad Request", body = ApiResponseEnum<PageResult<BacktestOptimizationInfo>>)
+    )
+)]
+#[instrument(skip(star_river))]
+pub async fn get_backtest_optimization_list(
+    State(star_river): State<StarRiver>,
+    Path(strategy_id): Path<i32>,
+    Query(params): Query<GetBacktestOptimizationListQuery>,
+) -> (StatusCode, Json<ApiResponseEnum<PageResult<BacktestOptimizationInfo>>>) {
+    if params.page == 0 {
+        let error = PageMustGreaterThanOneSnafu { page: params.page }.build();
+        return (error.http_status_code(), Json(ApiResponseEnum::error(error)));
+    }
+    if params.items_per_page == 0 || params.items_per_page > 100 {
+        let error = TooManyItemsPerPageSnafu {
+            items_per_page: params.items_per_page,
+        }
+        .build();
+        return (error.http_status_code(), Json(ApiResponseEnum::error(error)));
+    }
+
+    let db = &star_river.database.lock().await.conn;
+    match BacktestOptimizationQuery::get_backtest_optimization_list_in_page(db, strategy_id, params.page, params.items_per_page).await {
+        Ok(page_result) => {
+            tracing::debug!("get optimization list of strategy {} successfully", strategy_id);
+            (StatusCode::OK, Json(ApiResponseEnum::success(page_result)))
+        }
+        Err(e) => {
+            let report = Report::from_error(&e);
+            tracing::error!("get optimization list of strategy {} failed: {}", strategy_id, report);
+            (e.http_status_code(), Json(ApiResponseEnum::error(e)))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategy/backtest/optimizations/{optimization_id}",
+    tag = "Backtest Strategy",
+    summary = "Get parameter optimization detail with ranked trials",
+    params(
+        ("optimization_id" = i32, Path, description = "The ID of the optimization")
+    ),
+    responses(
+        (status = 200, description = "Success", body = ApiResponseEnum<BacktestOptimizationDetail>),
+        (status = 400, description = "Bad Request", body = ApiResponseEnum<BacktestOptimizationDetail>)
+    )
+)]
+#[instrument(skip(star_river))]
+pub async fn get_backtest_optimization(
+    State(star_river): State<StarRiver>,
+    Path(optimization_id): Path<i32>,
+) -> (StatusCode, Json<ApiResponseEnum<BacktestOptimizationDetail>>) {
+    let db = &star_river.database.lock().await.conn;
+    match BacktestOptimizationQuery::get_backtest_optimization_by_id(db, optimization_id).await {
+        Ok(optimization) => {
+            tracing::debug!("get optimization {} successfully", optimization_id);
+            (StatusCode::OK, Json(ApiResponseEnum::success(optimization)))
+        }
+        Err(e) => {
+            let report = Report::from_error(&e);
+            tracing::error!("get optimization {} failed: {}", optimization_id, report);
+            (e.http_status_code(), Json(ApiResponseEnum::error(e)))
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/strategy/backtest/optimizations/{optimization_id}",
+    tag = "Backtest Strategy",
+    summary = "Delete parameter optimization",
+    params(
+        ("optimization_id" = i32, Path, description = "The ID of the optimization to delete")
+    ),
+    responses(
+        (status = 200, description = "Optimization deleted successfully", content_type = "application/json"),
+        (status = 400, description = "Optimization deletion failed", content_type = "application/json")
+    )
+)]
+pub async fn delete_backtest_optimization(
+    State(star_river): State<StarRiver>,
+    Path(optimization_id): Path<i32>,
+) -> (StatusCode, Json<ApiResponseEnum<()>>) {
+    let db = &star_river.database.lock().await.conn;
+    match BacktestOptimizationMutation::delete_backtest_optimization(db, optimization_id).await {
+        Ok(_) => {
+            tracing::info!("Delete optimization {optimization_id} successfully");
+            (StatusCode::OK, Json(ApiResponseEnum::success(())))
+        }
+        Err(e) => {
+            let report = Report::from_error(&e);
+            tracing::error!("{report}");
+            (e.http_status_code(), Json(ApiResponseEnum::error(e)))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategy/backtest/{strategy_id}/walk-forwards",
+    tag = "Backtest Strategy",
+    summary = "Get walk-forward analysis history of a strategy",
+    params(
+        ("strategy_id" = i32, Path, description = "The ID of the strategy"),
+        GetBacktestOptimizationListQuery
+    ),
+    responses(
+        (status = 200, description = "Success", body = ApiResponseEnum<PageResult<BacktestWalkForwardInfo>>),
+        (status = 400, description = "Bad Request", body = ApiResponseEnum<PageResult<BacktestWalkForwardInfo>>)
+    )
+)]
+#[instrument(skip(star_river))]
+pub async fn get_walk_forward_list(
+    State(star_river): State<StarRiver>,
+    Path(strategy_id): Path<i32>,
+    Query(params): Query<GetBacktestOptimizationListQuery>,
+) -> (StatusCode, Json<ApiResponseEnum<PageResult<BacktestWalkForwardInfo>>>) {
+    if params.page == 0 {
+        let error = PageMustGreaterThanOneSnafu { page: params.page }.build();
+        return (error.http_status_code(), Json(ApiResponseEnum::error(error)));
+    }
+    if params.items_per_page == 0 || params.items_per_page > 100 {
+        let error = TooManyItemsPerPageSnafu {
+            items_per_page: params.items_per_page,
+        }
+        .build();
+        return (error.http_status_code(), Json(ApiResponseEnum::error(error)));
+    }
+
+    let db = &star_river.database.lock().await.conn;
+    match BacktestWalkForwardQuery::get_backtest_walk_forward_list_in_page(db, strategy_id, params.page, params.items_per_page).await {
+        Ok(page_result) => {
+            tracing::debug!("get walk-forward list of strategy {} successfully", strategy_id);
+            (StatusCode::OK, Json(ApiResponseEnum::success(page_result)))
+        }
+        Err(e) => {
+            let report = Report::from_error(&e);
+            tracing::error!("get walk-forward list of strategy {} failed: {}", strategy_id, report);
+            (e.http_status_code(), Json(ApiResponseEnum::error(e)))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategy/backtest/walk-forwards/{walk_forward_id}",
+    tag = "Backtest Strategy",
+    summary = "Get walk-forward analysis detail with its windows",
+    params(
+        ("walk_forward_id" = i32, Path, description = "The ID of the walk-forward analysis")
+    ),
+    responses(
+        (status = 200, description = "Success", body = ApiResponseEnum<BacktestWalkForwardDetail>),
+        (status = 400, description = "Bad Request", body = ApiResponseEnum<BacktestWalkForwardDetail>)
+    )
+)]
+#[instrument(skip(star_river))]
+pub async fn get_walk_forward(
+    State(star_river): State<StarRiver>,
+    Path(walk_forward_id): Path<i32>,
+) -> (StatusCode, Json<ApiResponseEnum<BacktestWalkForwardDetail>>) {
+    let db = &star_river.database.lock().await.conn;
+    match BacktestWalkForwardQuery::get_backtest_walk_forward_by_id(db, walk_forward_id).await {
+        Ok(walk_forward) => {
+            tracing::debug!("get walk-forward {} successfully", walk_forward_id);
+            (StatusCode::OK, Json(ApiResponseEnum::success(walk_forward)))
+        }
+        Err(e) => {
+            let report = Report::from_error(&e);
+            tracing::error!("get walk-forward {} failed: {}", walk_forward_id, report);
+            (e.http_status_code(), Json(ApiResponseEnum::error(e)))
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/strategy/backtest/walk-forwards/{walk_forward_id}",
+    tag = "Backtest Strategy",
+    summary = "Delete walk-forward analysis",
+    params(
+        ("walk_forward_id" = i32, Path, description = "The ID of the walk-forward analysis to delete")
+    ),
+    responses(
+        (status = 200, description = "Walk-forward analysis deleted successfully", content_type = "application/json"),
+        (status = 400, description = "Walk-forward analysis deletion failed", content_type = "application/json")
+    )
+)]
+pub async fn delete_walk_forward(
+    State(star_river): State<StarRiver>,
+    Path(walk_forward_id): Path<i32>,
+) -> (StatusCode, Json<ApiResponseEnum<()>>) {
+    let db = &star_river.database.lock().await.conn;
+    match BacktestWalkForwardMutation::delete_backtest_walk_forward(db, walk_forward_id).await {
+        Ok(_) => {
+            tracing::info!("Delete walk-forward {walk_forward_id} successfully");
+            (StatusCode::OK, Json(ApiResponseEnum::success(())))
+        }
+        Err(e) => {
+            let report = Report::from_error(&e);
+            tracing::error!("{report}");
+            (e.http_status_code(), Json(ApiResponseEnum::error(e)))
+        }
+    }
+}
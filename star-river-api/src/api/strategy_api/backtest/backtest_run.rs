@@ -0,0 +1,143 @@
+use axum::{
+    extract::{Json, Path, Query, State},
+    http::StatusCode,
+};
+use database::{mutation::backtest_run_mutation::BacktestRunMutation, page::PageResult, query::backtest_run_query::BacktestRunQuery};
+use serde::{Deserialize, Serialize};
+use snafu::Report;
+use star_river_core::{
+    backtest_run::{BacktestRunDetail, BacktestRunInfo},
+    error::StarRiverErrorTrait,
+};
+use tracing::instrument;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    api::response::ApiResponseEnum,
+    error::{PageMustGreaterThanOneSnafu, TooManyItemsPerPageSnafu},
+    star_river::StarRiver,
+};
+
+#[derive(Debug, Serialize, Deserialize, IntoParams, ToSchema)]
+#[schema(
+    title = "Get backtest run list query",
+    description = "Get backtest run list query",
+    example = json!({
+        "page": 1,
+        "items_per_page": 10
+    })
+)]
+pub struct GetBacktestRunListQuery {
+    /// Page number (starts from 1)
+    #[schema(example = 1, minimum = 1, default = 1)]
+    pub page: u64,
+    /// Number of backtest runs per page
+    #[schema(example = 10, minimum = 1, maximum = 100, default = 10)]
+    pub items_per_page: u64,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategy/backtest/{strategy_id}/runs",
+    tag = "Backtest Strategy",
+    summary = "Get backtest run history of a strategy",
+    params(
+        ("strategy_id" = i32, Path, description = "The ID of the strategy"),
+        GetBacktestRunListQuery
+    ),
+    responses(
+        (status = 200, description = "Success", body = ApiResponseEnum<PageResult<BacktestRunInfo>>),
+        (status = 400, description = "Bad Request", body = ApiResponseEnum<PageResult<BacktestRunInfo>>)
+    )
+)]
+#[instrument(skip(star_river))]
+pub async fn get_backtest_run_list(
+    State(star_river): State<StarRiver>,
+    Path(strategy_id): Path<i32>,
+    Query(params): Query<GetBacktestRunListQuery>,
+) -> (StatusCode, Json<ApiResponseEnum<PageResult<BacktestRunInfo>>>) {
+    if params.page == 0 {
+        let error = PageMustGreaterThanOneSnafu { page: params.page }.build();
+        return (error.http_status_code(), Json(ApiResponseEnum::error(error)));
+    }
+    if params.items_per_page == 0 || params.items_per_page > 100 {
+        let error = TooManyItemsPerPageSnafu {
+            items_per_page: params.items_per_page,
+        }
+        .build();
+        return (error.http_status_code(), Json(ApiResponseEnum::error(error)));
+    }
+
+    let db = &star_river.database.lock().await.conn;
+    match BacktestRunQuery::get_backtest_run_list_in_page(db, strategy_id, params.page, params.items_per_page).await {
+        Ok(page_result) => {
+            tracing::debug!("get backtest run list of strategy {} successfully", strategy_id);
+            (StatusCode::OK, Json(ApiResponseEnum::success(page_result)))
+        }
+        Err(e) => {
+            let report = Report::from_error(&e);
+            tracing::error!("get backtest run list of strategy {} failed: {}", strategy_id, report);
+            (e.http_status_code(), Json(ApiResponseEnum::error(e)))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategy/backtest/runs/{run_id}",
+    tag = "Backtest Strategy",
+    summary = "Get backtest run detail with orders, positions, transactions and equity curve",
+    params(
+        ("run_id" = i32, Path, description = "The ID of the backtest run")
+    ),
+    responses(
+        (status = 200, description = "Success", body = ApiResponseEnum<BacktestRunDetail>),
+        (status = 400, description = "Bad Request", body = ApiResponseEnum<BacktestRunDetail>)
+    )
+)]
+#[instrument(skip(star_river))]
+pub async fn get_backtest_run(
+    State(star_river): State<StarRiver>,
+    Path(run_id): Path<i32>,
+) -> (StatusCode, Json<ApiResponseEnum<BacktestRunDetail>>) {
+    let db = &star_river.database.lock().await.conn;
+    match BacktestRunQuery::get_backtest_run_by_id(db, run_id).await {
+        Ok(backtest_run) => {
+            tracing::debug!("get backtest run {} successfully", run_id);
+            (StatusCode::OK, Json(ApiResponseEnum::success(backtest_run)))
+        }
+        Err(e) => {
+            let report = Report::from_error(&e);
+            tracing::error!("get backtest run {} failed: {}", run_id, report);
+            (e.http_status_code(), Json(ApiResponseEnum::error(e)))
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/strategy/backtest/runs/{run_id}",
+    tag = "Backtest Strategy",
+    summary = "Delete backtest run",
+    params(
+        ("run_id" = i32, Path, description = "The ID of the backtest run to delete")
+    ),
+    responses(
+        (status = 200, description = "Backtest run deleted successfully", content_type = "application/json"),
+        (status = 400, description = "Backtest run deletion failed", content_type = "application/json")
+    )
+)]
+pub async fn delete_backtest_run(State(star_river): State<StarRiver>, Path(run_id): Path<i32>) -> (StatusCode, Json<ApiResponseEnum<()>>) {
+    let db = &star_river.database.lock().await.conn;
+    match BacktestRunMutation::delete_backtest_run(db, run_id).await {
+        Ok(_) => {
+            tracing::info!("Delete backtest run {run_id} successfully");
+            (StatusCode::OK, Json(ApiResponseEnum::success(())))
+        }
+        Err(e) => {
+            let report = Report::from_error(&e);
+            tracing::error!("{report}");
+            (e.http_status_code(), Json(ApiResponseEnum::error(e)))
+        }
+    }
+}
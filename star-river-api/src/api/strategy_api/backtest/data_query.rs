@@ -16,7 +16,7 @@ use strategy_core::{
     strategy::context_trait::{StrategyBenchmarkExt, StrategyVariableExt},
     variable::StrategyVariable,
 };
-use strategy_stats::StatsSnapshot;
+use strategy_stats::{StatsSnapshot, TradingPerformanceReport};
 use tracing::instrument;
 use utoipa::{IntoParams, ToSchema};
 use virtual_trading::types::{VirtualOrder, VirtualPosition, VirtualTransaction};
@@ -448,6 +448,43 @@ pub async fn get_strategy_performance_report(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/strategy/backtest/{strategy_id}/trading-performance",
+    tag = "Backtest Strategy",
+    summary = "Get trading performance metrics",
+    params(
+        ("strategy_id" = i32, Path, description = "The ID of the strategy to get trading performance metrics")
+    ),
+    responses(
+        (status = 200, description = "Get trading performance metrics successfully", body = NewApiResponse<TradingPerformanceReport>),
+        (status = 400, description = "Get trading performance metrics failed", body = NewApiResponse<TradingPerformanceReport>)
+    )
+)]
+#[axum::debug_handler]
+pub async fn get_trading_performance(
+    State(star_river): State<StarRiver>,
+    Path(strategy_id): Path<i32>,
+) -> (StatusCode, Json<NewApiResponse<TradingPerformanceReport>>) {
+    let engine_manager = star_river.engine_manager.lock().await;
+    let engine = engine_manager.backtest_engine().await;
+    let engine_guard = engine.lock().await;
+
+    let result: Result<TradingPerformanceReport, BacktestEngineError> = engine_guard
+        .with_ctx_read_async(|ctx| {
+            Box::pin(async move {
+                ctx.with_strategy_ctx_read_async(strategy_id, |ctx| Box::pin(async move { ctx.get_trading_performance().await }))
+                    .await
+            })
+        })
+        .await;
+
+    match result {
+        Ok(trading_performance) => (StatusCode::OK, Json(NewApiResponse::success(trading_performance))),
+        Err(e) => (StatusCode::NOT_FOUND, Json(NewApiResponse::error(e))),
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/api/v1/strategy/{strategy_id}/cache-keys",
@@ -2,7 +2,7 @@ use axum::{
     extract::{Json, Path, State},
     http::StatusCode,
 };
-use backtest_engine::engine_error::BacktestEngineError;
+use backtest_engine::{HeadlessRunResult, SeekResult, SeekTarget, engine_error::BacktestEngineError};
 use engine_core::EngineContextAccessor;
 use snafu::Report;
 use star_river_core::{custom_type::CycleId, error::StarRiverErrorTrait};
@@ -159,6 +159,101 @@ pub async fn play_one(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategy/backtest/{strategy_id}/run-headless",
+    tag = BACKTEST_CONTROL_TAG,
+    summary = "Run to completion",
+    params(
+        ("strategy_id" = i32, Path, description = "The ID of the strategy to run")
+    ),
+    responses(
+        (status = 200, description = "Run strategy to completion successfully"),
+        (status = 400, description = "Run strategy to completion failed")
+    )
+)]
+pub async fn run_headless(
+    State(star_river): State<StarRiver>,
+    Path(strategy_id): Path<i32>,
+) -> (StatusCode, Json<NewApiResponse<HeadlessRunResult>>) {
+    // Plays without delay and only pushes headless-run-progress-event, the response is sent once all klines are played.
+    // The engine is not kept locked until then
+    let engine_context = {
+        let engine_manager = star_river.engine_manager.lock().await;
+        let engine = engine_manager.backtest_engine().await;
+        let engine_guard = engine.lock().await;
+        engine_guard.with_ctx_read(|ctx| ctx.clone()).await
+    };
+
+    match engine_context.run_headless(strategy_id).await {
+        Ok(result) => (StatusCode::OK, Json(NewApiResponse::success(result))),
+        Err(e) => (StatusCode::BAD_REQUEST, Json(NewApiResponse::error(e))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategy/backtest/{strategy_id}/seek",
+    tag = BACKTEST_CONTROL_TAG,
+    summary = "Seek",
+    params(
+        ("strategy_id" = i32, Path, description = "The ID of the strategy to seek")
+    ),
+    request_body = SeekTarget,
+    responses(
+        (status = 200, description = "Seek successfully"),
+        (status = 400, description = "Seek failed")
+    )
+)]
+pub async fn seek(
+    State(star_river): State<StarRiver>,
+    Path(strategy_id): Path<i32>,
+    Json(target): Json<SeekTarget>,
+) -> (StatusCode, Json<NewApiResponse<SeekResult>>) {
+    // Klines up to the target are replayed without delay, the response is sent once the target is reached
+    let engine_context = {
+        let engine_manager = star_river.engine_manager.lock().await;
+        let engine = engine_manager.backtest_engine().await;
+        let engine_guard = engine.lock().await;
+        engine_guard.with_ctx_read(|ctx| ctx.clone()).await
+    };
+
+    match engine_context.seek(strategy_id, target).await {
+        Ok(result) => (StatusCode::OK, Json(NewApiResponse::success(result))),
+        Err(e) => (StatusCode::BAD_REQUEST, Json(NewApiResponse::error(e))),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/strategy/backtest/{strategy_id}/step-back",
+    tag = BACKTEST_CONTROL_TAG,
+    summary = "Step back one kline",
+    params(
+        ("strategy_id" = i32, Path, description = "The ID of the strategy to step back")
+    ),
+    responses(
+        (status = 200, description = "Step back successfully"),
+        (status = 400, description = "Step back failed")
+    )
+)]
+pub async fn step_back(
+    State(star_river): State<StarRiver>,
+    Path(strategy_id): Path<i32>,
+) -> (StatusCode, Json<NewApiResponse<SeekResult>>) {
+    let engine_context = {
+        let engine_manager = star_river.engine_manager.lock().await;
+        let engine = engine_manager.backtest_engine().await;
+        let engine_guard = engine.lock().await;
+        engine_guard.with_ctx_read(|ctx| ctx.clone()).await
+    };
+
+    match engine_context.seek(strategy_id, SeekTarget::StepBack).await {
+        Ok(result) => (StatusCode::OK, Json(NewApiResponse::success(result))),
+        Err(e) => (StatusCode::BAD_REQUEST, Json(NewApiResponse::error(e))),
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/api/v1/strategy/backtest/{strategy_id}/pause",
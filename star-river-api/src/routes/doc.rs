@@ -23,6 +23,9 @@ use crate::api::{response::ApiResponse, system_api::SystemConfigUpdateParams};
         crate::api::strategy_api::backtest::pause,
         crate::api::strategy_api::backtest::reset,
         crate::api::strategy_api::backtest::play_one,
+        crate::api::strategy_api::backtest::run_headless,
+        crate::api::strategy_api::backtest::seek,
+        crate::api::strategy_api::backtest::step_back,
         crate::api::strategy_api::backtest::update_backtest_chart_config,
         crate::api::strategy_api::backtest::get_backtest_chart_config,
         crate::api::strategy_api::backtest::get_cycle_id,
@@ -37,6 +40,22 @@ use crate::api::{response::ApiResponse, system_api::SystemConfigUpdateParams};
         crate::api::strategy_api::backtest::get_strategy_data,
         crate::api::strategy_api::backtest::get_strategy_variable,
         crate::api::strategy_api::backtest::get_strategy_performance_report,
+        crate::api::strategy_api::backtest::get_trading_performance,
+        crate::api::strategy_api::backtest::get_backtest_run_list,
+        crate::api::strategy_api::backtest::get_backtest_run,
+        crate::api::strategy_api::backtest::delete_backtest_run,
+        crate::api::strategy_api::backtest::save_backtest_checkpoint,
+        crate::api::strategy_api::backtest::load_backtest_checkpoint,
+        crate::api::strategy_api::backtest::get_backtest_checkpoint_list,
+        crate::api::strategy_api::backtest::delete_backtest_checkpoint,
+        crate::api::strategy_api::backtest::start_backtest_optimization,
+        crate::api::strategy_api::backtest::get_backtest_optimization_list,
+        crate::api::strategy_api::backtest::get_backtest_optimization,
+        crate::api::strategy_api::backtest::delete_backtest_optimization,
+        crate::api::strategy_api::backtest::start_walk_forward,
+        crate::api::strategy_api::backtest::get_walk_forward_list,
+        crate::api::strategy_api::backtest::get_walk_forward,
+        crate::api::strategy_api::backtest::delete_walk_forward,
         crate::api::strategy_api::backtest::get_strategy_keys,
         // Account related paths
         // crate::api::account_api::get_account_configs,
@@ -31,6 +31,9 @@ pub fn create_backtest_strategy_routes() -> Router<StarRiver> {
         .route("/{strategy_id}/play", post(play))
         .route("/{strategy_id}/pause", post(pause))
         .route("/{strategy_id}/play-one", post(play_one))
+        .route("/{strategy_id}/run-headless", post(run_headless))
+        .route("/{strategy_id}/seek", post(seek))
+        .route("/{strategy_id}/step-back", post(step_back))
         .route("/{strategy_id}/reset", post(reset))
         .route("/{strategy_id}/chart_config", post(update_backtest_chart_config))
         .route("/{strategy_id}/chart_config", get(get_backtest_chart_config))
@@ -46,4 +49,20 @@ pub fn create_backtest_strategy_routes() -> Router<StarRiver> {
         .route("/{strategy_id}/data", get(get_strategy_data))
         .route("/{strategy_id}/variable", get(get_strategy_variable))
         .route("/{strategy_id}/performance-report", get(get_strategy_performance_report))
+        .route("/{strategy_id}/trading-performance", get(get_trading_performance))
+        .route("/{strategy_id}/runs", get(get_backtest_run_list))
+        .route("/runs/{run_id}", get(get_backtest_run))
+        .route("/runs/{run_id}", delete(delete_backtest_run))
+        .route("/{strategy_id}/checkpoints", post(save_backtest_checkpoint))
+        .route("/{strategy_id}/checkpoints", get(get_backtest_checkpoint_list))
+        .route("/{strategy_id}/checkpoints/{checkpoint_id}/load", post(load_backtest_checkpoint))
+        .route("/checkpoints/{checkpoint_id}", delete(delete_backtest_checkpoint))
+        .route("/{strategy_id}/optimizations", post(start_backtest_optimization))
+        .route("/{strategy_id}/optimizations", get(get_backtest_optimization_list))
+        .route("/optimizations/{optimization_id}", get(get_backtest_optimization))
+        .route("/optimizations/{optimization_id}", delete(delete_backtest_optimization))
+        .route("/{strategy_id}/walk-forwards", post(start_walk_forward))
+        .route("/{strategy_id}/walk-forwards", get(get_walk_forward_list))
+        .route("/walk-forwards/{walk_forward_id}", get(get_walk_forward))
+        .route("/walk-forwards/{walk_forward_id}", delete(delete_walk_forward))
 }
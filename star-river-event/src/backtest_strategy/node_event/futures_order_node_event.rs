@@ -45,6 +45,10 @@ pub enum FuturesOrderNodeEvent {
     #[serde(rename = "stop-loss-order-canceled-event")]
     StopLossOrderCanceled(StopLossOrderCanceledEvent), // Stop loss order canceled
 
+    #[strum(serialize = "stop-loss-order-updated-event")]
+    #[serde(rename = "stop-loss-order-updated-event")]
+    StopLossOrderUpdated(StopLossOrderUpdatedEvent), // Stop loss price moved by a trailing stop or break-even
+
     #[strum(serialize = "transaction-created-event")]
     #[serde(rename = "transaction-created-event")]
     TransactionCreated(TransactionCreatedEvent), // Transaction created
@@ -62,6 +66,7 @@ impl FuturesOrderNodeEvent {
             FuturesOrderNodeEvent::StopLossOrderCreated(event) => event.cycle_id(),
             FuturesOrderNodeEvent::StopLossOrderFilled(event) => event.cycle_id(),
             FuturesOrderNodeEvent::StopLossOrderCanceled(event) => event.cycle_id(),
+            FuturesOrderNodeEvent::StopLossOrderUpdated(event) => event.cycle_id(),
             FuturesOrderNodeEvent::TransactionCreated(event) => event.cycle_id(),
         }
     }
@@ -77,6 +82,7 @@ impl FuturesOrderNodeEvent {
             FuturesOrderNodeEvent::StopLossOrderCreated(event) => event.datetime(),
             FuturesOrderNodeEvent::StopLossOrderFilled(event) => event.datetime(),
             FuturesOrderNodeEvent::StopLossOrderCanceled(event) => event.datetime(),
+            FuturesOrderNodeEvent::StopLossOrderUpdated(event) => event.datetime(),
             FuturesOrderNodeEvent::TransactionCreated(event) => event.datetime(),
         }
     }
@@ -92,6 +98,7 @@ impl FuturesOrderNodeEvent {
             FuturesOrderNodeEvent::StopLossOrderCreated(event) => event.node_id(),
             FuturesOrderNodeEvent::StopLossOrderFilled(event) => event.node_id(),
             FuturesOrderNodeEvent::StopLossOrderCanceled(event) => event.node_id(),
+            FuturesOrderNodeEvent::StopLossOrderUpdated(event) => event.node_id(),
             FuturesOrderNodeEvent::TransactionCreated(event) => event.node_id(),
         }
     }
@@ -106,6 +113,7 @@ impl FuturesOrderNodeEvent {
             FuturesOrderNodeEvent::StopLossOrderCreated(event) => event.node_name(),
             FuturesOrderNodeEvent::StopLossOrderFilled(event) => event.node_name(),
             FuturesOrderNodeEvent::StopLossOrderCanceled(event) => event.node_name(),
+            FuturesOrderNodeEvent::StopLossOrderUpdated(event) => event.node_name(),
             FuturesOrderNodeEvent::TransactionCreated(event) => event.node_name(),
         }
     }
@@ -120,6 +128,7 @@ impl FuturesOrderNodeEvent {
             FuturesOrderNodeEvent::StopLossOrderCreated(event) => event.output_handle_id(),
             FuturesOrderNodeEvent::StopLossOrderFilled(event) => event.output_handle_id(),
             FuturesOrderNodeEvent::StopLossOrderCanceled(event) => event.output_handle_id(),
+            FuturesOrderNodeEvent::StopLossOrderUpdated(event) => event.output_handle_id(),
             FuturesOrderNodeEvent::TransactionCreated(event) => event.output_handle_id(),
         }
     }
@@ -135,6 +144,7 @@ pub type TakeProfitOrderCanceledEvent = NodeEvent<TakeProfitOrderCanceledPayload
 pub type StopLossOrderCreatedEvent = NodeEvent<StopLossOrderCreatedPayload>;
 pub type StopLossOrderFilledEvent = NodeEvent<StopLossOrderFilledPayload>;
 pub type StopLossOrderCanceledEvent = NodeEvent<StopLossOrderCanceledPayload>;
+pub type StopLossOrderUpdatedEvent = NodeEvent<StopLossOrderUpdatedPayload>;
 pub type TransactionCreatedEvent = NodeEvent<TransactionCreatedPayload>;
 
 // Payload type definitions - each event has a unique payload type (to avoid From trait conflicts)
@@ -246,6 +256,18 @@ impl StopLossOrderCanceledPayload {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StopLossOrderUpdatedPayload {
+    #[serde(rename = "stopLossOrder")]
+    pub stop_loss_order: VirtualOrder,
+}
+
+impl StopLossOrderUpdatedPayload {
+    pub fn new(stop_loss_order: VirtualOrder) -> Self {
+        Self { stop_loss_order }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionCreatedPayload {
     #[serde(rename = "transaction")]
@@ -10,7 +10,7 @@ use strategy_core::event::{
 };
 use strategy_stats::event::StrategyStatsUpdatedEvent;
 use strum::Display;
-use virtual_trading::types::{VirtualOrder, VirtualPosition, VirtualTransaction};
+use virtual_trading::types::{FundingPayment, VirtualOrder, VirtualPosition, VirtualTransaction};
 
 use super::node_event::{
     indicator_node_event::IndicatorUpdateEvent,
@@ -25,6 +25,10 @@ pub enum BacktestStrategyEvent {
     #[serde(rename = "play-finished-event")]
     PlayFinished(PlayFinishedEvent), // Backtest playback finished event
 
+    #[strum(serialize = "headless-run-progress-event")]
+    #[serde(rename = "headless-run-progress-event")]
+    HeadlessRunProgress(HeadlessRunProgressEvent), // Headless run progress event
+
     #[strum(serialize = "kline-update-event")]
     #[serde(rename = "kline-update-event")]
     KlineUpdate(KlineUpdateEvent), // Backtest kline update event
@@ -64,6 +68,22 @@ pub enum BacktestStrategyEvent {
         futures_order: VirtualOrder,
     }, // Futures order canceled event
 
+    #[strum(serialize = "futures-order-modified-event")]
+    #[serde(rename = "futures-order-modified-event")]
+    #[from(ignore)]
+    FuturesOrderModified {
+        #[serde(rename = "futuresOrder")]
+        futures_order: VirtualOrder,
+    }, // Futures order modified event
+
+    #[strum(serialize = "order-group-leg-activated-event")]
+    #[serde(rename = "order-group-leg-activated-event")]
+    #[from(ignore)]
+    OrderGroupLegActivated {
+        #[serde(rename = "virtualOrder")]
+        virtual_order: VirtualOrder,
+    }, // Waiting leg of an oto or bracket group activated event
+
     #[strum(serialize = "take-profit-order-created-event")]
     #[serde(rename = "take-profit-order-created-event")]
     #[from(ignore)]
@@ -112,6 +132,14 @@ pub enum BacktestStrategyEvent {
         stop_loss_order: VirtualOrder,
     }, // Stop loss order canceled event
 
+    #[strum(serialize = "stop-loss-order-updated-event")]
+    #[serde(rename = "stop-loss-order-updated-event")]
+    #[from(ignore)]
+    StopLossOrderUpdated {
+        #[serde(rename = "stopLossOrder")]
+        stop_loss_order: VirtualOrder,
+    }, // Stop loss price moved by a trailing stop or break-even
+
     #[strum(serialize = "position-created-event")]
     #[serde(rename = "position-created-event")]
     PositionCreated {
@@ -135,6 +163,18 @@ pub enum BacktestStrategyEvent {
         virtual_position: VirtualPosition,
     }, // Position closed event
 
+    #[strum(serialize = "position-liquidated-event")]
+    #[serde(rename = "position-liquidated-event")]
+    #[from(ignore)]
+    PositionLiquidated {
+        #[serde(rename = "liquidationPrice")]
+        liquidation_price: f64,
+        #[serde(rename = "liquidationFee")]
+        liquidation_fee: f64,
+        #[serde(rename = "virtualPosition")]
+        virtual_position: VirtualPosition,
+    }, // Position liquidated event
+
     #[strum(serialize = "transaction-created-event")]
     #[serde(rename = "transaction-created-event")]
     TransactionCreated {
@@ -142,6 +182,13 @@ pub enum BacktestStrategyEvent {
         transaction: VirtualTransaction,
     }, // Transaction created event
 
+    #[strum(serialize = "funding-settled-event")]
+    #[serde(rename = "funding-settled-event")]
+    FundingSettled {
+        #[serde(rename = "fundingPayment")]
+        funding_payment: FundingPayment,
+    }, // Funding settled event
+
     #[strum(serialize = "strategy-stats-updated-event")]
     #[serde(rename = "strategy-stats-updated-event")]
     StrategyStatsUpdated(StrategyStatsUpdatedEvent), // Strategy stats updated event
@@ -186,3 +233,27 @@ impl PlayFinishedEvent {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeadlessRunProgressEvent {
+    pub strategy_id: i32,
+    pub strategy_name: String,
+    pub play_index: i32,
+    pub total_signal_count: u64,
+    pub progress_percentage: f64,
+    pub datetime: DateTimeUtc,
+}
+
+impl HeadlessRunProgressEvent {
+    pub fn new(strategy_id: i32, strategy_name: String, play_index: i32, total_signal_count: u64, progress_percentage: f64) -> Self {
+        Self {
+            strategy_id,
+            strategy_name,
+            play_index,
+            total_signal_count,
+            progress_percentage,
+            datetime: Utc::now(),
+        }
+    }
+}
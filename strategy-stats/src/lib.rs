@@ -1,6 +1,8 @@
 pub mod event;
+pub mod performance;
 pub mod snapshot;
 pub mod strategy_stats;
 
 pub use event::StrategyStatsEvent;
+pub use performance::{PerformanceMetric, TradingPerformanceReport};
 pub use snapshot::StatsSnapshot;
@@ -0,0 +1,445 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use star_river_core::custom_type::{Balance, PositionId};
+use strum::{Display, EnumString};
+use utoipa::ToSchema;
+use virtual_trading::types::VirtualTransaction;
+
+use crate::snapshot::StatsSnapshot;
+
+/// Seconds of a calendar year, crypto markets trade 24/7 so no trading-day calendar is applied
+const SECONDS_PER_YEAR: f64 = 365.0 * 24.0 * 60.0 * 60.0;
+
+/// Trading performance report - calculated from asset snapshots and virtual transactions
+///
+/// All ratios are fractions (0.1 = 10%), the risk-free rate is assumed to be 0
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TradingPerformanceReport {
+    /// First snapshot datetime
+    pub start_time: Option<DateTime<Utc>>,
+
+    /// Last snapshot datetime
+    pub end_time: Option<DateTime<Utc>>,
+
+    /// Initial balance
+    pub initial_balance: Balance,
+
+    /// Equity of the last snapshot
+    pub final_equity: f64,
+
+    /// Total return
+    pub total_return: f64,
+
+    /// Compound annual growth rate
+    pub cagr: f64,
+
+    /// Annualised volatility of the per-snapshot returns
+    pub annualized_volatility: f64,
+
+    /// Annualised Sharpe ratio
+    pub sharpe_ratio: f64,
+
+    /// Annualised Sortino ratio
+    pub sortino_ratio: f64,
+
+    /// CAGR / max drawdown
+    pub calmar_ratio: f64,
+
+    /// Maximum drawdown
+    pub max_drawdown: f64,
+
+    /// Longest time (seconds) between an equity peak and its recovery
+    pub max_drawdown_duration: i64,
+
+    /// Number of closing transactions
+    pub total_trades: usize,
+
+    /// Number of closing transactions with net-of-fee profit > 0
+    pub winning_trades: usize,
+
+    /// Number of closing transactions with net-of-fee profit < 0
+    pub losing_trades: usize,
+
+    /// Winning trades / total trades
+    pub win_rate: f64,
+
+    /// Average profit of winning trades
+    pub average_win: f64,
+
+    /// Average loss of losing trades (negative)
+    pub average_loss: f64,
+
+    /// Largest profit of a single trade
+    pub largest_win: f64,
+
+    /// Largest loss of a single trade (negative)
+    pub largest_loss: f64,
+
+    /// Gross profit / gross loss, None if there is no losing trade
+    pub profit_factor: Option<f64>,
+
+    /// Average profit per trade
+    pub expectancy: f64,
+
+    /// Longest streak of winning trades
+    pub max_consecutive_wins: usize,
+
+    /// Longest streak of losing trades
+    pub max_consecutive_losses: usize,
+
+    /// Fraction of the backtest time with at least one open position
+    pub exposure_time: f64,
+}
+
+impl TradingPerformanceReport {
+    pub fn new(initial_balance: Balance, snapshots: &[StatsSnapshot], transactions: &[VirtualTransaction]) -> Self {
+        let mut report = Self {
+            initial_balance,
+            final_equity: initial_balance,
+            ..Default::default()
+        };
+        report.calculate_equity_metrics(snapshots);
+        report.calculate_trade_metrics(transactions);
+        report.exposure_time = Self::calculate_exposure_time(snapshots, transactions);
+        report
+    }
+
+    fn calculate_equity_metrics(&mut self, snapshots: &[StatsSnapshot]) {
+        let (Some(first), Some(last)) = (snapshots.first(), snapshots.last()) else {
+            return;
+        };
+        self.start_time = Some(first.datetime);
+        self.end_time = Some(last.datetime);
+        self.final_equity = last.equity;
+        if self.initial_balance <= 0.0 {
+            return;
+        }
+        self.total_return = (last.equity - self.initial_balance) / self.initial_balance;
+
+        let span_seconds = (last.datetime - first.datetime).num_seconds() as f64;
+        if span_seconds > 0.0 && last.equity > 0.0 {
+            self.cagr = (last.equity / self.initial_balance).powf(SECONDS_PER_YEAR / span_seconds) - 1.0;
+        }
+
+        // per-snapshot returns, the first one is relative to the initial balance
+        let returns = std::iter::once(self.initial_balance)
+            .chain(snapshots.iter().map(|snapshot| snapshot.equity))
+            .collect::<Vec<f64>>()
+            .windows(2)
+            .map(|pair| if pair[0] != 0.0 { pair[1] / pair[0] - 1.0 } else { 0.0 })
+            .collect::<Vec<f64>>();
+
+        // annualise with the average snapshot interval
+        if snapshots.len() > 1 && span_seconds > 0.0 {
+            let periods_per_year = SECONDS_PER_YEAR / (span_seconds / (snapshots.len() - 1) as f64);
+            let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+            let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / returns.len() as f64;
+            let downside_deviation = (returns.iter().map(|r| r.min(0.0).powi(2)).sum::<f64>() / returns.len() as f64).sqrt();
+
+            self.annualized_volatility = variance.sqrt() * periods_per_year.sqrt();
+            if variance > 0.0 {
+                self.sharpe_ratio = mean / variance.sqrt() * periods_per_year.sqrt();
+            }
+            if downside_deviation > 0.0 {
+                self.sortino_ratio = mean / downside_deviation * periods_per_year.sqrt();
+            }
+        }
+
+        // drawdown and its duration, measured from the peak to the snapshot that recovers it
+        let mut peak_equity = self.initial_balance;
+        let mut peak_time = first.datetime;
+        let mut max_drawdown = 0.0_f64;
+        let mut max_drawdown_duration = 0_i64;
+        let mut under_water = false;
+        for snapshot in snapshots {
+            if snapshot.equity >= peak_equity {
+                if under_water {
+                    max_drawdown_duration = max_drawdown_duration.max((snapshot.datetime - peak_time).num_seconds());
+                    under_water = false;
+                }
+                peak_equity = snapshot.equity;
+                peak_time = snapshot.datetime;
+                continue;
+            }
+            under_water = true;
+            max_drawdown = max_drawdown.max((peak_equity - snapshot.equity) / peak_equity);
+        }
+        // a drawdown that never recovers lasts until the last snapshot
+        if under_water {
+            max_drawdown_duration = max_drawdown_duration.max((last.datetime - peak_time).num_seconds());
+        }
+        self.max_drawdown = max_drawdown;
+        self.max_drawdown_duration = max_drawdown_duration;
+        if max_drawdown > 0.0 {
+            self.calmar_ratio = self.cagr / max_drawdown;
+        }
+    }
+
+    fn calculate_trade_metrics(&mut self, transactions: &[VirtualTransaction]) {
+        // only closing transactions carry realized profit, which excludes fees. A trade's net profit
+        // is its closing profit minus its own fee and the opening fees not yet charged to a close
+        let mut pending_fees: HashMap<PositionId, f64> = HashMap::new();
+        let profits = transactions
+            .iter()
+            .filter_map(|transaction| {
+                let pending_fee = pending_fees.entry(transaction.position_id).or_default();
+                match transaction.profit {
+                    None => {
+                        *pending_fee += transaction.fee;
+                        None
+                    }
+                    Some(profit) => Some(profit - transaction.fee - std::mem::take(pending_fee)),
+                }
+            })
+            .collect::<Vec<f64>>();
+        if profits.is_empty() {
+            return;
+        }
+
+        let wins = profits.iter().copied().filter(|profit| *profit > 0.0).collect::<Vec<f64>>();
+        let losses = profits.iter().copied().filter(|profit| *profit < 0.0).collect::<Vec<f64>>();
+        let gross_profit = wins.iter().sum::<f64>();
+        let gross_loss = losses.iter().sum::<f64>();
+
+        self.total_trades = profits.len();
+        self.winning_trades = wins.len();
+        self.losing_trades = losses.len();
+        self.win_rate = wins.len() as f64 / profits.len() as f64;
+        if !wins.is_empty() {
+            self.average_win = gross_profit / wins.len() as f64;
+        }
+        if !losses.is_empty() {
+            self.average_loss = gross_loss / losses.len() as f64;
+            self.profit_factor = Some(gross_profit / gross_loss.abs());
+        }
+        self.largest_win = wins.iter().copied().fold(0.0, f64::max);
+        self.largest_loss = losses.iter().copied().fold(0.0, f64::min);
+        self.expectancy = profits.iter().sum::<f64>() / profits.len() as f64;
+
+        let (mut win_streak, mut loss_streak) = (0, 0);
+        for profit in &profits {
+            if *profit > 0.0 {
+                win_streak += 1;
+                loss_streak = 0;
+            } else if *profit < 0.0 {
+                loss_streak += 1;
+                win_streak = 0;
+            } else {
+                win_streak = 0;
+                loss_streak = 0;
+            }
+            self.max_consecutive_wins = self.max_consecutive_wins.max(win_streak);
+            self.max_consecutive_losses = self.max_consecutive_losses.max(loss_streak);
+        }
+    }
+
+    // Opening and adding transactions have no profit, closing ones do. A position counts as
+    // open while its opened quantity has not been fully closed
+    fn calculate_exposure_time(snapshots: &[StatsSnapshot], transactions: &[VirtualTransaction]) -> f64 {
+        let (Some(first), Some(last)) = (snapshots.first(), snapshots.last()) else {
+            return 0.0;
+        };
+        let total_seconds = (last.datetime - first.datetime).num_seconds();
+        if total_seconds <= 0 {
+            return 0.0;
+        }
+
+        let mut open_quantity: HashMap<PositionId, f64> = HashMap::new();
+        let mut exposure_start: Option<DateTime<Utc>> = None;
+        let mut exposure_seconds = 0_i64;
+        for transaction in transactions {
+            let quantity = open_quantity.entry(transaction.position_id).or_default();
+            match transaction.profit {
+                None => *quantity += transaction.quantity,
+                Some(_) => *quantity -= transaction.quantity,
+            }
+            if *quantity <= f64::EPSILON {
+                open_quantity.remove(&transaction.position_id);
+            }
+
+            match (exposure_start, open_quantity.is_empty()) {
+                (None, false) => exposure_start = Some(transaction.create_time),
+                (Some(start), true) => {
+                    exposure_seconds += (transaction.create_time - start).num_seconds();
+                    exposure_start = None;
+                }
+                _ => {}
+            }
+        }
+        // still holding positions at the end of the backtest
+        if let Some(start) = exposure_start {
+            exposure_seconds += (last.datetime - start).num_seconds().max(0);
+        }
+
+        (exposure_seconds as f64 / total_seconds as f64).min(1.0)
+    }
+
+    /// Score of the report under the given metric, higher is always better
+    pub fn score(&self, metric: &PerformanceMetric) -> f64 {
+        match metric {
+            PerformanceMetric::TotalReturn => self.total_return,
+            PerformanceMetric::Cagr => self.cagr,
+            PerformanceMetric::SharpeRatio => self.sharpe_ratio,
+            PerformanceMetric::SortinoRatio => self.sortino_ratio,
+            PerformanceMetric::CalmarRatio => self.calmar_ratio,
+            // smaller drawdown ranks higher
+            PerformanceMetric::MaxDrawdown => -self.max_drawdown,
+            // without a losing trade the profit factor is unbounded, winning only ranks first and no trade ranks last.
+            // f64::MAX / f64::MIN rather than infinity keeps the score storable as JSON
+            PerformanceMetric::ProfitFactor => match self.profit_factor {
+                Some(profit_factor) => profit_factor,
+                None if self.winning_trades > 0 => f64::MAX,
+                None => f64::MIN,
+            },
+            PerformanceMetric::WinRate => self.win_rate,
+            PerformanceMetric::Expectancy => self.expectancy,
+            PerformanceMetric::FinalEquity => self.final_equity,
+        }
+    }
+}
+
+/// Metric used to rank backtest results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema, Display, EnumString)]
+#[serde(rename_all = "camelCase")]
+#[strum(serialize_all = "camelCase")]
+pub enum PerformanceMetric {
+    #[default]
+    TotalReturn,
+    Cagr,
+    SharpeRatio,
+    SortinoRatio,
+    CalmarRatio,
+    MaxDrawdown,
+    ProfitFactor,
+    WinRate,
+    Expectancy,
+    FinalEquity,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use star_river_core::{exchange::Exchange, transaction::FuturesTransSide};
+
+    use super::*;
+
+    fn snapshot(day: u32, equity: f64) -> StatsSnapshot {
+        let datetime = Utc.with_ymd_and_hms(2024, 1, day, 0, 0, 0).unwrap();
+        StatsSnapshot::new(datetime, 1000.0, equity, equity, 0.0, equity, equity - 1000.0, 0.0, 0.0)
+    }
+
+    fn transaction(position_id: PositionId, day: u32, profit: Option<f64>) -> VirtualTransaction {
+        let datetime = Utc.with_ymd_and_hms(2024, 1, day, 0, 0, 0).unwrap();
+        VirtualTransaction::new(
+            position_id,
+            1,
+            position_id,
+            1,
+            "node".to_string(),
+            "node".to_string(),
+            1,
+            Exchange::Binance,
+            "BTCUSDT".to_string(),
+            FuturesTransSide::Long,
+            1.0,
+            100.0,
+            profit,
+            datetime,
+        )
+    }
+
+    #[test]
+    fn test_trading_performance_report() {
+        let snapshots = vec![
+            snapshot(1, 1000.0),
+            snapshot(2, 1100.0),
+            snapshot(3, 990.0),
+            snapshot(4, 1050.0),
+            snapshot(5, 1210.0),
+        ];
+        let transactions = vec![
+            transaction(1, 1, None),
+            transaction(1, 2, Some(100.0)),
+            transaction(2, 2, None),
+            transaction(2, 3, Some(-110.0)),
+            transaction(3, 4, None),
+            transaction(3, 5, Some(220.0)),
+        ];
+
+        let report = TradingPerformanceReport::new(1000.0, &snapshots, &transactions);
+        assert!((report.total_return - 0.21).abs() < 1e-9);
+        assert!((report.max_drawdown - 0.1).abs() < 1e-9);
+        // peak on day 2, still under water on day 4, recovered on day 5
+        assert_eq!(report.max_drawdown_duration, 3 * 24 * 60 * 60);
+        assert_eq!(report.total_trades, 3);
+        assert!((report.win_rate - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(report.profit_factor, Some(320.0 / 110.0));
+        assert!((report.expectancy - 70.0).abs() < 1e-9);
+        assert_eq!(report.max_consecutive_wins, 1);
+        assert_eq!(report.max_consecutive_losses, 1);
+        // flat from day 3 to day 4 only
+        assert!((report.exposure_time - 0.75).abs() < 1e-9);
+        assert!(report.sharpe_ratio > 0.0 && report.sortino_ratio > report.sharpe_ratio);
+    }
+
+    #[test]
+    fn test_trade_metrics_are_net_of_fees() {
+        let snapshots = vec![snapshot(1, 1000.0), snapshot(2, 990.0), snapshot(3, 995.0)];
+        let mut transactions = vec![
+            transaction(1, 1, None),
+            transaction(1, 2, Some(5.0)),
+            transaction(2, 2, None),
+            transaction(2, 3, Some(20.0)),
+        ];
+        for transaction in transactions.iter_mut() {
+            transaction.fee = 4.0;
+        }
+
+        let report = TradingPerformanceReport::new(1000.0, &snapshots, &transactions);
+        // 5 - 4 - 4 is a loss once both fees are charged, 20 - 4 - 4 stays a win
+        assert_eq!(report.winning_trades, 1);
+        assert_eq!(report.losing_trades, 1);
+        assert_eq!(report.profit_factor, Some(12.0 / 3.0));
+        // never recovers the day 1 peak
+        assert_eq!(report.max_drawdown_duration, 2 * 24 * 60 * 60);
+    }
+
+    #[test]
+    fn test_empty_report() {
+        let report = TradingPerformanceReport::new(1000.0, &[], &[]);
+        assert_eq!(report.final_equity, 1000.0);
+        assert_eq!(report.total_trades, 0);
+        assert_eq!(report.profit_factor, None);
+        assert!(report.score(&PerformanceMetric::ProfitFactor) < 0.0);
+    }
+
+    #[test]
+    fn test_profit_factor_score_of_winning_only_report() {
+        let snapshots = vec![snapshot(1, 1000.0), snapshot(2, 1010.0), snapshot(3, 1030.0)];
+        let winning_only = TradingPerformanceReport::new(
+            1000.0,
+            &snapshots,
+            &[
+                transaction(1, 1, None),
+                transaction(1, 2, Some(10.0)),
+                transaction(2, 2, None),
+                transaction(2, 3, Some(20.0)),
+            ],
+        );
+        let mixed = TradingPerformanceReport::new(
+            1000.0,
+            &snapshots,
+            &[
+                transaction(1, 1, None),
+                transaction(1, 2, Some(40.0)),
+                transaction(2, 2, None),
+                transaction(2, 3, Some(-10.0)),
+            ],
+        );
+        assert_eq!(winning_only.profit_factor, None);
+        assert!(winning_only.score(&PerformanceMetric::ProfitFactor) > mixed.score(&PerformanceMetric::ProfitFactor));
+    }
+}
@@ -27,6 +27,14 @@ pub struct StatsSnapshot {
 
     /// Realized P&L
     pub realized_pnl: f64,
+
+    /// Total trading fees charged
+    #[serde(default)]
+    pub total_fee: f64,
+
+    /// Total funding received (positive) or paid (negative)
+    #[serde(default)]
+    pub total_funding: f64,
 }
 
 impl StatsSnapshot {
@@ -39,6 +47,8 @@ impl StatsSnapshot {
         unrealized_pnl: f64,
         equity: Equity,
         realized_pnl: f64,
+        total_fee: f64,
+        total_funding: f64,
     ) -> Self {
         // Cumulative return = (equity - initial capital) / initial capital * 100%
         let cumulative_return = if initial_balance != 0.0 {
@@ -55,6 +65,8 @@ impl StatsSnapshot {
             equity,
             cumulative_return,
             realized_pnl,
+            total_fee,
+            total_funding,
         }
     }
 
@@ -177,10 +189,11 @@ impl StatsSnapshotHistory {
         self.max_drawdown = 0.0;
     }
 
-    /// Recalculate max drawdown (only when necessary, such as removing snapshots containing the highest equity)
+    /// Recalculate max drawdown (only when necessary, such as removing snapshots containing the highest equity,
+    /// or after deserialization since the cached values are not serialized)
     ///
     /// Time complexity: O(n)
-    fn recalculate_max_drawdown(&mut self) {
+    pub fn recalculate_max_drawdown(&mut self) {
         if self.snapshots.is_empty() {
             self.max_equity = 0.0;
             self.max_drawdown = 0.0;
@@ -219,4 +232,84 @@ impl StatsSnapshotHistory {
             .cloned()
             .collect()
     }
+
+    /// Chain histories that each started from the same initial balance into one compounded curve
+    ///
+    /// Every history is scaled by the equity the previous ones ended with, pnl, fee and funding keep accumulating
+    pub fn stitch(initial_balance: Balance, histories: &[StatsSnapshotHistory]) -> Self {
+        let mut stitched = Self::new(None);
+        if initial_balance == 0.0 {
+            return stitched;
+        }
+
+        let mut scale = 1.0;
+        let (mut carried_realized_pnl, mut carried_fee, mut carried_funding) = (0.0, 0.0, 0.0);
+        for history in histories {
+            let Some(last) = history.snapshots.last() else {
+                continue;
+            };
+            for snapshot in &history.snapshots {
+                stitched.add_snapshot(StatsSnapshot::new(
+                    snapshot.datetime,
+                    initial_balance,
+                    snapshot.balance * scale,
+                    snapshot.available_balance * scale,
+                    snapshot.unrealized_pnl * scale,
+                    snapshot.equity * scale,
+                    carried_realized_pnl + snapshot.realized_pnl * scale,
+                    carried_fee + snapshot.total_fee * scale,
+                    carried_funding + snapshot.total_funding * scale,
+                ));
+            }
+            carried_realized_pnl += last.realized_pnl * scale;
+            carried_fee += last.total_fee * scale;
+            carried_funding += last.total_funding * scale;
+            scale *= last.equity / initial_balance;
+        }
+        stitched
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn history(start_day: u32, equities: &[f64]) -> StatsSnapshotHistory {
+        let mut history = StatsSnapshotHistory::new(None);
+        for (i, equity) in equities.iter().enumerate() {
+            let datetime = Utc.with_ymd_and_hms(2024, 1, start_day + i as u32, 0, 0, 0).unwrap();
+            history.add_snapshot(StatsSnapshot::new(
+                datetime,
+                1000.0,
+                *equity,
+                *equity,
+                0.0,
+                *equity,
+                equity - 1000.0,
+                1.0,
+                0.0,
+            ));
+        }
+        history
+    }
+
+    #[test]
+    fn test_stitch_compounds_histories() {
+        let histories = vec![history(1, &[1000.0, 1100.0]), history(3, &[1000.0, 900.0])];
+        let stitched = StatsSnapshotHistory::stitch(1000.0, &histories);
+
+        let expected = [1000.0, 1100.0, 1100.0, 990.0];
+        assert_eq!(stitched.len(), expected.len());
+        for (snapshot, equity) in stitched.snapshots.iter().zip(expected) {
+            assert!((snapshot.equity - equity).abs() < 1e-9);
+        }
+
+        let last = stitched.get_latest_snapshot().unwrap();
+        assert!((last.cumulative_return - -0.01).abs() < 1e-9);
+        assert!((last.realized_pnl - -10.0).abs() < 1e-9);
+        assert!((last.total_fee - 2.1).abs() < 1e-9);
+        assert!((stitched.calculate_max_drawdown() - 10.0).abs() < 1e-9);
+    }
 }